@@ -5,7 +5,7 @@
 //! - **Event Loop**: Tokio-powered async event handling with configurable tick rate
 //! - **Keybindings**: Declarative action mapping with the builder API
 //! - **Focus Management**: Tab/Shift+Tab navigation between focusable "widgets"
-//! - **Theming**: Dark/light themes with semantic colors
+//! - **Theming**: Built-in themes with semantic colors, cycled via a theme registry
 //! - **Tracing**: Structured logging for debugging TUI applications
 //!
 //! # Running the Example
@@ -20,7 +20,7 @@
 //!
 //! - `Tab` / `Shift+Tab`: Navigate between widgets
 //! - `Enter` / `Space`: Activate the focused widget
-//! - `t`: Toggle between dark and light themes
+//! - `t`: Cycle through the built-in themes
 //! - `Ctrl+q` / `q`: Quit the application
 //!
 //! # Architecture
@@ -37,21 +37,23 @@
 
 use std::time::Duration;
 
-use crossterm::event::KeyEventKind;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
-use terminput_crossterm::to_terminput_key;
+use terminput::KeyEventKind;
 use tuilib::{
     component_render_span, component_update_span,
-    event::{restore_terminal, setup_terminal, AppEvent, ControlFlow, EventLoop, EventLoopConfig},
+    components::{Renderable, StatusBar},
+    event::{
+        restore_terminal, setup_terminal, AppEvent, ControlFlow, EventLoop, EventLoopConfig,
+        TerminalEvent,
+    },
     focus::{FocusDirection, FocusId, FocusManager},
     focus_span,
-    input::{Action, InputMatcher, KeyBindings},
-    theme::Theme,
+    input::{Action, InputMatcher, KeyBindings, MatchResult},
+    theme::{Theme, ThemeRegistry},
     tracing::{init_tracing, TracingConfig},
 };
 
@@ -65,10 +67,12 @@ struct App {
     focus_manager: FocusManager,
     /// Input matcher for mapping key events to semantic actions
     input_matcher: InputMatcher,
-    /// Current theme (dark or light)
+    /// Keybindings, kept for the help line
+    bindings: KeyBindings,
+    /// Current theme
     theme: Theme,
-    /// Whether the theme is dark (for toggling)
-    is_dark_theme: bool,
+    /// Themes to cycle through
+    themes: ThemeRegistry,
     /// Counter to demonstrate widget activation
     counter: i32,
     /// Status message to display
@@ -110,14 +114,9 @@ impl App {
         // =====================================================================
         //
         // The InputMatcher maintains state for key sequence matching (e.g., for
-        // multi-key sequences like Ctrl+X Ctrl+S). We register all our bindings.
+        // multi-key sequences like Ctrl+X Ctrl+S). We register all our global bindings.
 
-        let mut input_matcher = InputMatcher::new(Duration::from_secs(1));
-
-        // Register all global bindings with the matcher
-        for (sequence, action) in bindings.global_bindings() {
-            input_matcher.register(sequence.clone(), action.clone());
-        }
+        let input_matcher = InputMatcher::from_key_bindings(&bindings, None);
 
         tracing::debug!(
             matcher_bindings = input_matcher.binding_count(),
@@ -150,8 +149,9 @@ impl App {
         Self {
             focus_manager,
             input_matcher,
+            bindings,
             theme: Theme::dark(),
-            is_dark_theme: true,
+            themes: ThemeRegistry::builtin(),
             counter: 0,
             status: "Press Tab to navigate, Enter/Space to activate".to_string(),
             should_exit: false,
@@ -193,13 +193,10 @@ impl App {
             }
 
             "toggle_theme" => {
-                self.is_dark_theme = !self.is_dark_theme;
-                self.theme = if self.is_dark_theme {
-                    Theme::dark()
-                } else {
-                    Theme::light()
-                };
-                tracing::info!(theme = self.theme.name(), "Theme toggled");
+                if let Some(theme) = self.themes.cycle() {
+                    self.theme = theme.clone();
+                }
+                tracing::info!(theme = self.theme.name(), "Theme changed");
                 self.status = format!("Theme: {}", self.theme.name());
             }
 
@@ -366,31 +363,30 @@ impl App {
     }
 
     fn render_help(&self, frame: &mut Frame, area: Rect) {
-        let help_text = vec![Line::from(vec![
-            Span::styled("Tab", self.theme.emphasis_style()),
-            Span::styled("/", self.theme.secondary_text_style()),
-            Span::styled("Shift+Tab", self.theme.emphasis_style()),
-            Span::styled(": Navigate  ", self.theme.secondary_text_style()),
-            Span::styled("Enter", self.theme.emphasis_style()),
-            Span::styled("/", self.theme.secondary_text_style()),
-            Span::styled("Space", self.theme.emphasis_style()),
-            Span::styled(": Activate  ", self.theme.secondary_text_style()),
-            Span::styled("t", self.theme.emphasis_style()),
-            Span::styled(": Toggle Theme  ", self.theme.secondary_text_style()),
-            Span::styled("q", self.theme.emphasis_style()),
-            Span::styled(": Quit", self.theme.secondary_text_style()),
-        ])];
-
-        let help = Paragraph::new(help_text)
-            .alignment(Alignment::Center)
-            .block(
-                Block::default()
-                    .title(format!("Theme: {}", self.theme.name()))
-                    .title_style(self.theme.muted_style())
-                    .borders(Borders::TOP)
-                    .border_style(self.theme.border_style()),
-            );
-        frame.render_widget(help, area);
+        let block = Block::default()
+            .title(format!("Theme: {}", self.theme.name()))
+            .title_style(self.theme.muted_style())
+            .borders(Borders::TOP)
+            .border_style(self.theme.border_style());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // The help line is generated from the keybindings, in priority order
+        StatusBar::new(&self.bindings)
+            .with_order([
+                "focus_next",
+                "focus_prev",
+                "activate",
+                "toggle_theme",
+                "quit",
+            ])
+            .with_label("focus_next", "Next")
+            .with_label("focus_prev", "Previous")
+            .with_label("activate", "Activate")
+            .with_label("toggle_theme", "Next Theme")
+            .with_label("quit", "Quit")
+            .with_theme(self.theme.clone())
+            .render(frame, inner);
     }
 }
 
@@ -462,17 +458,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             let control = match &event {
                 // Handle terminal events (keyboard, mouse, resize)
                 AppEvent::Terminal(term_event) => {
-                    if let crossterm::event::Event::Key(key_event) = term_event {
+                    if let TerminalEvent::Key(key_event) = term_event {
                         // Only process key press events (not release or repeat)
                         if key_event.kind == KeyEventKind::Press {
-                            // Convert crossterm KeyEvent to terminput KeyEvent
-                            if let Ok(terminput_event) = to_terminput_key(*key_event) {
-                                // Match the key event against registered bindings
-                                let result = app.input_matcher.process(&terminput_event);
-
-                                if let Some(action) = result.into_action() {
-                                    app.handle_action(&action);
-                                }
+                            // Match the key event against registered bindings
+                            let result = app.input_matcher.process(key_event);
+
+                            // A broken sequence can yield more than one action
+                            for action in result.into_actions() {
+                                app.handle_action(&action);
                             }
                         }
                     }
@@ -489,10 +483,32 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
 
+                // This example redraws after each input, never suspends and
+                // does not configure an idle timeout
+                AppEvent::Render | AppEvent::Suspended | AppEvent::Resumed | AppEvent::Idle => {
+                    ControlFlow::Continue
+                }
+
                 // Handle tick events for periodic updates
                 AppEvent::Tick => {
-                    // In a real app, you might update animations or fetch data here
-                    ControlFlow::Continue
+                    // Fire a pending key sequence once the user stops typing
+                    if let Some(action) = app
+                        .input_matcher
+                        .poll_now()
+                        .and_then(MatchResult::into_action)
+                    {
+                        app.handle_action(&action);
+                        if let Err(e) = terminal.draw(|f| app.render(f)) {
+                            tracing::error!(error = %e, "Render error");
+                        }
+                    }
+
+                    // In a real app, you might also update animations or fetch data here
+                    if app.should_exit {
+                        ControlFlow::Exit
+                    } else {
+                        ControlFlow::Continue
+                    }
                 }
 
                 // Handle shutdown signal
@@ -504,6 +520,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 // Handle custom messages (not used in this example)
                 AppEvent::Message(_) => ControlFlow::Continue,
 
+                // Mouse and paste events are not used in this example
+                AppEvent::Mouse(_) | AppEvent::Paste(_) => ControlFlow::Continue,
+
+                // Redraw at the new size
+                AppEvent::Resize(_, _) => {
+                    if let Err(e) = terminal.draw(|f| app.render(f)) {
+                        tracing::error!(error = %e, "Render error");
+                    }
+                    ControlFlow::Continue
+                }
+
                 // Handle actions sent through the channel
                 AppEvent::Action(action) => {
                     app.handle_action(action);
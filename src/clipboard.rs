@@ -0,0 +1,431 @@
+//! Clipboard access for text components.
+//!
+//! This module defines the [`Clipboard`] trait used by components such as
+//! [`TextInput`](crate::components::TextInput) to read and write clipboard
+//! text, along with two implementations:
+//!
+//! - [`MemoryClipboard`]: an in-process clipboard for headless use and tests
+//! - `SystemClipboard`: the operating system clipboard (requires the
+//!   `clipboard` feature)
+//!
+//! On X11 and Wayland there are two selections: the clipboard, written by
+//! explicit copy commands, and the primary selection, which holds the most
+//! recently selected text and is pasted with a middle click. Both are
+//! addressed with a [`ClipboardKind`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use tuilib::clipboard::{Clipboard, MemoryClipboard};
+//! use tuilib::components::{Component, TextInput, TextInputMsg};
+//!
+//! let clipboard = Arc::new(MemoryClipboard::new());
+//! let mut input = TextInput::new().with_clipboard(clipboard.clone());
+//!
+//! input.set_text("hello");
+//! input.update(TextInputMsg::SelectAll);
+//! input.update(TextInputMsg::Copy);
+//!
+//! assert_eq!(clipboard.get_text().unwrap(), "hello");
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Error type for clipboard failures.
+#[derive(Debug)]
+pub enum ClipboardError {
+    /// No clipboard is available (e.g. no display server is running).
+    Unavailable(String),
+    /// The clipboard is empty or does not contain text.
+    Empty,
+    /// Communicating with the clipboard failed.
+    IoError(std::io::Error),
+}
+
+impl fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClipboardError::Unavailable(e) => write!(f, "Clipboard unavailable: {}", e),
+            ClipboardError::Empty => write!(f, "Clipboard is empty"),
+            ClipboardError::IoError(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClipboardError::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ClipboardError {
+    fn from(err: std::io::Error) -> Self {
+        ClipboardError::IoError(err)
+    }
+}
+
+/// Which selection a clipboard operation targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ClipboardKind {
+    /// The clipboard written by copy and cut (Ctrl+C, Ctrl+X).
+    #[default]
+    Clipboard,
+    /// The primary selection, set by selecting text and pasted with a
+    /// middle click. Only X11 and Wayland have one.
+    Primary,
+}
+
+/// A source and sink for clipboard text.
+///
+/// Methods take `&self` so a single clipboard can be shared between
+/// components as an `Arc<dyn Clipboard>`.
+pub trait Clipboard: Send + Sync {
+    /// Returns the current clipboard text.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClipboardError::Empty`] if there is no text, or another
+    /// error if the clipboard cannot be read.
+    fn get_text(&self) -> Result<String, ClipboardError>;
+
+    /// Replaces the clipboard contents with `text`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clipboard cannot be written.
+    fn set_text(&self, text: &str) -> Result<(), ClipboardError>;
+
+    /// Returns the text of the given selection.
+    ///
+    /// The default reads [`ClipboardKind::Clipboard`] with
+    /// [`get_text`](Self::get_text) and reports the primary selection as
+    /// unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClipboardError::Empty`] if there is no text, or another
+    /// error if the selection cannot be read.
+    fn get_text_from(&self, kind: ClipboardKind) -> Result<String, ClipboardError> {
+        match kind {
+            ClipboardKind::Clipboard => self.get_text(),
+            ClipboardKind::Primary => Err(primary_unsupported()),
+        }
+    }
+
+    /// Replaces the contents of the given selection with `text`.
+    ///
+    /// The default writes [`ClipboardKind::Clipboard`] with
+    /// [`set_text`](Self::set_text) and reports the primary selection as
+    /// unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the selection cannot be written.
+    fn set_text_in(&self, kind: ClipboardKind, text: &str) -> Result<(), ClipboardError> {
+        match kind {
+            ClipboardKind::Clipboard => self.set_text(text),
+            ClipboardKind::Primary => Err(primary_unsupported()),
+        }
+    }
+}
+
+/// Returns the error for clipboards without a primary selection.
+fn primary_unsupported() -> ClipboardError {
+    ClipboardError::Unavailable("primary selection not supported".to_string())
+}
+
+/// An in-memory clipboard.
+///
+/// Useful for headless applications, tests, and as a fallback when no
+/// system clipboard is available. The clipboard and the primary selection
+/// are stored separately.
+#[derive(Debug, Default)]
+pub struct MemoryClipboard {
+    text: Mutex<Option<String>>,
+    primary: Mutex<Option<String>>,
+}
+
+impl MemoryClipboard {
+    /// Creates an empty in-memory clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl MemoryClipboard {
+    /// Returns the slot holding the given selection.
+    fn slot(&self, kind: ClipboardKind) -> &Mutex<Option<String>> {
+        match kind {
+            ClipboardKind::Clipboard => &self.text,
+            ClipboardKind::Primary => &self.primary,
+        }
+    }
+}
+
+impl Clipboard for MemoryClipboard {
+    fn get_text(&self) -> Result<String, ClipboardError> {
+        self.get_text_from(ClipboardKind::Clipboard)
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), ClipboardError> {
+        self.set_text_in(ClipboardKind::Clipboard, text)
+    }
+
+    fn get_text_from(&self, kind: ClipboardKind) -> Result<String, ClipboardError> {
+        self.slot(kind)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .ok_or(ClipboardError::Empty)
+    }
+
+    fn set_text_in(&self, kind: ClipboardKind, text: &str) -> Result<(), ClipboardError> {
+        *self.slot(kind).lock().unwrap_or_else(|e| e.into_inner()) = Some(text.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "clipboard")]
+pub use system::SystemClipboard;
+
+/// Returns a clipboard to share between components.
+///
+/// This is a `SystemClipboard` when the `clipboard` feature is enabled and
+/// the system clipboard can be opened, and a fresh [`MemoryClipboard`]
+/// otherwise. Call it once and pass clones of the result to each component,
+/// so that text copied in one can be pasted in another.
+pub fn default_clipboard() -> Arc<dyn Clipboard> {
+    #[cfg(feature = "clipboard")]
+    if let Ok(clipboard) = SystemClipboard::new() {
+        return Arc::new(clipboard);
+    }
+    Arc::new(MemoryClipboard::new())
+}
+
+#[cfg(feature = "clipboard")]
+mod system {
+    use std::fmt;
+    use std::sync::{Mutex, MutexGuard};
+
+    use super::{Clipboard, ClipboardError, ClipboardKind};
+
+    /// The operating system clipboard, backed by [`arboard`].
+    ///
+    /// The primary selection is supported on Linux and the BSDs, under both
+    /// X11 and Wayland. Elsewhere it is reported as unavailable.
+    ///
+    /// On X11 and Wayland the copied text is served by this handle, so keep
+    /// it alive, for example shared as an `Arc<dyn Clipboard>`, for as long
+    /// as other applications should be able to paste it.
+    ///
+    /// Requires the `clipboard` feature.
+    pub struct SystemClipboard {
+        inner: Mutex<arboard::Clipboard>,
+    }
+
+    impl SystemClipboard {
+        /// Opens the system clipboard.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ClipboardError::Unavailable`] if there is no clipboard
+        /// to open, such as when no display server is running.
+        pub fn new() -> Result<Self, ClipboardError> {
+            let inner = arboard::Clipboard::new()?;
+            Ok(Self {
+                inner: Mutex::new(inner),
+            })
+        }
+
+        /// Locks the clipboard for a read or write.
+        fn lock(&self) -> MutexGuard<'_, arboard::Clipboard> {
+            self.inner.lock().unwrap_or_else(|e| e.into_inner())
+        }
+    }
+
+    impl fmt::Debug for SystemClipboard {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("SystemClipboard").finish_non_exhaustive()
+        }
+    }
+
+    impl From<arboard::Error> for ClipboardError {
+        fn from(err: arboard::Error) -> Self {
+            match err {
+                arboard::Error::ContentNotAvailable => ClipboardError::Empty,
+                err => ClipboardError::Unavailable(err.to_string()),
+            }
+        }
+    }
+
+    /// Selection access where there is a primary selection.
+    #[cfg(all(
+        unix,
+        not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+    ))]
+    mod selection {
+        use arboard::{GetExtLinux, LinuxClipboardKind, SetExtLinux};
+
+        use super::super::{ClipboardError, ClipboardKind};
+
+        fn linux_kind(kind: ClipboardKind) -> LinuxClipboardKind {
+            match kind {
+                ClipboardKind::Clipboard => LinuxClipboardKind::Clipboard,
+                ClipboardKind::Primary => LinuxClipboardKind::Primary,
+            }
+        }
+
+        pub(super) fn read(
+            clipboard: &mut arboard::Clipboard,
+            kind: ClipboardKind,
+        ) -> Result<String, ClipboardError> {
+            Ok(clipboard.get().clipboard(linux_kind(kind)).text()?)
+        }
+
+        pub(super) fn write(
+            clipboard: &mut arboard::Clipboard,
+            kind: ClipboardKind,
+            text: &str,
+        ) -> Result<(), ClipboardError> {
+            Ok(clipboard.set().clipboard(linux_kind(kind)).text(text)?)
+        }
+    }
+
+    /// Selection access where there is only the clipboard.
+    #[cfg(not(all(
+        unix,
+        not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+    )))]
+    mod selection {
+        use super::super::{primary_unsupported, ClipboardError, ClipboardKind};
+
+        pub(super) fn read(
+            clipboard: &mut arboard::Clipboard,
+            kind: ClipboardKind,
+        ) -> Result<String, ClipboardError> {
+            match kind {
+                ClipboardKind::Clipboard => Ok(clipboard.get_text()?),
+                ClipboardKind::Primary => Err(primary_unsupported()),
+            }
+        }
+
+        pub(super) fn write(
+            clipboard: &mut arboard::Clipboard,
+            kind: ClipboardKind,
+            text: &str,
+        ) -> Result<(), ClipboardError> {
+            match kind {
+                ClipboardKind::Clipboard => Ok(clipboard.set_text(text)?),
+                ClipboardKind::Primary => Err(primary_unsupported()),
+            }
+        }
+    }
+
+    impl Clipboard for SystemClipboard {
+        fn get_text(&self) -> Result<String, ClipboardError> {
+            self.get_text_from(ClipboardKind::Clipboard)
+        }
+
+        fn set_text(&self, text: &str) -> Result<(), ClipboardError> {
+            self.set_text_in(ClipboardKind::Clipboard, text)
+        }
+
+        fn get_text_from(&self, kind: ClipboardKind) -> Result<String, ClipboardError> {
+            selection::read(&mut self.lock(), kind)
+        }
+
+        fn set_text_in(&self, kind: ClipboardKind, text: &str) -> Result<(), ClipboardError> {
+            selection::write(&mut self.lock(), kind, text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_clipboard_round_trip() {
+        let clipboard = MemoryClipboard::new();
+        assert!(matches!(clipboard.get_text(), Err(ClipboardError::Empty)));
+
+        clipboard.set_text("hello").unwrap();
+        assert_eq!(clipboard.get_text().unwrap(), "hello");
+
+        clipboard.set_text("world").unwrap();
+        assert_eq!(clipboard.get_text().unwrap(), "world");
+    }
+
+    #[test]
+    fn test_memory_clipboard_primary_is_independent() {
+        let clipboard = MemoryClipboard::new();
+        clipboard
+            .set_text_in(ClipboardKind::Primary, "selected")
+            .unwrap();
+        assert!(matches!(clipboard.get_text(), Err(ClipboardError::Empty)));
+
+        clipboard.set_text("copied").unwrap();
+        assert_eq!(
+            clipboard.get_text_from(ClipboardKind::Primary).unwrap(),
+            "selected"
+        );
+        assert_eq!(
+            clipboard.get_text_from(ClipboardKind::Clipboard).unwrap(),
+            "copied"
+        );
+    }
+
+    #[test]
+    fn test_default_primary_is_unavailable() {
+        struct ClipboardOnly(MemoryClipboard);
+        impl Clipboard for ClipboardOnly {
+            fn get_text(&self) -> Result<String, ClipboardError> {
+                self.0.get_text()
+            }
+            fn set_text(&self, text: &str) -> Result<(), ClipboardError> {
+                self.0.set_text(text)
+            }
+        }
+
+        let clipboard = ClipboardOnly(MemoryClipboard::new());
+        clipboard
+            .set_text_in(ClipboardKind::Clipboard, "copied")
+            .unwrap();
+        assert_eq!(clipboard.get_text().unwrap(), "copied");
+        assert!(matches!(
+            clipboard.set_text_in(ClipboardKind::Primary, "selected"),
+            Err(ClipboardError::Unavailable(_))
+        ));
+        assert!(matches!(
+            clipboard.get_text_from(ClipboardKind::Primary),
+            Err(ClipboardError::Unavailable(_))
+        ));
+    }
+
+    #[cfg(feature = "clipboard")]
+    #[test]
+    fn test_system_clipboard_errors() {
+        assert!(matches!(
+            ClipboardError::from(arboard::Error::ContentNotAvailable),
+            ClipboardError::Empty
+        ));
+        assert!(matches!(
+            ClipboardError::from(arboard::Error::ClipboardOccupied),
+            ClipboardError::Unavailable(_)
+        ));
+    }
+
+    #[test]
+    fn test_clipboard_error_display() {
+        assert_eq!(ClipboardError::Empty.to_string(), "Clipboard is empty");
+        assert_eq!(
+            ClipboardError::Unavailable("no tool".to_string()).to_string(),
+            "Clipboard unavailable: no tool"
+        );
+    }
+}
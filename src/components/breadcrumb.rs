@@ -0,0 +1,512 @@
+//! Breadcrumb component showing the path to the current location.
+//!
+//! A [`Breadcrumb`] renders a list of segment labels joined by a separator
+//! (`›` by default), with the last segment, the current location,
+//! emphasized. When the path is wider than the area, segments are dropped
+//! from the middle and replaced with `…`, keeping the first segment, the
+//! highlighted one, and as many trailing segments as fit.
+//!
+//! Segments can be made selectable for navigation. A selectable breadcrumb
+//! highlights a segment while focused, moves the highlight with
+//! [`BreadcrumbMsg::Prev`] and [`BreadcrumbMsg::Next`], and reports the
+//! chosen segment with [`BreadcrumbAction::Selected`]. Mouse clicks are
+//! mapped to segments with [`Breadcrumb::segment_at`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::Breadcrumb;
+//!
+//! let path = Breadcrumb::new(["home", "user", "projects", "tuilib", "src"]);
+//! assert_eq!(path.line(80).to_string(), "home › user › projects › tuilib › src");
+//! assert_eq!(path.line(24).to_string(), "home › … › tuilib › src");
+//! ```
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use terminput::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{Component, Focusable, Measurable, Renderable};
+use crate::focus::FocusId;
+use crate::text::{display_width, truncate};
+use crate::theme::Theme;
+
+/// Separator placed between segments unless another is set.
+const DEFAULT_SEPARATOR: &str = "›";
+
+/// Marker standing in for segments dropped from the middle.
+const ELLIPSIS: &str = "…";
+
+/// Messages that the Breadcrumb component can handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreadcrumbMsg {
+    /// Move the highlight to the previous segment.
+    Prev,
+    /// Move the highlight to the next segment.
+    Next,
+    /// Choose the highlighted segment.
+    Select,
+    /// Choose the segment at an index, e.g. after a click.
+    SelectAt(usize),
+}
+
+impl BreadcrumbMsg {
+    /// Maps a key press to a Breadcrumb message.
+    ///
+    /// Left and Right move the highlight and Enter chooses the highlighted
+    /// segment. Other keys return `None`.
+    pub fn from_key(key: &KeyEvent) -> Option<Self> {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CTRL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+
+        let msg = match key.code {
+            KeyCode::Left => BreadcrumbMsg::Prev,
+            KeyCode::Right => BreadcrumbMsg::Next,
+            KeyCode::Enter => BreadcrumbMsg::Select,
+            _ => return None,
+        };
+        Some(msg)
+    }
+}
+
+/// Actions emitted by the Breadcrumb component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreadcrumbAction {
+    /// The segment at this index was chosen.
+    Selected(usize),
+}
+
+/// What a piece of the laid out breadcrumb shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PieceKind {
+    /// The segment at this index.
+    Segment(usize),
+    /// A separator between segments.
+    Separator,
+    /// The marker for dropped segments.
+    Ellipsis,
+}
+
+/// A piece of the breadcrumb line and the text drawn for it.
+#[derive(Debug, Clone)]
+struct Piece {
+    kind: PieceKind,
+    text: String,
+}
+
+/// A path of segments leading to the current location.
+#[derive(Debug, Clone)]
+pub struct Breadcrumb {
+    /// Optional identifier for focus management.
+    id: Option<FocusId>,
+    /// Segment labels, from the root to the current location.
+    segments: Vec<String>,
+    /// Text placed between segments, padded with a space on each side.
+    separator: String,
+    /// Whether segments can be highlighted and chosen.
+    selectable: bool,
+    /// Index of the highlighted segment.
+    highlighted: usize,
+    /// Whether the breadcrumb has focus.
+    focused: bool,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl Breadcrumb {
+    /// Creates a breadcrumb from segment labels, root first.
+    pub fn new<I, S>(segments: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let segments: Vec<String> = segments.into_iter().map(Into::into).collect();
+        Self {
+            id: None,
+            highlighted: segments.len().saturating_sub(1),
+            segments,
+            separator: DEFAULT_SEPARATOR.to_string(),
+            selectable: false,
+            focused: false,
+            theme: None,
+        }
+    }
+
+    /// Sets the breadcrumb's identifier.
+    pub fn with_id(mut self, id: impl Into<FocusId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Returns the breadcrumb's identifier, if set.
+    pub fn id(&self) -> Option<&FocusId> {
+        self.id.as_ref()
+    }
+
+    /// Sets the separator drawn between segments.
+    ///
+    /// A space is added on each side of the separator.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets whether segments can be highlighted and chosen.
+    pub fn with_selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the segment labels.
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// Replaces the segments, highlighting the new last segment.
+    pub fn set_segments<I, S>(&mut self, segments: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.segments = segments.into_iter().map(Into::into).collect();
+        self.highlighted = self.segments.len().saturating_sub(1);
+    }
+
+    /// Returns the separator drawn between segments.
+    pub fn separator(&self) -> &str {
+        &self.separator
+    }
+
+    /// Returns true if segments can be highlighted and chosen.
+    pub fn is_selectable(&self) -> bool {
+        self.selectable
+    }
+
+    /// Returns the index of the highlighted segment, if segments are
+    /// selectable and there are any.
+    pub fn highlighted(&self) -> Option<usize> {
+        (self.selectable && !self.segments.is_empty()).then_some(self.highlighted)
+    }
+
+    /// Returns the index of the segment drawn at a position, if any.
+    ///
+    /// `area` is the area the breadcrumb was rendered into. Positions over
+    /// separators, the `…` marker or outside the line return `None`, as do
+    /// all positions when segments are not selectable.
+    pub fn segment_at(&self, area: Rect, x: u16, y: u16) -> Option<usize> {
+        if !self.selectable || y != area.y || x < area.x || x >= area.right() {
+            return None;
+        }
+        let column = (x - area.x) as usize;
+        let mut start = 0;
+        for piece in self.layout(area.width) {
+            let end = start + display_width(&piece.text);
+            if column < end {
+                return match piece.kind {
+                    PieceKind::Segment(index) => Some(index),
+                    _ => None,
+                };
+            }
+            start = end;
+        }
+        None
+    }
+
+    /// Returns the separator with its padding.
+    fn padded_separator(&self) -> String {
+        format!(" {} ", self.separator)
+    }
+
+    /// Lays out the segments to fit the given width.
+    ///
+    /// Shows every segment if they fit. Otherwise keeps the first segment,
+    /// the highlighted segment, and as many trailing segments as fit, with
+    /// `…` marking each gap. If even that does not fit, only the highlighted
+    /// segment (or the last one) is shown, truncated to the width.
+    fn layout(&self, width: u16) -> Vec<Piece> {
+        let width = width as usize;
+        let count = self.segments.len();
+        let all: Vec<usize> = (0..count).collect();
+        if count <= 1 || self.pieces_width(&all) <= width {
+            let mut pieces = self.pieces(&all);
+            if let Some(piece) = pieces.first_mut() {
+                piece.text = truncate(&piece.text, width);
+            }
+            return pieces;
+        }
+
+        // Keep the first and highlighted segments and the longest tail that
+        // fits
+        let last = count - 1;
+        let kept = self.highlighted().unwrap_or(last);
+        for tail in (1..count - 1).rev() {
+            let start = count - tail;
+            let middle = (kept > 0 && kept < start).then_some(kept);
+            let shown: Vec<usize> = std::iter::once(0)
+                .chain(middle)
+                .chain(start..count)
+                .collect();
+            if self.pieces_width(&shown) <= width {
+                return self.pieces(&shown);
+            }
+        }
+
+        vec![Piece {
+            kind: PieceKind::Segment(kept),
+            text: truncate(&self.segments[kept], width),
+        }]
+    }
+
+    /// Builds the pieces for the shown segments, with `…` standing in for
+    /// the segments between any two that are not adjacent.
+    fn pieces(&self, shown: &[usize]) -> Vec<Piece> {
+        let separator = self.padded_separator();
+        let mut pieces = Vec::new();
+        let mut previous: Option<usize> = None;
+        for &index in shown {
+            if let Some(previous) = previous {
+                pieces.push(Piece {
+                    kind: PieceKind::Separator,
+                    text: separator.clone(),
+                });
+                if index > previous + 1 {
+                    pieces.push(Piece {
+                        kind: PieceKind::Ellipsis,
+                        text: ELLIPSIS.to_string(),
+                    });
+                    pieces.push(Piece {
+                        kind: PieceKind::Separator,
+                        text: separator.clone(),
+                    });
+                }
+            }
+            pieces.push(Piece {
+                kind: PieceKind::Segment(index),
+                text: self.segments[index].clone(),
+            });
+            previous = Some(index);
+        }
+        pieces
+    }
+
+    /// Returns the width of the pieces for the shown segments.
+    fn pieces_width(&self, shown: &[usize]) -> usize {
+        self.pieces(shown)
+            .iter()
+            .map(|piece| display_width(&piece.text))
+            .sum()
+    }
+
+    /// Builds the breadcrumb line for the given width.
+    pub fn line(&self, width: u16) -> Line<'static> {
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+        let separator_style = theme.muted_style();
+        let segment_style = theme.secondary_text_style();
+        let current_style = theme.primary_text_style().add_modifier(Modifier::BOLD);
+        let highlight = self.highlighted().filter(|_| self.focused);
+        let last = self.segments.len().saturating_sub(1);
+
+        let spans: Vec<Span<'static>> = self
+            .layout(width)
+            .into_iter()
+            .map(|piece| {
+                let style = match piece.kind {
+                    PieceKind::Segment(index) if Some(index) == highlight => {
+                        theme.list_selected_style()
+                    }
+                    PieceKind::Segment(index) if index == last => current_style,
+                    PieceKind::Segment(_) => segment_style,
+                    PieceKind::Separator | PieceKind::Ellipsis => separator_style,
+                };
+                Span::styled(piece.text, style)
+            })
+            .collect();
+        Line::from(spans)
+    }
+}
+
+impl Component for Breadcrumb {
+    type Message = BreadcrumbMsg;
+    type Action = BreadcrumbAction;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        if !self.selectable || self.segments.is_empty() {
+            return None;
+        }
+        match msg {
+            BreadcrumbMsg::Prev => {
+                self.highlighted = self.highlighted.saturating_sub(1);
+                None
+            }
+            BreadcrumbMsg::Next => {
+                self.highlighted = (self.highlighted + 1).min(self.segments.len() - 1);
+                None
+            }
+            BreadcrumbMsg::Select => Some(BreadcrumbAction::Selected(self.highlighted)),
+            BreadcrumbMsg::SelectAt(index) if index < self.segments.len() => {
+                self.highlighted = index;
+                Some(BreadcrumbAction::Selected(index))
+            }
+            BreadcrumbMsg::SelectAt(_) => None,
+        }
+    }
+}
+
+impl Focusable for Breadcrumb {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+impl Renderable for Breadcrumb {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let row = Rect::new(area.x, area.y, area.width, 1);
+        frame.render_widget(Paragraph::new(self.line(area.width)), row);
+    }
+}
+
+impl Measurable for Breadcrumb {
+    fn measure(&self, max: Size) -> Size {
+        if self.segments.is_empty() {
+            return Size::new(0, 0);
+        }
+        let all: Vec<usize> = (0..self.segments.len()).collect();
+        let width = u16::try_from(self.pieces_width(&all)).unwrap_or(u16::MAX);
+        Size::new(width.min(max.width), 1.min(max.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{render, to_lines};
+
+    fn path() -> Breadcrumb {
+        Breadcrumb::new(["home", "user", "projects", "tuilib", "src"])
+    }
+
+    #[test]
+    fn test_breadcrumb_truncates_middle() {
+        let crumbs = path();
+        assert_eq!(crumbs.measure(Size::new(80, 1)), Size::new(37, 1));
+        assert_eq!(
+            crumbs.line(37).to_string(),
+            "home › user › projects › tuilib › src"
+        );
+
+        // Middle segments are dropped first, keeping the longest tail
+        assert_eq!(
+            crumbs.line(36).to_string(),
+            "home › … › projects › tuilib › src"
+        );
+        assert_eq!(crumbs.line(23).to_string(), "home › … › tuilib › src");
+        assert_eq!(crumbs.line(14).to_string(), "home › … › src");
+
+        // Too narrow for the first and last: the last segment alone
+        assert_eq!(crumbs.line(13).to_string(), "src");
+        assert_eq!(
+            Breadcrumb::new(["a", "documents"]).line(5).to_string(),
+            "docu…"
+        );
+
+        let buffer = render(&crumbs, Rect::new(0, 0, 16, 1));
+        assert_eq!(to_lines(&buffer), vec!["home › … › src  "]);
+    }
+
+    #[test]
+    fn test_breadcrumb_emphasizes_last_segment() {
+        let theme = Theme::default();
+        let crumbs = Breadcrumb::new(["a", "b"]).with_separator("/");
+        let buffer = render(&crumbs, Rect::new(0, 0, 5, 1));
+        assert_eq!(to_lines(&buffer), vec!["a / b"]);
+
+        assert_eq!(buffer[(4, 0)].fg, theme.colors().text_primary);
+        assert!(buffer[(4, 0)].modifier.contains(Modifier::BOLD));
+        assert_eq!(buffer[(0, 0)].fg, theme.secondary_text_style().fg.unwrap());
+        assert!(!buffer[(0, 0)].modifier.contains(Modifier::BOLD));
+        assert_eq!(buffer[(2, 0)].fg, theme.muted_style().fg.unwrap());
+    }
+
+    #[test]
+    fn test_breadcrumb_selection_and_clicks() {
+        let mut crumbs = path();
+        assert_eq!(crumbs.update(BreadcrumbMsg::Select), None);
+        assert_eq!(crumbs.segment_at(Rect::new(0, 0, 80, 1), 0, 0), None);
+
+        crumbs = crumbs.with_selectable(true);
+        assert_eq!(crumbs.highlighted(), Some(4));
+        crumbs.update(BreadcrumbMsg::Prev);
+        crumbs.update(BreadcrumbMsg::Prev);
+        assert_eq!(
+            crumbs.update(BreadcrumbMsg::Select),
+            Some(BreadcrumbAction::Selected(2))
+        );
+        assert_eq!(crumbs.update(BreadcrumbMsg::SelectAt(9)), None);
+        crumbs.update(BreadcrumbMsg::Next);
+        crumbs.update(BreadcrumbMsg::Next);
+
+        // Clicks map through the truncated layout: "home › … › tuilib › src"
+        let area = Rect::new(10, 3, 23, 1);
+        assert_eq!(crumbs.segment_at(area, 10, 3), Some(0));
+        assert_eq!(crumbs.segment_at(area, 15, 3), None);
+        assert_eq!(crumbs.segment_at(area, 17, 3), None);
+        assert_eq!(crumbs.segment_at(area, 21, 3), Some(3));
+        assert_eq!(crumbs.segment_at(area, 31, 3), Some(4));
+        assert_eq!(crumbs.segment_at(area, 21, 4), None);
+        let index = crumbs.segment_at(area, 21, 3).unwrap();
+        assert_eq!(
+            crumbs.update(BreadcrumbMsg::SelectAt(index)),
+            Some(BreadcrumbAction::Selected(3))
+        );
+
+        // The highlight is drawn only while focused
+        crumbs.set_focused(true);
+        let buffer = render(&crumbs, area);
+        let selected = Theme::default().list_selected_style();
+        assert_eq!(buffer[(21, 3)].fg, selected.fg.unwrap());
+        assert!(buffer[(21, 3)].modifier.contains(selected.add_modifier));
+    }
+
+    #[test]
+    fn test_breadcrumb_keeps_highlight_visible() {
+        let mut crumbs = path().with_selectable(true);
+        crumbs.set_focused(true);
+        assert_eq!(crumbs.line(23).to_string(), "home › … › tuilib › src");
+
+        // Moving the highlight into the elided middle keeps it drawn
+        crumbs.update(BreadcrumbMsg::Prev);
+        crumbs.update(BreadcrumbMsg::Prev);
+        crumbs.update(BreadcrumbMsg::Prev);
+        assert_eq!(crumbs.highlighted(), Some(1));
+        assert_eq!(crumbs.line(23).to_string(), "home › user › … › src");
+        let area = Rect::new(0, 0, 23, 1);
+        assert_eq!(crumbs.segment_at(area, 7, 0), Some(1));
+        let buffer = render(&crumbs, area);
+        let selected = Theme::default().list_selected_style();
+        assert_eq!(buffer[(7, 0)].fg, selected.fg.unwrap());
+
+        // A later segment in the middle gets its own gap on each side
+        crumbs.update(BreadcrumbMsg::Next);
+        assert_eq!(crumbs.line(29).to_string(), "home › … › projects › … › src");
+
+        // Too narrow for the rest: the highlighted segment alone
+        assert_eq!(crumbs.line(10).to_string(), "projects");
+    }
+}
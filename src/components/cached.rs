@@ -0,0 +1,253 @@
+//! Render caching for components that rarely change.
+//!
+//! [`Cached`] wraps a [`Renderable`] and keeps the cells it last drew. Later
+//! frames copy those cells back instead of rendering the component again,
+//! until the component is marked dirty or given a different area. This is
+//! worth it for data-heavy panes that are expensive to lay out but change
+//! far less often than the screen is redrawn.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use tuilib::components::Cached;
+//! use tuilib::components::modal::Button;
+//!
+//! let mut pane = Cached::new(Button::new("ok", "OK"));
+//! assert!(pane.is_dirty());
+//!
+//! // Changing the component through the wrapper invalidates the cache
+//! pane.inner_mut().set_disabled(true);
+//! assert!(pane.is_dirty());
+//! ```
+
+use std::cell::{Cell, RefCell};
+
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+
+use super::{Component, Focusable, Measurable, Renderable};
+
+/// A wrapper that reuses a component's last rendering while it is unchanged.
+///
+/// The cache is invalidated when:
+/// - [`mark_dirty`](Self::mark_dirty) is called
+/// - the inner component is borrowed through [`inner_mut`](Self::inner_mut)
+/// - a message is sent through [`Component::update`] or the focus changes
+/// - the component is rendered into a different area
+///
+/// State the wrapper cannot see, such as a shared theme or data behind an
+/// `Arc`, needs an explicit `mark_dirty` when it changes.
+///
+/// # Rendering Notes
+///
+/// - The inner component should draw every cell of its area, for example
+///   by rendering a [`Block`](ratatui::widgets::Block) or
+///   [`Clear`](ratatui::widgets::Clear) first. Cells it leaves alone are
+///   cached with whatever was underneath on the first frame.
+/// - A cursor position set by the inner component is not replayed when the
+///   cache is used.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::prelude::*;
+/// use tuilib::components::{Cached, Renderable};
+/// use tuilib::components::modal::Button;
+///
+/// struct Dashboard {
+///     stats: Cached<Button>,
+/// }
+///
+/// impl Renderable for Dashboard {
+///     fn render(&self, frame: &mut Frame, area: Rect) {
+///         // Only re-rendered when the stats change or the area moves
+///         self.stats.render(frame, area);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cached<C> {
+    inner: C,
+    cache: RefCell<Option<Buffer>>,
+    dirty: Cell<bool>,
+}
+
+impl<C> Cached<C> {
+    /// Creates a cache around the given component.
+    ///
+    /// The first render always draws the component.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            cache: RefCell::new(None),
+            dirty: Cell::new(true),
+        }
+    }
+
+    /// Returns a reference to the inner component.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner component.
+    ///
+    /// This marks the cache dirty, since the component may be changed.
+    pub fn inner_mut(&mut self) -> &mut C {
+        self.mark_dirty();
+        &mut self.inner
+    }
+
+    /// Consumes the wrapper and returns the inner component.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// Forces the next render to draw the inner component again.
+    pub fn mark_dirty(&self) {
+        self.dirty.set(true);
+    }
+
+    /// Returns true if the next render will draw the inner component.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.get() || self.cache.borrow().is_none()
+    }
+}
+
+impl<C: Renderable> Renderable for Cached<C> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let area = area.intersection(frame.area());
+        let mut cache = self.cache.borrow_mut();
+
+        if let Some(cached) = cache.as_ref() {
+            if !self.dirty.get() && cached.area == area {
+                copy_cells(cached, frame.buffer_mut(), area);
+                return;
+            }
+        }
+
+        self.inner.render(frame, area);
+
+        let mut snapshot = Buffer::empty(area);
+        copy_cells(frame.buffer_mut(), &mut snapshot, area);
+        *cache = Some(snapshot);
+        self.dirty.set(false);
+    }
+}
+
+/// Copies the cells of `area` from one buffer to another.
+fn copy_cells(from: &Buffer, to: &mut Buffer, area: Rect) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            to[(x, y)] = from[(x, y)].clone();
+        }
+    }
+}
+
+impl<C: Component> Component for Cached<C> {
+    type Message = C::Message;
+    type Action = C::Action;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        self.inner_mut().update(msg)
+    }
+}
+
+impl<C: Focusable> Focusable for Cached<C> {
+    fn is_focused(&self) -> bool {
+        self.inner.is_focused()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.inner.is_focused() != focused {
+            self.inner_mut().set_focused(focused);
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        self.inner.can_focus()
+    }
+
+    fn focus_order(&self) -> i32 {
+        self.inner.focus_order()
+    }
+
+    fn on_focus(&mut self) {
+        self.inner_mut().on_focus();
+    }
+
+    fn on_blur(&mut self) {
+        self.inner_mut().on_blur();
+    }
+}
+
+impl<C: Measurable> Measurable for Cached<C> {
+    fn measure(&self, max: Size) -> Size {
+        self.inner.measure(max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{to_lines, TestTerminal};
+    use ratatui::widgets::Paragraph;
+
+    struct Counting {
+        text: String,
+        renders: Cell<usize>,
+    }
+
+    impl Counting {
+        fn new(text: &str) -> Self {
+            Self {
+                text: text.to_string(),
+                renders: Cell::new(0),
+            }
+        }
+    }
+
+    impl Renderable for Counting {
+        fn render(&self, frame: &mut Frame, area: Rect) {
+            self.renders.set(self.renders.get() + 1);
+            frame.render_widget(Paragraph::new(self.text.as_str()), area);
+        }
+    }
+
+    #[test]
+    fn test_clean_render_reuses_cache() {
+        let mut terminal = TestTerminal::new(5, 1);
+        let cached = Cached::new(Counting::new("hi"));
+        let area = terminal.area();
+
+        terminal.render(&cached, area);
+        assert!(!cached.is_dirty());
+
+        // The terminal is cleared, so the text must come from the cache
+        terminal.render(&cached, area);
+        assert_eq!(cached.inner().renders.get(), 1);
+        assert_eq!(terminal.to_lines(), vec!["hi   "]);
+
+        cached.mark_dirty();
+        terminal.render(&cached, area);
+        assert_eq!(cached.inner().renders.get(), 2);
+
+        // A new area invalidates the cache
+        terminal.render(&cached, Rect::new(1, 0, 4, 1));
+        assert_eq!(cached.inner().renders.get(), 3);
+        assert_eq!(terminal.to_lines(), vec![" hi  "]);
+    }
+
+    #[test]
+    fn test_inner_mut_marks_dirty() {
+        let mut terminal = TestTerminal::new(5, 1);
+        let mut cached = Cached::new(Counting::new("hi"));
+        let area = terminal.area();
+        terminal.render(&cached, area);
+
+        cached.inner_mut().text = "bye".to_string();
+        assert!(cached.is_dirty());
+        terminal.render(&cached, area);
+        assert_eq!(to_lines(terminal.buffer()), vec!["bye  "]);
+        assert_eq!(cached.inner().renders.get(), 2);
+    }
+}
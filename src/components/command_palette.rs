@@ -0,0 +1,598 @@
+//! CommandPalette component for running commands by name.
+//!
+//! A [`CommandPalette`] is an overlay with a [`TextInput`] above a list of
+//! [`Command`]s. Typing filters the list with [`fuzzy_match`], ranking the
+//! closest names first and highlighting the matched characters. Enter
+//! closes the palette and emits the selected command's [`Action`], ready to
+//! be handed to an [`ActionRouter`](crate::input::ActionRouter).
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::{
+//!     Command, CommandPalette, CommandPaletteAction, CommandPaletteMsg, Component,
+//! };
+//! use tuilib::input::Action;
+//!
+//! let mut palette = CommandPalette::new()
+//!     .with_command(Command::new("Open File", Action::new("open")))
+//!     .with_command(Command::new("Save File", Action::new("save")).with_hint("Ctrl+S"));
+//!
+//! palette.update(CommandPaletteMsg::Open);
+//! palette.update(CommandPaletteMsg::SetQuery("sav".to_string()));
+//!
+//! let action = palette.update(CommandPaletteMsg::Confirm);
+//! assert_eq!(action, Some(CommandPaletteAction::Execute(Action::new("save"))));
+//! assert!(!palette.is_open());
+//! ```
+
+use std::cell::Cell;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use terminput::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::modal::Overlay;
+use super::{Component, Focusable, Renderable, ScrollState, TextInput, TextInputMsg};
+use crate::input::Action;
+use crate::text::{display_width, fuzzy_match, FuzzyMatch};
+use crate::theme::Theme;
+
+/// Height of the query input, including its border.
+const INPUT_HEIGHT: u16 = 3;
+
+/// A named command that a [`CommandPalette`] can run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Command {
+    /// Name shown in the list and matched against the query.
+    name: String,
+    /// Optional text shown at the right of the row, such as a shortcut.
+    hint: Option<String>,
+    /// Action emitted when the command is run.
+    action: Action,
+}
+
+impl Command {
+    /// Creates a command that emits `action` when run.
+    pub fn new(name: impl Into<String>, action: Action) -> Self {
+        Self {
+            name: name.into(),
+            hint: None,
+            action,
+        }
+    }
+
+    /// Sets text shown at the right of the command's row.
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Returns the command's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the command's hint, if set.
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+
+    /// Returns the action emitted when the command is run.
+    pub fn action(&self) -> &Action {
+        &self.action
+    }
+}
+
+/// Messages that the CommandPalette component can handle.
+#[derive(Debug, Clone)]
+pub enum CommandPaletteMsg {
+    /// Show the palette with an empty query.
+    Open,
+    /// Hide the palette.
+    Close,
+    /// Replace the query.
+    SetQuery(String),
+    /// Forward an editing message to the query input.
+    Input(TextInputMsg),
+    /// Select the next command, wrapping around.
+    SelectNext,
+    /// Select the previous command, wrapping around.
+    SelectPrev,
+    /// Run the selected command.
+    Confirm,
+    /// A key press: Escape closes, Enter confirms, Up/Down and
+    /// Ctrl+P/Ctrl+N move the selection, and other keys edit the query.
+    Key(KeyEvent),
+}
+
+/// Actions emitted by the CommandPalette component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandPaletteAction {
+    /// A command was chosen; the palette has closed.
+    Execute(Action),
+    /// The palette was closed without running a command.
+    Closed,
+}
+
+/// A filtered command list opened as an overlay.
+///
+/// Messages other than [`Open`](CommandPaletteMsg::Open) are ignored while
+/// the palette is closed.
+#[derive(Debug, Clone)]
+pub struct CommandPalette {
+    /// All commands, in registration order.
+    commands: Vec<Command>,
+    /// The query input.
+    input: TextInput,
+    /// Commands matching the query, best first, as indices into `commands`.
+    matches: Vec<(usize, FuzzyMatch)>,
+    /// Index into `matches` of the selected command.
+    selected: usize,
+    /// List rows shown in the last render; adjusted to keep the selection
+    /// visible.
+    scroll: Cell<ScrollState>,
+    /// Whether the palette is shown.
+    open: bool,
+    /// Title shown on the palette's border.
+    title: String,
+    /// Maximum number of list rows shown at once.
+    max_visible: u16,
+    /// Width of the palette as a fraction of the screen width.
+    width_percent: f32,
+    /// Whether the background is covered by an overlay.
+    show_overlay: bool,
+    /// Overlay drawn behind the palette.
+    overlay: Overlay,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl Default for CommandPalette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandPalette {
+    /// Creates a closed palette with no commands.
+    pub fn new() -> Self {
+        let mut input = TextInput::new().with_placeholder("Type a command...");
+        input.set_focused(true);
+        Self {
+            commands: Vec::new(),
+            input,
+            matches: Vec::new(),
+            selected: 0,
+            scroll: Cell::new(ScrollState::default()),
+            open: false,
+            title: "Commands".to_string(),
+            max_visible: 10,
+            width_percent: 0.6,
+            show_overlay: true,
+            overlay: Overlay::new().with_shadow(true),
+            theme: None,
+        }
+    }
+
+    /// Adds a command after the existing ones.
+    pub fn with_command(mut self, command: Command) -> Self {
+        self.add_command(command);
+        self
+    }
+
+    /// Adds several commands after the existing ones.
+    pub fn with_commands(mut self, commands: impl IntoIterator<Item = Command>) -> Self {
+        self.commands.extend(commands);
+        self.refilter();
+        self
+    }
+
+    /// Sets the title shown on the palette's border.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the placeholder shown while the query is empty.
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.input = self.input.with_placeholder(placeholder);
+        self
+    }
+
+    /// Sets the maximum number of commands shown at once.
+    pub fn with_max_visible(mut self, rows: u16) -> Self {
+        self.max_visible = rows.max(1);
+        self
+    }
+
+    /// Sets the width as a fraction of the screen width (0.0 to 1.0).
+    pub fn with_width_percent(mut self, value: f32) -> Self {
+        self.width_percent = value.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets whether an overlay covers the background while open.
+    pub fn with_overlay(mut self, value: bool) -> Self {
+        self.show_overlay = value;
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.input = self.input.with_theme(theme.clone());
+        self.overlay = self.overlay.with_theme(theme.clone());
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Adds a command after the existing ones.
+    pub fn add_command(&mut self, command: Command) {
+        self.commands.push(command);
+        self.refilter();
+    }
+
+    /// Returns all commands in registration order.
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Returns true if the palette is shown.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Returns the current query.
+    pub fn query(&self) -> &str {
+        self.input.text()
+    }
+
+    /// Returns the commands matching the query, best match first.
+    ///
+    /// With an empty query every command matches, in registration order.
+    pub fn matches(&self) -> Vec<&Command> {
+        self.matches
+            .iter()
+            .map(|(index, _)| &self.commands[*index])
+            .collect()
+    }
+
+    /// Returns the selected command, if any commands match.
+    pub fn selected(&self) -> Option<&Command> {
+        self.matches
+            .get(self.selected)
+            .map(|(index, _)| &self.commands[*index])
+    }
+
+    /// Re-ranks the commands against the query and selects the best match.
+    fn refilter(&mut self) {
+        let query = self.input.text();
+        let mut matches: Vec<(usize, FuzzyMatch)> = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter_map(|(index, command)| Some((index, fuzzy_match(query, &command.name)?)))
+            .collect();
+        // Stable, so ties keep registration order
+        matches.sort_by_key(|(index, m)| {
+            (
+                std::cmp::Reverse(m.score),
+                self.commands[*index].name.chars().count(),
+            )
+        });
+        self.matches = matches;
+        self.selected = 0;
+    }
+
+    /// Moves the selection by `delta` rows, wrapping around.
+    fn select_by(&mut self, delta: isize) {
+        let len = self.matches.len();
+        if len > 0 {
+            self.selected = (self.selected as isize + delta).rem_euclid(len as isize) as usize;
+        }
+    }
+
+    /// Maps a key press to the message it stands for.
+    fn key_msg(key: &KeyEvent) -> Option<CommandPaletteMsg> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CTRL);
+        let msg = match key.code {
+            KeyCode::Esc => CommandPaletteMsg::Close,
+            KeyCode::Enter => CommandPaletteMsg::Confirm,
+            KeyCode::Down => CommandPaletteMsg::SelectNext,
+            KeyCode::Up => CommandPaletteMsg::SelectPrev,
+            KeyCode::Char('n') if ctrl => CommandPaletteMsg::SelectNext,
+            KeyCode::Char('p') if ctrl => CommandPaletteMsg::SelectPrev,
+            _ => CommandPaletteMsg::Input(TextInputMsg::from_key(key)?),
+        };
+        Some(msg)
+    }
+
+    /// Returns the palette's area within the screen.
+    fn palette_area(&self, area: Rect) -> Rect {
+        let rows = (self.matches.len() as u16).clamp(1, self.max_visible);
+        let height = (INPUT_HEIGHT + rows + 2).min(area.height);
+        let width = ((f32::from(area.width) * self.width_percent) as u16)
+            .max(20)
+            .min(area.width);
+        let x = area.x + (area.width - width) / 2;
+        let y = area.y + (area.height - height) / 5;
+        Rect::new(x, y, width, height)
+    }
+
+    /// Builds the list row for a matched command.
+    fn command_line(
+        command: &Command,
+        m: &FuzzyMatch,
+        selected: bool,
+        width: u16,
+        theme: &Theme,
+    ) -> Line<'static> {
+        let row_style = if selected {
+            theme.list_selected_style()
+        } else {
+            theme.list_item_style()
+        };
+        // Underlined as well, since the selected row is already bold
+        let matched_style = row_style
+            .fg(theme.colors().primary)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+
+        let mut spans: Vec<Span> = command
+            .name
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let style = if m.positions.contains(&i) {
+                    matched_style
+                } else {
+                    row_style
+                };
+                Span::styled(c.to_string(), style)
+            })
+            .collect();
+
+        let name_width = display_width(&command.name);
+        let hint = command.hint.as_deref().unwrap_or_default();
+        let padding = (width as usize).saturating_sub(name_width + display_width(hint));
+        spans.push(Span::styled(" ".repeat(padding), row_style));
+        if !hint.is_empty() {
+            let hint_style = row_style.patch(theme.muted_style());
+            spans.push(Span::styled(hint.to_string(), hint_style));
+        }
+        Line::from(spans)
+    }
+}
+
+impl Component for CommandPalette {
+    type Message = CommandPaletteMsg;
+    type Action = CommandPaletteAction;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        if !self.open && !matches!(msg, CommandPaletteMsg::Open) {
+            return None;
+        }
+
+        match msg {
+            CommandPaletteMsg::Open => {
+                self.open = true;
+                self.input.set_text("");
+                self.refilter();
+                None
+            }
+            CommandPaletteMsg::Close => {
+                self.open = false;
+                Some(CommandPaletteAction::Closed)
+            }
+            CommandPaletteMsg::SetQuery(query) => {
+                self.input.set_text(query);
+                self.refilter();
+                None
+            }
+            CommandPaletteMsg::Input(msg) => {
+                if self.input.update(msg).is_some() {
+                    self.refilter();
+                }
+                None
+            }
+            CommandPaletteMsg::SelectNext => {
+                self.select_by(1);
+                None
+            }
+            CommandPaletteMsg::SelectPrev => {
+                self.select_by(-1);
+                None
+            }
+            CommandPaletteMsg::Confirm => {
+                let action = self.selected()?.action.clone();
+                self.open = false;
+                Some(CommandPaletteAction::Execute(action))
+            }
+            CommandPaletteMsg::Key(key) => {
+                let msg = Self::key_msg(&key)?;
+                self.update(msg)
+            }
+        }
+    }
+}
+
+impl Renderable for CommandPalette {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+
+        if self.show_overlay {
+            self.overlay.render(frame, area);
+        }
+        let palette_area = self.palette_area(area);
+        self.overlay.render_shadow(frame, palette_area);
+        frame.render_widget(Clear, palette_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(theme.components().modal.border_type)
+            .border_style(theme.border_focused_style())
+            .title(Span::styled(self.title.as_str(), theme.modal_title_style()))
+            .style(theme.modal_content_style());
+        let inner = block.inner(palette_area);
+        frame.render_widget(block, palette_area);
+
+        let [input_area, list_area] =
+            Layout::vertical([Constraint::Length(INPUT_HEIGHT), Constraint::Fill(1)]).areas(inner);
+        self.input.render(frame, input_area);
+
+        if self.matches.is_empty() {
+            let empty = Paragraph::new("No matching commands").style(theme.muted_style());
+            frame.render_widget(empty, list_area);
+            return;
+        }
+
+        // Scroll just far enough to keep the selection visible
+        let visible = list_area.height as usize;
+        let mut scroll = self.scroll.get();
+        scroll.set_total(self.matches.len());
+        scroll.set_viewport(visible);
+        scroll.ensure_visible(self.selected);
+        self.scroll.set(scroll);
+        let rows = self
+            .matches
+            .iter()
+            .enumerate()
+            .skip(scroll.offset())
+            .take(visible);
+        for (row, (position, (index, m))) in rows.enumerate() {
+            let selected = position == self.selected;
+            let command = &self.commands[*index];
+            let line = Self::command_line(command, m, selected, list_area.width, &theme);
+            let row_area = Rect::new(list_area.x, list_area.y + row as u16, list_area.width, 1);
+            frame.render_widget(Paragraph::new(line), row_area);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{render, to_lines};
+    use terminput::{KeyEventKind, KeyEventState};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    fn palette() -> CommandPalette {
+        let mut palette = CommandPalette::new().with_commands(
+            [
+                ("Format Document", "format"),
+                ("Go to File", "goto_file"),
+                ("Open File", "open_file"),
+                ("Save File", "save"),
+                ("Toggle Sidebar", "toggle_sidebar"),
+            ]
+            .map(|(name, action)| Command::new(name, Action::new(action))),
+        );
+        palette.update(CommandPaletteMsg::Open);
+        palette
+    }
+
+    fn names(palette: &CommandPalette) -> Vec<&str> {
+        palette.matches().into_iter().map(Command::name).collect()
+    }
+
+    #[test]
+    fn test_query_filters_and_ranks_commands() {
+        let mut palette = palette();
+        assert_eq!(names(&palette).len(), 5);
+
+        palette.update(CommandPaletteMsg::SetQuery("of".to_string()));
+        assert_eq!(names(&palette), vec!["Open File", "Go to File"]);
+
+        // Equal scores put shorter names first, then keep registration order
+        palette.update(CommandPaletteMsg::SetQuery("fi".to_string()));
+        assert_eq!(
+            names(&palette),
+            vec!["Open File", "Save File", "Go to File"]
+        );
+
+        palette.update(CommandPaletteMsg::SetQuery("gtf".to_string()));
+        assert_eq!(names(&palette)[0], "Go to File");
+
+        palette.update(CommandPaletteMsg::SetQuery("zzz".to_string()));
+        assert!(palette.matches().is_empty());
+        assert_eq!(palette.update(CommandPaletteMsg::Confirm), None);
+        assert!(palette.is_open());
+    }
+
+    #[test]
+    fn test_keys_select_and_execute() {
+        let mut palette = palette();
+        for c in "file".chars() {
+            palette.update(CommandPaletteMsg::Key(key(KeyCode::Char(c))));
+        }
+        assert_eq!(palette.query(), "file");
+        assert_eq!(palette.selected().map(Command::name), Some("Open File"));
+
+        // The selection wraps in both directions
+        palette.update(CommandPaletteMsg::Key(key(KeyCode::Up)));
+        assert_eq!(palette.selected().map(Command::name), Some("Go to File"));
+        palette.update(CommandPaletteMsg::Key(key(KeyCode::Down)));
+        palette.update(CommandPaletteMsg::Key(key(KeyCode::Down)));
+        assert_eq!(palette.selected().map(Command::name), Some("Save File"));
+
+        let action = palette.update(CommandPaletteMsg::Key(key(KeyCode::Enter)));
+        assert_eq!(
+            action,
+            Some(CommandPaletteAction::Execute(Action::new("save")))
+        );
+        assert!(!palette.is_open());
+        assert_eq!(
+            palette.update(CommandPaletteMsg::Key(key(KeyCode::Enter))),
+            None
+        );
+
+        // Reopening clears the query; Escape closes
+        palette.update(CommandPaletteMsg::Open);
+        assert_eq!(palette.query(), "");
+        assert_eq!(
+            palette.update(CommandPaletteMsg::Key(key(KeyCode::Esc))),
+            Some(CommandPaletteAction::Closed)
+        );
+    }
+
+    #[test]
+    fn test_renders_filtered_list_with_hints() {
+        let mut palette = CommandPalette::new()
+            .with_overlay(false)
+            .with_width_percent(1.0)
+            .with_command(Command::new("Save File", Action::new("save")).with_hint("Ctrl+S"))
+            .with_command(Command::new("Quit", Action::new("quit")));
+        assert!(to_lines(&render(&palette, Rect::new(0, 0, 30, 10)))
+            .iter()
+            .all(|line| line.trim().is_empty()));
+
+        palette.update(CommandPaletteMsg::Open);
+        palette.update(CommandPaletteMsg::SetQuery("sf".to_string()));
+        let buffer = render(&palette, Rect::new(0, 0, 30, 10));
+        let lines = to_lines(&buffer);
+        let row = lines.iter().position(|l| l.contains("Save File")).unwrap();
+        // The hint is right-aligned against the border
+        let hint: String = lines[row].chars().skip(23).take(6).collect();
+        assert_eq!(hint, "Ctrl+S", "{lines:#?}");
+        assert!(!lines.iter().any(|l| l.contains("Quit")));
+
+        // Matched characters are highlighted
+        let x = lines[row].chars().position(|c| c == 'S').unwrap() as u16;
+        assert!(buffer[(x, row as u16)]
+            .modifier
+            .contains(Modifier::UNDERLINED));
+        assert!(!buffer[(x + 1, row as u16)]
+            .modifier
+            .contains(Modifier::UNDERLINED));
+    }
+}
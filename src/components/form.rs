@@ -0,0 +1,488 @@
+//! Form container coordinating multiple input fields.
+//!
+//! A [`Form`] holds named fields, registers them with a [`FocusManager`] so
+//! Tab and Shift+Tab move between them, forwards other keys to the focused
+//! field, and validates every field on submit. Any component implementing
+//! [`FormField`] can be placed in a form; [`TextInput`], [`NumberInput`]
+//! and [`Toggle`] implement it out of the box.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::{validators, Component, Form, FormAction, FormMsg, TextInput};
+//!
+//! let mut form = Form::new()
+//!     .with_field("name", TextInput::new().with_validator(validators::non_empty()))
+//!     .with_field("email", TextInput::new());
+//!
+//! // The empty name fails validation
+//! let action = form.update(FormMsg::Submit);
+//! assert!(matches!(action, Some(FormAction::ValidationFailed(ref errors)) if errors.len() == 1));
+//! ```
+
+use std::collections::HashMap;
+
+use ratatui::prelude::*;
+use terminput::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{
+    Component, Focusable, NumberInput, NumberMsg, Renderable, TextInput, TextInputMsg, Toggle,
+    ToggleMsg, ValidationResult,
+};
+use crate::focus::{FocusId, FocusManager};
+
+/// Height of each field when the form is rendered.
+const FIELD_HEIGHT: u16 = 3;
+
+/// A component that can be placed in a [`Form`].
+pub trait FormField: Focusable + Renderable {
+    /// Returns the field's current value as submitted by the form.
+    fn value(&self) -> String;
+
+    /// Validates the field immediately and returns the result.
+    fn validate(&mut self) -> ValidationResult;
+
+    /// Handles a key press forwarded by the form.
+    ///
+    /// Returns true if the key was consumed.
+    fn handle_key(&mut self, key: &KeyEvent) -> bool;
+}
+
+impl FormField for TextInput {
+    fn value(&self) -> String {
+        self.text().to_string()
+    }
+
+    fn validate(&mut self) -> ValidationResult {
+        self.validate_now()
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        match TextInputMsg::from_key(key) {
+            Some(msg) => {
+                self.update(msg);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl FormField for NumberInput {
+    fn value(&self) -> String {
+        self.format_value(NumberInput::value(self))
+    }
+
+    fn validate(&mut self) -> ValidationResult {
+        // Bounds and precision are enforced as the value is edited
+        ValidationResult::Valid
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        match NumberMsg::from_key(key) {
+            Some(msg) => {
+                self.update(msg);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl FormField for Toggle {
+    fn value(&self) -> String {
+        self.is_on().to_string()
+    }
+
+    fn validate(&mut self) -> ValidationResult {
+        ValidationResult::Valid
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        match ToggleMsg::from_key(key) {
+            Some(msg) => {
+                self.update(msg);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Messages that the Form component can handle.
+#[derive(Debug, Clone)]
+pub enum FormMsg {
+    /// Move focus to the next field.
+    FocusNext,
+    /// Move focus to the previous field.
+    FocusPrev,
+    /// Validate all fields and submit if they are valid.
+    Submit,
+    /// A key press: Tab and Shift+Tab move focus, Enter submits, and other
+    /// keys go to the focused field.
+    Key(KeyEvent),
+}
+
+/// Actions emitted by the Form component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormAction {
+    /// All fields were valid; maps field names to values.
+    Submit(HashMap<String, String>),
+    /// Some fields were invalid; lists `(field name, message)` pairs in
+    /// field order.
+    ValidationFailed(Vec<(String, String)>),
+}
+
+/// A named field in a form.
+struct NamedField {
+    name: String,
+    field: Box<dyn FormField>,
+}
+
+/// A container of named fields with focus navigation and validation.
+pub struct Form {
+    /// Fields in display and tab order.
+    fields: Vec<NamedField>,
+    /// Focus navigation between fields.
+    focus: FocusManager,
+    /// Errors from the last failed submit.
+    errors: Vec<(String, String)>,
+    /// Whether the form is focused.
+    focused: bool,
+}
+
+impl std::fmt::Debug for Form {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Form")
+            .field(
+                "fields",
+                &self.fields.iter().map(|f| &f.name).collect::<Vec<_>>(),
+            )
+            .field("focused_field", &self.focused_field())
+            .field("errors", &self.errors)
+            .field("focused", &self.focused)
+            .finish()
+    }
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Form {
+    /// Creates an empty form.
+    pub fn new() -> Self {
+        Self {
+            fields: Vec::new(),
+            focus: FocusManager::new(),
+            errors: Vec::new(),
+            focused: false,
+        }
+    }
+
+    /// Adds a field; see [`add_field`](Self::add_field).
+    pub fn with_field(mut self, name: impl Into<String>, field: impl FormField + 'static) -> Self {
+        self.add_field(name, field);
+        self
+    }
+
+    /// Adds a field after the existing ones.
+    ///
+    /// The first field added receives focus. Adding a field with a name
+    /// already in use replaces the existing field.
+    pub fn add_field(&mut self, name: impl Into<String>, field: impl FormField + 'static) {
+        let name = name.into();
+        let field: Box<dyn FormField> = Box::new(field);
+
+        let is_current = self.focused_field() == Some(name.as_str());
+        if let Some(existing) = self.fields.iter_mut().find(|f| f.name == name) {
+            existing.field = field;
+            existing.field.set_focused(is_current && self.focused);
+            return;
+        }
+
+        let order = i32::try_from(self.fields.len()).unwrap_or(i32::MAX);
+        self.focus.register(FocusId::from(name.clone()), order);
+        self.fields.push(NamedField { name, field });
+        if self.fields.len() == 1 {
+            let first = FocusId::from(self.fields[0].name.clone());
+            self.move_focus(|focus| focus.focus(&first).then_some(first.clone()));
+        }
+    }
+
+    /// Returns the field names in order.
+    pub fn field_names(&self) -> Vec<&str> {
+        self.fields.iter().map(|f| f.name.as_str()).collect()
+    }
+
+    /// Returns a field by name.
+    pub fn field(&self, name: &str) -> Option<&dyn FormField> {
+        self.fields
+            .iter()
+            .find(|f| f.name == name)
+            .map(|f| f.field.as_ref())
+    }
+
+    /// Returns a field by name for modification.
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut (dyn FormField + 'static)> {
+        self.fields
+            .iter_mut()
+            .find(|f| f.name == name)
+            .map(|f| f.field.as_mut())
+    }
+
+    /// Returns the name of the focused field.
+    pub fn focused_field(&self) -> Option<&str> {
+        self.focus.current().map(|id| id.as_str())
+    }
+
+    /// Focuses a field by name, returning false if it does not exist.
+    pub fn focus_field(&mut self, name: &str) -> bool {
+        let id = FocusId::from(name.to_string());
+        if !self.focus.contains(&id) {
+            return false;
+        }
+        self.move_focus(|focus| focus.focus(&id).then_some(id.clone()));
+        true
+    }
+
+    /// Returns the errors from the last failed submit.
+    pub fn errors(&self) -> &[(String, String)] {
+        &self.errors
+    }
+
+    /// Returns the current values of all fields.
+    pub fn values(&self) -> HashMap<String, String> {
+        self.fields
+            .iter()
+            .map(|f| (f.name.clone(), f.field.value()))
+            .collect()
+    }
+
+    /// Moves focus with `navigate`, notifying the old and new fields.
+    fn move_focus(&mut self, navigate: impl FnOnce(&mut FocusManager) -> Option<FocusId>) {
+        let previous = self.focus.current().cloned();
+        let Some(next) = navigate(&mut self.focus) else {
+            return;
+        };
+        if previous.as_ref() == Some(&next) {
+            return;
+        }
+
+        if let Some(previous) = previous {
+            if let Some(field) = self.field_mut(previous.as_str()) {
+                field.set_focused(false);
+                field.on_blur();
+            }
+        }
+        let form_focused = self.focused;
+        if let Some(field) = self.field_mut(next.as_str()) {
+            field.set_focused(form_focused);
+            field.on_focus();
+        }
+    }
+
+    /// Validates every field, focusing the first invalid one.
+    fn submit(&mut self) -> FormAction {
+        self.errors = self
+            .fields
+            .iter_mut()
+            .filter_map(|f| match f.field.validate() {
+                ValidationResult::Invalid(message) => Some((f.name.clone(), message)),
+                _ => None,
+            })
+            .collect();
+
+        match self.errors.first().map(|(name, _)| name.clone()) {
+            Some(first_invalid) => {
+                self.focus_field(&first_invalid);
+                FormAction::ValidationFailed(self.errors.clone())
+            }
+            None => FormAction::Submit(self.values()),
+        }
+    }
+}
+
+impl Component for Form {
+    type Message = FormMsg;
+    type Action = FormAction;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        match msg {
+            FormMsg::FocusNext => {
+                self.move_focus(FocusManager::focus_next);
+                None
+            }
+            FormMsg::FocusPrev => {
+                self.move_focus(FocusManager::focus_prev);
+                None
+            }
+            FormMsg::Submit => Some(self.submit()),
+            FormMsg::Key(key) => match key.code {
+                KeyCode::Tab if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                    self.update(FormMsg::FocusPrev)
+                }
+                KeyCode::Tab => self.update(FormMsg::FocusNext),
+                KeyCode::Enter => self.update(FormMsg::Submit),
+                _ => {
+                    let name = self.focused_field()?.to_string();
+                    self.field_mut(&name)?.handle_key(&key);
+                    None
+                }
+            },
+        }
+    }
+}
+
+impl Focusable for Form {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if let Some(name) = self.focused_field().map(str::to_string) {
+            if let Some(field) = self.field_mut(&name) {
+                field.set_focused(focused);
+            }
+        }
+    }
+}
+
+impl Renderable for Form {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        for (index, named) in self.fields.iter().enumerate() {
+            let y = area.y + (index as u16).saturating_mul(FIELD_HEIGHT);
+            if y >= area.bottom() {
+                break;
+            }
+            let height = FIELD_HEIGHT.min(area.bottom() - y);
+            named
+                .field
+                .render(frame, Rect::new(area.x, y, area.width, height));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::validators;
+    use terminput::{KeyEventKind, KeyEventState};
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    fn type_str(form: &mut Form, text: &str) {
+        for c in text.chars() {
+            form.update(FormMsg::Key(key(KeyCode::Char(c), KeyModifiers::NONE)));
+        }
+    }
+
+    fn signup_form() -> Form {
+        let mut form = Form::new()
+            .with_field(
+                "user",
+                TextInput::new().with_validator(validators::non_empty()),
+            )
+            .with_field("age", NumberInput::new().with_range(0.0, 150.0));
+        form.set_focused(true);
+        form
+    }
+
+    #[test]
+    fn test_tab_navigation() {
+        let mut form = signup_form();
+        assert_eq!(form.field_names(), vec!["user", "age"]);
+        assert_eq!(form.focused_field(), Some("user"));
+        assert!(form.field("user").unwrap().is_focused());
+
+        form.update(FormMsg::Key(key(KeyCode::Tab, KeyModifiers::NONE)));
+        assert_eq!(form.focused_field(), Some("age"));
+        assert!(!form.field("user").unwrap().is_focused());
+        assert!(form.field("age").unwrap().is_focused());
+
+        form.update(FormMsg::Key(key(KeyCode::Tab, KeyModifiers::SHIFT)));
+        assert_eq!(form.focused_field(), Some("user"));
+    }
+
+    #[test]
+    fn test_submit_fails_then_succeeds() {
+        let mut form = signup_form();
+        form.focus_field("age");
+        type_str(&mut form, "42");
+
+        let action = form.update(FormMsg::Submit);
+        assert_eq!(
+            action,
+            Some(FormAction::ValidationFailed(vec![(
+                "user".to_string(),
+                "Field is required".to_string()
+            )]))
+        );
+        assert_eq!(form.errors().len(), 1);
+        // Focus jumps to the invalid field
+        assert_eq!(form.focused_field(), Some("user"));
+
+        type_str(&mut form, "alice");
+        let action = form.update(FormMsg::Key(key(KeyCode::Enter, KeyModifiers::NONE)));
+        let expected: HashMap<String, String> = [
+            ("user".to_string(), "alice".to_string()),
+            ("age".to_string(), "42".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(action, Some(FormAction::Submit(expected)));
+        assert!(form.errors().is_empty());
+    }
+
+    #[test]
+    fn test_keys_go_to_focused_field() {
+        let mut form = signup_form();
+        type_str(&mut form, "bob");
+        form.update(FormMsg::Key(key(KeyCode::Backspace, KeyModifiers::NONE)));
+
+        form.update(FormMsg::FocusNext);
+        form.update(FormMsg::Key(key(KeyCode::Up, KeyModifiers::NONE)));
+
+        let values = form.values();
+        assert_eq!(values["user"], "bo");
+        assert_eq!(values["age"], "1");
+    }
+
+    #[test]
+    fn test_toggle_field_submits_state() {
+        let mut form = signup_form().with_field("newsletter", Toggle::new());
+        type_str(&mut form, "bob");
+        form.update(FormMsg::FocusNext);
+        form.update(FormMsg::FocusNext);
+        form.update(FormMsg::Key(key(KeyCode::Char(' '), KeyModifiers::NONE)));
+
+        let action = form.update(FormMsg::Submit);
+        let Some(FormAction::Submit(values)) = action else {
+            panic!("expected submit, got {action:?}");
+        };
+        assert_eq!(values["newsletter"], "true");
+    }
+
+    #[test]
+    fn test_replacing_field_keeps_order() {
+        let mut form = signup_form();
+        form.add_field("user", TextInput::new());
+        assert_eq!(form.field_names(), vec!["user", "age"]);
+        assert!(form.field("user").unwrap().is_focused());
+        assert!(matches!(
+            form.update(FormMsg::Submit),
+            Some(FormAction::Submit(_))
+        ));
+        assert!(!form.focus_field("missing"));
+    }
+}
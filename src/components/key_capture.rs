@@ -0,0 +1,480 @@
+//! KeyCaptureInput component for recording a key binding.
+//!
+//! A [`KeyCaptureInput`] lets users change a binding by pressing it. Once
+//! armed, it records the next key press, ignoring presses of modifier keys
+//! on their own, and reports it as a [`KeySequence`]. For multi-key
+//! sequences such as `g g`, raise the limit with
+//! [`with_max_keys`](KeyCaptureInput::with_max_keys); capture then ends when
+//! the limit is reached, on [`KeyCaptureMsg::Confirm`], or once no key has
+//! been pressed for the sequence timeout.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::{Component, KeyCaptureAction, KeyCaptureInput, KeyCaptureMsg};
+//! use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+//!
+//! let mut capture = KeyCaptureInput::new();
+//! capture.update(KeyCaptureMsg::Arm);
+//!
+//! let ctrl_s = KeyEvent {
+//!     code: KeyCode::Char('s'),
+//!     modifiers: KeyModifiers::CTRL,
+//!     kind: KeyEventKind::Press,
+//!     state: KeyEventState::NONE,
+//! };
+//! match capture.update(KeyCaptureMsg::Key(ctrl_s)) {
+//!     Some(KeyCaptureAction::Captured(sequence)) => {
+//!         assert_eq!(sequence.to_string(), "Ctrl+s");
+//!     }
+//!     other => panic!("expected a capture, got {other:?}"),
+//! }
+//! ```
+
+use std::time::Duration;
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use super::{Component, Focusable, Measurable, Renderable};
+use crate::focus::FocusId;
+use crate::input::{KeyBinding, KeySequence};
+use crate::text::display_width;
+use crate::theme::Theme;
+
+/// Default time to wait for the next key of a sequence.
+const DEFAULT_SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Prompt shown while waiting for the first key.
+const PROMPT: &str = "Press a key…";
+
+/// Messages that the KeyCaptureInput component can handle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyCaptureMsg {
+    /// Start recording keys, discarding any partial capture.
+    Arm,
+    /// Stop recording without changing the binding.
+    Cancel,
+    /// A key event to record.
+    Key(KeyEvent),
+    /// Finish a multi-key capture with the keys recorded so far.
+    Confirm,
+    /// Advance time by the given duration, finishing the capture if the
+    /// sequence timeout has passed since the last key.
+    Tick(Duration),
+    /// Remove the current binding.
+    Clear,
+}
+
+/// Actions emitted by the KeyCaptureInput component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyCaptureAction {
+    /// A new binding was recorded.
+    Captured(KeySequence),
+    /// Capture was cancelled; the binding is unchanged.
+    Cancelled,
+    /// The binding was removed.
+    Cleared,
+}
+
+/// A field that records a key binding when the user presses it.
+#[derive(Debug, Clone)]
+pub struct KeyCaptureInput {
+    /// Optional identifier for focus management.
+    id: Option<FocusId>,
+    /// The current binding, if any.
+    binding: Option<KeySequence>,
+    /// Keys recorded since the capture was armed.
+    pending: Vec<KeyBinding>,
+    /// Modifiers held down without another key, echoed while capturing.
+    held: KeyModifiers,
+    /// Whether key presses are being recorded.
+    armed: bool,
+    /// Number of keys after which capture ends on its own.
+    max_keys: usize,
+    /// How long to wait for the next key of a sequence.
+    sequence_timeout: Duration,
+    /// Time since the last recorded key.
+    idle: Duration,
+    /// Text shown when there is no binding.
+    placeholder: String,
+    /// Whether the field has focus.
+    focused: bool,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl Default for KeyCaptureInput {
+    fn default() -> Self {
+        Self {
+            id: None,
+            binding: None,
+            pending: Vec::new(),
+            held: KeyModifiers::NONE,
+            armed: false,
+            max_keys: 1,
+            sequence_timeout: DEFAULT_SEQUENCE_TIMEOUT,
+            idle: Duration::ZERO,
+            placeholder: "Not set".to_string(),
+            focused: false,
+            theme: None,
+        }
+    }
+}
+
+impl KeyCaptureInput {
+    /// Creates an unarmed field with no binding that captures single keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the field's identifier.
+    pub fn with_id(mut self, id: impl Into<FocusId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Returns the field's identifier, if set.
+    pub fn id(&self) -> Option<&FocusId> {
+        self.id.as_ref()
+    }
+
+    /// Sets the initial binding.
+    pub fn with_binding(mut self, binding: KeySequence) -> Self {
+        self.binding = Some(binding);
+        self
+    }
+
+    /// Sets how many keys a sequence may have; at least one.
+    ///
+    /// Capture ends on its own once this many keys have been pressed.
+    pub fn with_max_keys(mut self, max_keys: usize) -> Self {
+        self.max_keys = max_keys.max(1);
+        self
+    }
+
+    /// Sets how long to wait for the next key of a sequence before ending
+    /// the capture.
+    pub fn with_sequence_timeout(mut self, timeout: Duration) -> Self {
+        self.sequence_timeout = timeout;
+        self
+    }
+
+    /// Sets the text shown when there is no binding.
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the current binding, if any.
+    pub fn binding(&self) -> Option<&KeySequence> {
+        self.binding.as_ref()
+    }
+
+    /// Returns true if key presses are being recorded.
+    pub fn is_armed(&self) -> bool {
+        self.armed
+    }
+
+    /// Returns the keys recorded so far in the current capture.
+    pub fn pending_keys(&self) -> &[KeyBinding] {
+        &self.pending
+    }
+
+    /// Handles a key press routed to the field.
+    ///
+    /// Keys are ignored unless the field has focus. When not armed, Enter
+    /// or Space arms it. When armed, Esc cancels and any other key is
+    /// recorded.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> Option<KeyCaptureAction> {
+        if !self.focused || key.kind == KeyEventKind::Release {
+            return None;
+        }
+        if !self.armed {
+            return match key.code {
+                KeyCode::Enter | KeyCode::Char(' ') => self.update(KeyCaptureMsg::Arm),
+                _ => None,
+            };
+        }
+        if key.code == KeyCode::Esc {
+            return self.update(KeyCaptureMsg::Cancel);
+        }
+        self.update(KeyCaptureMsg::Key(*key))
+    }
+
+    /// Stops recording and discards any partial capture.
+    fn reset(&mut self) {
+        self.armed = false;
+        self.pending.clear();
+        self.held = KeyModifiers::NONE;
+        self.idle = Duration::ZERO;
+    }
+
+    /// Ends the capture, keeping the recorded keys as the new binding.
+    fn finish(&mut self) -> Option<KeyCaptureAction> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        let sequence = KeySequence::new(std::mem::take(&mut self.pending));
+        self.reset();
+        self.binding = Some(sequence.clone());
+        Some(KeyCaptureAction::Captured(sequence))
+    }
+
+    /// Records a key press while armed.
+    fn record(&mut self, key: KeyEvent) -> Option<KeyCaptureAction> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        // A modifier on its own can't be bound; show it until a key follows
+        if matches!(key.code, KeyCode::Modifier(..)) {
+            self.held = key.modifiers;
+            return None;
+        }
+
+        self.pending
+            .push(KeyBinding::with_mods(key.code, key.modifiers));
+        self.held = KeyModifiers::NONE;
+        self.idle = Duration::ZERO;
+        if self.pending.len() >= self.max_keys {
+            return self.finish();
+        }
+        None
+    }
+
+    /// Returns the text shown in the field and whether it is a prompt or
+    /// placeholder rather than keys.
+    fn display_text(&self) -> (String, bool) {
+        if !self.armed {
+            return match &self.binding {
+                Some(binding) => (binding.to_string(), false),
+                None => (self.placeholder.clone(), true),
+            };
+        }
+
+        let mut parts: Vec<String> = self.pending.iter().map(ToString::to_string).collect();
+        if !self.held.is_empty() {
+            parts.push(format!("{}…", modifier_prefix(self.held)));
+        } else if self.pending.is_empty() {
+            return (PROMPT.to_string(), true);
+        } else {
+            parts.push("…".to_string());
+        }
+        (parts.join(" "), false)
+    }
+}
+
+/// Formats modifiers in the order [`KeyBinding`] displays them, each
+/// followed by `+`.
+fn modifier_prefix(modifiers: KeyModifiers) -> String {
+    [
+        (KeyModifiers::CTRL, "Ctrl+"),
+        (KeyModifiers::ALT, "Alt+"),
+        (KeyModifiers::SHIFT, "Shift+"),
+        (KeyModifiers::SUPER, "Super+"),
+    ]
+    .into_iter()
+    .filter(|(modifier, _)| modifiers.contains(*modifier))
+    .map(|(_, name)| name)
+    .collect()
+}
+
+impl Component for KeyCaptureInput {
+    type Message = KeyCaptureMsg;
+    type Action = KeyCaptureAction;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        match msg {
+            KeyCaptureMsg::Arm => {
+                self.reset();
+                self.armed = true;
+                None
+            }
+            KeyCaptureMsg::Cancel => {
+                let was_armed = self.armed;
+                self.reset();
+                was_armed.then_some(KeyCaptureAction::Cancelled)
+            }
+            KeyCaptureMsg::Key(key) if self.armed => self.record(key),
+            KeyCaptureMsg::Key(_) => None,
+            KeyCaptureMsg::Confirm if self.armed => self.finish(),
+            KeyCaptureMsg::Confirm => None,
+            KeyCaptureMsg::Tick(elapsed) => {
+                if !self.armed || self.pending.is_empty() {
+                    return None;
+                }
+                self.idle = self.idle.saturating_add(elapsed);
+                if self.idle >= self.sequence_timeout {
+                    return self.finish();
+                }
+                None
+            }
+            KeyCaptureMsg::Clear => {
+                self.reset();
+                self.binding.take().map(|_| KeyCaptureAction::Cleared)
+            }
+        }
+    }
+}
+
+impl Focusable for KeyCaptureInput {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.reset();
+        }
+    }
+}
+
+impl Renderable for KeyCaptureInput {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+
+        let bracket_style = if self.focused {
+            theme.border_focused_style().add_modifier(Modifier::BOLD)
+        } else {
+            theme.border_style()
+        };
+        let (text, is_prompt) = self.display_text();
+        let text_style = if is_prompt {
+            theme.input_placeholder_style()
+        } else if self.armed {
+            theme.emphasis_style()
+        } else {
+            theme.primary_text_style()
+        };
+
+        let line = Line::from(vec![
+            Span::styled("[", bracket_style),
+            Span::styled(text, text_style),
+            Span::styled("]", bracket_style),
+        ]);
+        let row = Rect::new(area.x, area.y, area.width, 1);
+        frame.render_widget(Paragraph::new(line), row);
+    }
+}
+
+impl Measurable for KeyCaptureInput {
+    fn measure(&self, max: Size) -> Size {
+        let text_width = display_width(&self.display_text().0).max(display_width(PROMPT));
+        let width = u16::try_from(text_width + 2).unwrap_or(u16::MAX);
+        Size::new(width.min(max.width), 1.min(max.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{render, to_lines};
+    use terminput::{KeyEventState, ModifierDirection, ModifierKeyCode};
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    #[test]
+    fn test_captures_single_key_with_modifiers() {
+        let mut capture = KeyCaptureInput::new();
+        capture.set_focused(true);
+
+        // Unarmed fields ignore keys other than the ones that arm them
+        let ctrl_s = key(KeyCode::Char('s'), KeyModifiers::CTRL);
+        assert_eq!(capture.handle_key(&ctrl_s), None);
+        assert_eq!(capture.binding(), None);
+
+        capture.handle_key(&key(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(capture.is_armed());
+
+        // A modifier on its own is echoed but not captured
+        let ctrl = key(
+            KeyCode::Modifier(ModifierKeyCode::Control, ModifierDirection::Left),
+            KeyModifiers::CTRL,
+        );
+        assert_eq!(capture.handle_key(&ctrl), None);
+        assert_eq!(capture.display_text(), ("Ctrl+…".to_string(), false));
+
+        let expected = KeySequence::single(KeyBinding::with_mods(
+            KeyCode::Char('s'),
+            KeyModifiers::CTRL,
+        ));
+        assert_eq!(
+            capture.handle_key(&ctrl_s),
+            Some(KeyCaptureAction::Captured(expected.clone()))
+        );
+        assert!(!capture.is_armed());
+        assert_eq!(capture.binding(), Some(&expected));
+    }
+
+    #[test]
+    fn test_captures_sequence_until_confirm_or_timeout() {
+        let g = key(KeyCode::Char('g'), KeyModifiers::NONE);
+        let gg = KeySequence::new(vec![KeyBinding::new(KeyCode::Char('g')); 2]);
+
+        let mut capture = KeyCaptureInput::new().with_max_keys(3);
+        capture.update(KeyCaptureMsg::Arm);
+        assert_eq!(capture.update(KeyCaptureMsg::Key(g)), None);
+        assert_eq!(capture.update(KeyCaptureMsg::Key(g)), None);
+        assert_eq!(capture.pending_keys().len(), 2);
+        assert_eq!(
+            capture.update(KeyCaptureMsg::Confirm),
+            Some(KeyCaptureAction::Captured(gg.clone()))
+        );
+
+        // Without a confirm, the capture ends once keys stop arriving
+        let mut capture = KeyCaptureInput::new()
+            .with_max_keys(3)
+            .with_sequence_timeout(Duration::from_millis(500));
+        capture.update(KeyCaptureMsg::Arm);
+        capture.update(KeyCaptureMsg::Key(g));
+        let tick = KeyCaptureMsg::Tick(Duration::from_millis(300));
+        assert_eq!(capture.update(tick.clone()), None);
+        capture.update(KeyCaptureMsg::Key(g));
+        assert_eq!(capture.update(tick.clone()), None);
+        assert_eq!(capture.update(tick), Some(KeyCaptureAction::Captured(gg)));
+    }
+
+    #[test]
+    fn test_cancel_clear_and_render() {
+        let binding = KeySequence::single(KeyBinding::new(KeyCode::Char('q')));
+        let mut capture = KeyCaptureInput::new().with_binding(binding.clone());
+        capture.set_focused(true);
+        let buffer = render(&capture, Rect::new(0, 0, 8, 1));
+        assert_eq!(to_lines(&buffer), vec!["[q]     "]);
+
+        capture.handle_key(&key(KeyCode::Char(' '), KeyModifiers::NONE));
+        let buffer = render(&capture, Rect::new(0, 0, 16, 1));
+        assert_eq!(to_lines(&buffer), vec!["[Press a key…]  "]);
+
+        assert_eq!(
+            capture.handle_key(&key(KeyCode::Esc, KeyModifiers::NONE)),
+            Some(KeyCaptureAction::Cancelled)
+        );
+        assert_eq!(capture.binding(), Some(&binding));
+
+        assert_eq!(
+            capture.update(KeyCaptureMsg::Clear),
+            Some(KeyCaptureAction::Cleared)
+        );
+        let buffer = render(&capture, Rect::new(0, 0, 10, 1));
+        assert_eq!(to_lines(&buffer), vec!["[Not set] "]);
+    }
+}
@@ -0,0 +1,383 @@
+//! LogView component showing recent tracing events.
+//!
+//! A [`LogView`] reads records from a [`LogBuffer`] and renders the newest
+//! ones in a list, with each level drawn in its own color. The view follows
+//! new records as they arrive until it is scrolled up, and can hide records
+//! below a minimum level.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tracing::Level;
+//! use tuilib::components::{Component, LogView, LogViewMsg};
+//! use tuilib::tracing::{LogBuffer, LogRecord};
+//!
+//! let logs = LogBuffer::new(100);
+//! logs.push(LogRecord {
+//!     level: Level::DEBUG,
+//!     target: "app".to_string(),
+//!     message: "loading".to_string(),
+//! });
+//! logs.push(LogRecord {
+//!     level: Level::WARN,
+//!     target: "app".to_string(),
+//!     message: "slow disk".to_string(),
+//! });
+//!
+//! let mut view = LogView::new(logs);
+//! view.update(LogViewMsg::SetLevel(Level::INFO));
+//! assert_eq!(view.visible_records().len(), 1);
+//! ```
+
+use std::cell::Cell;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{List, ListItem};
+use tracing::Level;
+
+use super::{Component, Focusable, Renderable, ScrollState};
+use crate::theme::Theme;
+use crate::tracing::{LogBuffer, LogRecord};
+
+/// Number of lines moved by page navigation before the view is first rendered.
+const DEFAULT_PAGE_SIZE: usize = 10;
+
+/// Messages that the LogView component can handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogViewMsg {
+    /// Scroll towards older records by one line.
+    ScrollUp,
+    /// Scroll towards newer records by one line.
+    ScrollDown,
+    /// Scroll towards older records by one page.
+    PageUp,
+    /// Scroll towards newer records by one page.
+    PageDown,
+    /// Scroll to the oldest record.
+    Top,
+    /// Scroll to the newest record and follow new ones.
+    Bottom,
+    /// Show only records at this level or more severe.
+    SetLevel(Level),
+}
+
+/// A scrollable, level-filtered view of a [`LogBuffer`].
+#[derive(Debug, Clone)]
+pub struct LogView {
+    /// The records to show.
+    buffer: LogBuffer,
+    /// Least severe level shown.
+    level: Level,
+    /// Scroll position over the visible records, sized by the last render.
+    scroll: Cell<ScrollState>,
+    /// Whether the view shows the newest record and follows new ones.
+    following: bool,
+    /// Whether the view is focused.
+    focused: bool,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl LogView {
+    /// Creates a view of `buffer` showing every level.
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self {
+            buffer,
+            level: Level::TRACE,
+            scroll: Cell::new(ScrollState::new(0, DEFAULT_PAGE_SIZE)),
+            following: true,
+            focused: false,
+            theme: None,
+        }
+    }
+
+    /// Shows only records at `level` or more severe.
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the least severe level shown.
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Returns how many lines the view is scrolled up from the newest record.
+    pub fn scroll(&self) -> usize {
+        let scroll = self.scroll_state(self.visible_records().len());
+        scroll.max_offset() - scroll.offset()
+    }
+
+    /// Returns true if the view shows the newest record and follows new ones.
+    ///
+    /// While scrolled up, new records are added below the view without
+    /// moving the lines on screen.
+    pub fn is_following(&self) -> bool {
+        self.following
+    }
+
+    /// Returns the records that pass the level filter, oldest first.
+    pub fn visible_records(&self) -> Vec<LogRecord> {
+        self.buffer
+            .records()
+            .into_iter()
+            .filter(|record| record.level <= self.level)
+            .collect()
+    }
+
+    /// Returns the scroll position over `records` lines, on the last page
+    /// while following.
+    fn scroll_state(&self, records: usize) -> ScrollState {
+        let mut scroll = self.scroll.get();
+        scroll.set_total(records);
+        if self.following {
+            scroll.scroll_to(scroll.max_offset());
+        }
+        scroll
+    }
+
+    /// Applies a scroll change, following new records once the last page
+    /// is reached.
+    fn scroll_with(&mut self, change: impl FnOnce(&mut ScrollState)) {
+        let mut scroll = self.scroll_state(self.visible_records().len());
+        change(&mut scroll);
+        self.following = scroll.offset() == scroll.max_offset();
+        self.scroll.set(scroll);
+    }
+
+    fn theme(&self) -> Theme {
+        self.theme.as_ref().cloned().unwrap_or_default()
+    }
+}
+
+/// Returns the style for a record's level.
+fn level_style(level: Level, theme: &Theme) -> Style {
+    match level {
+        Level::ERROR => theme.error_text_style(),
+        Level::WARN => theme.warning_text_style(),
+        Level::INFO => theme.info_text_style(),
+        Level::DEBUG => theme.muted_style(),
+        _ => theme.disabled_text_style(),
+    }
+}
+
+impl Component for LogView {
+    type Message = LogViewMsg;
+    type Action = ();
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        match msg {
+            LogViewMsg::ScrollUp => self.scroll_with(|scroll| scroll.scroll_by(-1)),
+            LogViewMsg::ScrollDown => self.scroll_with(|scroll| scroll.scroll_by(1)),
+            LogViewMsg::PageUp => self.scroll_with(ScrollState::page_up),
+            LogViewMsg::PageDown => self.scroll_with(ScrollState::page_down),
+            LogViewMsg::Top => self.scroll_with(|scroll| scroll.scroll_to(0)),
+            LogViewMsg::Bottom => self.following = true,
+            LogViewMsg::SetLevel(level) => {
+                self.level = level;
+                if !self.following {
+                    self.scroll_with(|_| {});
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Focusable for LogView {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+impl Renderable for LogView {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.height == 0 || area.width == 0 {
+            return;
+        }
+
+        let theme = self.theme();
+        let records = self.visible_records();
+        let mut scroll = self.scroll.get();
+        scroll.set_total(records.len());
+        scroll.set_viewport(area.height as usize);
+        self.scroll.set(scroll);
+
+        let scroll = self.scroll_state(records.len());
+        let items: Vec<ListItem> = records[scroll.visible_range()]
+            .iter()
+            .map(|record| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<5} ", record.level),
+                        level_style(record.level, &theme),
+                    ),
+                    Span::styled(format!("{}: ", record.target), theme.muted_style()),
+                    Span::styled(record.message.clone(), theme.primary_text_style()),
+                ]))
+            })
+            .collect();
+
+        frame.render_widget(List::new(items), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{render, to_lines};
+
+    fn record(level: Level, message: &str) -> LogRecord {
+        LogRecord {
+            level,
+            target: "app".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    fn numbered_logs(count: usize) -> LogBuffer {
+        let logs = LogBuffer::new(100);
+        for i in 0..count {
+            logs.push(record(Level::INFO, &format!("line {i}")));
+        }
+        logs
+    }
+
+    fn lines(view: &LogView, width: u16, height: u16) -> Vec<String> {
+        to_lines(&render(view, Rect::new(0, 0, width, height)))
+            .into_iter()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_filters_tracing_events_by_level() {
+        use tracing_subscriber::prelude::*;
+
+        let logs = LogBuffer::new(10);
+        let subscriber = tracing_subscriber::registry().with(logs.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!("connecting");
+            tracing::info!("connected");
+            tracing::warn!("slow response");
+            tracing::error!("disconnected");
+        });
+        assert_eq!(logs.len(), 4);
+
+        let mut view = LogView::new(logs);
+        assert_eq!(view.visible_records().len(), 4);
+
+        view.update(LogViewMsg::SetLevel(Level::WARN));
+        let messages: Vec<String> = view
+            .visible_records()
+            .into_iter()
+            .map(|r| r.message)
+            .collect();
+        assert_eq!(messages, vec!["slow response", "disconnected"]);
+    }
+
+    #[test]
+    fn test_follows_newest_records() {
+        let logs = numbered_logs(5);
+        let view = LogView::new(logs.clone());
+
+        assert_eq!(
+            lines(&view, 20, 2),
+            vec!["INFO  app: line 3", "INFO  app: line 4"]
+        );
+
+        logs.push(record(Level::ERROR, "boom"));
+        assert_eq!(lines(&view, 20, 2)[1], "ERROR app: boom");
+    }
+
+    #[test]
+    fn test_scrolling_clamps() {
+        let mut view = LogView::new(numbered_logs(6));
+        lines(&view, 20, 2);
+
+        view.update(LogViewMsg::ScrollUp);
+        assert!(!view.is_following());
+        assert_eq!(
+            lines(&view, 20, 2),
+            vec!["INFO  app: line 3", "INFO  app: line 4"]
+        );
+
+        view.update(LogViewMsg::PageUp);
+        view.update(LogViewMsg::PageUp);
+        assert_eq!(view.scroll(), 4);
+        assert_eq!(lines(&view, 20, 2)[0], "INFO  app: line 0");
+
+        view.update(LogViewMsg::PageDown);
+        assert_eq!(view.scroll(), 2);
+
+        view.update(LogViewMsg::Bottom);
+        assert!(view.is_following());
+        view.update(LogViewMsg::ScrollDown);
+        assert_eq!(view.scroll(), 0);
+
+        view.update(LogViewMsg::Top);
+        assert_eq!(view.scroll(), 4);
+
+        // Filtering out records pulls the scroll back into range
+        view.update(LogViewMsg::SetLevel(Level::ERROR));
+        assert_eq!(view.scroll(), 0);
+        assert!(lines(&view, 20, 2).iter().all(|line| line.is_empty()));
+    }
+
+    #[test]
+    fn test_scrolled_view_stays_put_as_records_arrive() {
+        let logs = numbered_logs(6);
+        let mut view = LogView::new(logs.clone());
+        lines(&view, 20, 2);
+
+        view.update(LogViewMsg::PageUp);
+        let before = lines(&view, 20, 2);
+        assert_eq!(before, vec!["INFO  app: line 2", "INFO  app: line 3"]);
+
+        for i in 6..10 {
+            logs.push(record(Level::INFO, &format!("line {i}")));
+        }
+        assert_eq!(lines(&view, 20, 2), before);
+        assert!(!view.is_following());
+        assert_eq!(view.scroll(), 6);
+
+        // Scrolling back to the bottom follows new records again
+        view.update(LogViewMsg::PageDown);
+        view.update(LogViewMsg::PageDown);
+        view.update(LogViewMsg::PageDown);
+        assert!(view.is_following());
+        logs.push(record(Level::INFO, "line 10"));
+        assert_eq!(lines(&view, 20, 2)[1], "INFO  app: line 10");
+    }
+
+    #[test]
+    fn test_level_colors() {
+        let theme = Theme::default();
+        let logs = LogBuffer::new(10);
+        logs.push(record(Level::ERROR, "bad"));
+        logs.push(record(Level::WARN, "iffy"));
+        let view = LogView::new(logs).with_theme(theme.clone());
+
+        let buffer = render(&view, Rect::new(0, 0, 20, 2));
+        assert_eq!(buffer[(0, 0)].fg, theme.error_text_style().fg.unwrap());
+        assert_eq!(buffer[(0, 1)].fg, theme.warning_text_style().fg.unwrap());
+        assert_eq!(buffer[(6, 1)].fg, theme.muted_style().fg.unwrap());
+    }
+
+    #[test]
+    fn test_log_view_renders_into_tiny_areas() {
+        let view = LogView::new(numbered_logs(3));
+        assert!(lines(&view, 0, 0).is_empty());
+        assert_eq!(lines(&view, 1, 1), ["I"]);
+    }
+}
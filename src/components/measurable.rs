@@ -0,0 +1,58 @@
+//! Measurable trait for components with a preferred size.
+//!
+//! The [`Measurable`] trait lets layout code ask a component how much space
+//! it would like before allocating any, so containers can be sized to their
+//! content.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use tuilib::components::Measurable;
+//! use ratatui::layout::Size;
+//!
+//! struct Label {
+//!     text: String,
+//! }
+//!
+//! impl Measurable for Label {
+//!     fn measure(&self, max: Size) -> Size {
+//!         Size::new((self.text.len() as u16).min(max.width), 1.min(max.height))
+//!     }
+//! }
+//!
+//! let label = Label { text: "Hello".into() };
+//! assert_eq!(label.measure(Size::new(80, 24)), Size::new(5, 1));
+//! ```
+
+use ratatui::layout::Size;
+
+/// Trait for components that can report their preferred size.
+///
+/// # Implementation Notes
+///
+/// - `max` is the most space the caller can offer. The returned size should
+///   not exceed it; components that do not fit should report `max` and cope
+///   with the smaller area when rendered.
+/// - Measuring must not change the component, so it can be done every frame.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::components::modal::Button;
+/// use tuilib::components::Measurable;
+/// use ratatui::layout::{Constraint, Layout, Rect, Size};
+///
+/// let button = Button::new("ok", "OK");
+/// let size = button.measure(Size::new(80, 24));
+///
+/// let [button_area, _] = Layout::horizontal([
+///     Constraint::Length(size.width),
+///     Constraint::Fill(1),
+/// ])
+/// .areas(Rect::new(0, 0, 80, size.height));
+/// assert_eq!(button_area.width, 6);
+/// ```
+pub trait Measurable {
+    /// Returns the size this component would like, within `max`.
+    fn measure(&self, max: Size) -> Size;
+}
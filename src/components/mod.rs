@@ -12,6 +12,11 @@
 //! - [`Focusable`]: Trait for components that can receive and manage focus
 //! - [`Component`]: Main trait following the Elm architecture pattern
 //!
+//! Components may also implement [`Measurable`] to report the size they
+//! would like, so layout code can size containers to their content.
+//! Wrapping a component in [`Cached`] reuses its last rendering until it
+//! changes.
+//!
 //! # Architecture
 //!
 //! Components in tuilib follow the Elm architecture:
@@ -72,13 +77,50 @@
 //! }
 //! ```
 
+mod breadcrumb;
+mod cached;
+mod command_palette;
 mod component;
 mod focusable;
+mod form;
+mod key_capture;
+mod log_view;
+mod measurable;
 pub mod modal;
+mod number_input;
 mod renderable;
+mod scroll;
+mod slider;
+mod sparkline;
+mod status_bar;
+mod table;
 mod text_input;
+mod theme_preview;
+mod toast;
+mod toggle;
+mod tooltip;
+mod tree;
+pub mod validators;
 
+pub use breadcrumb::{Breadcrumb, BreadcrumbAction, BreadcrumbMsg};
+pub use cached::Cached;
+pub use command_palette::{Command, CommandPalette, CommandPaletteAction, CommandPaletteMsg};
 pub use component::{Component, FocusableComponent, StatelessComponent};
 pub use focusable::{FocusWrapper, Focusable};
+pub use form::{Form, FormAction, FormField, FormMsg};
+pub use key_capture::{KeyCaptureAction, KeyCaptureInput, KeyCaptureMsg};
+pub use log_view::{LogView, LogViewMsg};
+pub use measurable::Measurable;
+pub use number_input::{NumberAction, NumberInput, NumberMsg};
 pub use renderable::Renderable;
-pub use text_input::{TextInput, TextInputAction, TextInputMsg, ValidationResult};
+pub use scroll::ScrollState;
+pub use slider::{Slider, SliderAction, SliderMsg};
+pub use sparkline::Sparkline;
+pub use status_bar::StatusBar;
+pub use table::{Column, Table, TableAction, TableMsg};
+pub use text_input::{TextInput, TextInputAction, TextInputMsg, ValidationResult, ValidatorFn};
+pub use theme_preview::ThemePreview;
+pub use toast::{Severity, Toast, ToastPosition, ToastStack};
+pub use toggle::{Toggle, ToggleAction, ToggleMsg};
+pub use tooltip::{Tooltip, TooltipPlacement};
+pub use tree::{NodeId, Tree, TreeAction, TreeMsg, TreeNode};
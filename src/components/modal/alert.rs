@@ -3,13 +3,13 @@
 //! A simple modal for displaying information to the user with an OK button.
 
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Paragraph};
 
 use super::{
-    calculate_modal_area, Button, ButtonAction, ButtonVariant, Modal, ModalAction, ModalConfig,
-    ModalMsg, Overlay,
+    calculate_modal_area, wrap_message, Button, ButtonAction, ButtonVariant, Modal, ModalAction,
+    ModalConfig, ModalDrag, ModalMsg, Overlay,
 };
-use crate::components::{Component, Focusable, Renderable};
+use crate::components::{Component, Focusable, Measurable, Renderable};
 use crate::focus::FocusId;
 use crate::theme::Theme;
 
@@ -49,6 +49,8 @@ pub struct AlertModal {
     theme: Option<Theme>,
     /// Overlay for background dimming.
     overlay: Overlay,
+    /// Offset from the centered position when dragged.
+    drag: ModalDrag,
 }
 
 impl AlertModal {
@@ -70,6 +72,7 @@ impl AlertModal {
             ok_button,
             theme: None,
             overlay: Overlay::new().with_shadow(true),
+            drag: ModalDrag::new(),
         }
     }
 
@@ -106,6 +109,12 @@ impl AlertModal {
         self
     }
 
+    /// Sets whether the modal can be dragged by its title bar.
+    pub fn with_draggable(mut self, value: bool) -> Self {
+        self.config = self.config.draggable(value);
+        self
+    }
+
     /// Returns the modal title.
     pub fn title(&self) -> &str {
         &self.config.title
@@ -125,6 +134,17 @@ impl AlertModal {
     pub fn config(&self) -> &ModalConfig {
         &self.config
     }
+
+    /// Returns the drag state, including the area from the last render.
+    pub fn drag(&self) -> &ModalDrag {
+        &self.drag
+    }
+
+    /// Returns the height of the modal's content for a wrapped message.
+    fn content_height(lines: &[String]) -> u16 {
+        // message + spacing + button (3 high) + spacing
+        (lines.len() as u16).max(1) + 4
+    }
 }
 
 impl Modal for AlertModal {
@@ -156,6 +176,12 @@ impl Component for AlertModal {
             }
             // AlertModal has only one button, focus navigation is a no-op
             ModalMsg::FocusNext | ModalMsg::FocusPrev => None,
+            ModalMsg::Mouse(event) => {
+                if self.config.draggable {
+                    self.drag.handle_mouse(&event);
+                }
+                None
+            }
             _ => None,
         }
     }
@@ -176,24 +202,30 @@ impl Renderable for AlertModal {
         let theme = self.theme.as_ref().cloned().unwrap_or_default();
 
         // Calculate content height: message lines + button row + spacing
-        let message_width = (area.width as f32 * self.config.width_percent) as u16;
-        let message_width = message_width.saturating_sub(4); // Account for borders/padding
-        let message_lines = (self.message.len() as u16 / message_width.max(1)) + 1;
-        let content_height = message_lines + 4; // message + spacing + button (3 high) + spacing
+        let lines = wrap_message(area, self.config.width_percent, &self.message);
+        let content_height = Self::content_height(&lines);
 
         // Render overlay if enabled
         if self.config.show_overlay {
             self.overlay.render(frame, area);
         }
 
-        // Calculate modal area
-        let modal_area = calculate_modal_area(area, self.config.width_percent, content_height);
+        // Calculate modal area, moved by any drag
+        let modal_area = self.drag.place(
+            area,
+            calculate_modal_area(area, self.config.width_percent, content_height),
+        );
 
         // Render shadow if enabled
         if self.config.show_shadow {
             self.overlay.render_shadow(frame, modal_area);
         }
 
+        // Nothing fits on a screen too small for the modal's border
+        if modal_area.is_empty() {
+            return;
+        }
+
         // Render modal background and border
         let block = Block::default()
             .title(self.config.title.as_str())
@@ -216,9 +248,9 @@ impl Renderable for AlertModal {
             .split(inner_area);
 
         // Render message
-        let message = Paragraph::new(self.message.as_str())
+        let text: Vec<Line> = lines.into_iter().map(Line::from).collect();
+        let message = Paragraph::new(text)
             .style(theme.primary_text_style())
-            .wrap(Wrap { trim: true })
             .alignment(if theme.components().modal.center_content {
                 Alignment::Center
             } else {
@@ -228,18 +260,35 @@ impl Renderable for AlertModal {
         frame.render_widget(message, chunks[0]);
 
         // Render button (centered)
-        let button_width = (self.ok_button.label().len() + 4) as u16;
+        let button_width = self.ok_button.measure(chunks[1].as_size()).width;
         let button_x = chunks[1].x + (chunks[1].width.saturating_sub(button_width)) / 2;
-        let button_area = Rect::new(button_x, chunks[1].y, button_width, 3);
+        let button_area = Rect::new(button_x, chunks[1].y, button_width, 3).intersection(chunks[1]);
 
         self.ok_button.render(frame, button_area);
     }
 }
 
+impl Measurable for AlertModal {
+    /// Measures the modal as it would be placed on a `max`-sized screen,
+    /// with the message wrapped to the modal's width.
+    fn measure(&self, max: Size) -> Size {
+        let screen = Rect::new(0, 0, max.width, max.height);
+        let lines = wrap_message(screen, self.config.width_percent, &self.message);
+        calculate_modal_area(
+            screen,
+            self.config.width_percent,
+            Self::content_height(&lines),
+        )
+        .as_size()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::ButtonMsg;
     use super::*;
+    use crate::event::{MouseButton, MouseEvent, MouseEventKind};
+    use crate::test_util::render;
 
     #[test]
     fn test_alert_modal_creation() {
@@ -327,4 +376,90 @@ mod tests {
         assert!(!modal.config().show_overlay);
         assert!(!modal.config().show_shadow);
     }
+
+    #[test]
+    fn test_alert_modal_drag_title_bar() {
+        let screen = Rect::new(0, 0, 100, 50);
+        let drag = |modal: &mut AlertModal, kind, column, row| {
+            modal.update(ModalMsg::Mouse(MouseEvent::new(kind, column, row)));
+        };
+
+        let mut modal = AlertModal::new("Test", "Message").with_draggable(true);
+        render(&modal, screen);
+        let start = modal.drag().area();
+
+        let (x, y) = (start.x + 5, start.y);
+        drag(&mut modal, MouseEventKind::Down(MouseButton::Left), x, y);
+        drag(
+            &mut modal,
+            MouseEventKind::Drag(MouseButton::Left),
+            x + 3,
+            y - 2,
+        );
+        drag(
+            &mut modal,
+            MouseEventKind::Drag(MouseButton::Left),
+            x + 7,
+            y + 4,
+        );
+        drag(
+            &mut modal,
+            MouseEventKind::Up(MouseButton::Left),
+            x + 7,
+            y + 4,
+        );
+        render(&modal, screen);
+        let moved = modal.drag().area();
+        assert_eq!((moved.x, moved.y), (start.x + 7, start.y + 4));
+        assert_eq!((moved.width, moved.height), (start.width, start.height));
+
+        // Dragging past the edge keeps the modal on screen
+        drag(
+            &mut modal,
+            MouseEventKind::Down(MouseButton::Left),
+            moved.x,
+            moved.y,
+        );
+        drag(&mut modal, MouseEventKind::Drag(MouseButton::Left), 0, 0);
+        render(&modal, screen);
+        assert_eq!(modal.drag().area().x, 0);
+        assert_eq!(modal.drag().area().y, 0);
+
+        // Modals are not draggable by default
+        let mut fixed = AlertModal::new("Test", "Message");
+        render(&fixed, screen);
+        drag(&mut fixed, MouseEventKind::Down(MouseButton::Left), x, y);
+        drag(
+            &mut fixed,
+            MouseEventKind::Drag(MouseButton::Left),
+            x + 7,
+            y + 4,
+        );
+        assert_eq!(fixed.drag().area(), start);
+    }
+
+    #[test]
+    fn test_alert_modal_measure_matches_wrapped_message() {
+        let screen = Size::new(60, 40);
+        let short = AlertModal::new("Alert", "Saved").with_width_percent(0.5);
+        let long = AlertModal::new("Alert", "x".repeat(70)).with_width_percent(0.5);
+
+        // Borders and title (3), message, spacing and button (4)
+        assert_eq!(short.measure(screen), Size::new(30, 1 + 7));
+        assert_eq!(long.measure(screen), Size::new(30, 3 + 7));
+
+        // The measured size is the size the modal is drawn at
+        render(&long, Rect::new(0, 0, screen.width, screen.height));
+        assert_eq!(long.drag().area().as_size(), long.measure(screen));
+    }
+
+    #[test]
+    fn test_alert_modal_renders_on_tiny_screens() {
+        use crate::test_util::to_lines;
+
+        let modal = AlertModal::new("Error", "Something went wrong");
+        assert!(to_lines(&render(&modal, Rect::new(0, 0, 0, 0))).is_empty());
+        assert_eq!(to_lines(&render(&modal, Rect::new(0, 0, 1, 1))), [" "]);
+        assert_eq!(to_lines(&render(&modal, Rect::new(0, 0, 10, 5))).len(), 5);
+    }
 }
@@ -2,12 +2,19 @@
 //!
 //! A simple button component with focus support, used within modal dialogs
 //! for actions like OK, Cancel, Yes, No, etc.
+//!
+//! Labels may mark a keyboard mnemonic with `&`: `"&Save"` shows "Save" with
+//! the "S" underlined and gives the button an Alt+S accelerator. Use `&&`
+//! for a literal ampersand.
 
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
+use terminput::{KeyCode, KeyEvent, KeyModifiers};
 
-use crate::components::{Component, Focusable, Renderable};
+use crate::components::{Component, Focusable, Measurable, Renderable};
 use crate::focus::FocusId;
+use crate::input::KeyBinding;
+use crate::text::display_width;
 use crate::theme::Theme;
 
 /// Visual variant for buttons.
@@ -20,6 +27,22 @@ pub enum ButtonVariant {
     Primary,
     /// Destructive action button (e.g., Delete).
     Danger,
+    /// Positive action button (e.g., Apply, Install).
+    Success,
+}
+
+impl ButtonVariant {
+    /// Returns the theme color for this variant's label, if it has one.
+    ///
+    /// Danger uses the theme's error color and Success its success color;
+    /// other variants keep the theme's normal button colors.
+    pub fn color(&self, theme: &Theme) -> Option<Color> {
+        match self {
+            ButtonVariant::Default | ButtonVariant::Primary => None,
+            ButtonVariant::Danger => Some(theme.colors().error),
+            ButtonVariant::Success => Some(theme.colors().success),
+        }
+    }
 }
 
 /// Messages that the Button can handle.
@@ -50,14 +73,18 @@ pub enum ButtonAction {
 pub struct Button {
     /// Unique identifier for this button.
     id: FocusId,
-    /// Button label text.
+    /// Button label text, with mnemonic markers removed.
     label: String,
+    /// Index (in chars) of the mnemonic character within the label.
+    mnemonic: Option<usize>,
     /// Visual variant.
     variant: ButtonVariant,
     /// Whether the button is focused.
     focused: bool,
     /// Whether the button is disabled.
     disabled: bool,
+    /// Whether the mouse pointer is over the button.
+    hovered: bool,
     /// Optional theme for styling.
     theme: Option<Theme>,
 }
@@ -68,14 +95,17 @@ impl Button {
     /// # Arguments
     ///
     /// * `id` - Unique identifier for focus management
-    /// * `label` - Text displayed on the button
+    /// * `label` - Text displayed on the button; `&` marks the mnemonic
     pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        let (label, mnemonic) = parse_mnemonic(&label.into());
         Self {
             id: FocusId::from(id.into()),
-            label: label.into(),
+            label,
+            mnemonic,
             variant: ButtonVariant::Default,
             focused: false,
             disabled: false,
+            hovered: false,
             theme: None,
         }
     }
@@ -98,6 +128,13 @@ impl Button {
         self
     }
 
+    /// Sets whether the button is enabled.
+    ///
+    /// This is the inverse of [`with_disabled`](Self::with_disabled).
+    pub fn with_enabled(self, enabled: bool) -> Self {
+        self.with_disabled(!enabled)
+    }
+
     /// Returns the button's focus ID.
     pub fn id(&self) -> &FocusId {
         &self.id
@@ -108,6 +145,26 @@ impl Button {
         &self.label
     }
 
+    /// Returns the mnemonic character, if the label marks one.
+    pub fn mnemonic(&self) -> Option<char> {
+        self.mnemonic
+            .and_then(|index| self.label.chars().nth(index))
+    }
+
+    /// Returns the accelerator key binding (Alt + mnemonic), if any.
+    ///
+    /// The binding uses the lowercase form of the mnemonic character.
+    pub fn accelerator(&self) -> Option<KeyBinding> {
+        let c = self.mnemonic()?.to_lowercase().next()?;
+        Some(KeyBinding::with_mods(KeyCode::Char(c), KeyModifiers::ALT))
+    }
+
+    /// Returns true if the key event triggers this button's accelerator.
+    pub fn matches_accelerator(&self, event: &KeyEvent) -> bool {
+        self.accelerator()
+            .is_some_and(|binding| binding.matches_ignoring_shift_case(event))
+    }
+
     /// Returns the button's variant.
     pub fn variant(&self) -> ButtonVariant {
         self.variant
@@ -122,6 +179,33 @@ impl Button {
     pub fn set_disabled(&mut self, disabled: bool) {
         self.disabled = disabled;
     }
+
+    /// Returns whether the button is enabled.
+    pub fn is_enabled(&self) -> bool {
+        !self.disabled
+    }
+
+    /// Sets the enabled state.
+    ///
+    /// A disabled button is drawn in the theme's disabled style, cannot be
+    /// focused and ignores presses.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.disabled = !enabled;
+    }
+
+    /// Returns whether the mouse pointer is over the button.
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Sets whether the mouse pointer is over the button.
+    ///
+    /// A hovered button that is not focused or disabled is drawn with the
+    /// theme's hover border style. Unless disabled, it also uses the
+    /// [`hover`](crate::theme::BorderStyles::hover) border type.
+    pub fn set_hovered(&mut self, hovered: bool) {
+        self.hovered = hovered;
+    }
 }
 
 impl Component for Button {
@@ -159,31 +243,56 @@ impl Renderable for Button {
 
         // Determine style based on state
         let (text_style, border_style) = if self.disabled {
-            (theme.button_disabled_style(), theme.border_style())
-        } else if self.focused {
-            let text_style = match self.variant {
-                ButtonVariant::Primary => theme.button_focused_style(),
-                ButtonVariant::Danger => theme.button_focused_style().fg(theme.colors().error),
-                ButtonVariant::Default => theme.button_focused_style(),
-            };
-            (text_style, theme.border_focused_style())
+            (theme.button_disabled_style(), theme.border_disabled_style())
         } else {
-            let text_style = match self.variant {
-                ButtonVariant::Primary => theme.button_normal_style(),
-                ButtonVariant::Danger => theme.button_normal_style().fg(theme.colors().error),
-                ButtonVariant::Default => theme.button_normal_style(),
+            let (text_style, border_style) = if self.focused {
+                (theme.button_focused_style(), theme.border_focused_style())
+            } else if self.hovered {
+                (theme.button_normal_style(), theme.border_hover_style())
+            } else {
+                (theme.button_normal_style(), theme.border_style())
+            };
+            let text_style = match self.variant.color(&theme) {
+                Some(color) => text_style.fg(color),
+                None => text_style,
             };
-            (text_style, theme.border_style())
+            (text_style, border_style)
+        };
+        let text_style = theme.resolve_style(&self.id, text_style);
+        let border_style = theme.resolve_border_style(&self.id, border_style);
+        let border_type = if self.disabled {
+            theme.borders().disabled
+        } else if self.hovered {
+            theme.borders().hover
+        } else {
+            theme.components().button.border_type
         };
 
         // Build block
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_type(theme.components().button.border_type)
+            .border_type(border_type)
             .border_style(border_style);
 
+        // Underline the mnemonic character, if any
+        let label = match self.mnemonic {
+            Some(index) => {
+                let mnemonic_style =
+                    text_style.add_modifier(theme.components().button.mnemonic_modifier);
+                let before: String = self.label.chars().take(index).collect();
+                let mnemonic: String = self.label.chars().skip(index).take(1).collect();
+                let after: String = self.label.chars().skip(index + 1).collect();
+                Line::from(vec![
+                    Span::styled(before, text_style),
+                    Span::styled(mnemonic, mnemonic_style),
+                    Span::styled(after, text_style),
+                ])
+            }
+            None => Line::from(self.label.as_str()),
+        };
+
         // Create paragraph with centered text
-        let paragraph = Paragraph::new(self.label.as_str())
+        let paragraph = Paragraph::new(label)
             .style(text_style)
             .alignment(Alignment::Center)
             .block(block);
@@ -192,6 +301,46 @@ impl Renderable for Button {
     }
 }
 
+impl Measurable for Button {
+    /// Measures the label plus a border and one cell of padding on each
+    /// side, three rows high.
+    fn measure(&self, max: Size) -> Size {
+        let width = u16::try_from(display_width(&self.label) + 4).unwrap_or(u16::MAX);
+        Size::new(width.min(max.width), 3.min(max.height))
+    }
+}
+
+/// Splits a label into its display text and mnemonic position.
+///
+/// The first character preceded by a single `&` is the mnemonic; `&&` is a
+/// literal `&`. A trailing `&` is dropped.
+fn parse_mnemonic(label: &str) -> (String, Option<usize>) {
+    let mut text = String::with_capacity(label.len());
+    let mut mnemonic = None;
+    let mut count = 0;
+    let mut chars = label.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.next() {
+                Some('&') => text.push('&'),
+                Some(next) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some(count);
+                    }
+                    text.push(next);
+                }
+                None => break,
+            }
+        } else {
+            text.push(c);
+        }
+        count += 1;
+    }
+
+    (text, mnemonic)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,4 +405,241 @@ mod tests {
         button.set_disabled(true);
         assert!(button.is_disabled());
     }
+
+    #[test]
+    fn test_button_render_uses_style_override() {
+        use crate::theme::ComponentStyleOverride;
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let theme =
+            Theme::dark().with_override("special", ComponentStyleOverride::new().fg(Color::Yellow));
+        let special = Button::new("special", "A").with_theme(theme.clone());
+        let plain = Button::new("plain", "B").with_theme(theme.clone());
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 6)).unwrap();
+        terminal
+            .draw(|frame| {
+                special.render(frame, Rect::new(0, 0, 5, 3));
+                plain.render(frame, Rect::new(0, 3, 5, 3));
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(2, 1)].fg, Color::Yellow);
+        assert_eq!(buffer[(2, 4)].fg, theme.colors().text_primary);
+    }
+
+    #[test]
+    fn test_button_mnemonic() {
+        let button = Button::new("save", "&Save");
+        assert_eq!(button.label(), "Save");
+        assert_eq!(button.mnemonic(), Some('S'));
+        assert_eq!(
+            button.accelerator(),
+            Some(KeyBinding::with_mods(KeyCode::Char('s'), KeyModifiers::ALT))
+        );
+
+        let event = |code, modifiers| KeyEvent {
+            code,
+            modifiers,
+            kind: terminput::KeyEventKind::Press,
+            state: terminput::KeyEventState::NONE,
+        };
+        assert!(button.matches_accelerator(&event(KeyCode::Char('s'), KeyModifiers::ALT)));
+        assert!(!button.matches_accelerator(&event(KeyCode::Char('s'), KeyModifiers::NONE)));
+
+        // Only the first marker counts
+        let button = Button::new("open", "Sa&ve &As");
+        assert_eq!(button.label(), "Save As");
+        assert_eq!(button.mnemonic(), Some('v'));
+
+        let button = Button::new("ok", "OK");
+        assert_eq!(button.mnemonic(), None);
+        assert_eq!(button.accelerator(), None);
+    }
+
+    #[test]
+    fn test_button_literal_ampersand() {
+        let button = Button::new("both", "Save && &Quit");
+        assert_eq!(button.label(), "Save & Quit");
+        assert_eq!(button.mnemonic(), Some('Q'));
+
+        let button = Button::new("amp", "A&&B&");
+        assert_eq!(button.label(), "A&B");
+        assert_eq!(button.mnemonic(), None);
+    }
+
+    #[test]
+    fn test_button_render_underlines_mnemonic() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let button = Button::new("save", "&Save");
+        let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+        terminal
+            .draw(|frame| button.render(frame, Rect::new(0, 0, 8, 3)))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row: String = (1..7).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert_eq!(row, " Save ");
+        assert!(buffer[(2, 1)].modifier.contains(Modifier::UNDERLINED));
+        assert!(!buffer[(3, 1)].modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn test_button_variant_colors() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let theme = Theme::dark();
+        assert_eq!(
+            ButtonVariant::Danger.color(&theme),
+            Some(theme.colors().error)
+        );
+        assert_eq!(
+            ButtonVariant::Success.color(&theme),
+            Some(theme.colors().success)
+        );
+        assert_eq!(ButtonVariant::Primary.color(&theme), None);
+
+        let mut danger = Button::new("delete", "Delete")
+            .with_variant(ButtonVariant::Danger)
+            .with_theme(theme.clone());
+        let success = Button::new("apply", "Apply")
+            .with_variant(ButtonVariant::Success)
+            .with_theme(theme.clone());
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 6)).unwrap();
+        terminal
+            .draw(|frame| {
+                danger.render(frame, Rect::new(0, 0, 10, 3));
+                success.render(frame, Rect::new(0, 3, 10, 3));
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(3, 1)].fg, theme.colors().error);
+        assert_eq!(buffer[(3, 4)].fg, theme.colors().success);
+
+        // Focus keeps the variant color
+        danger.set_focused(true);
+        terminal
+            .draw(|frame| danger.render(frame, Rect::new(0, 0, 10, 3)))
+            .unwrap();
+        assert_eq!(terminal.backend().buffer()[(3, 1)].fg, theme.colors().error);
+    }
+
+    #[test]
+    fn test_button_enabled() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let theme = Theme::dark();
+        let mut button = Button::new("submit", "Submit")
+            .with_theme(theme.clone())
+            .with_enabled(false);
+        assert!(!button.is_enabled());
+        assert!(!button.can_focus());
+        assert_eq!(button.update(ButtonMsg::Press), None);
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+        terminal
+            .draw(|frame| button.render(frame, Rect::new(0, 0, 10, 3)))
+            .unwrap();
+        assert_eq!(
+            terminal.backend().buffer()[(2, 1)].fg,
+            theme.button_disabled_style().fg.unwrap()
+        );
+
+        button.set_enabled(true);
+        assert!(button.is_enabled());
+        assert!(button.can_focus());
+        assert_eq!(button.update(ButtonMsg::Press), Some(ButtonAction::Pressed));
+    }
+
+    #[test]
+    fn test_button_border_follows_state() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let theme = Theme::dark();
+        let mut button = Button::new("submit", "Submit").with_theme(theme.clone());
+        let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+        let mut border = |button: &Button| {
+            terminal
+                .draw(|frame| button.render(frame, Rect::new(0, 0, 10, 3)))
+                .unwrap();
+            terminal.backend().buffer()[(0, 0)].style()
+        };
+
+        assert_eq!(border(&button).fg, theme.border_style().fg);
+
+        button.set_hovered(true);
+        assert!(button.is_hovered());
+        let hovered = border(&button);
+        assert_eq!(hovered.fg, theme.border_hover_style().fg);
+        assert!(hovered.add_modifier.contains(Modifier::BOLD));
+
+        // Focus and disabled both take precedence over hover
+        button.set_focused(true);
+        assert_eq!(border(&button).fg, theme.border_focused_style().fg);
+        button.set_focused(false);
+        button.set_disabled(true);
+        assert_eq!(border(&button).fg, Some(theme.colors().text_disabled));
+    }
+
+    #[test]
+    fn test_hover_uses_hover_border_type() {
+        use crate::theme::ThemeBuilder;
+        use ratatui::backend::TestBackend;
+        use ratatui::widgets::BorderType;
+        use ratatui::Terminal;
+
+        let theme = ThemeBuilder::new()
+            .hover_border_type(BorderType::Double)
+            .disabled_border_type(BorderType::Thick)
+            .build();
+        let mut button = Button::new("submit", "Submit").with_theme(theme);
+        let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+        let mut corner = |button: &Button| {
+            terminal
+                .draw(|frame| button.render(frame, Rect::new(0, 0, 10, 3)))
+                .unwrap();
+            terminal.backend().buffer()[(0, 0)].symbol().to_string()
+        };
+
+        assert_eq!(corner(&button), "╭");
+        button.set_hovered(true);
+        assert_eq!(corner(&button), "╔");
+        button.set_focused(true);
+        assert_eq!(corner(&button), "╔");
+        // The disabled border type takes precedence over hover
+        button.set_disabled(true);
+        assert_eq!(corner(&button), "┏");
+    }
+
+    #[test]
+    fn test_button_measure() {
+        let max = Size::new(80, 24);
+
+        // Label plus a border and a cell of padding on each side
+        let button = Button::new("save", "&Save");
+        assert_eq!(button.measure(max), Size::new("Save".len() as u16 + 4, 3));
+
+        let wide = Button::new("ok", "確認");
+        assert_eq!(wide.measure(max), Size::new(8, 3));
+
+        assert_eq!(button.measure(Size::new(5, 2)), Size::new(5, 2));
+    }
+
+    #[test]
+    fn test_button_renders_into_tiny_areas() {
+        use crate::test_util::{render, to_lines};
+
+        let mut button = Button::new("ok", "OK");
+        button.set_focused(true);
+        assert!(to_lines(&render(&button, Rect::new(0, 0, 0, 0))).is_empty());
+        assert_eq!(to_lines(&render(&button, Rect::new(0, 0, 1, 1))), ["╭"]);
+    }
 }
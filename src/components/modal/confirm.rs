@@ -3,21 +3,45 @@
 //! A modal for getting yes/no confirmation from the user.
 
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Paragraph};
 
 use super::{
-    calculate_modal_area, Button, ButtonAction, ButtonVariant, Modal, ModalAction, ModalConfig,
-    ModalMsg, Overlay,
+    calculate_modal_area, wrap_message, Button, ButtonAction, ButtonVariant, Modal, ModalAction,
+    ModalConfig, ModalDrag, ModalMsg, Overlay,
 };
-use crate::components::{Component, Focusable, Renderable};
-use crate::focus::FocusId;
+use crate::components::{Component, Focusable, Measurable, Renderable};
+use crate::focus::{FocusId, FocusTrap};
 use crate::theme::Theme;
 
+/// A button of a [`ConfirmModal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfirmChoice {
+    /// The confirmation button.
+    #[default]
+    Yes,
+    /// The cancellation button.
+    No,
+}
+
+impl ConfirmChoice {
+    /// Returns the button's index within the modal.
+    fn index(self) -> usize {
+        match self {
+            ConfirmChoice::Yes => 0,
+            ConfirmChoice::No => 1,
+        }
+    }
+}
+
 /// A confirm modal dialog with Yes/No buttons.
 ///
 /// Confirm modals are used to get user confirmation before performing
 /// an action. Returns `true` if the user confirms, `false` if they cancel.
 ///
+/// Enter activates the focused button, which starts out as the
+/// [default](Self::with_default). Escape closes the modal without a result
+/// unless [`with_escape_result`](Self::with_escape_result) says otherwise.
+///
 /// # Example
 ///
 /// ```rust
@@ -53,9 +77,17 @@ pub struct ConfirmModal {
     theme: Option<Theme>,
     /// Overlay for background dimming.
     overlay: Overlay,
+    /// Offset from the centered position when dragged.
+    drag: ModalDrag,
     /// Custom labels for buttons.
     yes_label: String,
     no_label: String,
+    /// Visual variant of the Yes button.
+    confirm_variant: ButtonVariant,
+    /// The button focused initially and activated when neither is focused.
+    default_choice: ConfirmChoice,
+    /// What Escape reports; `None` closes without a result.
+    escape_result: Option<bool>,
 }
 
 impl ConfirmModal {
@@ -81,8 +113,12 @@ impl ConfirmModal {
             focused_button: 0,
             theme: None,
             overlay: Overlay::new().with_shadow(true),
+            drag: ModalDrag::new(),
             yes_label: "Yes".to_string(),
             no_label: "No".to_string(),
+            confirm_variant: ButtonVariant::Primary,
+            default_choice: ConfirmChoice::Yes,
+            escape_result: None,
         }
     }
 
@@ -120,6 +156,12 @@ impl ConfirmModal {
         self
     }
 
+    /// Sets whether the modal can be dragged by its title bar.
+    pub fn with_draggable(mut self, value: bool) -> Self {
+        self.config = self.config.draggable(value);
+        self
+    }
+
     /// Sets custom button labels.
     ///
     /// # Arguments
@@ -134,7 +176,7 @@ impl ConfirmModal {
         self.yes_label = yes_label.into();
         self.no_label = no_label.into();
         self.yes_button =
-            Button::new("confirm-yes", self.yes_label.clone()).with_variant(ButtonVariant::Primary);
+            Button::new("confirm-yes", self.yes_label.clone()).with_variant(self.confirm_variant);
         self.no_button =
             Button::new("confirm-no", self.no_label.clone()).with_variant(ButtonVariant::Default);
 
@@ -147,6 +189,60 @@ impl ConfirmModal {
         self
     }
 
+    /// Sets the variant of the Yes button (default: Primary).
+    ///
+    /// Use [`ButtonVariant::Danger`] for destructive confirmations such as
+    /// deleting a file.
+    pub fn with_confirm_variant(mut self, variant: ButtonVariant) -> Self {
+        self.confirm_variant = variant;
+        self.yes_button = self.yes_button.with_variant(variant);
+        self
+    }
+
+    /// Sets the default button (default: Yes).
+    ///
+    /// The default button is focused when the modal opens or regains focus,
+    /// so Enter activates it. It is also what Enter activates when neither
+    /// button has focus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tuilib::components::Component;
+    /// use tuilib::components::modal::{ConfirmChoice, ConfirmModal, ModalAction, ModalMsg};
+    ///
+    /// let mut modal = ConfirmModal::new("Delete", "Delete this file?")
+    ///     .with_default(ConfirmChoice::No);
+    /// assert_eq!(modal.update(ModalMsg::Confirm), Some(ModalAction::Confirm(false)));
+    /// ```
+    pub fn with_default(mut self, choice: ConfirmChoice) -> Self {
+        self.default_choice = choice;
+        self.focused_button = choice.index();
+        self.update_focus();
+        self
+    }
+
+    /// Sets what Escape reports (default: `None`).
+    ///
+    /// `Some(result)` makes Escape report [`ModalAction::Confirm`] with that
+    /// result, as if the matching button were pressed; `None` reports
+    /// [`ModalAction::Close`]. Escape does nothing while
+    /// [`with_close_on_escape`](Self::with_close_on_escape) is off.
+    pub fn with_escape_result(mut self, result: Option<bool>) -> Self {
+        self.escape_result = result;
+        self
+    }
+
+    /// Returns the default button.
+    pub fn default_choice(&self) -> ConfirmChoice {
+        self.default_choice
+    }
+
+    /// Returns what Escape reports; `None` means it closes without a result.
+    pub fn escape_result(&self) -> Option<bool> {
+        self.escape_result
+    }
+
     /// Returns the modal title.
     pub fn title(&self) -> &str {
         &self.config.title
@@ -177,6 +273,11 @@ impl ConfirmModal {
         &self.config
     }
 
+    /// Returns the drag state, including the area from the last render.
+    pub fn drag(&self) -> &ModalDrag {
+        &self.drag
+    }
+
     /// Updates the focus state of buttons based on focused_button index.
     fn update_focus(&mut self) {
         self.yes_button.set_focused(self.focused_button == 0);
@@ -194,12 +295,31 @@ impl ConfirmModal {
         self.focused_button = if self.focused_button == 0 { 1 } else { 0 };
         self.update_focus();
     }
+
+    /// Returns the height of the modal's content for a wrapped message.
+    fn content_height(lines: &[String]) -> u16 {
+        // message + spacing + button (3 high) + spacing
+        (lines.len() as u16).max(1) + 4
+    }
 }
 
 impl Modal for ConfirmModal {
     fn focus_ids(&self) -> Vec<FocusId> {
         vec![self.yes_button.id().clone(), self.no_button.id().clone()]
     }
+
+    /// Creates a focus trap that starts on the default button.
+    fn create_focus_trap(&self) -> FocusTrap {
+        let default = match self.default_choice {
+            ConfirmChoice::Yes => self.yes_button.id(),
+            ConfirmChoice::No => self.no_button.id(),
+        };
+        let mut trap = FocusTrap::new().with_initial(default.clone());
+        for (i, id) in self.focus_ids().into_iter().enumerate() {
+            trap.register(id, i as i32);
+        }
+        trap
+    }
 }
 
 impl Component for ConfirmModal {
@@ -209,19 +329,22 @@ impl Component for ConfirmModal {
     fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
         match msg {
             ModalMsg::Close => {
-                if self.config.close_on_escape {
-                    Some(ModalAction::Close)
-                } else {
-                    None
+                if !self.config.close_on_escape {
+                    return None;
+                }
+                match self.escape_result {
+                    Some(result) => Some(ModalAction::Confirm(result)),
+                    None => Some(ModalAction::Close),
                 }
             }
             ModalMsg::Confirm => {
-                // Confirm the currently focused button
-                if self.focused_button == 0 {
-                    Some(ModalAction::Confirm(true))
+                // Confirm the focused button, or the default if neither is
+                let index = if self.is_focused() {
+                    self.focused_button
                 } else {
-                    Some(ModalAction::Confirm(false))
-                }
+                    self.default_choice.index()
+                };
+                Some(ModalAction::Confirm(index == 0))
             }
             ModalMsg::FocusNext => {
                 self.focus_next();
@@ -247,6 +370,12 @@ impl Component for ConfirmModal {
                     None
                 }
             }
+            ModalMsg::Mouse(event) => {
+                if self.config.draggable {
+                    self.drag.handle_mouse(&event);
+                }
+                None
+            }
             _ => None,
         }
     }
@@ -259,8 +388,8 @@ impl Focusable for ConfirmModal {
 
     fn set_focused(&mut self, focused: bool) {
         if focused {
-            // Focus the first button when modal gains focus
-            self.focused_button = 0;
+            // Focus the default button when modal gains focus
+            self.focused_button = self.default_choice.index();
             self.update_focus();
         } else {
             self.yes_button.set_focused(false);
@@ -274,24 +403,30 @@ impl Renderable for ConfirmModal {
         let theme = self.theme.as_ref().cloned().unwrap_or_default();
 
         // Calculate content height: message lines + button row + spacing
-        let message_width = (area.width as f32 * self.config.width_percent) as u16;
-        let message_width = message_width.saturating_sub(4); // Account for borders/padding
-        let message_lines = (self.message.len() as u16 / message_width.max(1)) + 1;
-        let content_height = message_lines + 4; // message + spacing + button (3 high) + spacing
+        let lines = wrap_message(area, self.config.width_percent, &self.message);
+        let content_height = Self::content_height(&lines);
 
         // Render overlay if enabled
         if self.config.show_overlay {
             self.overlay.render(frame, area);
         }
 
-        // Calculate modal area
-        let modal_area = calculate_modal_area(area, self.config.width_percent, content_height);
+        // Calculate modal area, moved by any drag
+        let modal_area = self.drag.place(
+            area,
+            calculate_modal_area(area, self.config.width_percent, content_height),
+        );
 
         // Render shadow if enabled
         if self.config.show_shadow {
             self.overlay.render_shadow(frame, modal_area);
         }
 
+        // Nothing fits on a screen too small for the modal's border
+        if modal_area.is_empty() {
+            return;
+        }
+
         // Render modal background and border
         let block = Block::default()
             .title(self.config.title.as_str())
@@ -314,9 +449,9 @@ impl Renderable for ConfirmModal {
             .split(inner_area);
 
         // Render message
-        let message = Paragraph::new(self.message.as_str())
+        let text: Vec<Line> = lines.into_iter().map(Line::from).collect();
+        let message = Paragraph::new(text)
             .style(theme.primary_text_style())
-            .wrap(Wrap { trim: true })
             .alignment(if theme.components().modal.center_content {
                 Alignment::Center
             } else {
@@ -326,26 +461,43 @@ impl Renderable for ConfirmModal {
         frame.render_widget(message, chunks[0]);
 
         // Render buttons (centered, side by side)
-        let yes_width = (self.yes_label.len() + 4) as u16;
-        let no_width = (self.no_label.len() + 4) as u16;
+        let yes_width = self.yes_button.measure(chunks[1].as_size()).width;
+        let no_width = self.no_button.measure(chunks[1].as_size()).width;
         let button_spacing = 2u16;
         let total_button_width = yes_width + button_spacing + no_width;
 
         let buttons_x = chunks[1].x + (chunks[1].width.saturating_sub(total_button_width)) / 2;
 
-        let yes_area = Rect::new(buttons_x, chunks[1].y, yes_width, 3);
+        // Clip to the button row, which may be short or narrow on small screens
+        let yes_area = Rect::new(buttons_x, chunks[1].y, yes_width, 3).intersection(chunks[1]);
         let no_area = Rect::new(
             buttons_x + yes_width + button_spacing,
             chunks[1].y,
             no_width,
             3,
-        );
+        )
+        .intersection(chunks[1]);
 
         self.yes_button.render(frame, yes_area);
         self.no_button.render(frame, no_area);
     }
 }
 
+impl Measurable for ConfirmModal {
+    /// Measures the modal as it would be placed on a `max`-sized screen,
+    /// with the message wrapped to the modal's width.
+    fn measure(&self, max: Size) -> Size {
+        let screen = Rect::new(0, 0, max.width, max.height);
+        let lines = wrap_message(screen, self.config.width_percent, &self.message);
+        calculate_modal_area(
+            screen,
+            self.config.width_percent,
+            Self::content_height(&lines),
+        )
+        .as_size()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::ButtonMsg;
@@ -374,6 +526,37 @@ mod tests {
         assert_eq!(modal.no_button().label(), "Keep");
     }
 
+    #[test]
+    fn test_confirm_modal_confirm_variant() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let theme = Theme::dark();
+        let modal = ConfirmModal::new("Delete", "Delete file?")
+            .with_theme(theme.clone())
+            .with_confirm_variant(ButtonVariant::Danger)
+            .with_labels("Delete", "Keep");
+        assert_eq!(modal.yes_button().variant(), ButtonVariant::Danger);
+        assert_eq!(modal.no_button().variant(), ButtonVariant::Default);
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+        terminal
+            .draw(|frame| modal.render(frame, frame.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let position = (0..20)
+            .flat_map(|y| (0..54).map(move |x| (x, y)))
+            .find(|&(x, y)| {
+                (0..6)
+                    .map(|i| buffer[(x + i, y)].symbol())
+                    .collect::<String>()
+                    == "Delete"
+                    && buffer[(x, y)].fg == theme.colors().error
+            });
+        assert!(position.is_some(), "Delete button not drawn in error color");
+    }
+
     #[test]
     fn test_confirm_modal_close_on_escape() {
         let mut modal = ConfirmModal::new("Test", "Message");
@@ -482,4 +665,65 @@ mod tests {
         let action = modal.update(ModalMsg::ButtonMsg(1, ButtonMsg::Press));
         assert_eq!(action, Some(ModalAction::Confirm(false)));
     }
+
+    #[test]
+    fn test_confirm_modal_default_choice() {
+        let mut modal = ConfirmModal::new("Delete", "Delete?").with_default(ConfirmChoice::No);
+        assert_eq!(modal.default_choice(), ConfirmChoice::No);
+        assert_eq!(modal.focused_button_index(), 1);
+        assert!(modal.no_button().is_focused());
+        assert!(!modal.yes_button().is_focused());
+
+        // Enter activates the pre-focused default
+        assert_eq!(
+            modal.update(ModalMsg::Confirm),
+            Some(ModalAction::Confirm(false))
+        );
+
+        // With neither button focused, Enter still activates the default
+        modal.update(ModalMsg::FocusNext);
+        modal.set_focused(false);
+        assert_eq!(
+            modal.update(ModalMsg::Confirm),
+            Some(ModalAction::Confirm(false))
+        );
+
+        // Regaining focus returns to the default, as does the focus trap
+        modal.set_focused(true);
+        assert_eq!(modal.focused_button_index(), 1);
+        let trap = modal.create_focus_trap();
+        assert_eq!(trap.initial(), Some(modal.no_button().id()));
+    }
+
+    #[test]
+    fn test_confirm_modal_escape_result() {
+        let mut modal = ConfirmModal::new("Test", "Message");
+        assert_eq!(modal.escape_result(), None);
+        assert_eq!(modal.update(ModalMsg::Close), Some(ModalAction::Close));
+
+        let mut modal = modal.with_escape_result(Some(false));
+        assert_eq!(
+            modal.update(ModalMsg::Close),
+            Some(ModalAction::Confirm(false))
+        );
+
+        let mut modal = modal.with_escape_result(Some(true));
+        assert_eq!(
+            modal.update(ModalMsg::Close),
+            Some(ModalAction::Confirm(true))
+        );
+
+        let mut modal = modal.with_close_on_escape(false);
+        assert_eq!(modal.update(ModalMsg::Close), None);
+    }
+
+    #[test]
+    fn test_confirm_modal_renders_on_tiny_screens() {
+        use crate::test_util::{render, to_lines};
+
+        let modal = ConfirmModal::new("Delete", "Delete this file?");
+        assert!(to_lines(&render(&modal, Rect::new(0, 0, 0, 0))).is_empty());
+        assert_eq!(to_lines(&render(&modal, Rect::new(0, 0, 1, 1))), [" "]);
+        assert_eq!(to_lines(&render(&modal, Rect::new(0, 0, 10, 5))).len(), 5);
+    }
 }
@@ -0,0 +1,349 @@
+//! Custom modal dialog.
+//!
+//! A modal shell around arbitrary content, providing the overlay, centering,
+//! border, focus trap and escape handling shared by the built-in modals.
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders};
+
+use super::{calculate_modal_area, Modal, ModalAction, ModalConfig, ModalDrag, Overlay};
+use crate::components::{Component, Focusable, Measurable, Renderable};
+use crate::event::MouseEvent;
+use crate::focus::FocusId;
+use crate::theme::Theme;
+
+/// Content height used until one is set with
+/// [`with_content_height`](CustomModal::with_content_height).
+const DEFAULT_CONTENT_HEIGHT: u16 = 5;
+
+/// Messages that a [`CustomModal`] can handle.
+#[derive(Debug, Clone)]
+pub enum CustomModalMsg<M> {
+    /// Close the modal (cancel/escape).
+    Close,
+    /// Forward a message to the content.
+    Content(M),
+    /// A mouse event, used to drag the modal if it is draggable.
+    Mouse(MouseEvent),
+}
+
+/// Actions that a [`CustomModal`] can emit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomModalAction<A> {
+    /// An action of the modal itself, such as [`ModalAction::Close`].
+    Modal(ModalAction),
+    /// An action emitted by the content.
+    Content(A),
+}
+
+/// A modal dialog wrapping custom content.
+///
+/// The content is drawn inside the modal's border and receives the messages
+/// sent as [`CustomModalMsg::Content`]; its actions come back as
+/// [`CustomModalAction::Content`]. Escape handling, the overlay and shadow,
+/// and dragging follow the modal's [`ModalConfig`].
+///
+/// The modal cannot tell how tall arbitrary content is, so the height given
+/// to the content is set with [`with_content_height`](Self::with_content_height).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::prelude::*;
+/// use tuilib::components::modal::{
+///     CustomModal, CustomModalAction, CustomModalMsg, ModalAction, ModalConfig,
+/// };
+/// use tuilib::components::{Component, Renderable};
+///
+/// struct Counter(u32);
+///
+/// impl Component for Counter {
+///     type Message = ();
+///     type Action = u32;
+///
+///     fn update(&mut self, _msg: ()) -> Option<u32> {
+///         self.0 += 1;
+///         Some(self.0)
+///     }
+/// }
+///
+/// impl Renderable for Counter {
+///     fn render(&self, frame: &mut Frame, area: Rect) {
+///         frame.render_widget(format!("Count: {}", self.0), area);
+///     }
+/// }
+///
+/// let mut modal = CustomModal::new(Counter(0))
+///     .with_config(ModalConfig::new("Counter"))
+///     .with_content_height(1);
+///
+/// let action = modal.update(CustomModalMsg::Content(()));
+/// assert_eq!(action, Some(CustomModalAction::Content(1)));
+///
+/// let action = modal.update(CustomModalMsg::Close);
+/// assert_eq!(action, Some(CustomModalAction::Modal(ModalAction::Close)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CustomModal<C> {
+    /// Modal configuration.
+    config: ModalConfig,
+    /// The content drawn inside the modal.
+    content: C,
+    /// Height of the area given to the content.
+    content_height: u16,
+    /// Focus IDs of the focusable elements in the content.
+    focus_ids: Vec<FocusId>,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+    /// Overlay for background dimming.
+    overlay: Overlay,
+    /// Offset from the centered position when dragged.
+    drag: ModalDrag,
+}
+
+impl<C: Component + Renderable> CustomModal<C> {
+    /// Creates a modal around the given content, with the default
+    /// [`ModalConfig`].
+    pub fn new(content: C) -> Self {
+        Self {
+            config: ModalConfig::default(),
+            content,
+            content_height: DEFAULT_CONTENT_HEIGHT,
+            focus_ids: vec![FocusId::new("custom-modal")],
+            theme: None,
+            overlay: Overlay::new().with_shadow(true),
+            drag: ModalDrag::new(),
+        }
+    }
+
+    /// Sets the modal configuration.
+    pub fn with_config(mut self, config: ModalConfig) -> Self {
+        self.overlay = self.overlay.with_shadow(config.show_shadow);
+        self.config = config;
+        self
+    }
+
+    /// Sets the height of the area given to the content.
+    pub fn with_content_height(mut self, height: u16) -> Self {
+        self.content_height = height;
+        self
+    }
+
+    /// Sets the focus IDs of the focusable elements in the content.
+    ///
+    /// These make up the modal's [focus trap](Modal::create_focus_trap), in
+    /// the given order. By default the trap holds a single
+    /// `"custom-modal"` ID, so that focus stays on the modal.
+    pub fn with_focus_ids(mut self, ids: impl IntoIterator<Item = FocusId>) -> Self {
+        self.focus_ids = ids.into_iter().collect();
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.overlay = self.overlay.with_theme(theme.clone());
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the modal title.
+    pub fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    /// Returns the modal configuration.
+    pub fn config(&self) -> &ModalConfig {
+        &self.config
+    }
+
+    /// Returns the content.
+    pub fn content(&self) -> &C {
+        &self.content
+    }
+
+    /// Returns the content for modification.
+    pub fn content_mut(&mut self) -> &mut C {
+        &mut self.content
+    }
+
+    /// Consumes the modal, returning its content.
+    pub fn into_content(self) -> C {
+        self.content
+    }
+
+    /// Returns the drag state, including the area from the last render.
+    pub fn drag(&self) -> &ModalDrag {
+        &self.drag
+    }
+}
+
+impl<C: Component + Renderable> Modal for CustomModal<C> {
+    fn focus_ids(&self) -> Vec<FocusId> {
+        self.focus_ids.clone()
+    }
+}
+
+impl<C: Component + Renderable> Component for CustomModal<C> {
+    type Message = CustomModalMsg<C::Message>;
+    type Action = CustomModalAction<C::Action>;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        match msg {
+            CustomModalMsg::Close => self
+                .config
+                .close_on_escape
+                .then_some(CustomModalAction::Modal(ModalAction::Close)),
+            CustomModalMsg::Content(msg) => {
+                self.content.update(msg).map(CustomModalAction::Content)
+            }
+            CustomModalMsg::Mouse(event) => {
+                if self.config.draggable {
+                    self.drag.handle_mouse(&event);
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<C: Component + Renderable + Focusable> Focusable for CustomModal<C> {
+    fn is_focused(&self) -> bool {
+        self.content.is_focused()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.content.set_focused(focused);
+    }
+}
+
+impl<C: Component + Renderable> Renderable for CustomModal<C> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+
+        // Render overlay if enabled
+        if self.config.show_overlay {
+            self.overlay.render(frame, area);
+        }
+
+        // Calculate modal area, moved by any drag
+        let modal_area = self.drag.place(
+            area,
+            calculate_modal_area(area, self.config.width_percent, self.content_height),
+        );
+
+        // Render shadow if enabled
+        if self.config.show_shadow {
+            self.overlay.render_shadow(frame, modal_area);
+        }
+
+        // Nothing fits on a screen too small for the modal's border
+        if modal_area.is_empty() {
+            return;
+        }
+
+        // Render modal background and border
+        let block = Block::default()
+            .title(self.config.title.as_str())
+            .title_style(theme.modal_title_style())
+            .borders(Borders::ALL)
+            .border_type(theme.components().modal.border_type)
+            .border_style(theme.border_focused_style())
+            .style(theme.modal_content_style());
+
+        let inner_area = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        if !inner_area.is_empty() {
+            self.content.render(frame, inner_area);
+        }
+    }
+}
+
+impl<C: Component + Renderable> Measurable for CustomModal<C> {
+    /// Measures the modal as it would be placed on a `max`-sized screen.
+    fn measure(&self, max: Size) -> Size {
+        let screen = Rect::new(0, 0, max.width, max.height);
+        calculate_modal_area(screen, self.config.width_percent, self.content_height).as_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{render, to_lines};
+
+    /// Content that records the messages it receives.
+    #[derive(Debug, Default)]
+    struct Notes {
+        lines: Vec<String>,
+    }
+
+    impl Component for Notes {
+        type Message = String;
+        type Action = usize;
+
+        fn update(&mut self, msg: String) -> Option<usize> {
+            self.lines.push(msg);
+            Some(self.lines.len())
+        }
+    }
+
+    impl Renderable for Notes {
+        fn render(&self, frame: &mut Frame, area: Rect) {
+            let text: Vec<Line> = self.lines.iter().map(|l| Line::from(l.as_str())).collect();
+            frame.render_widget(ratatui::widgets::Paragraph::new(text), area);
+        }
+    }
+
+    #[test]
+    fn test_custom_modal_escape_closes() {
+        let mut modal = CustomModal::new(Notes::default());
+        assert_eq!(
+            modal.update(CustomModalMsg::Close),
+            Some(CustomModalAction::Modal(ModalAction::Close))
+        );
+
+        let mut modal = CustomModal::new(Notes::default())
+            .with_config(ModalConfig::new("Notes").close_on_escape(false));
+        assert_eq!(modal.update(CustomModalMsg::Close), None);
+        assert_eq!(modal.title(), "Notes");
+    }
+
+    #[test]
+    fn test_custom_modal_forwards_content_messages() {
+        let mut modal = CustomModal::new(Notes::default())
+            .with_config(
+                ModalConfig::new("Notes")
+                    .show_overlay(false)
+                    .show_shadow(false),
+            )
+            .with_content_height(2);
+
+        assert_eq!(
+            modal.update(CustomModalMsg::Content("first".to_string())),
+            Some(CustomModalAction::Content(1))
+        );
+        modal.update(CustomModalMsg::Content("second".to_string()));
+        assert_eq!(modal.content().lines, ["first", "second"]);
+
+        // Content is drawn inside the centered border
+        let buffer = render(&modal, Rect::new(0, 0, 30, 9));
+        assert_eq!(modal.measure(Size::new(30, 9)), Size::new(20, 5));
+        let lines = to_lines(&buffer);
+        assert_eq!(&lines[2][5..], "╔Notes═════════════╗     ");
+        assert!(lines[3].contains("║first"));
+        assert!(lines[4].contains("║second"));
+    }
+
+    #[test]
+    fn test_custom_modal_focus_trap() {
+        let modal = CustomModal::new(Notes::default());
+        assert_eq!(modal.focus_ids(), vec![FocusId::new("custom-modal")]);
+
+        let modal = CustomModal::new(Notes::default())
+            .with_focus_ids([FocusId::new("name"), FocusId::new("ok")]);
+        let trap = modal.create_focus_trap();
+        assert!(trap.contains(&FocusId::new("name")));
+        assert!(trap.contains(&FocusId::new("ok")));
+    }
+}
@@ -0,0 +1,190 @@
+//! Mouse dragging for modal dialogs.
+//!
+//! A [`ModalDrag`] tracks how far a modal has been dragged from its centered
+//! position. Modals built with `with_draggable(true)` forward
+//! [`ModalMsg::Mouse`](super::ModalMsg::Mouse) events to it: pressing the
+//! left button on the title bar starts a drag, moving the mouse moves the
+//! modal, and releasing the button drops it. The modal is always kept on
+//! screen.
+
+use std::cell::Cell;
+
+use ratatui::layout::Rect;
+
+use crate::event::{MouseButton, MouseEvent, MouseEventKind};
+
+/// Drag state for a modal dialog.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Rect;
+/// use tuilib::components::modal::ModalDrag;
+/// use tuilib::event::{MouseButton, MouseEvent, MouseEventKind};
+///
+/// let mut drag = ModalDrag::new();
+/// let screen = Rect::new(0, 0, 80, 24);
+/// let modal = drag.place(screen, Rect::new(20, 8, 40, 8));
+///
+/// // Press on the title bar, move, and release
+/// drag.handle_mouse(&MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 30, 8));
+/// drag.handle_mouse(&MouseEvent::new(MouseEventKind::Drag(MouseButton::Left), 35, 10));
+/// drag.handle_mouse(&MouseEvent::new(MouseEventKind::Up(MouseButton::Left), 35, 10));
+///
+/// assert_eq!(drag.offset(), (5, 2));
+/// assert_eq!(drag.area(), Rect::new(25, 10, 40, 8));
+/// assert_ne!(drag.area(), modal);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ModalDrag {
+    /// Offset from the centered position, in cells.
+    offset: (i32, i32),
+    /// Where the drag started and the offset at that moment.
+    grab: Option<((u16, u16), (i32, i32))>,
+    /// Screen area from the last placement.
+    screen: Cell<Rect>,
+    /// Centered modal area from the last placement.
+    centered: Cell<Rect>,
+}
+
+impl ModalDrag {
+    /// Creates drag state with the modal centered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the offset from the centered position, kept within the
+    /// screen as of the last placement.
+    pub fn offset(&self) -> (i32, i32) {
+        self.clamp(self.offset)
+    }
+
+    /// Returns true while a drag is in progress.
+    pub fn is_dragging(&self) -> bool {
+        self.grab.is_some()
+    }
+
+    /// Returns the modal area from the last placement, including the offset.
+    pub fn area(&self) -> Rect {
+        let centered = self.centered.get();
+        let (dx, dy) = self.offset();
+        Rect::new(
+            (centered.x as i32 + dx) as u16,
+            (centered.y as i32 + dy) as u16,
+            centered.width,
+            centered.height,
+        )
+    }
+
+    /// Moves the modal back to the centered position.
+    pub fn reset(&mut self) {
+        self.offset = (0, 0);
+        self.grab = None;
+    }
+
+    /// Records where the modal would be centered on `screen` and returns
+    /// the area it should be drawn in.
+    ///
+    /// Call this from `render` with the result of
+    /// [`calculate_modal_area`](super::calculate_modal_area).
+    pub fn place(&self, screen: Rect, centered: Rect) -> Rect {
+        self.screen.set(screen);
+        self.centered.set(centered);
+        self.area()
+    }
+
+    /// Handles a mouse event, returning true if it was part of a drag.
+    ///
+    /// A drag only starts on the modal's top row, where the title is drawn.
+    pub fn handle_mouse(&mut self, event: &MouseEvent) -> bool {
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let area = self.area();
+                let title_bar = Rect::new(area.x, area.y, area.width, area.height.min(1));
+                if !event.is_inside(title_bar) {
+                    return false;
+                }
+                self.grab = Some(((event.column, event.row), self.offset()));
+                true
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some(((column, row), start)) = self.grab else {
+                    return false;
+                };
+                self.offset = self.clamp((
+                    start.0 + event.column as i32 - column as i32,
+                    start.1 + event.row as i32 - row as i32,
+                ));
+                true
+            }
+            MouseEventKind::Up(MouseButton::Left) => self.grab.take().is_some(),
+            _ => false,
+        }
+    }
+
+    /// Limits an offset so the modal stays within the screen.
+    fn clamp(&self, (dx, dy): (i32, i32)) -> (i32, i32) {
+        let screen = self.screen.get();
+        let centered = self.centered.get();
+        let axis = |offset: i32, start: u16, size: u16, screen_start: u16, screen_end: u16| {
+            let min = screen_start as i32 - start as i32;
+            let max = (screen_end as i32 - size as i32 - start as i32).max(min);
+            offset.clamp(min, max)
+        };
+        (
+            axis(dx, centered.x, centered.width, screen.x, screen.right()),
+            axis(dy, centered.y, centered.height, screen.y, screen.bottom()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent::new(kind, column, row)
+    }
+
+    #[test]
+    fn test_drag_requires_title_bar() {
+        let mut drag = ModalDrag::new();
+        drag.place(Rect::new(0, 0, 80, 24), Rect::new(20, 8, 40, 8));
+
+        // Pressing inside the body does not start a drag
+        let down = mouse(MouseEventKind::Down(MouseButton::Left), 30, 10);
+        assert!(!drag.handle_mouse(&down));
+        let moved = mouse(MouseEventKind::Drag(MouseButton::Left), 40, 12);
+        assert!(!drag.handle_mouse(&moved));
+        assert_eq!(drag.offset(), (0, 0));
+
+        let down = mouse(MouseEventKind::Down(MouseButton::Left), 30, 8);
+        assert!(drag.handle_mouse(&down));
+        assert!(drag.is_dragging());
+        assert!(drag.handle_mouse(&mouse(MouseEventKind::Up(MouseButton::Left), 30, 8)));
+        assert!(!drag.is_dragging());
+    }
+
+    #[test]
+    fn test_drag_clamped_to_screen() {
+        let mut drag = ModalDrag::new();
+        let screen = Rect::new(0, 0, 80, 24);
+        drag.place(screen, Rect::new(20, 8, 40, 8));
+
+        drag.handle_mouse(&mouse(MouseEventKind::Down(MouseButton::Left), 30, 8));
+        drag.handle_mouse(&mouse(MouseEventKind::Drag(MouseButton::Left), 79, 0));
+        assert_eq!(drag.area(), Rect::new(40, 0, 40, 8));
+
+        // Moving back tracks the mouse from where the drag started
+        drag.handle_mouse(&mouse(MouseEventKind::Drag(MouseButton::Left), 25, 9));
+        assert_eq!(drag.offset(), (-5, 1));
+        drag.handle_mouse(&mouse(MouseEventKind::Up(MouseButton::Left), 25, 9));
+
+        // A smaller screen pulls the modal back on screen
+        let area = drag.place(Rect::new(0, 0, 50, 12), Rect::new(5, 2, 40, 8));
+        assert_eq!(area, Rect::new(0, 3, 40, 8));
+
+        drag.reset();
+        assert_eq!(drag.offset(), (0, 0));
+    }
+}
@@ -12,6 +12,9 @@
 //! - [`ConfirmModal`]: Yes/No confirmation dialog returning a boolean
 //! - [`PromptModal`]: Text input dialog returning user input
 //!
+//! For other content, [`CustomModal`] wraps any component in the same modal
+//! shell.
+//!
 //! All modals share common features:
 //!
 //! - Automatic focus trapping when open
@@ -19,6 +22,7 @@
 //! - Enter key confirms the focused button
 //! - Async result handling via channels
 //! - Themed styling with borders
+//! - Optional dragging by the title bar (see [`ModalDrag`])
 //!
 //! # Examples
 //!
@@ -99,16 +103,22 @@
 mod alert;
 mod button;
 mod confirm;
+mod custom;
+mod drag;
 mod overlay;
 mod prompt;
 
 pub use alert::AlertModal;
 pub use button::{Button, ButtonAction, ButtonMsg, ButtonVariant};
-pub use confirm::ConfirmModal;
+pub use confirm::{ConfirmChoice, ConfirmModal};
+pub use custom::{CustomModal, CustomModalAction, CustomModalMsg};
+pub use drag::ModalDrag;
 pub use overlay::Overlay;
 pub use prompt::PromptModal;
 
+use crate::event::MouseEvent;
 use crate::focus::{FocusId, FocusTrap};
+use crate::text::wrap_text;
 
 /// Messages that modal dialogs can handle.
 #[derive(Debug, Clone)]
@@ -127,6 +137,14 @@ pub enum ModalMsg {
     ButtonMsg(usize, ButtonMsg),
     /// Forward a message to the text input (for PromptModal).
     InputMsg(super::TextInputMsg),
+    /// Cut the text input's selection to the clipboard (for PromptModal).
+    Cut,
+    /// Copy the text input's selection to the clipboard (for PromptModal).
+    Copy,
+    /// Paste clipboard text into the text input (for PromptModal).
+    Paste,
+    /// A mouse event, used to drag modals that are draggable.
+    Mouse(MouseEvent),
 }
 
 /// Actions that modal dialogs can emit.
@@ -153,6 +171,8 @@ pub struct ModalConfig {
     pub width_percent: f32,
     /// Whether to show an overlay behind the modal.
     pub show_overlay: bool,
+    /// Whether the modal can be moved by dragging its title bar.
+    pub draggable: bool,
 }
 
 impl Default for ModalConfig {
@@ -163,6 +183,7 @@ impl Default for ModalConfig {
             show_shadow: true,
             width_percent: 0.6,
             show_overlay: true,
+            draggable: false,
         }
     }
 }
@@ -199,6 +220,12 @@ impl ModalConfig {
         self.show_overlay = value;
         self
     }
+
+    /// Sets whether the modal can be dragged by its title bar.
+    pub fn draggable(mut self, value: bool) -> Self {
+        self.draggable = value;
+        self
+    }
 }
 
 /// Trait for modal dialogs that can create focus traps.
@@ -222,18 +249,33 @@ pub fn calculate_modal_area(
     width_percent: f32,
     content_height: u16,
 ) -> ratatui::prelude::Rect {
-    let width = ((full_area.width as f32) * width_percent).round() as u16;
-    let width = width.max(20).min(full_area.width.saturating_sub(4));
+    let width = modal_width(full_area, width_percent);
 
     // Add 2 for borders, 1 for title
     let height = (content_height + 3).min(full_area.height.saturating_sub(4));
 
-    let x = (full_area.width.saturating_sub(width)) / 2;
-    let y = (full_area.height.saturating_sub(height)) / 2;
+    let x = full_area.x + (full_area.width.saturating_sub(width)) / 2;
+    let y = full_area.y + (full_area.height.saturating_sub(height)) / 2;
 
     ratatui::prelude::Rect::new(x, y, width, height)
 }
 
+/// Returns the width of a modal, including borders, on the given screen.
+pub fn modal_width(full_area: ratatui::prelude::Rect, width_percent: f32) -> u16 {
+    let width = ((full_area.width as f32) * width_percent).round() as u16;
+    width.max(20).min(full_area.width.saturating_sub(4))
+}
+
+/// Wraps a modal message to the width inside the modal's borders.
+fn wrap_message(
+    full_area: ratatui::prelude::Rect,
+    width_percent: f32,
+    message: &str,
+) -> Vec<String> {
+    let inner_width = modal_width(full_area, width_percent).saturating_sub(2);
+    wrap_text(message, inner_width as usize)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,6 +288,7 @@ mod tests {
         assert!(config.show_shadow);
         assert!((config.width_percent - 0.6).abs() < 0.01);
         assert!(config.show_overlay);
+        assert!(!config.draggable);
     }
 
     #[test]
@@ -254,13 +297,15 @@ mod tests {
             .close_on_escape(false)
             .show_shadow(false)
             .width_percent(0.8)
-            .show_overlay(false);
+            .show_overlay(false)
+            .draggable(true);
 
         assert_eq!(config.title, "Test");
         assert!(!config.close_on_escape);
         assert!(!config.show_shadow);
         assert!((config.width_percent - 0.8).abs() < 0.01);
         assert!(!config.show_overlay);
+        assert!(config.draggable);
     }
 
     #[test]
@@ -284,6 +329,15 @@ mod tests {
         assert_eq!(area.y, 18); // (50 - 13) / 2 (approx)
     }
 
+    #[test]
+    fn test_wrap_message_fits_inner_width() {
+        let full = Rect::new(0, 0, 40, 20);
+        assert_eq!(modal_width(full, 0.5), 20);
+
+        let lines = wrap_message(full, 0.5, "a message that wraps onto several lines");
+        assert_eq!(lines, vec!["a message that", "wraps onto several", "lines"]);
+    }
+
     #[test]
     fn test_calculate_modal_area_small_screen() {
         let full = Rect::new(0, 0, 30, 20);
@@ -293,4 +347,15 @@ mod tests {
         assert!(area.width <= 26); // full_width - 4
         assert!(area.height <= 16); // full_height - 4
     }
+
+    #[test]
+    fn test_calculate_modal_area_offset_and_tiny_screens() {
+        // Centered within a screen that does not start at the origin
+        let area = calculate_modal_area(Rect::new(10, 5, 100, 50), 0.6, 10);
+        assert_eq!((area.x, area.y), (30, 23));
+
+        // Screens smaller than the margins leave no room at all
+        assert!(calculate_modal_area(Rect::new(0, 0, 0, 0), 0.6, 10).is_empty());
+        assert!(calculate_modal_area(Rect::new(0, 0, 3, 3), 0.6, 10).is_empty());
+    }
 }
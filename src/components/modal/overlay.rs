@@ -6,13 +6,17 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Clear, Widget};
 
-use crate::theme::Theme;
+use crate::theme::{lerp_color, Theme};
 
 /// A semi-transparent overlay rendered behind modal dialogs.
 ///
 /// The overlay dims the background content to focus attention on the modal.
 /// It also optionally renders a shadow effect for depth perception.
 ///
+/// By default the background is replaced with the theme's background color.
+/// With [`with_dim`](Self::with_dim), the existing content stays visible and
+/// its colors are blended toward the dim color instead.
+///
 /// # Example
 ///
 /// ```rust
@@ -20,12 +24,18 @@ use crate::theme::Theme;
 /// use ratatui::prelude::*;
 ///
 /// let overlay = Overlay::new()
-///     .with_shadow(true);
+///     .with_shadow(true)
+///     .with_dim(0.6)
+///     .with_dim_color(Color::Black);
 /// ```
 #[derive(Debug, Clone, Default)]
 pub struct Overlay {
     /// Whether to show a shadow effect.
     show_shadow: bool,
+    /// How strongly to blend the background toward the dim color.
+    dim: Option<f32>,
+    /// Color the background is dimmed toward; defaults to the theme background.
+    dim_color: Option<Color>,
     /// Optional theme for styling.
     theme: Option<Theme>,
 }
@@ -42,6 +52,27 @@ impl Overlay {
         self
     }
 
+    /// Dims the existing background content by `factor`.
+    ///
+    /// The factor runs from 0.0 (content unchanged) to 1.0 (a solid fill of
+    /// the dim color) and is clamped to that range.
+    pub fn with_dim(mut self, factor: f32) -> Self {
+        self.dim = Some(if factor.is_nan() {
+            0.0
+        } else {
+            factor.clamp(0.0, 1.0)
+        });
+        self
+    }
+
+    /// Sets the color the background is dimmed toward.
+    ///
+    /// Defaults to the theme's background color.
+    pub fn with_dim_color(mut self, color: Color) -> Self {
+        self.dim_color = Some(color);
+        self
+    }
+
     /// Sets the theme for styling.
     pub fn with_theme(mut self, theme: Theme) -> Self {
         self.theme = Some(theme);
@@ -53,15 +84,28 @@ impl Overlay {
         self.show_shadow
     }
 
+    /// Returns the dim factor, if dimming is enabled.
+    pub fn dim(&self) -> Option<f32> {
+        self.dim
+    }
+
     /// Renders the overlay to the given frame covering the full area.
     ///
     /// Call this before rendering the modal content.
     pub fn render(&self, frame: &mut Frame, full_area: Rect) {
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+
+        if let Some(factor) = self.dim {
+            let colors = theme.colors();
+            let color = self.dim_color.unwrap_or(colors.background);
+            let defaults = (colors.text_primary, colors.background);
+            dim_area(frame.buffer_mut(), full_area, color, factor, defaults);
+            return;
+        }
+
         // Clear the area to prepare for overlay
         frame.render_widget(Clear, full_area);
 
-        let theme = self.theme.as_ref().cloned().unwrap_or_default();
-
         // Render a dimmed background
         // Using a block with a semi-transparent style
         let overlay_style = Style::default().bg(theme.colors().background);
@@ -132,6 +176,38 @@ impl Overlay {
     }
 }
 
+/// Blends the colors of every cell in `area` toward `color` by `factor`.
+///
+/// A factor of 0 leaves the buffer untouched and a factor of 1 replaces the
+/// content with a solid fill. Cells drawn in the terminal's default colors
+/// have no RGB value to blend, so [`Color::Reset`] is read as the `defaults`
+/// foreground and background colors.
+fn dim_area(buf: &mut Buffer, area: Rect, color: Color, factor: f32, defaults: (Color, Color)) {
+    let (default_fg, default_bg) = defaults;
+    let resolve = |cell_color: Color, default: Color| match cell_color {
+        Color::Reset => default,
+        cell_color => cell_color,
+    };
+
+    if factor <= 0.0 {
+        return;
+    }
+
+    let area = buf.area.intersection(area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let cell = &mut buf[(x, y)];
+            if factor >= 1.0 {
+                cell.reset();
+                cell.set_bg(color);
+            } else {
+                cell.fg = lerp_color(resolve(cell.fg, default_fg), color, factor);
+                cell.bg = lerp_color(resolve(cell.bg, default_bg), color, factor);
+            }
+        }
+    }
+}
+
 /// A simple widget that renders shadow characters.
 struct ShadowBlock {
     style: Style,
@@ -162,6 +238,7 @@ impl Widget for ShadowBlock {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::event::setup_test_terminal;
 
     #[test]
     fn test_overlay_default() {
@@ -175,10 +252,101 @@ mod tests {
         assert!(overlay.has_shadow());
     }
 
+    fn dimmed(overlay: &Overlay) -> Buffer {
+        let mut terminal = setup_test_terminal(4, 1).unwrap();
+        terminal
+            .draw(|frame| {
+                let style = Style::default()
+                    .fg(Color::Rgb(200, 100, 0))
+                    .bg(Color::Rgb(100, 200, 50));
+                frame.render_widget(Span::styled("ab", style), frame.area());
+                overlay.render(frame, Rect::new(0, 0, 2, 1));
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    #[test]
+    fn test_dim_blends_halfway() {
+        let overlay = Overlay::new()
+            .with_dim(0.5)
+            .with_dim_color(Color::Rgb(0, 0, 0));
+        let buffer = dimmed(&overlay);
+
+        assert_eq!(buffer[(0, 0)].symbol(), "a");
+        assert_eq!(buffer[(0, 0)].fg, Color::Rgb(100, 50, 0));
+        assert_eq!(buffer[(0, 0)].bg, Color::Rgb(50, 100, 25));
+        // Cells outside the overlay are untouched
+        assert_eq!(buffer[(2, 0)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn test_dim_blends_default_colors() {
+        let theme = Theme::dark();
+        let overlay = Overlay::new()
+            .with_dim(0.25)
+            .with_dim_color(Color::Rgb(0, 0, 0))
+            .with_theme(theme.clone());
+        let mut terminal = setup_test_terminal(2, 1).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget("ab", frame.area());
+                overlay.render(frame, frame.area());
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+
+        // Default colors blend from the theme's text and background colors
+        let colors = theme.colors();
+        let cell = &buffer[(0, 0)];
+        assert_eq!(cell.symbol(), "a");
+        assert_eq!(
+            cell.fg,
+            lerp_color(colors.text_primary, Color::Rgb(0, 0, 0), 0.25)
+        );
+        assert_eq!(
+            cell.bg,
+            lerp_color(colors.background, Color::Rgb(0, 0, 0), 0.25)
+        );
+        assert_ne!(cell.fg, cell.bg);
+    }
+
+    #[test]
+    fn test_dim_extremes() {
+        let original = dimmed(&Overlay::new().with_dim(0.0));
+        assert_eq!(original[(1, 0)].symbol(), "b");
+        assert_eq!(original[(1, 0)].fg, Color::Rgb(200, 100, 0));
+        assert_eq!(original[(1, 0)].bg, Color::Rgb(100, 200, 50));
+
+        let theme = Theme::dark();
+        let solid = dimmed(&Overlay::new().with_dim(3.0).with_theme(theme.clone()));
+        assert_eq!(solid[(1, 0)].symbol(), " ");
+        assert_eq!(solid[(1, 0)].bg, theme.colors().background);
+        assert_eq!(Overlay::new().with_dim(3.0).dim(), Some(1.0));
+    }
+
     #[test]
     fn test_overlay_with_theme() {
         let theme = Theme::dark();
         let overlay = Overlay::new().with_theme(theme);
         assert!(overlay.theme.is_some());
     }
+
+    #[test]
+    fn test_overlay_renders_into_tiny_areas() {
+        let overlay = Overlay::new().with_shadow(true);
+        for size in [0, 1] {
+            let mut terminal = setup_test_terminal(size, size).unwrap();
+            terminal
+                .draw(|frame| {
+                    let area = frame.area();
+                    overlay.render(frame, area);
+                    overlay.render_shadow(frame, area);
+                    overlay.render_shadow(frame, Rect::new(0, 0, 0, 0));
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            assert!(buffer.content().iter().all(|cell| cell.symbol() == " "));
+        }
+    }
 }
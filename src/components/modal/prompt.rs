@@ -1,15 +1,24 @@
 //! Prompt modal dialog.
 //!
 //! A modal for getting text input from the user.
+//!
+//! Once a clipboard is attached with [`PromptModal::with_clipboard`], Ctrl+C,
+//! Ctrl+X and Ctrl+V copy, cut and paste through [`PromptModal::key_msg`].
+
+use std::sync::Arc;
 
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use terminput::{KeyCode, KeyEvent, KeyModifiers};
 
 use super::{
-    calculate_modal_area, Button, ButtonAction, ButtonVariant, Modal, ModalAction, ModalConfig,
-    ModalMsg, Overlay,
+    calculate_modal_area, wrap_message, Button, ButtonAction, ButtonVariant, Modal, ModalAction,
+    ModalConfig, ModalDrag, ModalMsg, Overlay,
+};
+use crate::clipboard::Clipboard;
+use crate::components::{
+    Component, Focusable, Measurable, Renderable, TextInput, TextInputMsg, ValidationResult,
 };
-use crate::components::{Component, Focusable, Renderable, TextInput};
 use crate::focus::FocusId;
 use crate::theme::Theme;
 
@@ -57,6 +66,8 @@ pub struct PromptModal {
     theme: Option<Theme>,
     /// Overlay for background dimming.
     overlay: Overlay,
+    /// Offset from the centered position when dragged.
+    drag: ModalDrag,
     /// Custom labels for buttons.
     ok_label: String,
     cancel_label: String,
@@ -88,6 +99,7 @@ impl PromptModal {
             focused_element: 0, // Input focused by default
             theme: None,
             overlay: Overlay::new().with_shadow(true),
+            drag: ModalDrag::new(),
             ok_label: "OK".to_string(),
             cancel_label: "Cancel".to_string(),
         }
@@ -103,6 +115,16 @@ impl PromptModal {
         self
     }
 
+    /// Sets the clipboard used to cut, copy and paste the input text.
+    ///
+    /// No clipboard is attached by default. Share one clipboard, such as
+    /// [`default_clipboard`](crate::clipboard::default_clipboard), between
+    /// the widgets that should copy and paste to each other.
+    pub fn with_clipboard(mut self, clipboard: Arc<dyn Clipboard>) -> Self {
+        self.input = self.input.with_clipboard(clipboard);
+        self
+    }
+
     /// Sets whether Escape closes the modal.
     pub fn with_close_on_escape(mut self, value: bool) -> Self {
         self.config = self.config.close_on_escape(value);
@@ -128,9 +150,30 @@ impl PromptModal {
         self
     }
 
+    /// Sets whether the modal can be dragged by its title bar.
+    pub fn with_draggable(mut self, value: bool) -> Self {
+        self.config = self.config.draggable(value);
+        self
+    }
+
     /// Sets the default text in the input.
     pub fn with_default(mut self, text: impl Into<String>) -> Self {
         self.input.set_text(text);
+        self.sync_ok_enabled();
+        self
+    }
+
+    /// Sets a validator for the input.
+    ///
+    /// The OK button is disabled, and the prompt cannot be submitted, while
+    /// the input is invalid. The current text is validated immediately.
+    pub fn with_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> ValidationResult + Send + Sync + 'static,
+    {
+        self.input = self.input.with_validator(validator);
+        self.input.validate_now();
+        self.sync_ok_enabled();
         self
     }
 
@@ -153,8 +196,9 @@ impl PromptModal {
     ) -> Self {
         self.ok_label = ok_label.into();
         self.cancel_label = cancel_label.into();
-        self.ok_button =
-            Button::new("prompt-ok", self.ok_label.clone()).with_variant(ButtonVariant::Primary);
+        self.ok_button = Button::new("prompt-ok", self.ok_label.clone())
+            .with_variant(ButtonVariant::Primary)
+            .with_enabled(self.ok_button.is_enabled());
         self.cancel_button = Button::new("prompt-cancel", self.cancel_label.clone())
             .with_variant(ButtonVariant::Default);
 
@@ -207,6 +251,54 @@ impl PromptModal {
         &self.config
     }
 
+    /// Returns the drag state, including the area from the last render.
+    pub fn drag(&self) -> &ModalDrag {
+        &self.drag
+    }
+
+    /// Returns whether the prompt can currently be submitted.
+    pub fn can_submit(&self) -> bool {
+        self.ok_button.is_enabled()
+    }
+
+    /// Enables the OK button only while the input is valid.
+    fn sync_ok_enabled(&mut self) {
+        self.ok_button.set_enabled(self.input.is_valid());
+    }
+
+    /// Maps a key event to a message for this prompt.
+    ///
+    /// Ctrl+C, Ctrl+X and Ctrl+V map to [`ModalMsg::Copy`],
+    /// [`ModalMsg::Cut`] and [`ModalMsg::Paste`] wherever focus is. Other
+    /// keys are forwarded as [`ModalMsg::InputMsg`] while the input is
+    /// focused, except Enter and Escape, which are left to the caller to map
+    /// to [`ModalMsg::Confirm`] and [`ModalMsg::Close`].
+    pub fn key_msg(&self, key: &KeyEvent) -> Option<ModalMsg> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CTRL);
+        match key.code {
+            KeyCode::Char(c) if ctrl && !key.modifiers.contains(KeyModifiers::ALT) => {
+                match c.to_ascii_lowercase() {
+                    'c' => return Some(ModalMsg::Copy),
+                    'x' => return Some(ModalMsg::Cut),
+                    'v' => return Some(ModalMsg::Paste),
+                    _ => {}
+                }
+            }
+            KeyCode::Enter | KeyCode::Esc => return None,
+            _ => {}
+        }
+        if self.focused_element != 0 {
+            return None;
+        }
+        TextInputMsg::from_key(key).map(ModalMsg::InputMsg)
+    }
+
+    /// Returns the submit action, or `None` while the input is invalid.
+    fn submit(&self) -> Option<ModalAction> {
+        self.can_submit()
+            .then(|| ModalAction::Submit(self.input.text().to_string()))
+    }
+
     /// Updates the focus state of all elements based on focused_element index.
     fn update_focus(&mut self) {
         self.input.set_focused(self.focused_element == 0);
@@ -214,30 +306,44 @@ impl PromptModal {
         self.cancel_button.set_focused(self.focused_element == 2);
     }
 
-    /// Focuses the next element.
+    /// Returns whether the element at `index` can receive focus.
+    fn can_focus_element(&self, index: usize) -> bool {
+        index != 1 || self.ok_button.can_focus()
+    }
+
+    /// Focuses the next element, skipping a disabled OK button.
     fn focus_next(&mut self) {
         self.focused_element = (self.focused_element + 1) % 3;
+        if !self.can_focus_element(self.focused_element) {
+            self.focused_element = (self.focused_element + 1) % 3;
+        }
         self.update_focus();
     }
 
-    /// Focuses the previous element.
+    /// Focuses the previous element, skipping a disabled OK button.
     fn focus_prev(&mut self) {
-        self.focused_element = if self.focused_element == 0 {
-            2
-        } else {
-            self.focused_element - 1
-        };
+        self.focused_element = (self.focused_element + 2) % 3;
+        if !self.can_focus_element(self.focused_element) {
+            self.focused_element = (self.focused_element + 2) % 3;
+        }
         self.update_focus();
     }
+
+    /// Returns the height of the modal's content for a wrapped message.
+    fn content_height(lines: &[String]) -> u16 {
+        // message + input (3) + spacing + button (3)
+        (lines.len() as u16).max(1) + 7
+    }
 }
 
 impl Modal for PromptModal {
     fn focus_ids(&self) -> Vec<FocusId> {
-        vec![
-            FocusId::new("prompt-input"),
-            self.ok_button.id().clone(),
-            self.cancel_button.id().clone(),
-        ]
+        let mut ids = vec![FocusId::new("prompt-input")];
+        if self.ok_button.can_focus() {
+            ids.push(self.ok_button.id().clone());
+        }
+        ids.push(self.cancel_button.id().clone());
+        ids
     }
 }
 
@@ -259,7 +365,7 @@ impl Component for PromptModal {
                 match self.focused_element {
                     0 | 1 => {
                         // Input or OK button: submit the text
-                        Some(ModalAction::Submit(self.input.text().to_string()))
+                        self.submit()
                     }
                     2 => {
                         // Cancel button: close
@@ -278,7 +384,7 @@ impl Component for PromptModal {
             }
             ModalMsg::ButtonPressed(1) => {
                 // OK button
-                Some(ModalAction::Submit(self.input.text().to_string()))
+                self.submit()
             }
             ModalMsg::ButtonPressed(2) => {
                 // Cancel button
@@ -300,6 +406,27 @@ impl Component for PromptModal {
             }
             ModalMsg::InputMsg(input_msg) => {
                 self.input.update(input_msg);
+                self.sync_ok_enabled();
+                None
+            }
+            ModalMsg::Copy => {
+                self.input.update(TextInputMsg::Copy);
+                None
+            }
+            ModalMsg::Cut | ModalMsg::Paste if self.focused_element == 0 => {
+                let input_msg = if matches!(msg, ModalMsg::Cut) {
+                    TextInputMsg::Cut
+                } else {
+                    TextInputMsg::PasteFromClipboard
+                };
+                self.input.update(input_msg);
+                self.sync_ok_enabled();
+                None
+            }
+            ModalMsg::Mouse(event) => {
+                if self.config.draggable {
+                    self.drag.handle_mouse(&event);
+                }
                 None
             }
             _ => None,
@@ -330,24 +457,31 @@ impl Renderable for PromptModal {
         let theme = self.theme.as_ref().cloned().unwrap_or_default();
 
         // Calculate content height: message + input + button row + spacing
-        let message_width = (area.width as f32 * self.config.width_percent) as u16;
-        let message_width = message_width.saturating_sub(4); // Account for borders/padding
-        let message_lines = (self.message.len() as u16 / message_width.max(1)) + 1;
-        let content_height = message_lines + 7; // message + input (3) + spacing + button (3)
+        let lines = wrap_message(area, self.config.width_percent, &self.message);
+        let message_lines = (lines.len() as u16).max(1);
+        let content_height = Self::content_height(&lines);
 
         // Render overlay if enabled
         if self.config.show_overlay {
             self.overlay.render(frame, area);
         }
 
-        // Calculate modal area
-        let modal_area = calculate_modal_area(area, self.config.width_percent, content_height);
+        // Calculate modal area, moved by any drag
+        let modal_area = self.drag.place(
+            area,
+            calculate_modal_area(area, self.config.width_percent, content_height),
+        );
 
         // Render shadow if enabled
         if self.config.show_shadow {
             self.overlay.render_shadow(frame, modal_area);
         }
 
+        // Nothing fits on a screen too small for the modal's border
+        if modal_area.is_empty() {
+            return;
+        }
+
         // Render modal background and border
         let block = Block::default()
             .title(self.config.title.as_str())
@@ -371,9 +505,9 @@ impl Renderable for PromptModal {
             .split(inner_area);
 
         // Render message
-        let message = Paragraph::new(self.message.as_str())
+        let text: Vec<Line> = lines.into_iter().map(Line::from).collect();
+        let message = Paragraph::new(text)
             .style(theme.primary_text_style())
-            .wrap(Wrap { trim: true })
             .alignment(if theme.components().modal.center_content {
                 Alignment::Center
             } else {
@@ -386,31 +520,50 @@ impl Renderable for PromptModal {
         self.input.render(frame, chunks[1]);
 
         // Render buttons (centered, side by side)
-        let ok_width = (self.ok_label.len() + 4) as u16;
-        let cancel_width = (self.cancel_label.len() + 4) as u16;
+        let ok_width = self.ok_button.measure(chunks[2].as_size()).width;
+        let cancel_width = self.cancel_button.measure(chunks[2].as_size()).width;
         let button_spacing = 2u16;
         let total_button_width = ok_width + button_spacing + cancel_width;
 
         let buttons_x = chunks[2].x + (chunks[2].width.saturating_sub(total_button_width)) / 2;
 
-        let ok_area = Rect::new(buttons_x, chunks[2].y, ok_width, 3);
+        // Clip to the button row, which may be short or narrow on small screens
+        let ok_area = Rect::new(buttons_x, chunks[2].y, ok_width, 3).intersection(chunks[2]);
         let cancel_area = Rect::new(
             buttons_x + ok_width + button_spacing,
             chunks[2].y,
             cancel_width,
             3,
-        );
+        )
+        .intersection(chunks[2]);
 
         self.ok_button.render(frame, ok_area);
         self.cancel_button.render(frame, cancel_area);
     }
 }
 
+impl Measurable for PromptModal {
+    /// Measures the modal as it would be placed on a `max`-sized screen,
+    /// with the message wrapped to the modal's width.
+    fn measure(&self, max: Size) -> Size {
+        let screen = Rect::new(0, 0, max.width, max.height);
+        let lines = wrap_message(screen, self.config.width_percent, &self.message);
+        calculate_modal_area(
+            screen,
+            self.config.width_percent,
+            Self::content_height(&lines),
+        )
+        .as_size()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::ButtonMsg;
     use super::*;
     use crate::components::TextInputMsg;
+    use crate::test_util::{render, to_lines};
+    use crate::text::{measure_height, wrap_text};
 
     #[test]
     fn test_prompt_modal_creation() {
@@ -587,4 +740,163 @@ mod tests {
         // Placeholder is stored in input, verified during render
         assert!(modal.text().is_empty());
     }
+
+    #[test]
+    fn test_prompt_modal_disables_ok_while_invalid() {
+        let mut modal = PromptModal::new("Rename", "New name:").with_validator(|text| {
+            if text.is_empty() {
+                ValidationResult::Invalid("Name is required".to_string())
+            } else {
+                ValidationResult::Valid
+            }
+        });
+        assert!(!modal.ok_button().is_enabled());
+        assert!(!modal.can_submit());
+        assert!(!modal.focus_ids().contains(modal.ok_button().id()));
+
+        // Submitting is rejected, and focus skips the OK button
+        assert_eq!(modal.update(ModalMsg::Confirm), None);
+        assert_eq!(modal.update(ModalMsg::ButtonPressed(1)), None);
+        modal.update(ModalMsg::FocusNext);
+        assert_eq!(modal.focused_element_index(), 2);
+        modal.update(ModalMsg::FocusPrev);
+        assert_eq!(modal.focused_element_index(), 0);
+
+        modal.update(ModalMsg::InputMsg(TextInputMsg::InsertChar('a')));
+        assert!(modal.ok_button().is_enabled());
+        modal.update(ModalMsg::FocusNext);
+        assert_eq!(modal.focused_element_index(), 1);
+        assert_eq!(
+            modal.update(ModalMsg::Confirm),
+            Some(ModalAction::Submit("a".to_string()))
+        );
+    }
+
+    /// Renders a prompt with `message` on a 60x40 screen, where the modal is
+    /// 30 cells wide with 28 inside the borders, and returns the modal's
+    /// height and rendered rows.
+    fn render_prompt(message: &str) -> (u16, Vec<String>) {
+        let modal = PromptModal::new("Prompt", message).with_width_percent(0.5);
+        let buffer = render(&modal, Rect::new(0, 0, 60, 40));
+        (modal.drag().area().height, to_lines(&buffer))
+    }
+
+    #[test]
+    fn test_prompt_modal_height_matches_wrapped_message() {
+        let ascii = "Please enter the new name for the selected file below";
+        let token = "x".repeat(70);
+        let cjk = "新しいファイル名を入力してください。保存先も確認してください。";
+
+        for (message, expected_lines) in [(ascii, 2), (token.as_str(), 3), (cjk, 3)] {
+            assert_eq!(measure_height(message, 28), expected_lines, "{message}");
+
+            // Borders and title (3), input (3), spacing (1), and buttons (3)
+            let (height, rows) = render_prompt(message);
+            assert_eq!(height, expected_lines as u16 + 10, "{message}");
+            let modal = PromptModal::new("Prompt", message).with_width_percent(0.5);
+            assert_eq!(modal.measure(Size::new(60, 40)).height, height, "{message}");
+
+            // The last wrapped line is drawn rather than clipped
+            let last = wrap_text(message, 28).pop().unwrap();
+            assert!(
+                rows.iter().any(|row| row.contains(&last)),
+                "{last:?} missing from {rows:#?}"
+            );
+        }
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent {
+            code: KeyCode::Char(c),
+            modifiers: KeyModifiers::CTRL,
+            kind: terminput::KeyEventKind::Press,
+            state: terminput::KeyEventState::NONE,
+        }
+    }
+
+    #[test]
+    fn test_prompt_clipboard_keys_copy_and_paste() {
+        let clipboard = Arc::new(crate::clipboard::MemoryClipboard::new());
+        let mut modal = PromptModal::new("Rename", "New name:")
+            .with_default("abc")
+            .with_clipboard(clipboard.clone());
+
+        modal.update(ModalMsg::InputMsg(TextInputMsg::SelectAll));
+        let copy = modal.key_msg(&ctrl('c')).unwrap();
+        assert!(matches!(copy, ModalMsg::Copy));
+        modal.update(copy);
+        assert_eq!(clipboard.get_text().unwrap(), "abc");
+
+        modal.update(ModalMsg::InputMsg(TextInputMsg::CursorEnd));
+        let paste = modal.key_msg(&ctrl('v')).unwrap();
+        modal.update(paste);
+        assert_eq!(modal.text(), "abcabc");
+
+        // Copy still works with a button focused, but paste does not edit
+        modal.update(ModalMsg::InputMsg(TextInputMsg::SelectAll));
+        modal.update(ModalMsg::FocusNext);
+        assert!(matches!(modal.key_msg(&ctrl('c')), Some(ModalMsg::Copy)));
+        modal.update(ModalMsg::Copy);
+        assert_eq!(clipboard.get_text().unwrap(), "abcabc");
+        modal.update(ModalMsg::Paste);
+        assert_eq!(modal.text(), "abcabc");
+    }
+
+    #[test]
+    fn test_prompts_share_a_clipboard() {
+        let clipboard: Arc<dyn Clipboard> = Arc::new(crate::clipboard::MemoryClipboard::new());
+        let mut source = PromptModal::new("Copy", "Name:")
+            .with_default("shared")
+            .with_clipboard(clipboard.clone());
+        let mut target = PromptModal::new("Paste", "Name:").with_clipboard(clipboard);
+
+        source.update(ModalMsg::InputMsg(TextInputMsg::SelectAll));
+        source.update(ModalMsg::Copy);
+        target.update(ModalMsg::Paste);
+        assert_eq!(target.text(), "shared");
+
+        // Without a clipboard, paste leaves the input alone
+        let mut detached = PromptModal::new("Paste", "Name:");
+        detached.update(ModalMsg::Paste);
+        assert_eq!(detached.text(), "");
+    }
+
+    #[test]
+    fn test_prompt_cut_updates_ok_button() {
+        let clipboard = Arc::new(crate::clipboard::MemoryClipboard::new());
+        let mut modal = PromptModal::new("Rename", "New name:")
+            .with_default("name")
+            .with_validator(|text| {
+                if text.is_empty() {
+                    ValidationResult::Invalid("Name is required".to_string())
+                } else {
+                    ValidationResult::Valid
+                }
+            })
+            .with_clipboard(clipboard.clone());
+
+        modal.update(ModalMsg::InputMsg(TextInputMsg::SelectAll));
+        modal.update(modal.key_msg(&ctrl('x')).unwrap());
+        assert_eq!(modal.text(), "");
+        assert_eq!(clipboard.get_text().unwrap(), "name");
+        assert!(!modal.can_submit());
+
+        modal.update(ModalMsg::Paste);
+        assert_eq!(modal.text(), "name");
+        assert!(modal.can_submit());
+    }
+
+    #[test]
+    fn test_prompt_modal_renders_on_tiny_screens() {
+        let mut modal = PromptModal::new("Rename", "Enter a new name for the file");
+        modal.set_focused(true);
+        assert!(to_lines(&render(&modal, Rect::new(0, 0, 0, 0))).is_empty());
+        assert_eq!(to_lines(&render(&modal, Rect::new(0, 0, 1, 1))), [" "]);
+
+        // Only the border fits; the input and buttons are clipped rather
+        // than drawn past it
+        let lines = to_lines(&render(&modal, Rect::new(0, 0, 12, 6)));
+        assert_eq!(lines[2], "  ╔Rename╗  ");
+        assert_eq!(lines[3], "  ╚══════╝░░");
+    }
 }
@@ -0,0 +1,686 @@
+//! NumberInput component for editing numeric values.
+//!
+//! A [`TextInput`] constrained to numbers, supporting:
+//! - Increment/decrement by a configurable step (Up/Down)
+//! - Optional minimum and maximum bounds
+//! - Fixed decimal precision
+//! - Optional up/down indicators beside the field
+//!
+//! Characters that cannot form a number are rejected as they are typed.
+//! Bounds and precision are applied to the value immediately and to the
+//! displayed text when the input loses focus.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::{Component, NumberAction, NumberInput, NumberMsg};
+//!
+//! let mut quantity = NumberInput::new()
+//!     .with_range(0.0, 10.0)
+//!     .with_step(2.5)
+//!     .with_precision(1)
+//!     .with_value(9.0);
+//!
+//! let action = quantity.update(NumberMsg::Increment);
+//! assert!(matches!(action, Some(NumberAction::Changed(v)) if v == 10.0));
+//! assert_eq!(quantity.text(), "10.0");
+//! ```
+
+use std::sync::Arc;
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use terminput::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{Component, Focusable, Renderable, TextInput, TextInputAction, TextInputMsg};
+use crate::clipboard::{Clipboard, ClipboardKind};
+use crate::focus::FocusId;
+use crate::theme::Theme;
+
+/// Messages that the NumberInput component can handle.
+#[derive(Debug, Clone)]
+pub enum NumberMsg {
+    /// Increase the value by one step.
+    Increment,
+    /// Decrease the value by one step.
+    Decrement,
+    /// Set the value directly.
+    SetValue(f64),
+    /// Forward an editing message to the underlying text input.
+    ///
+    /// Edits that would make the text non-numeric are rejected.
+    Input(TextInputMsg),
+}
+
+impl NumberMsg {
+    /// Maps a key press to a NumberInput message.
+    ///
+    /// Up and Down step the value; digits, `-`, `.`, and the usual editing
+    /// keys are forwarded to the text input. Other keys return `None`.
+    pub fn from_key(key: &KeyEvent) -> Option<Self> {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CTRL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+
+        let msg = match key.code {
+            KeyCode::Up => NumberMsg::Increment,
+            KeyCode::Down => NumberMsg::Decrement,
+            KeyCode::Char(c) => NumberMsg::Input(TextInputMsg::InsertChar(c)),
+            KeyCode::Backspace => NumberMsg::Input(TextInputMsg::Backspace),
+            KeyCode::Delete => NumberMsg::Input(TextInputMsg::Delete),
+            KeyCode::Left => NumberMsg::Input(TextInputMsg::CursorLeft),
+            KeyCode::Right => NumberMsg::Input(TextInputMsg::CursorRight),
+            KeyCode::Home => NumberMsg::Input(TextInputMsg::CursorHome),
+            KeyCode::End => NumberMsg::Input(TextInputMsg::CursorEnd),
+            _ => return None,
+        };
+        Some(msg)
+    }
+}
+
+/// Actions emitted by the NumberInput component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberAction {
+    /// The value changed.
+    Changed(f64),
+}
+
+/// A numeric input with stepping, bounds, and precision.
+#[derive(Debug, Clone)]
+pub struct NumberInput {
+    /// The underlying text input.
+    input: TextInput,
+    /// The current value.
+    value: f64,
+    /// Amount added or removed by increment and decrement.
+    step: f64,
+    /// Minimum allowed value.
+    min: Option<f64>,
+    /// Maximum allowed value.
+    max: Option<f64>,
+    /// Number of decimal places, or `None` for no rounding.
+    precision: Option<usize>,
+    /// Whether up/down indicators are shown.
+    show_indicators: bool,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl Default for NumberInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberInput {
+    /// Creates a new NumberInput with a value of zero and a step of one.
+    pub fn new() -> Self {
+        let mut input = Self {
+            input: TextInput::new(),
+            value: 0.0,
+            step: 1.0,
+            min: None,
+            max: None,
+            precision: None,
+            show_indicators: false,
+            theme: None,
+        };
+        input.sync_text();
+        input
+    }
+
+    /// Sets the input's identifier.
+    pub fn with_id(mut self, id: impl Into<FocusId>) -> Self {
+        self.input = self.input.with_id(id);
+        self
+    }
+
+    /// Returns the input's identifier, if set.
+    pub fn id(&self) -> Option<&FocusId> {
+        self.input.id()
+    }
+
+    /// Sets the initial value, applying bounds and precision.
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Sets the step used by increment and decrement.
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Sets the minimum allowed value.
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        let value = self.value;
+        self.set_value(value);
+        self
+    }
+
+    /// Sets the maximum allowed value.
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        let value = self.value;
+        self.set_value(value);
+        self
+    }
+
+    /// Sets both the minimum and maximum allowed values.
+    pub fn with_range(self, min: f64, max: f64) -> Self {
+        self.with_min(min).with_max(max)
+    }
+
+    /// Sets the number of decimal places shown and kept.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        let value = self.value;
+        self.set_value(value);
+        self
+    }
+
+    /// Sets whether up/down indicators are shown beside the field.
+    pub fn with_indicators(mut self, show: bool) -> Self {
+        self.show_indicators = show;
+        self
+    }
+
+    /// Attaches a clipboard for copy, cut and paste.
+    ///
+    /// Pasted text is only inserted if it keeps the input numeric.
+    pub fn with_clipboard(mut self, clipboard: Arc<dyn Clipboard>) -> Self {
+        self.input = self.input.with_clipboard(clipboard);
+        self
+    }
+
+    /// Sets whether the input uses the primary selection of the attached
+    /// clipboard.
+    ///
+    /// See [`TextInput::with_primary_selection`].
+    pub fn with_primary_selection(mut self, enabled: bool) -> Self {
+        self.input = self.input.with_primary_selection(enabled);
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.input = self.input.with_theme(theme.clone());
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Sets the value, applying bounds and precision, and updates the text.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = self.normalize(value);
+        self.sync_text();
+    }
+
+    /// Returns the displayed text.
+    pub fn text(&self) -> &str {
+        self.input.text()
+    }
+
+    /// Returns the step used by increment and decrement.
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Returns the minimum allowed value, if set.
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// Returns the maximum allowed value, if set.
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// Returns the number of decimal places, if set.
+    pub fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Returns the underlying text input.
+    pub fn input(&self) -> &TextInput {
+        &self.input
+    }
+
+    /// Formats a value using the configured precision.
+    pub fn format_value(&self, value: f64) -> String {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        }
+    }
+
+    /// Rounds to the configured precision and clamps to the bounds.
+    fn normalize(&self, value: f64) -> f64 {
+        let mut value = match self.precision {
+            Some(precision) => {
+                let factor = 10f64.powi(precision.min(i32::MAX as usize) as i32);
+                (value * factor).round() / factor
+            }
+            None => value,
+        };
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        value
+    }
+
+    /// Replaces the text with the formatted value.
+    fn sync_text(&mut self) {
+        let text = self.format_value(self.value);
+        self.input.set_text(text);
+    }
+
+    /// Sets a new value, returning an action if it changed.
+    fn change_value(&mut self, value: f64) -> Option<NumberAction> {
+        let previous = self.value;
+        self.set_value(value);
+        (self.value != previous).then_some(NumberAction::Changed(self.value))
+    }
+
+    /// Returns true if the text could be the start of a valid number.
+    ///
+    /// Partial input such as `-` or `1.` is accepted so values can be typed
+    /// one character at a time.
+    fn is_partial_number(&self, text: &str) -> bool {
+        let unsigned = match text.strip_prefix('-') {
+            Some(rest) => {
+                if self.min.is_some_and(|min| min >= 0.0) {
+                    return false;
+                }
+                rest
+            }
+            None => text,
+        };
+
+        let (whole, fraction) = match unsigned.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (unsigned, None),
+        };
+        if !whole.chars().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+        match fraction {
+            None => true,
+            Some(fraction) => {
+                fraction.chars().all(|c| c.is_ascii_digit())
+                    && self
+                        .precision
+                        .map_or(true, |p| p > 0 && fraction.len() <= p)
+            }
+        }
+    }
+
+    /// Returns the text that would result from inserting at the cursor.
+    fn text_after_insert(&self, inserted: &str) -> String {
+        let text = self.input.text();
+        let range = self
+            .input
+            .selection()
+            .unwrap_or(self.input.cursor()..self.input.cursor());
+        format!("{}{}{}", &text[..range.start], inserted, &text[range.end..])
+    }
+
+    /// Returns true if the editing message keeps the text numeric.
+    fn accepts(&self, msg: &TextInputMsg) -> bool {
+        match msg {
+            TextInputMsg::InsertChar(c) => {
+                self.is_partial_number(&self.text_after_insert(&c.to_string()))
+            }
+            TextInputMsg::Paste(text) => self.is_partial_number(&self.text_after_insert(text)),
+            TextInputMsg::SetText(text) => self.is_partial_number(text),
+            _ => true,
+        }
+    }
+
+    /// Applies typed text to the value without rewriting the text.
+    fn commit_typed(&mut self) -> Option<NumberAction> {
+        let parsed: f64 = self.input.text().parse().ok()?;
+        let value = self.normalize(parsed);
+        if value == self.value {
+            return None;
+        }
+        self.value = value;
+        Some(NumberAction::Changed(value))
+    }
+
+    /// Handles an editing message for the underlying text input.
+    fn update_input(&mut self, msg: TextInputMsg) -> Option<NumberAction> {
+        // Resolve clipboard pastes up front so their contents can be checked
+        let msg = match msg {
+            TextInputMsg::PasteFromClipboard => {
+                let text = self.input.clipboard()?.get_text().ok()?;
+                TextInputMsg::Paste(text)
+            }
+            TextInputMsg::PasteFromPrimary
+                if self.input.uses_primary_selection() && !self.input.is_read_only() =>
+            {
+                // Publish and clear the selection first, as the text input
+                // does, so the text is inserted at the cursor
+                self.input.update(TextInputMsg::ClearSelection);
+                let text = self
+                    .input
+                    .clipboard()?
+                    .get_text_from(ClipboardKind::Primary)
+                    .ok()?;
+                TextInputMsg::Paste(text)
+            }
+            msg => msg,
+        };
+
+        if !self.accepts(&msg) {
+            return None;
+        }
+
+        match self.input.update(msg)? {
+            TextInputAction::Changed(_) => self.commit_typed(),
+            TextInputAction::Submit(_) => {
+                self.sync_text();
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Component for NumberInput {
+    type Message = NumberMsg;
+    type Action = NumberAction;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        match msg {
+            NumberMsg::Increment => self.change_value(self.value + self.step),
+            NumberMsg::Decrement => self.change_value(self.value - self.step),
+            NumberMsg::SetValue(value) => self.change_value(value),
+            NumberMsg::Input(msg) => self.update_input(msg),
+        }
+    }
+}
+
+impl Focusable for NumberInput {
+    fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.input.set_focused(focused);
+    }
+
+    fn on_focus(&mut self) {
+        self.input.on_focus();
+    }
+
+    fn on_blur(&mut self) {
+        self.input.on_blur();
+        // Replace partial or unformatted input with the committed value
+        self.sync_text();
+    }
+}
+
+impl Renderable for NumberInput {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.show_indicators || area.width < 2 {
+            self.input.render(frame, area);
+            return;
+        }
+
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+        let [input_area, indicator_area] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+        self.input.render(frame, input_area);
+
+        // Dim an indicator once its bound has been reached
+        let indicator_style = |at_bound: bool| {
+            if at_bound {
+                theme.disabled_text_style()
+            } else {
+                theme.muted_style()
+            }
+        };
+        let at_max = self.max.is_some_and(|max| self.value >= max);
+        let at_min = self.min.is_some_and(|min| self.value <= min);
+
+        let up = Rect::new(indicator_area.x, indicator_area.y, 1, 1);
+        frame.render_widget(Paragraph::new("▲").style(indicator_style(at_max)), up);
+        if indicator_area.height > 1 {
+            let down = Rect::new(indicator_area.x, indicator_area.bottom() - 1, 1, 1);
+            frame.render_widget(Paragraph::new("▼").style(indicator_style(at_min)), down);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use terminput::{KeyEventKind, KeyEventState};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    fn type_str(input: &mut NumberInput, text: &str) {
+        for c in text.chars() {
+            input.update(NumberMsg::Input(TextInputMsg::InsertChar(c)));
+        }
+    }
+
+    #[test]
+    fn test_new_number_input() {
+        let input = NumberInput::new();
+        assert_eq!(input.value(), 0.0);
+        assert_eq!(input.text(), "0");
+        assert_eq!(input.step(), 1.0);
+        assert!(input.min().is_none());
+        assert!(input.max().is_none());
+    }
+
+    #[test]
+    fn test_increment_decrement() {
+        let mut input = NumberInput::new().with_step(5.0);
+
+        assert_eq!(
+            input.update(NumberMsg::Increment),
+            Some(NumberAction::Changed(5.0))
+        );
+        assert_eq!(input.text(), "5");
+        assert_eq!(
+            input.update(NumberMsg::Decrement),
+            Some(NumberAction::Changed(0.0))
+        );
+        assert_eq!(
+            input.update(NumberMsg::Decrement),
+            Some(NumberAction::Changed(-5.0))
+        );
+    }
+
+    #[test]
+    fn test_step_clamps_at_bounds() {
+        let mut input = NumberInput::new()
+            .with_range(0.0, 10.0)
+            .with_step(4.0)
+            .with_value(8.0);
+
+        assert_eq!(
+            input.update(NumberMsg::Increment),
+            Some(NumberAction::Changed(10.0))
+        );
+        assert!(input.update(NumberMsg::Increment).is_none());
+        assert_eq!(input.value(), 10.0);
+
+        input.set_value(3.0);
+        assert_eq!(
+            input.update(NumberMsg::Decrement),
+            Some(NumberAction::Changed(0.0))
+        );
+        assert!(input.update(NumberMsg::Decrement).is_none());
+        assert_eq!(input.text(), "0");
+    }
+
+    #[test]
+    fn test_bounds_clamp_initial_value() {
+        let input = NumberInput::new().with_value(50.0).with_max(20.0);
+        assert_eq!(input.value(), 20.0);
+
+        let input = NumberInput::new().with_min(1.0);
+        assert_eq!(input.value(), 1.0);
+    }
+
+    #[test]
+    fn test_rejects_non_numeric_characters() {
+        let mut input = NumberInput::new();
+        input.update(NumberMsg::Input(TextInputMsg::Clear));
+
+        type_str(&mut input, "1a2.b5x");
+        assert_eq!(input.text(), "12.5");
+        assert_eq!(input.value(), 12.5);
+
+        // A second decimal point and a misplaced sign are rejected
+        type_str(&mut input, ".-");
+        assert_eq!(input.text(), "12.5");
+
+        let pasted = input.update(NumberMsg::Input(TextInputMsg::Paste("abc".to_string())));
+        assert!(pasted.is_none());
+        assert_eq!(input.text(), "12.5");
+    }
+
+    #[test]
+    fn test_rejects_non_numeric_primary_paste() {
+        use crate::clipboard::MemoryClipboard;
+
+        let clipboard = Arc::new(MemoryClipboard::new());
+        clipboard
+            .set_text_in(ClipboardKind::Primary, "abc")
+            .unwrap();
+        let mut input = NumberInput::new()
+            .with_clipboard(clipboard.clone())
+            .with_primary_selection(true)
+            .with_value(12.0);
+
+        let pasted = input.update(NumberMsg::Input(TextInputMsg::PasteFromPrimary));
+        assert!(pasted.is_none());
+        assert_eq!(input.text(), "12");
+
+        clipboard.set_text_in(ClipboardKind::Primary, "5").unwrap();
+        let pasted = input.update(NumberMsg::Input(TextInputMsg::PasteFromPrimary));
+        assert_eq!(pasted, Some(NumberAction::Changed(125.0)));
+        assert_eq!(input.text(), "125");
+    }
+
+    #[test]
+    fn test_sign_only_allowed_below_zero() {
+        let mut input = NumberInput::new();
+        input.update(NumberMsg::Input(TextInputMsg::Clear));
+        type_str(&mut input, "-3");
+        assert_eq!(input.value(), -3.0);
+
+        let mut input = NumberInput::new().with_min(0.0);
+        input.update(NumberMsg::Input(TextInputMsg::Clear));
+        type_str(&mut input, "-3");
+        assert_eq!(input.text(), "3");
+    }
+
+    #[test]
+    fn test_precision_formatting() {
+        let mut input = NumberInput::new().with_precision(2).with_step(0.1);
+        assert_eq!(input.text(), "0.00");
+
+        input.update(NumberMsg::Increment);
+        input.update(NumberMsg::Increment);
+        input.update(NumberMsg::Increment);
+        assert_eq!(input.text(), "0.30");
+        assert_eq!(input.value(), 0.3);
+
+        input.set_value(1.23456);
+        assert_eq!(input.value(), 1.23);
+        assert_eq!(input.format_value(2.0), "2.00");
+
+        // Typing more decimals than the precision allows is rejected
+        input.update(NumberMsg::Input(TextInputMsg::InsertChar('9')));
+        assert_eq!(input.text(), "1.23");
+    }
+
+    #[test]
+    fn test_zero_precision_rejects_decimal_point() {
+        let mut input = NumberInput::new().with_precision(0).with_value(7.6);
+        assert_eq!(input.text(), "8");
+
+        input.update(NumberMsg::Input(TextInputMsg::InsertChar('.')));
+        assert_eq!(input.text(), "8");
+    }
+
+    #[test]
+    fn test_blur_normalizes_text() {
+        let mut input = NumberInput::new().with_range(0.0, 100.0).with_precision(1);
+        input.set_focused(true);
+        input.update(NumberMsg::Input(TextInputMsg::Clear));
+
+        type_str(&mut input, "250");
+        assert_eq!(input.text(), "250");
+        assert_eq!(input.value(), 100.0);
+
+        input.on_blur();
+        assert_eq!(input.text(), "100.0");
+    }
+
+    #[test]
+    fn test_from_key() {
+        assert!(matches!(
+            NumberMsg::from_key(&key(KeyCode::Up)),
+            Some(NumberMsg::Increment)
+        ));
+        assert!(matches!(
+            NumberMsg::from_key(&key(KeyCode::Down)),
+            Some(NumberMsg::Decrement)
+        ));
+        assert!(matches!(
+            NumberMsg::from_key(&key(KeyCode::Char('4'))),
+            Some(NumberMsg::Input(TextInputMsg::InsertChar('4')))
+        ));
+        assert!(NumberMsg::from_key(&key(KeyCode::Tab)).is_none());
+    }
+
+    #[test]
+    fn test_render_indicators() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let theme = Theme::default();
+        let input = NumberInput::new()
+            .with_max(5.0)
+            .with_value(5.0)
+            .with_indicators(true)
+            .with_theme(theme.clone());
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+        terminal
+            .draw(|frame| input.render(frame, Rect::new(0, 0, 10, 3)))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(1, 1)].symbol(), "5");
+        assert_eq!(buffer[(9, 0)].symbol(), "▲");
+        assert_eq!(buffer[(9, 2)].symbol(), "▼");
+        assert_eq!(buffer[(9, 0)].fg, theme.disabled_text_style().fg.unwrap());
+    }
+}
@@ -0,0 +1,205 @@
+//! Scroll position shared by components that show a window onto a list.
+//!
+//! A [`ScrollState`] tracks which items of a list are on screen: the
+//! `offset` of the first visible item, the `viewport` size (how many items
+//! fit), and the `total` number of items. It keeps the offset in range as
+//! any of these change, so components only decide *what* to show rather
+//! than re-deriving the clamping arithmetic.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::ScrollState;
+//!
+//! let mut scroll = ScrollState::new(100, 10);
+//! scroll.ensure_visible(25);
+//! assert_eq!(scroll.offset(), 16);
+//! assert_eq!(scroll.visible_range(), 16..26);
+//!
+//! scroll.page_down();
+//! assert_eq!(scroll.offset(), 26);
+//! assert_eq!(scroll.max_offset(), 90);
+//! ```
+
+use std::ops::Range;
+
+/// The scroll position of a viewport over a list of items.
+///
+/// The offset never goes past [`max_offset`](Self::max_offset), so the last
+/// page is always full when there are enough items to fill it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollState {
+    /// Index of the first visible item.
+    offset: usize,
+    /// Number of items that fit on screen.
+    viewport: usize,
+    /// Number of items in the list.
+    total: usize,
+}
+
+impl ScrollState {
+    /// Creates a state scrolled to the top of `total` items, showing
+    /// `viewport` at a time.
+    pub fn new(total: usize, viewport: usize) -> Self {
+        Self {
+            offset: 0,
+            viewport,
+            total,
+        }
+    }
+
+    /// Returns the index of the first visible item.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns how many items fit on screen.
+    pub fn viewport(&self) -> usize {
+        self.viewport
+    }
+
+    /// Returns the number of items in the list.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Sets how many items fit on screen, keeping the offset in range.
+    pub fn set_viewport(&mut self, viewport: usize) {
+        self.viewport = viewport;
+        self.clamp();
+    }
+
+    /// Sets the number of items in the list, keeping the offset in range.
+    pub fn set_total(&mut self, total: usize) {
+        self.total = total;
+        self.clamp();
+    }
+
+    /// Returns the largest offset, which shows the last page.
+    pub fn max_offset(&self) -> usize {
+        self.total.saturating_sub(self.viewport)
+    }
+
+    /// Returns the range of item indices on screen.
+    pub fn visible_range(&self) -> Range<usize> {
+        self.offset..(self.offset + self.viewport).min(self.total)
+    }
+
+    /// Returns true if the item at `index` is on screen.
+    pub fn is_visible(&self, index: usize) -> bool {
+        self.visible_range().contains(&index)
+    }
+
+    /// Scrolls so the item at `index` is first on screen, or as close to
+    /// first as the last page allows.
+    pub fn scroll_to(&mut self, index: usize) {
+        self.offset = index.min(self.max_offset());
+    }
+
+    /// Scrolls by `delta` items, up for negative values, stopping at
+    /// either end.
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.scroll_to(self.offset.saturating_add_signed(delta));
+    }
+
+    /// Scrolls up by one page, stopping at the top.
+    pub fn page_up(&mut self) {
+        self.scroll_by(-(self.page() as isize));
+    }
+
+    /// Scrolls down by one page, stopping at the last page.
+    pub fn page_down(&mut self) {
+        self.scroll_by(self.page() as isize);
+    }
+
+    /// Scrolls just far enough to bring the item at `index` on screen.
+    ///
+    /// Items above the viewport end up at the top and items below it at the
+    /// bottom; items already visible leave the offset unchanged.
+    pub fn ensure_visible(&mut self, index: usize) {
+        if index < self.offset {
+            self.offset = index;
+        } else if self.viewport > 0 && index >= self.offset + self.viewport {
+            self.offset = index + 1 - self.viewport;
+        }
+        self.clamp();
+    }
+
+    /// Returns the number of items a page scrolls by, at least one.
+    fn page(&self) -> usize {
+        self.viewport.max(1)
+    }
+
+    /// Pulls the offset back within range after the sizes change.
+    fn clamp(&mut self) {
+        self.offset = self.offset.min(self.max_offset());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ensure_visible_scrolls_both_directions() {
+        let mut scroll = ScrollState::new(20, 5);
+
+        // Already visible: no change
+        scroll.ensure_visible(4);
+        assert_eq!(scroll.offset(), 0);
+
+        // Below the viewport: the item lands on the bottom row
+        scroll.ensure_visible(9);
+        assert_eq!(scroll.offset(), 5);
+        assert_eq!(scroll.visible_range(), 5..10);
+
+        // Above the viewport: the item lands on the top row
+        scroll.ensure_visible(2);
+        assert_eq!(scroll.offset(), 2);
+        assert!(scroll.is_visible(2) && !scroll.is_visible(7));
+
+        // Out of range indices still stop at the last page
+        scroll.ensure_visible(50);
+        assert_eq!(scroll.offset(), 15);
+    }
+
+    #[test]
+    fn test_page_up_and_down_clamp() {
+        let mut scroll = ScrollState::new(12, 5);
+        scroll.page_down();
+        assert_eq!(scroll.offset(), 5);
+        scroll.page_down();
+        assert_eq!(scroll.offset(), 7);
+        scroll.page_down();
+        assert_eq!(scroll.offset(), 7);
+
+        scroll.page_up();
+        assert_eq!(scroll.offset(), 2);
+        scroll.page_up();
+        assert_eq!(scroll.offset(), 0);
+
+        // An empty viewport still pages one item at a time
+        let mut empty = ScrollState::new(3, 0);
+        empty.page_down();
+        assert_eq!(empty.offset(), 1);
+    }
+
+    #[test]
+    fn test_max_offset_keeps_last_page_full() {
+        let mut scroll = ScrollState::new(10, 4);
+        assert_eq!(scroll.max_offset(), 6);
+        scroll.scroll_to(8);
+        assert_eq!(scroll.offset(), 6);
+        assert_eq!(scroll.visible_range(), 6..10);
+
+        // Fewer items than fit: no scrolling at all
+        assert_eq!(ScrollState::new(3, 4).max_offset(), 0);
+        assert_eq!(ScrollState::new(3, 4).visible_range(), 0..3);
+
+        // Shrinking the list or growing the viewport pulls the offset back
+        scroll.set_total(7);
+        assert_eq!(scroll.offset(), 3);
+        scroll.set_viewport(7);
+        assert_eq!(scroll.offset(), 0);
+    }
+}
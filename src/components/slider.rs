@@ -0,0 +1,511 @@
+//! Slider component for picking a value from a range.
+//!
+//! A [`Slider`] draws a horizontal track with a handle, supporting:
+//! - A minimum/maximum range snapped to a configurable step
+//! - Keyboard adjustment: Left/Right by one step, PageUp/PageDown by a
+//!   page of steps, and Home/End to jump to the bounds
+//! - Setting the value from a ratio of the range, for example from a mouse
+//!   position along the track
+//! - An optional value label beside the track
+//!
+//! When the step does not divide the range evenly, the maximum is still
+//! reachable as a stop of its own.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::{Component, Slider, SliderAction, SliderMsg};
+//!
+//! let mut volume = Slider::new(0.0, 100.0).with_step(5.0).with_value(50.0);
+//!
+//! let action = volume.update(SliderMsg::Increase);
+//! assert_eq!(action, Some(SliderAction::Changed(55.0)));
+//!
+//! volume.update(SliderMsg::SetRatio(0.25));
+//! assert_eq!(volume.value(), 25.0);
+//! ```
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use terminput::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{Component, Focusable, Measurable, Renderable};
+use crate::focus::FocusId;
+use crate::theme::Theme;
+
+/// Tolerance for treating a value as lying on a step.
+const STEP_EPSILON: f64 = 1e-9;
+
+/// Width of the track when measured without constraints.
+const PREFERRED_TRACK_WIDTH: u16 = 20;
+
+/// Messages that the Slider component can handle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SliderMsg {
+    /// Move to the next stop above the value.
+    Increase,
+    /// Move to the next stop below the value.
+    Decrease,
+    /// Move up by a page of steps.
+    PageIncrease,
+    /// Move down by a page of steps.
+    PageDecrease,
+    /// Set the value from a fraction of the range, clamped to `0.0..=1.0`.
+    SetRatio(f32),
+    /// Set the value directly, snapped to the nearest stop.
+    SetValue(f64),
+}
+
+impl SliderMsg {
+    /// Maps a key press to a Slider message.
+    ///
+    /// Left/Down decrease and Right/Up increase by one step, PageDown and
+    /// PageUp move by a page, and Home and End jump to the minimum and
+    /// maximum. Other keys return `None`.
+    pub fn from_key(key: &KeyEvent) -> Option<Self> {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CTRL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+
+        let msg = match key.code {
+            KeyCode::Left | KeyCode::Down => SliderMsg::Decrease,
+            KeyCode::Right | KeyCode::Up => SliderMsg::Increase,
+            KeyCode::PageDown => SliderMsg::PageDecrease,
+            KeyCode::PageUp => SliderMsg::PageIncrease,
+            KeyCode::Home => SliderMsg::SetRatio(0.0),
+            KeyCode::End => SliderMsg::SetRatio(1.0),
+            _ => return None,
+        };
+        Some(msg)
+    }
+}
+
+/// Actions emitted by the Slider component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SliderAction {
+    /// The value changed.
+    Changed(f64),
+}
+
+/// A horizontal slider over a numeric range.
+///
+/// Valid values, or stops, are the minimum plus whole multiples of the
+/// step, and the maximum.
+#[derive(Debug, Clone)]
+pub struct Slider {
+    /// Optional identifier for focus management.
+    id: Option<FocusId>,
+    /// Lower bound of the range.
+    min: f64,
+    /// Upper bound of the range.
+    max: f64,
+    /// Distance between stops.
+    step: f64,
+    /// Number of steps moved by a page.
+    page_steps: u32,
+    /// The current value.
+    value: f64,
+    /// Number of decimal places in the label, or `None` for the shortest.
+    precision: Option<usize>,
+    /// Whether the value label is shown.
+    show_value: bool,
+    /// Whether the slider has focus.
+    focused: bool,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl Slider {
+    /// Creates a slider over `min..=max` with a step of one.
+    ///
+    /// The bounds are swapped if given in the wrong order, and the value
+    /// starts at the minimum. A NaN or infinite bound is replaced with
+    /// `0.0`.
+    pub fn new(min: f64, max: f64) -> Self {
+        let finite = |bound: f64| if bound.is_finite() { bound } else { 0.0 };
+        let (min, max) = (finite(min), finite(max));
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        Self {
+            id: None,
+            min,
+            max,
+            step: 1.0,
+            page_steps: 10,
+            value: min,
+            precision: None,
+            show_value: true,
+            focused: false,
+            theme: None,
+        }
+    }
+
+    /// Sets the slider's identifier.
+    pub fn with_id(mut self, id: impl Into<FocusId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Returns the slider's identifier, if set.
+    pub fn id(&self) -> Option<&FocusId> {
+        self.id.as_ref()
+    }
+
+    /// Sets the initial value, snapped to the nearest stop.
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Sets the distance between stops.
+    ///
+    /// Zero or non-finite steps are ignored. The current value is snapped to
+    /// the new stops.
+    pub fn with_step(mut self, step: f64) -> Self {
+        let step = step.abs();
+        if step > 0.0 && step.is_finite() {
+            self.step = step;
+            let value = self.value;
+            self.set_value(value);
+        }
+        self
+    }
+
+    /// Sets how many steps PageUp and PageDown move.
+    pub fn with_page_steps(mut self, steps: u32) -> Self {
+        self.page_steps = steps.max(1);
+        self
+    }
+
+    /// Sets the number of decimal places shown in the label.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets whether the value label is shown beside the track.
+    pub fn with_value_label(mut self, show: bool) -> Self {
+        self.show_value = show;
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Sets the value, snapped to the nearest stop.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = self.snap(value);
+    }
+
+    /// Returns the minimum value.
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the maximum value.
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the distance between stops.
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Returns the value's position in the range, from `0.0` to `1.0`.
+    ///
+    /// An empty range reports `0.0`.
+    pub fn ratio(&self) -> f64 {
+        let span = self.max - self.min;
+        if span > 0.0 {
+            ((self.value - self.min) / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Formats a value using the configured precision.
+    pub fn format_value(&self, value: f64) -> String {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        }
+    }
+
+    /// Returns the number of steps from the minimum to a value.
+    fn steps_to(&self, value: f64) -> f64 {
+        (value - self.min) / self.step
+    }
+
+    /// Returns the value of the stop `steps` steps above the minimum.
+    fn stop(&self, steps: f64) -> f64 {
+        (self.min + steps * self.step).clamp(self.min, self.max)
+    }
+
+    /// Returns the nearest stop to a value.
+    fn snap(&self, value: f64) -> f64 {
+        if value.is_nan() {
+            return self.min;
+        }
+        let value = value.clamp(self.min, self.max);
+        let nearest = self.stop(self.steps_to(value).round());
+        // The maximum is a stop even when the step does not reach it
+        if (self.max - value).abs() < (nearest - value).abs() {
+            self.max
+        } else {
+            nearest
+        }
+    }
+
+    /// Returns the first stop above a value, or the maximum.
+    fn stop_above(&self, value: f64) -> f64 {
+        self.stop((self.steps_to(value) + STEP_EPSILON).floor() + 1.0)
+    }
+
+    /// Returns the first stop below a value, or the minimum.
+    fn stop_below(&self, value: f64) -> f64 {
+        self.stop((self.steps_to(value) - STEP_EPSILON).ceil() - 1.0)
+    }
+
+    /// Sets a new value, returning an action if it changed.
+    fn change_value(&mut self, value: f64) -> Option<SliderAction> {
+        let previous = self.value;
+        self.value = value;
+        (self.value != previous).then_some(SliderAction::Changed(self.value))
+    }
+
+    /// Returns the label text, padded to the widest value in the range.
+    fn label(&self) -> String {
+        let width = self
+            .format_value(self.min)
+            .len()
+            .max(self.format_value(self.max).len());
+        format!("{:>width$}", self.format_value(self.value))
+    }
+}
+
+impl Component for Slider {
+    type Message = SliderMsg;
+    type Action = SliderAction;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        let value = match msg {
+            SliderMsg::Increase => self.stop_above(self.value),
+            SliderMsg::Decrease => self.stop_below(self.value),
+            SliderMsg::PageIncrease => {
+                (0..self.page_steps).fold(self.value, |value, _| self.stop_above(value))
+            }
+            SliderMsg::PageDecrease => {
+                (0..self.page_steps).fold(self.value, |value, _| self.stop_below(value))
+            }
+            SliderMsg::SetRatio(ratio) => {
+                let ratio = f64::from(ratio).clamp(0.0, 1.0);
+                self.snap(self.min + ratio * (self.max - self.min))
+            }
+            SliderMsg::SetValue(value) => self.snap(value),
+        };
+        self.change_value(value)
+    }
+}
+
+impl Focusable for Slider {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+impl Renderable for Slider {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+
+        let label = self.label();
+        let label_width = label.len() as u16 + 1;
+        let (track_area, label_area) = if self.show_value && area.width > label_width {
+            let [track, label] =
+                Layout::horizontal([Constraint::Fill(1), Constraint::Length(label_width)])
+                    .areas(area);
+            (track, Some(label))
+        } else {
+            (area, None)
+        };
+
+        let width = track_area.width as usize;
+        let handle = (self.ratio() * (width - 1) as f64).round() as usize;
+        let handle_style = if self.focused {
+            Style::default()
+                .fg(theme.colors().border_focused)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.colors().text_primary)
+        };
+        let track = Line::from(vec![
+            Span::styled(
+                "━".repeat(handle),
+                Style::default().fg(theme.colors().primary),
+            ),
+            Span::styled("●", handle_style),
+            Span::styled("─".repeat(width - handle - 1), theme.border_style()),
+        ]);
+        let row = Rect::new(track_area.x, track_area.y, track_area.width, 1);
+        frame.render_widget(Paragraph::new(track), row);
+
+        if let Some(label_area) = label_area {
+            let row = Rect::new(label_area.x, label_area.y, label_area.width, 1);
+            let label = Paragraph::new(label).alignment(Alignment::Right);
+            frame.render_widget(label.style(theme.secondary_text_style()), row);
+        }
+    }
+}
+
+impl Measurable for Slider {
+    fn measure(&self, max: Size) -> Size {
+        let label_width = if self.show_value {
+            self.label().len() as u16 + 1
+        } else {
+            0
+        };
+        let width = PREFERRED_TRACK_WIDTH + label_width;
+        Size::new(width.min(max.width), 1.min(max.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{render, to_lines};
+    use terminput::{KeyEventKind, KeyEventState};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    #[test]
+    fn test_slider_sanitises_non_finite_bounds() {
+        let mut slider = Slider::new(f64::NAN, 10.0);
+        assert_eq!((slider.min(), slider.max()), (0.0, 10.0));
+        slider.set_value(4.0);
+        assert_eq!(slider.value(), 4.0);
+
+        let slider = Slider::new(f64::NEG_INFINITY, f64::INFINITY).with_value(3.0);
+        assert_eq!((slider.min(), slider.max()), (0.0, 0.0));
+        assert_eq!(slider.value(), 0.0);
+
+        let mut slider = Slider::new(5.0, f64::NAN).with_value(2.0);
+        assert_eq!((slider.min(), slider.max()), (0.0, 5.0));
+        assert_eq!(slider.value(), 2.0);
+        slider.update(SliderMsg::Increase);
+        assert_eq!(slider.value(), 3.0);
+    }
+
+    #[test]
+    fn test_slider_clamps_at_bounds() {
+        let mut slider = Slider::new(10.0, 0.0).with_value(25.0);
+        assert_eq!((slider.min(), slider.max()), (0.0, 10.0));
+        assert_eq!(slider.value(), 10.0);
+
+        assert_eq!(slider.update(SliderMsg::Increase), None);
+        assert_eq!(slider.update(SliderMsg::PageIncrease), None);
+        assert_eq!(slider.value(), 10.0);
+
+        assert_eq!(
+            slider.update(SliderMsg::SetValue(-4.0)),
+            Some(SliderAction::Changed(0.0))
+        );
+        assert_eq!(slider.update(SliderMsg::Decrease), None);
+        assert_eq!(slider.update(SliderMsg::SetRatio(-1.0)), None);
+        assert_eq!(slider.ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_slider_steps_that_do_not_divide_range() {
+        let mut slider = Slider::new(0.0, 10.0).with_step(3.0);
+
+        let mut visited = Vec::new();
+        while let Some(SliderAction::Changed(value)) = slider.update(SliderMsg::Increase) {
+            visited.push(value);
+        }
+        assert_eq!(visited, vec![3.0, 6.0, 9.0, 10.0]);
+
+        // Stepping down from the maximum lands back on the grid
+        slider.update(SliderMsg::Decrease);
+        assert_eq!(slider.value(), 9.0);
+
+        // Off-grid values snap to the nearest stop
+        slider.set_value(4.4);
+        assert_eq!(slider.value(), 3.0);
+        slider.set_value(9.6);
+        assert_eq!(slider.value(), 10.0);
+
+        let mut paged = Slider::new(0.0, 100.0).with_step(5.0).with_page_steps(4);
+        paged.update(SliderMsg::PageIncrease);
+        assert_eq!(paged.value(), 20.0);
+        paged.update(SliderMsg::Decrease);
+        paged.update(SliderMsg::PageDecrease);
+        assert_eq!(paged.value(), 0.0);
+    }
+
+    #[test]
+    fn test_slider_ratio_mapping_and_keys() {
+        let mut slider = Slider::new(-1.0, 1.0).with_step(0.5);
+        assert_eq!(
+            slider.update(SliderMsg::SetRatio(0.75)),
+            Some(SliderAction::Changed(0.5))
+        );
+        assert_eq!(slider.ratio(), 0.75);
+        slider.update(SliderMsg::SetRatio(0.6));
+        assert_eq!(slider.value(), 0.0);
+        slider.update(SliderMsg::SetRatio(2.0));
+        assert_eq!(slider.value(), 1.0);
+
+        let mut slider = Slider::new(0.0, 50.0);
+        for (code, expected) in [
+            (KeyCode::Right, 1.0),
+            (KeyCode::PageUp, 11.0),
+            (KeyCode::End, 50.0),
+            (KeyCode::Left, 49.0),
+            (KeyCode::PageDown, 39.0),
+            (KeyCode::Home, 0.0),
+        ] {
+            let msg = SliderMsg::from_key(&key(code)).unwrap();
+            slider.update(msg);
+            assert_eq!(slider.value(), expected, "{code:?}");
+        }
+        assert_eq!(SliderMsg::from_key(&key(KeyCode::Tab)), None);
+    }
+
+    #[test]
+    fn test_slider_renders_track_handle_and_label() {
+        let slider = Slider::new(0.0, 100.0).with_value(50.0);
+        let buffer = render(&slider, Rect::new(0, 0, 15, 1));
+        assert_eq!(to_lines(&buffer), vec!["━━━━━●─────  50"]);
+        let primary = Theme::default().colors().primary;
+        assert_eq!(buffer[(0, 0)].fg, primary);
+        assert_ne!(buffer[(8, 0)].fg, primary);
+
+        let slider = Slider::new(0.0, 1.0).with_value_label(false);
+        let lines = to_lines(&render(&slider, Rect::new(0, 0, 5, 1)));
+        assert_eq!(lines, vec!["●────"]);
+    }
+}
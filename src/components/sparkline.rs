@@ -0,0 +1,254 @@
+//! Sparkline component for drawing a small chart of a data series.
+//!
+//! A [`Sparkline`] draws each value as a vertical bar of block characters,
+//! using eighth-height glyphs (`▁` to `█`) so that even a single row shows
+//! the shape of the series. Values are scaled between the series' own
+//! minimum and maximum unless a fixed range is set. When there are more
+//! values than columns, neighbouring values are averaged so the whole
+//! series fits.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::backend::TestBackend;
+//! use ratatui::prelude::*;
+//! use tuilib::components::{Renderable, Sparkline};
+//!
+//! let data = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+//! let sparkline = Sparkline::new(&data);
+//!
+//! let mut terminal = Terminal::new(TestBackend::new(8, 1)).unwrap();
+//! terminal
+//!     .draw(|frame| sparkline.render(frame, frame.area()))
+//!     .unwrap();
+//! let bars: String = terminal
+//!     .backend()
+//!     .buffer()
+//!     .content()
+//!     .iter()
+//!     .map(|cell| cell.symbol())
+//!     .collect();
+//! assert_eq!(bars, "▁▂▃▄▅▆▇█");
+//! ```
+
+use ratatui::prelude::*;
+
+use super::{Measurable, Renderable};
+use crate::theme::Theme;
+
+/// Bar glyphs indexed by how many eighths of the cell they fill.
+const BARS: [&str; 9] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+/// Number of levels a single cell can show.
+const LEVELS_PER_ROW: u16 = 8;
+
+/// A small bar chart of a data series.
+#[derive(Debug, Clone)]
+pub struct Sparkline<'a> {
+    /// The values to draw, oldest first.
+    data: &'a [f64],
+    /// Fixed `(min, max)` range, or `None` to fit the data.
+    range: Option<(f64, f64)>,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl<'a> Sparkline<'a> {
+    /// Creates a sparkline for a series, scaled to fit its values.
+    pub fn new(data: &'a [f64]) -> Self {
+        Self {
+            data,
+            range: None,
+            theme: None,
+        }
+    }
+
+    /// Scales bars to a fixed range instead of the series' minimum and
+    /// maximum.
+    ///
+    /// Values outside the range are drawn at the nearest end. The bounds are
+    /// swapped if given in the wrong order.
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some(if min <= max { (min, max) } else { (max, min) });
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the data series.
+    pub fn data(&self) -> &[f64] {
+        self.data
+    }
+
+    /// Returns the range bars are scaled to, or `None` if the series has no
+    /// finite values and no fixed range is set.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.range.or_else(|| {
+            self.data
+                .iter()
+                .copied()
+                .filter(|v| v.is_finite())
+                .fold(None, |range, v| match range {
+                    None => Some((v, v)),
+                    Some((min, max)) => Some((f64::min(min, v), f64::max(max, v))),
+                })
+        })
+    }
+
+    /// Returns the value drawn in each column for the given width.
+    ///
+    /// Series longer than the width are split into `width` consecutive
+    /// buckets whose finite values are averaged. Columns with no finite
+    /// value are `None`.
+    fn columns(&self, width: usize) -> Vec<Option<f64>> {
+        let finite = |v: f64| v.is_finite().then_some(v);
+        if self.data.len() <= width {
+            return self.data.iter().map(|&v| finite(v)).collect();
+        }
+
+        let len = self.data.len();
+        (0..width)
+            .map(|column| {
+                let bucket = &self.data[column * len / width..(column + 1) * len / width];
+                let (sum, count) = bucket
+                    .iter()
+                    .filter(|v| v.is_finite())
+                    .fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+                (count > 0).then(|| sum / count as f64)
+            })
+            .collect()
+    }
+}
+
+/// Returns the bar height for a value in eighths of a row.
+///
+/// The minimum maps to one eighth so that every value stays visible, and the
+/// maximum fills all `height` rows. A flat series is drawn at the minimum.
+fn bar_level(value: f64, (min, max): (f64, f64), height: u16) -> u16 {
+    let levels = height.saturating_mul(LEVELS_PER_ROW);
+    if max <= min {
+        return 1;
+    }
+    let ratio = ((value - min) / (max - min)).clamp(0.0, 1.0);
+    1 + (ratio * f64::from(levels - 1)).round() as u16
+}
+
+impl Renderable for Sparkline<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let Some(range) = self.range() else {
+            return;
+        };
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+        let style = Style::default().fg(theme.colors().primary);
+
+        let buf = frame.buffer_mut();
+        for (x, value) in (area.x..).zip(self.columns(area.width as usize)) {
+            let Some(value) = value else {
+                continue;
+            };
+            let level = bar_level(value, range, area.height);
+
+            // Fill from the bottom row up, one row's worth of levels at a time
+            for row in 0..area.height {
+                let filled = level
+                    .saturating_sub(row * LEVELS_PER_ROW)
+                    .min(LEVELS_PER_ROW);
+                if filled == 0 {
+                    break;
+                }
+                let y = area.bottom() - 1 - row;
+                buf[(x, y)]
+                    .set_symbol(BARS[filled as usize])
+                    .set_style(style);
+            }
+        }
+    }
+}
+
+impl Measurable for Sparkline<'_> {
+    fn measure(&self, max: Size) -> Size {
+        if self.data.is_empty() {
+            return Size::new(0, 0);
+        }
+        let width = u16::try_from(self.data.len()).unwrap_or(u16::MAX);
+        Size::new(width.min(max.width), 1.min(max.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{render, to_lines};
+
+    #[test]
+    fn test_sparkline_scales_to_area_height() {
+        let data = [0.0, 5.0, 10.0];
+        let sparkline = Sparkline::new(&data);
+        assert_eq!(sparkline.range(), Some((0.0, 10.0)));
+
+        // 16 levels over two rows: 1, 1 + round(7.5) = 9, 16
+        let buffer = render(&sparkline, Rect::new(0, 0, 3, 2));
+        assert_eq!(to_lines(&buffer), vec![" ▁█", "▁██"]);
+        assert_eq!(buffer[(2, 0)].fg, Theme::default().colors().primary);
+
+        let ramp = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        let buffer = render(&Sparkline::new(&ramp), Rect::new(0, 0, 10, 1));
+        assert_eq!(to_lines(&buffer), vec!["▁▂▃▄▅▆▇█  "]);
+    }
+
+    #[test]
+    fn test_sparkline_fixed_range_clamps() {
+        let data = [-10.0, 50.0, 150.0];
+        let sparkline = Sparkline::new(&data).with_range(100.0, 0.0);
+        assert_eq!(sparkline.range(), Some((0.0, 100.0)));
+
+        let buffer = render(&sparkline, Rect::new(0, 0, 3, 1));
+        assert_eq!(to_lines(&buffer), vec!["▁▅█"]);
+    }
+
+    #[test]
+    fn test_sparkline_handles_empty_and_short_series() {
+        let empty = Sparkline::new(&[]);
+        assert_eq!(empty.range(), None);
+        assert_eq!(empty.measure(Size::new(80, 24)), Size::new(0, 0));
+        let buffer = render(&empty, Rect::new(0, 0, 4, 2));
+        assert_eq!(to_lines(&buffer), vec!["    ", "    "]);
+
+        // A single point or a flat series draws a baseline
+        let single = [3.0];
+        let buffer = render(&Sparkline::new(&single), Rect::new(0, 0, 3, 1));
+        assert_eq!(to_lines(&buffer), vec!["▁  "]);
+        let flat = [2.0, 2.0, 2.0];
+        let buffer = render(&Sparkline::new(&flat), Rect::new(0, 0, 3, 1));
+        assert_eq!(to_lines(&buffer), vec!["▁▁▁"]);
+
+        // Non-finite values leave a gap
+        let gaps = [0.0, f64::NAN, 1.0];
+        let buffer = render(&Sparkline::new(&gaps), Rect::new(0, 0, 3, 1));
+        assert_eq!(to_lines(&buffer), vec!["▁ █"]);
+    }
+
+    #[test]
+    fn test_sparkline_downsamples_long_series() {
+        // Pairs are averaged into 0, 4 and 8
+        let data = [0.0, 0.0, 3.0, 5.0, 8.0, 8.0];
+        let sparkline = Sparkline::new(&data);
+        assert_eq!(sparkline.measure(Size::new(80, 24)), Size::new(6, 1));
+        assert_eq!(sparkline.measure(Size::new(3, 24)), Size::new(3, 1));
+
+        let buffer = render(&sparkline, Rect::new(0, 0, 3, 1));
+        assert_eq!(to_lines(&buffer), vec!["▁▅█"]);
+
+        // Uneven buckets still cover every value
+        let sparkline = Sparkline::new(&[0.0, 0.0, 0.0, 7.0, 7.0]);
+        let buffer = render(&sparkline, Rect::new(0, 0, 2, 1));
+        assert_eq!(to_lines(&buffer), vec!["▁▆"]);
+    }
+}
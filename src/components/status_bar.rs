@@ -0,0 +1,276 @@
+//! StatusBar component showing a key binding help line.
+//!
+//! A [`StatusBar`] renders a compact `key: action` line from a
+//! [`KeyBindings`] set using [`KeyBindings::help_entries`], so the help line
+//! always matches the bindings actually in effect. Entries that do not fit
+//! are dropped from the end and replaced with `…`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::StatusBar;
+//! use tuilib::input::KeyBindings;
+//!
+//! let bindings = KeyBindings::builder()
+//!     .bind("quit", "q")
+//!     .bind("save", "Ctrl+s")
+//!     .bind_multi("navigate", &["Tab", "Shift+Tab"])
+//!     .build();
+//!
+//! let status = StatusBar::new(&bindings)
+//!     .with_order(["navigate", "save"])
+//!     .with_label("navigate", "Navigate");
+//!
+//! assert_eq!(
+//!     status.line(80).to_string(),
+//!     "Tab/Shift+Tab: Navigate  Ctrl+s: save  q: quit"
+//! );
+//! ```
+
+use std::collections::HashMap;
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use super::Renderable;
+use crate::input::{HelpEntry, KeyBindings};
+use crate::theme::Theme;
+
+/// Separator placed between entries.
+const ENTRY_SEPARATOR: &str = "  ";
+
+/// Marker shown when entries were dropped for lack of space.
+const OVERFLOW_MARKER: &str = "…";
+
+/// A one-line help bar listing key bindings.
+#[derive(Debug, Clone)]
+pub struct StatusBar<'a> {
+    /// The bindings to describe.
+    bindings: &'a KeyBindings,
+    /// Context whose bindings are shown, in addition to global ones.
+    context: Option<String>,
+    /// Actions listed first, in this order.
+    order: Vec<String>,
+    /// Display labels overriding action names.
+    labels: HashMap<String, String>,
+    /// Actions left out of the help line.
+    hidden: Vec<String>,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl<'a> StatusBar<'a> {
+    /// Creates a status bar describing the global bindings.
+    pub fn new(bindings: &'a KeyBindings) -> Self {
+        Self {
+            bindings,
+            context: None,
+            order: Vec::new(),
+            labels: HashMap::new(),
+            hidden: Vec::new(),
+            theme: None,
+        }
+    }
+
+    /// Shows the bindings for a context (mode) as well as global ones.
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    /// Sets the actions to list first, in priority order.
+    ///
+    /// When space runs out, actions later in the line are dropped first, so
+    /// the most important actions should come first here. Actions not listed
+    /// follow in alphabetical order.
+    pub fn with_order<I, S>(mut self, order: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.order = order.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the label shown for an action instead of its name.
+    pub fn with_label(mut self, action: impl Into<String>, label: impl Into<String>) -> Self {
+        self.labels.insert(action.into(), label.into());
+        self
+    }
+
+    /// Leaves an action out of the help line.
+    pub fn with_hidden(mut self, action: impl Into<String>) -> Self {
+        self.hidden.push(action.into());
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the entries to display, in display order.
+    pub fn entries(&self) -> Vec<HelpEntry> {
+        let mut entries: Vec<HelpEntry> = self
+            .bindings
+            .help_entries(self.context.as_deref())
+            .into_iter()
+            .filter(|e| !e.keys.is_empty() && !self.hidden.iter().any(|h| h == e.action.name()))
+            .collect();
+
+        // Stable sort keeps the alphabetical order among unprioritized actions
+        entries.sort_by_key(|e| {
+            self.order
+                .iter()
+                .position(|o| o == e.action.name())
+                .unwrap_or(usize::MAX)
+        });
+        entries
+    }
+
+    /// Returns the label displayed for an action.
+    fn label<'b>(&'b self, entry: &'b HelpEntry) -> &'b str {
+        self.labels
+            .get(entry.action.name())
+            .map(String::as_str)
+            .unwrap_or(entry.action.name())
+    }
+
+    /// Builds the help line for the given width.
+    ///
+    /// Entries are added until the next one would overflow; if any were
+    /// dropped, the line ends with `…`.
+    pub fn line(&self, width: u16) -> Line<'static> {
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+        let key_style = theme.emphasis_style();
+        let label_style = theme.muted_style();
+
+        let width = width as usize;
+        let marker_width = Span::raw(OVERFLOW_MARKER).width();
+        let entries = self.entries();
+        let mut spans = Vec::new();
+        let mut used = 0;
+
+        for (index, entry) in entries.iter().enumerate() {
+            let keys = entry.keys.join("/");
+            let label = format!(": {}", self.label(entry));
+            let separator = if index == 0 { "" } else { ENTRY_SEPARATOR };
+            let entry_width = Span::raw(separator).width()
+                + Span::raw(keys.as_str()).width()
+                + Span::raw(label.as_str()).width();
+
+            // Leave room for the overflow marker unless this is the last entry
+            let is_last = index + 1 == entries.len();
+            let reserve = if is_last { 0 } else { marker_width + 1 };
+            if used + entry_width + reserve > width {
+                if used + 1 + marker_width <= width {
+                    if index > 0 {
+                        spans.push(Span::styled(" ", label_style));
+                    }
+                    spans.push(Span::styled(OVERFLOW_MARKER, label_style));
+                } else if used == 0 && marker_width <= width {
+                    spans.push(Span::styled(OVERFLOW_MARKER, label_style));
+                }
+                break;
+            }
+
+            if !separator.is_empty() {
+                spans.push(Span::styled(separator, label_style));
+            }
+            spans.push(Span::styled(keys, key_style));
+            spans.push(Span::styled(label, label_style));
+            used += entry_width;
+        }
+
+        Line::from(spans)
+    }
+}
+
+impl Renderable for StatusBar<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.height == 0 {
+            return;
+        }
+        frame.render_widget(Paragraph::new(self.line(area.width)), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_bindings() -> KeyBindings {
+        KeyBindings::builder()
+            .bind("quit", "q")
+            .bind("toggle_theme", "t")
+            .bind_multi("navigate", &["Tab", "Shift+Tab"])
+            .bind_multi("activate", &["Enter", "Space"])
+            .context("modal", |ctx| ctx.bind("close", "Escape"))
+            .build()
+    }
+
+    #[test]
+    fn test_renders_key_labels() {
+        let bindings = sample_bindings();
+        let status = StatusBar::new(&bindings)
+            .with_order(["navigate", "activate", "toggle_theme", "quit"])
+            .with_label("navigate", "Navigate")
+            .with_label("activate", "Activate")
+            .with_label("toggle_theme", "Toggle Theme")
+            .with_label("quit", "Quit");
+
+        assert_eq!(
+            status.line(100).to_string(),
+            "Tab/Shift+Tab: Navigate  Enter/Space: Activate  t: Toggle Theme  q: Quit"
+        );
+    }
+
+    #[test]
+    fn test_context_and_hidden_actions() {
+        let bindings = sample_bindings();
+        let status = StatusBar::new(&bindings)
+            .with_context("modal")
+            .with_hidden("navigate")
+            .with_hidden("activate")
+            .with_hidden("toggle_theme");
+
+        assert_eq!(status.line(40).to_string(), "Esc: close  q: quit");
+    }
+
+    #[test]
+    fn test_truncates_deterministically() {
+        let bindings = sample_bindings();
+        let status = StatusBar::new(&bindings).with_order(["quit", "toggle_theme"]);
+
+        // "q: quit  t: toggle_theme" is 24 wide; the marker needs 2 more
+        assert_eq!(status.line(26).to_string(), "q: quit  t: toggle_theme …");
+        assert_eq!(status.line(25).to_string(), "q: quit …");
+        assert_eq!(status.line(9).to_string(), "q: quit …");
+        assert_eq!(status.line(3).to_string(), "…");
+        assert_eq!(status.line(0).to_string(), "");
+
+        // Repeated calls give the same result
+        assert_eq!(status.line(25), status.line(25));
+    }
+
+    #[test]
+    fn test_render_styles() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let theme = Theme::default();
+        let bindings = KeyBindings::builder().bind("quit", "q").build();
+        let status = StatusBar::new(&bindings).with_theme(theme.clone());
+
+        let mut terminal = Terminal::new(TestBackend::new(20, 1)).unwrap();
+        terminal
+            .draw(|frame| status.render(frame, Rect::new(0, 0, 20, 1)))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(0, 0)].symbol(), "q");
+        assert_eq!(buffer[(0, 0)].fg, theme.emphasis_style().fg.unwrap());
+        assert_eq!(buffer[(3, 0)].fg, theme.muted_style().fg.unwrap());
+    }
+}
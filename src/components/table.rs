@@ -0,0 +1,469 @@
+//! Table component for tabular data.
+//!
+//! A [`Table`] displays rows of text cells under a header that stays fixed
+//! while the body scrolls. Column widths are given as ratatui
+//! [`Constraint`]s and resolved against the available width; cells that do
+//! not fit are truncated with an ellipsis.
+//!
+//! Styling comes from the theme's [`TableStyle`](crate::theme::TableStyle).
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::layout::Constraint;
+//! use tuilib::components::{Column, Component, Table, TableAction, TableMsg};
+//!
+//! let mut table = Table::new(vec![
+//!     Column::new("Name", Constraint::Fill(1)),
+//!     Column::new("Size", Constraint::Length(6)),
+//! ])
+//! .with_rows(vec![
+//!     vec!["README.md".to_string(), "2 KB".to_string()],
+//!     vec!["Cargo.toml".to_string(), "1 KB".to_string()],
+//! ]);
+//!
+//! table.update(TableMsg::Down);
+//! assert_eq!(table.selected(), Some(1));
+//! assert_eq!(table.update(TableMsg::Activate), Some(TableAction::RowActivated(1)));
+//! ```
+
+use std::cell::Cell;
+
+use ratatui::prelude::*;
+
+use super::{Component, Focusable, Renderable, ScrollState};
+use crate::focus::FocusId;
+use crate::text::truncate;
+use crate::theme::Theme;
+
+/// Number of rows moved by page navigation before the table is first rendered.
+const DEFAULT_PAGE_SIZE: usize = 10;
+
+/// A table column: a header label and a width constraint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    header: String,
+    width: Constraint,
+}
+
+impl Column {
+    /// Creates a column with the given header and width constraint.
+    pub fn new(header: impl Into<String>, width: Constraint) -> Self {
+        Self {
+            header: header.into(),
+            width,
+        }
+    }
+
+    /// Returns the header label.
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    /// Returns the width constraint.
+    pub fn width(&self) -> Constraint {
+        self.width
+    }
+}
+
+/// Messages that the Table component can handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableMsg {
+    /// Select the previous row.
+    Up,
+    /// Select the next row.
+    Down,
+    /// Move the selection up by one page.
+    PageUp,
+    /// Move the selection down by one page.
+    PageDown,
+    /// Activate the selected row (usually bound to Enter).
+    Activate,
+}
+
+/// Actions emitted by the Table component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableAction {
+    /// The row at this index was activated.
+    RowActivated(usize),
+}
+
+/// A scrollable table with a fixed header and row selection.
+#[derive(Debug, Clone)]
+pub struct Table {
+    /// Optional identifier for focus management and style overrides.
+    id: Option<FocusId>,
+    /// Column definitions.
+    columns: Vec<Column>,
+    /// Row data, one string per column.
+    rows: Vec<Vec<String>>,
+    /// Index of the selected row.
+    selected: usize,
+    /// Body rows shown in the last render; the offset is adjusted when
+    /// rendering to follow the selection.
+    scroll: Cell<ScrollState>,
+    /// Whether the table is focused.
+    focused: bool,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl Table {
+    /// Creates an empty table with the given columns.
+    pub fn new(columns: Vec<Column>) -> Self {
+        Self {
+            id: None,
+            columns,
+            rows: Vec::new(),
+            selected: 0,
+            scroll: Cell::new(ScrollState::new(0, DEFAULT_PAGE_SIZE)),
+            focused: false,
+            theme: None,
+        }
+    }
+
+    /// Sets the table's identifier.
+    ///
+    /// The ID is used to look up per-component style overrides on the theme.
+    pub fn with_id(mut self, id: impl Into<FocusId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Returns the table's identifier, if set.
+    pub fn id(&self) -> Option<&FocusId> {
+        self.id.as_ref()
+    }
+
+    /// Sets the rows.
+    pub fn with_rows(mut self, rows: Vec<Vec<String>>) -> Self {
+        self.set_rows(rows);
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Replaces the rows, keeping the selection in range.
+    pub fn set_rows(&mut self, rows: Vec<Vec<String>>) {
+        self.rows = rows;
+        self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+    }
+
+    /// Returns the columns.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Returns the rows.
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    /// Returns the selected row index, if there are any rows.
+    pub fn selected(&self) -> Option<usize> {
+        (!self.rows.is_empty()).then_some(self.selected)
+    }
+
+    /// Selects a row, clamped to the last row.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.rows.len().saturating_sub(1));
+    }
+
+    /// Returns the index of the first row shown in the last render.
+    pub fn offset(&self) -> usize {
+        self.scroll.get().offset()
+    }
+
+    /// Resolves the column widths for a table `width` cells wide.
+    ///
+    /// When column separators are enabled, one cell between each pair of
+    /// columns is reserved for the separator.
+    pub fn column_widths(&self, width: u16) -> Vec<u16> {
+        let spacing = u16::from(self.theme().components().table.show_column_separators);
+        Layout::horizontal(self.columns.iter().map(|c| c.width))
+            .spacing(spacing)
+            .split(Rect::new(0, 0, width, 1))
+            .iter()
+            .map(|r| r.width)
+            .collect()
+    }
+
+    fn theme(&self) -> Theme {
+        self.theme.as_ref().cloned().unwrap_or_default()
+    }
+
+    /// Moves the selection by `delta` rows, clamping at either end.
+    fn move_selection(&mut self, delta: isize) {
+        let last = self.rows.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+
+    /// Renders one row of cells into the buffer.
+    fn render_row(
+        &self,
+        buf: &mut Buffer,
+        cells: &[Rect],
+        y: u16,
+        values: &[String],
+        style: Style,
+    ) {
+        for (cell, value) in cells.iter().zip(values) {
+            let text = truncate(value, cell.width as usize);
+            buf.set_stringn(cell.x, y, text, cell.width as usize, style);
+        }
+    }
+}
+
+impl Component for Table {
+    type Message = TableMsg;
+    type Action = TableAction;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        if self.rows.is_empty() {
+            return None;
+        }
+
+        let page = self.scroll.get().viewport().max(1) as isize;
+        match msg {
+            TableMsg::Up => self.move_selection(-1),
+            TableMsg::Down => self.move_selection(1),
+            TableMsg::PageUp => self.move_selection(-page),
+            TableMsg::PageDown => self.move_selection(page),
+            TableMsg::Activate => return Some(TableAction::RowActivated(self.selected)),
+        }
+        None
+    }
+}
+
+impl Focusable for Table {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+impl Renderable for Table {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.height == 0 || area.width == 0 {
+            return;
+        }
+
+        let theme = self.theme();
+        let table_style = &theme.components().table;
+        let (header_style, row_style, selected_style, border_style) = match self.id {
+            Some(ref id) => (
+                theme.resolve_style(id, theme.table_header_style()),
+                theme.resolve_style(id, theme.table_row_style()),
+                theme.resolve_style(id, theme.table_selected_style()),
+                theme.resolve_border_style(id, theme.border_style()),
+            ),
+            None => (
+                theme.table_header_style(),
+                theme.table_row_style(),
+                theme.table_selected_style(),
+                theme.border_style(),
+            ),
+        };
+
+        let cells = Layout::horizontal(self.columns.iter().map(|c| c.width))
+            .spacing(u16::from(table_style.show_column_separators))
+            .split(Rect::new(area.x, 0, area.width, 1));
+
+        // Rows below the header; scroll just enough to keep the selection visible
+        let body_height = (area.height - 1) as usize;
+        let mut scroll = self.scroll.get();
+        scroll.set_total(self.rows.len());
+        scroll.set_viewport(body_height);
+        scroll.ensure_visible(self.selected);
+        self.scroll.set(scroll);
+
+        let buf = frame.buffer_mut();
+
+        // The header is drawn on the first line regardless of scrolling
+        let headers: Vec<String> = self.columns.iter().map(|c| c.header.clone()).collect();
+        self.render_row(buf, &cells, area.y, &headers, header_style);
+
+        for (line, (index, row)) in self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(scroll.offset())
+            .take(body_height)
+            .enumerate()
+        {
+            let y = area.y + 1 + line as u16;
+            let style = if table_style.highlight_rows && index == self.selected {
+                selected_style
+            } else {
+                row_style
+            };
+            buf.set_style(Rect::new(area.x, y, area.width, 1), style);
+            self.render_row(buf, &cells, y, row, style);
+        }
+
+        if table_style.show_column_separators {
+            for cell in cells.iter().skip(1) {
+                let x = cell.x - 1;
+                for y in area.y..area.bottom() {
+                    buf[(x, y)].set_symbol("│").set_style(border_style);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::TableStyle;
+
+    fn rows(count: usize) -> Vec<Vec<String>> {
+        (0..count)
+            .map(|i| vec![format!("row{}", i), format!("{}", i * 10)])
+            .collect()
+    }
+
+    fn sample_table(count: usize) -> Table {
+        Table::new(vec![
+            Column::new("Name", Constraint::Length(6)),
+            Column::new("Value", Constraint::Length(5)),
+        ])
+        .with_rows(rows(count))
+    }
+
+    fn render_lines(table: &Table, width: u16, height: u16) -> Vec<String> {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| table.render(frame, Rect::new(0, 0, width, height)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_navigation_clamps() {
+        let mut table = sample_table(3);
+        assert_eq!(table.selected(), Some(0));
+
+        table.update(TableMsg::Up);
+        assert_eq!(table.selected(), Some(0));
+
+        table.update(TableMsg::Down);
+        table.update(TableMsg::Down);
+        table.update(TableMsg::Down);
+        assert_eq!(table.selected(), Some(2));
+        assert_eq!(
+            table.update(TableMsg::Activate),
+            Some(TableAction::RowActivated(2))
+        );
+    }
+
+    #[test]
+    fn test_empty_table() {
+        let mut table = sample_table(0);
+        assert!(table.selected().is_none());
+        assert!(table.update(TableMsg::Activate).is_none());
+    }
+
+    #[test]
+    fn test_header_stays_fixed_while_scrolling() {
+        let mut table = sample_table(10);
+        table.select(6);
+
+        let lines = render_lines(&table, 12, 4);
+        assert_eq!(lines[0], "Name  │Value");
+        assert_eq!(&lines[1..], ["row4  │40", "row5  │50", "row6  │60"]);
+    }
+
+    #[test]
+    fn test_selection_scroll_follow() {
+        let mut table = sample_table(10);
+        render_lines(&table, 12, 4);
+        assert_eq!(table.offset(), 0);
+
+        // Moving within the page does not scroll
+        table.update(TableMsg::Down);
+        table.update(TableMsg::Down);
+        render_lines(&table, 12, 4);
+        assert_eq!(table.offset(), 0);
+
+        // Moving past the bottom scrolls by one row
+        table.update(TableMsg::Down);
+        render_lines(&table, 12, 4);
+        assert_eq!(table.offset(), 1);
+
+        // Page navigation uses the rendered body height
+        table.update(TableMsg::PageDown);
+        assert_eq!(table.selected(), Some(6));
+        render_lines(&table, 12, 4);
+        assert_eq!(table.offset(), 4);
+
+        table.update(TableMsg::PageUp);
+        table.update(TableMsg::PageUp);
+        assert_eq!(table.selected(), Some(0));
+        render_lines(&table, 12, 4);
+        assert_eq!(table.offset(), 0);
+    }
+
+    #[test]
+    fn test_proportional_column_widths() {
+        let table = Table::new(vec![
+            Column::new("A", Constraint::Fill(1)),
+            Column::new("B", Constraint::Fill(3)),
+        ]);
+        // 41 cells minus one separator leaves 40 to share 1:3
+        assert_eq!(table.column_widths(41), vec![10, 30]);
+
+        let theme = Theme::builder()
+            .table_style(TableStyle {
+                show_column_separators: false,
+                ..TableStyle::default()
+            })
+            .build();
+        let table = Table::new(vec![
+            Column::new("A", Constraint::Length(4)),
+            Column::new("B", Constraint::Percentage(50)),
+            Column::new("C", Constraint::Fill(1)),
+        ])
+        .with_theme(theme);
+        assert_eq!(table.column_widths(20), vec![4, 10, 6]);
+    }
+
+    #[test]
+    fn test_cells_truncate_with_ellipsis() {
+        let table = Table::new(vec![
+            Column::new("Description", Constraint::Length(6)),
+            Column::new("N", Constraint::Length(2)),
+        ])
+        .with_rows(vec![vec!["a long value".to_string(), "1".to_string()]]);
+
+        let lines = render_lines(&table, 9, 2);
+        assert_eq!(lines, vec!["Descr…│N", "a lon…│1"]);
+    }
+
+    #[test]
+    fn test_table_renders_into_tiny_areas() {
+        let mut table = sample_table(3);
+        table.set_focused(true);
+        assert!(render_lines(&table, 0, 0).is_empty());
+        assert_eq!(render_lines(&table, 1, 1), ["│"]);
+    }
+}
@@ -4,9 +4,13 @@
 //! - Cursor positioning and movement
 //! - Text selection with shift+arrow keys
 //! - Word navigation with Ctrl+Left/Right
-//! - Clipboard operations (cut/copy/paste)
+//! - Clipboard operations (cut/copy/paste) and bracketed paste
+//! - Optional primary selection with middle-click paste on X11/Wayland
 //! - Validation with error display
 //! - Placeholder text and character limits
+//! - Fixed prefix/suffix affixes and a read-only mode
+//! - Left, centered or right-aligned text with optional padding
+//! - Optional submission history with up/down recall
 //!
 //! # Examples
 //!
@@ -32,17 +36,31 @@
 //!     });
 //! ```
 
+use std::collections::VecDeque;
 use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
 
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
-
-use super::{Component, Focusable, Renderable};
+use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use unicode_width::UnicodeWidthChar;
+
+use super::{Component, Focusable, Measurable, Renderable};
+use crate::clipboard::{Clipboard, ClipboardKind};
+use crate::event::{
+    AppEvent, Clock, CursorShape, Debouncer, MouseButton, MouseEventKind, SystemClock,
+};
+use crate::focus::FocusId;
+use crate::text::display_width;
 use crate::theme::Theme;
 
 /// Type alias for validation functions.
 pub type ValidatorFn = Box<dyn Fn(&str) -> ValidationResult + Send + Sync>;
 
+/// Type alias for the clock used by debounced validation.
+type SharedClock = Arc<dyn Clock + Send + Sync>;
+
 /// Result of validating text input.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ValidationResult {
@@ -82,21 +100,21 @@ pub enum TextInputMsg {
     CursorLeft,
     /// Move cursor right.
     CursorRight,
-    /// Move cursor to start of text.
+    /// Move cursor to start of text, or of the line in a multiline input.
     CursorHome,
-    /// Move cursor to end of text.
+    /// Move cursor to end of text, or of the line in a multiline input.
     CursorEnd,
-    /// Move cursor to previous word boundary.
+    /// Move cursor to previous word boundary, stopping at line starts.
     CursorWordLeft,
-    /// Move cursor to next word boundary.
+    /// Move cursor to next word boundary, stopping at line ends.
     CursorWordRight,
     /// Extend selection left.
     SelectLeft,
     /// Extend selection right.
     SelectRight,
-    /// Extend selection to start.
+    /// Extend selection to start, or to the line start in a multiline input.
     SelectHome,
-    /// Extend selection to end.
+    /// Extend selection to end, or to the line end in a multiline input.
     SelectEnd,
     /// Extend selection to previous word.
     SelectWordLeft,
@@ -112,14 +130,137 @@ pub enum TextInputMsg {
     Copy,
     /// Paste text from clipboard.
     Paste(String),
+    /// Paste text read from the input's attached [`Clipboard`].
+    ///
+    /// Does nothing if no clipboard is attached or it holds no text.
+    PasteFromClipboard,
+    /// Insert the primary selection at the cursor, as a middle click does.
+    ///
+    /// Unlike other pastes, any selection is left in place rather than
+    /// replaced. Does nothing unless the
+    /// [primary selection](TextInput::with_primary_selection) is enabled
+    /// and a clipboard holding primary text is attached.
+    PasteFromPrimary,
     /// Set the entire text content.
     SetText(String),
     /// Clear all text.
     Clear,
+    /// Submit the current text, recording it in history if enabled.
+    Submit,
+    /// Abandon editing: clears any selection and emits
+    /// [`TextInputAction::Cancel`].
+    Cancel,
+    /// Recall the previous (older) history entry.
+    HistoryPrev,
+    /// Recall the next (newer) history entry, or the in-progress text.
+    HistoryNext,
+    /// Run any validation deferred by the validation debounce, if due.
+    Tick,
+}
+
+impl TextInputMsg {
+    /// Maps an application event to a TextInput message.
+    ///
+    /// Translates [`AppEvent::Paste`] into [`TextInputMsg::Paste`] and a
+    /// middle-button press into [`TextInputMsg::PasteFromPrimary`]; other
+    /// events return `None`. Mouse events should only be passed to the
+    /// input under the pointer.
+    pub fn from_event<M>(event: &AppEvent<M>) -> Option<Self> {
+        match event {
+            AppEvent::Paste(text) => Some(TextInputMsg::Paste(text.clone())),
+            AppEvent::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Middle) => {
+                Some(TextInputMsg::PasteFromPrimary)
+            }
+            _ => None,
+        }
+    }
+
+    /// Maps a key press to a TextInput message.
+    ///
+    /// Covers typing, deletion, cursor movement (with Shift to select and
+    /// Ctrl to move by word), Ctrl+A/C/X/V for select-all and the clipboard,
+    /// Enter to submit, Escape to cancel, and Up/Down for history. Other keys
+    /// return `None`.
+    pub fn from_key(key: &KeyEvent) -> Option<Self> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CTRL);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        if key.modifiers.contains(KeyModifiers::ALT) {
+            return None;
+        }
+
+        let msg = match key.code {
+            KeyCode::Char(c) if ctrl => match c.to_ascii_lowercase() {
+                'a' => TextInputMsg::SelectAll,
+                'c' => TextInputMsg::Copy,
+                'x' => TextInputMsg::Cut,
+                'v' => TextInputMsg::PasteFromClipboard,
+                _ => return None,
+            },
+            KeyCode::Char(c) => TextInputMsg::InsertChar(c),
+            KeyCode::Backspace => TextInputMsg::Backspace,
+            KeyCode::Delete => TextInputMsg::Delete,
+            KeyCode::Left => match (shift, ctrl) {
+                (true, true) => TextInputMsg::SelectWordLeft,
+                (true, false) => TextInputMsg::SelectLeft,
+                (false, true) => TextInputMsg::CursorWordLeft,
+                (false, false) => TextInputMsg::CursorLeft,
+            },
+            KeyCode::Right => match (shift, ctrl) {
+                (true, true) => TextInputMsg::SelectWordRight,
+                (true, false) => TextInputMsg::SelectRight,
+                (false, true) => TextInputMsg::CursorWordRight,
+                (false, false) => TextInputMsg::CursorRight,
+            },
+            KeyCode::Home if shift => TextInputMsg::SelectHome,
+            KeyCode::Home => TextInputMsg::CursorHome,
+            KeyCode::End if shift => TextInputMsg::SelectEnd,
+            KeyCode::End => TextInputMsg::CursorEnd,
+            KeyCode::Enter => TextInputMsg::Submit,
+            KeyCode::Esc => TextInputMsg::Cancel,
+            KeyCode::Up => TextInputMsg::HistoryPrev,
+            KeyCode::Down => TextInputMsg::HistoryNext,
+            _ => return None,
+        };
+        Some(msg)
+    }
+
+    /// Returns true if this message changes the text content.
+    ///
+    /// These messages are ignored by read-only inputs.
+    pub fn is_edit(&self) -> bool {
+        matches!(
+            self,
+            TextInputMsg::InsertChar(_)
+                | TextInputMsg::Backspace
+                | TextInputMsg::Delete
+                | TextInputMsg::Cut
+                | TextInputMsg::Paste(_)
+                | TextInputMsg::PasteFromClipboard
+                | TextInputMsg::PasteFromPrimary
+                | TextInputMsg::SetText(_)
+                | TextInputMsg::Clear
+                | TextInputMsg::HistoryPrev
+                | TextInputMsg::HistoryNext
+        )
+    }
+
+    /// Returns true if this message extends or replaces the selection.
+    pub fn is_select(&self) -> bool {
+        matches!(
+            self,
+            TextInputMsg::SelectLeft
+                | TextInputMsg::SelectRight
+                | TextInputMsg::SelectHome
+                | TextInputMsg::SelectEnd
+                | TextInputMsg::SelectWordLeft
+                | TextInputMsg::SelectWordRight
+                | TextInputMsg::SelectAll
+        )
+    }
 }
 
 /// Actions emitted by the TextInput component.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TextInputAction {
     /// The text content changed.
     Changed(String),
@@ -129,10 +270,80 @@ pub enum TextInputAction {
     CopiedToClipboard(String),
     /// The user pressed Enter (submit).
     Submit(String),
+    /// The user pressed Escape (cancel).
+    Cancel,
+}
+
+/// Previously submitted values for up/down recall.
+#[derive(Debug, Clone)]
+struct InputHistory {
+    /// Entries from oldest to newest.
+    entries: VecDeque<String>,
+    /// Maximum number of entries kept.
+    capacity: usize,
+    /// Index of the entry being shown, if navigating.
+    index: Option<usize>,
+    /// The in-progress text stashed when navigation started.
+    draft: String,
+}
+
+impl InputHistory {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            index: None,
+            draft: String::new(),
+        }
+    }
+
+    /// Records a submitted value, skipping consecutive duplicates.
+    fn push(&mut self, text: &str) {
+        self.index = None;
+        if text.is_empty()
+            || self.capacity == 0
+            || self.entries.back().map(String::as_str) == Some(text)
+        {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(text.to_string());
+    }
+
+    /// Moves to the previous entry, stashing `current` when starting.
+    fn prev(&mut self, current: &str) -> Option<&str> {
+        let index = match self.index {
+            None if self.entries.is_empty() => return None,
+            None => {
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+            Some(0) => return None,
+            Some(i) => i - 1,
+        };
+        self.index = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Moves to the next entry, returning the stashed draft past the newest.
+    fn next(&mut self) -> Option<String> {
+        let index = self.index?;
+        if index + 1 < self.entries.len() {
+            self.index = Some(index + 1);
+            self.entries.get(index + 1).cloned()
+        } else {
+            self.index = None;
+            Some(std::mem::take(&mut self.draft))
+        }
+    }
 }
 
 /// A single-line text input component with cursor, selection, and validation.
 pub struct TextInput {
+    /// Optional identifier for focus management and style overrides.
+    id: Option<FocusId>,
     /// The current text content.
     text: String,
     /// Cursor position (byte index in text).
@@ -143,28 +354,83 @@ pub struct TextInput {
     placeholder: Option<String>,
     /// Maximum number of characters allowed.
     max_length: Option<usize>,
+    /// Smallest width reported by [`Measurable::measure`].
+    min_width: u16,
+    /// Whether pasted newlines are kept.
+    multiline: bool,
+    /// Whether Left and Right move the cursor across line breaks.
+    line_wrap_cursor: bool,
+    /// Whether edits are rejected.
+    read_only: bool,
+    /// Fixed text rendered before the editable text.
+    prefix: Option<String>,
+    /// Fixed text rendered after the editable text.
+    suffix: Option<String>,
+    /// Horizontal alignment of the text within the input.
+    alignment: Alignment,
+    /// Blank columns kept on each side inside the border.
+    padding: u16,
     /// Validation function.
     validator: Option<ValidatorFn>,
     /// Current validation error/warning.
     validation_message: Option<ValidationResult>,
+    /// Debouncer deferring validation until typing pauses.
+    validation_debounce: Option<Debouncer<(), SharedClock>>,
     /// Whether the input is focused.
     focused: bool,
+    /// Whether the mouse pointer is over the input.
+    hovered: bool,
     /// Optional theme for styling.
     theme: Option<Theme>,
+    /// Optional clipboard used by cut, copy, and paste.
+    clipboard: Option<Arc<dyn Clipboard>>,
+    /// Whether selections are published to the primary selection.
+    primary_selection: bool,
+    /// Whether the selection changed since it was last published.
+    selection_unpublished: bool,
+    /// Optional submission history.
+    history: Option<InputHistory>,
+    /// Shape of the terminal cursor; `None` draws a styled cell instead.
+    cursor_shape: Option<CursorShape>,
+    /// Whether each line of a multi-line paste is submitted.
+    submit_on_newline: bool,
+    /// Pasted lines waiting to be submitted.
+    queued_lines: VecDeque<String>,
 }
 
 impl std::fmt::Debug for TextInput {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TextInput")
+            .field("id", &self.id)
             .field("text", &self.text)
             .field("cursor", &self.cursor)
             .field("selection", &self.selection)
             .field("placeholder", &self.placeholder)
             .field("max_length", &self.max_length)
+            .field("min_width", &self.min_width)
+            .field("multiline", &self.multiline)
+            .field("line_wrap_cursor", &self.line_wrap_cursor)
+            .field("read_only", &self.read_only)
+            .field("prefix", &self.prefix)
+            .field("suffix", &self.suffix)
+            .field("alignment", &self.alignment)
+            .field("padding", &self.padding)
             .field("validator", &self.validator.as_ref().map(|_| "<fn>"))
             .field("validation_message", &self.validation_message)
+            .field(
+                "validation_debounce",
+                &self.validation_debounce.as_ref().map(|d| d.delay()),
+            )
             .field("focused", &self.focused)
+            .field("hovered", &self.hovered)
             .field("theme", &self.theme.as_ref().map(|t| t.name()))
+            .field("clipboard", &self.clipboard.as_ref().map(|_| "<clipboard>"))
+            .field("primary_selection", &self.primary_selection)
+            .field("selection_unpublished", &self.selection_unpublished)
+            .field("history", &self.history)
+            .field("cursor_shape", &self.cursor_shape)
+            .field("submit_on_newline", &self.submit_on_newline)
+            .field("queued_lines", &self.queued_lines)
             .finish()
     }
 }
@@ -172,15 +438,33 @@ impl std::fmt::Debug for TextInput {
 impl Clone for TextInput {
     fn clone(&self) -> Self {
         Self {
+            id: self.id.clone(),
             text: self.text.clone(),
             cursor: self.cursor,
             selection: self.selection.clone(),
             placeholder: self.placeholder.clone(),
             max_length: self.max_length,
+            min_width: self.min_width,
+            multiline: self.multiline,
+            line_wrap_cursor: self.line_wrap_cursor,
+            read_only: self.read_only,
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+            alignment: self.alignment,
+            padding: self.padding,
             validator: None, // Validators cannot be cloned
             validation_message: self.validation_message.clone(),
+            validation_debounce: self.validation_debounce.clone(),
             focused: self.focused,
+            hovered: self.hovered,
             theme: self.theme.clone(),
+            clipboard: self.clipboard.clone(),
+            primary_selection: self.primary_selection,
+            selection_unpublished: self.selection_unpublished,
+            history: self.history.clone(),
+            cursor_shape: self.cursor_shape,
+            submit_on_newline: self.submit_on_newline,
+            queued_lines: self.queued_lines.clone(),
         }
     }
 }
@@ -195,18 +479,66 @@ impl TextInput {
     /// Creates a new empty TextInput.
     pub fn new() -> Self {
         Self {
+            id: None,
             text: String::new(),
             cursor: 0,
             selection: None,
             placeholder: None,
             max_length: None,
+            min_width: 0,
+            multiline: false,
+            line_wrap_cursor: true,
+            read_only: false,
+            prefix: None,
+            suffix: None,
+            alignment: Alignment::Left,
+            padding: 0,
             validator: None,
             validation_message: None,
+            validation_debounce: None,
             focused: false,
+            hovered: false,
             theme: None,
+            clipboard: None,
+            primary_selection: false,
+            selection_unpublished: false,
+            history: None,
+            cursor_shape: None,
+            submit_on_newline: false,
+            queued_lines: VecDeque::new(),
         }
     }
 
+    /// Sets the input's identifier.
+    ///
+    /// The ID is used to look up per-component style overrides on the theme.
+    pub fn with_id(mut self, id: impl Into<FocusId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Returns the input's identifier, if set.
+    pub fn id(&self) -> Option<&FocusId> {
+        self.id.as_ref()
+    }
+
+    /// Uses the real terminal cursor with the given shape.
+    ///
+    /// When focused, the input positions the terminal cursor instead of
+    /// drawing a highlighted cell, which matches native editing and works
+    /// with screen readers. Rendering cannot change the cursor shape, so the
+    /// application should pass [`cursor_style`](Self::cursor_style) to
+    /// [`set_cursor_shape`](crate::event::set_cursor_shape) after drawing.
+    pub fn with_cursor_style(mut self, shape: CursorShape) -> Self {
+        self.cursor_shape = Some(shape);
+        self
+    }
+
+    /// Returns the terminal cursor shape, if the real cursor is used.
+    pub fn cursor_style(&self) -> Option<CursorShape> {
+        self.cursor_shape
+    }
+
     /// Sets the placeholder text shown when the input is empty.
     pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = Some(placeholder.into());
@@ -219,6 +551,220 @@ impl TextInput {
         self
     }
 
+    /// Sets the smallest width, borders included, that the input asks for
+    /// when [measured](Measurable::measure).
+    pub fn with_min_width(mut self, width: u16) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Returns the smallest width the input asks for when measured.
+    pub fn min_width(&self) -> u16 {
+        self.min_width
+    }
+
+    /// Sets whether the input accepts newlines.
+    ///
+    /// Single-line inputs (the default) strip line breaks from pasted text.
+    /// Multiline inputs draw each line on its own row, scrolling to keep the
+    /// cursor's line in view, and Home, End and word moves stop at line
+    /// boundaries.
+    pub fn with_multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Returns whether the input accepts newlines.
+    pub fn is_multiline(&self) -> bool {
+        self.multiline
+    }
+
+    /// Sets whether moving the cursor left or right crosses line breaks in a
+    /// multiline input.
+    ///
+    /// When enabled (the default), Left at the start of a line moves to the
+    /// end of the previous line and Right at the end of a line moves to the
+    /// start of the next. When disabled, the cursor stops at the line's
+    /// edge instead; this applies to extending the selection with
+    /// Shift+Left and Shift+Right too. Single-line inputs are unaffected.
+    pub fn with_line_wrap_cursor(mut self, wrap: bool) -> Self {
+        self.line_wrap_cursor = wrap;
+        self
+    }
+
+    /// Returns whether moving the cursor left or right crosses line breaks.
+    pub fn line_wrap_cursor(&self) -> bool {
+        self.line_wrap_cursor
+    }
+
+    /// Sets whether pasting several lines submits each of them, as in a
+    /// REPL.
+    ///
+    /// When enabled, a paste containing a line break inserts its first line
+    /// and submits it, then submits each remaining line in turn, clearing
+    /// the input after every submission. A trailing line break does not
+    /// submit an extra empty line, and a line longer than the
+    /// [maximum length](Self::with_max_length) is cut to fit.
+    /// [`update`](Component::update) can only
+    /// return one action, so it submits the first line and queues the rest,
+    /// submitting one per [`TextInputMsg::Tick`];
+    /// [`update_many`](Self::update_many) submits them all at once.
+    pub fn with_submit_on_newline(mut self, enabled: bool) -> Self {
+        self.submit_on_newline = enabled;
+        self
+    }
+
+    /// Returns whether pasting several lines submits each of them.
+    pub fn submits_on_newline(&self) -> bool {
+        self.submit_on_newline
+    }
+
+    /// Returns true if pasted lines are waiting to be submitted.
+    pub fn has_queued_lines(&self) -> bool {
+        !self.queued_lines.is_empty()
+    }
+
+    /// Handles a message and returns every action it produces, in order.
+    ///
+    /// This is [`update`](Component::update) followed by submitting any
+    /// lines queued by a multi-line paste with
+    /// [`with_submit_on_newline`](Self::with_submit_on_newline) enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::components::{TextInput, TextInputAction, TextInputMsg};
+    ///
+    /// let mut input = TextInput::new().with_submit_on_newline(true);
+    /// let actions = input.update_many(TextInputMsg::Paste("ls\npwd\n".into()));
+    /// assert_eq!(
+    ///     actions,
+    ///     [
+    ///         TextInputAction::Submit("ls".into()),
+    ///         TextInputAction::Submit("pwd".into()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn update_many(&mut self, msg: TextInputMsg) -> Vec<TextInputAction> {
+        let mut actions: Vec<_> = self.update(msg).into_iter().collect();
+        while let Some(line) = self.queued_lines.pop_front() {
+            actions.extend(self.submit_line(&line));
+        }
+        actions
+    }
+
+    /// Inserts a pasted line, submits the text, and clears the input.
+    ///
+    /// A line that would exceed the maximum length is cut to fit, so that
+    /// it is submitted rather than silently dropped.
+    fn submit_line(&mut self, line: &str) -> Option<TextInputAction> {
+        let line = match self.remaining_length() {
+            Some(room) => match line.char_indices().nth(room) {
+                Some((end, _)) => &line[..end],
+                None => line,
+            },
+            None => line,
+        };
+        self.insert_text(line);
+        let action = self.update(TextInputMsg::Submit);
+        self.text.clear();
+        self.cursor = 0;
+        self.selection = None;
+        self.validate_edit();
+        action
+    }
+
+    /// Sets fixed text rendered before the editable text, such as `$ `.
+    ///
+    /// The prefix is not part of [`text`](Self::text) and cannot be edited.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Returns the prefix, if set.
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// Sets fixed text rendered after the editable text, such as ` kg`.
+    ///
+    /// The suffix is not part of [`text`](Self::text) and cannot be edited.
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Returns the suffix, if set.
+    pub fn suffix(&self) -> Option<&str> {
+        self.suffix.as_deref()
+    }
+
+    /// Sets the horizontal alignment of the text between the affixes.
+    ///
+    /// Right alignment suits numeric fields. The column after the last
+    /// character is kept for the cursor, so right-aligned text ends one cell
+    /// before the edge. Text wider than the input scrolls as usual; when
+    /// right-aligned it stays anchored to its end.
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Returns the horizontal alignment of the text.
+    pub fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Sets the number of blank columns kept on each side inside the border.
+    ///
+    /// Padding is reduced as needed so that at least one column is left for
+    /// the text and cursor.
+    pub fn with_padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Returns the padding on each side inside the border.
+    pub fn padding(&self) -> u16 {
+        self.padding
+    }
+
+    /// Sets whether the input is read-only.
+    ///
+    /// A read-only input ignores every message that would change its text,
+    /// but still supports cursor movement, selection, and copying. It is
+    /// drawn with the theme's
+    /// [`disabled`](crate::theme::BorderStyles::disabled) border type.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets whether the input is read-only.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    /// Returns whether the input is read-only.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns whether the mouse pointer is over the input.
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Sets whether the mouse pointer is over the input.
+    ///
+    /// An unfocused input that is hovered is drawn with the theme's hover
+    /// border style. Unless read-only, it also uses the
+    /// [`hover`](crate::theme::BorderStyles::hover) border type.
+    pub fn set_hovered(&mut self, hovered: bool) {
+        self.hovered = hovered;
+    }
+
     /// Sets a validation function.
     pub fn with_validator<F>(mut self, validator: F) -> Self
     where
@@ -228,12 +774,160 @@ impl TextInput {
         self
     }
 
+    /// Defers validation while typing until edits pause for `delay`.
+    ///
+    /// Edits no longer validate immediately; the previous validation message
+    /// stays visible until the input is quiet for `delay` and a
+    /// [`TextInputMsg::Tick`] is received. Losing focus validates
+    /// immediately regardless of the debounce.
+    pub fn with_validation_debounce(self, delay: Duration) -> Self {
+        self.with_validation_debounce_clock(delay, Arc::new(SystemClock))
+    }
+
+    /// Like [`with_validation_debounce`](Self::with_validation_debounce),
+    /// reading time from the given clock.
+    pub fn with_validation_debounce_clock(
+        mut self,
+        delay: Duration,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        self.validation_debounce = Some(Debouncer::with_clock(delay, clock));
+        self
+    }
+
+    /// Returns true if validation is waiting for the debounce to elapse.
+    pub fn is_validation_pending(&self) -> bool {
+        self.validation_debounce
+            .as_ref()
+            .is_some_and(|d| d.is_pending())
+    }
+
     /// Sets the theme for styling.
     pub fn with_theme(mut self, theme: Theme) -> Self {
         self.theme = Some(theme);
         self
     }
 
+    /// Attaches a clipboard for cut, copy, and paste.
+    ///
+    /// With a clipboard attached, [`TextInputMsg::Cut`] and
+    /// [`TextInputMsg::Copy`] write the selection to it and
+    /// [`TextInputMsg::PasteFromClipboard`] inserts its contents. The
+    /// `CutToClipboard`/`CopiedToClipboard` actions are still emitted.
+    pub fn with_clipboard(mut self, clipboard: Arc<dyn Clipboard>) -> Self {
+        self.clipboard = Some(clipboard);
+        self
+    }
+
+    /// Returns the attached clipboard, if any.
+    pub fn clipboard(&self) -> Option<&Arc<dyn Clipboard>> {
+        self.clipboard.as_ref()
+    }
+
+    /// Sets whether the input uses the primary selection of the attached
+    /// clipboard.
+    ///
+    /// When enabled, selected text is written to [`ClipboardKind::Primary`]
+    /// once the selection is finished, on the next message that does not
+    /// [select](TextInputMsg::is_select) or on losing focus. Likewise,
+    /// [`TextInputMsg::PasteFromPrimary`]
+    /// (sent for a middle click) inserts it. This mirrors X11 and Wayland
+    /// applications; on other platforms the system clipboard has no primary
+    /// selection and both do nothing.
+    pub fn with_primary_selection(mut self, enabled: bool) -> Self {
+        self.primary_selection = enabled;
+        self
+    }
+
+    /// Returns whether the input uses the primary selection.
+    pub fn uses_primary_selection(&self) -> bool {
+        self.primary_selection
+    }
+
+    /// Enables submission history holding up to `capacity` entries.
+    ///
+    /// Submitted values can then be recalled with
+    /// [`TextInputMsg::HistoryPrev`] and [`TextInputMsg::HistoryNext`].
+    /// Empty submissions and consecutive duplicates are not recorded.
+    pub fn with_history(mut self, capacity: usize) -> Self {
+        self.history = Some(InputHistory::new(capacity));
+        self
+    }
+
+    /// Returns the submission history from oldest to newest.
+    ///
+    /// Returns an empty list if history is not enabled.
+    pub fn history(&self) -> Vec<&str> {
+        self.history
+            .as_ref()
+            .map(|h| h.entries.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Writes text to the attached clipboard, if any.
+    fn write_clipboard(&self, text: &str) {
+        if let Some(clipboard) = &self.clipboard {
+            if let Err(e) = clipboard.set_text(text) {
+                tracing::warn!(error = %e, "Failed to write clipboard");
+            }
+        }
+    }
+
+    /// Writes the selected text to the primary selection, if enabled and
+    /// the selection changed since it was last written.
+    fn publish_selection(&mut self) {
+        if !std::mem::take(&mut self.selection_unpublished) || !self.primary_selection {
+            return;
+        }
+        let (Some(clipboard), Some(text)) = (&self.clipboard, self.selected_text()) else {
+            return;
+        };
+        if let Err(e) = clipboard.set_text_in(ClipboardKind::Primary, text) {
+            tracing::debug!(error = %e, "Failed to write primary selection");
+        }
+    }
+
+    /// Handles an application event, returning an action if it was consumed.
+    ///
+    /// Pasted text is routed through [`TextInputMsg::Paste`].
+    pub fn handle_event<M>(&mut self, event: &AppEvent<M>) -> Option<TextInputAction> {
+        TextInputMsg::from_event(event).and_then(|msg| self.update(msg))
+    }
+
+    /// Handles a key press, returning the resulting action.
+    ///
+    /// Keys are mapped with [`TextInputMsg::from_key`], so Enter yields
+    /// [`TextInputAction::Submit`] and Escape yields
+    /// [`TextInputAction::Cancel`]. Key releases and unmapped keys are
+    /// ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+    /// use tuilib::components::{TextInput, TextInputAction};
+    ///
+    /// let press = |code| KeyEvent {
+    ///     code,
+    ///     modifiers: KeyModifiers::NONE,
+    ///     kind: KeyEventKind::Press,
+    ///     state: KeyEventState::NONE,
+    /// };
+    ///
+    /// let mut input = TextInput::new();
+    /// input.handle_key(&press(KeyCode::Char('h')));
+    /// input.handle_key(&press(KeyCode::Char('i')));
+    ///
+    /// let action = input.handle_key(&press(KeyCode::Enter));
+    /// assert_eq!(action, Some(TextInputAction::Submit("hi".to_string())));
+    /// ```
+    pub fn handle_key(&mut self, key: &KeyEvent) -> Option<TextInputAction> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        TextInputMsg::from_key(key).and_then(|msg| self.update(msg))
+    }
+
     /// Returns the current text content.
     pub fn text(&self) -> &str {
         &self.text
@@ -244,7 +938,7 @@ impl TextInput {
         self.text = text.into();
         self.cursor = self.text.len();
         self.selection = None;
-        self.validate();
+        self.validate_now();
     }
 
     /// Returns the cursor position.
@@ -275,6 +969,28 @@ impl TextInput {
         self.validation_message.as_ref()
     }
 
+    /// Validates after an edit, deferring if validation is debounced.
+    fn validate_edit(&mut self) {
+        match &mut self.validation_debounce {
+            Some(debouncer) => debouncer.push(()),
+            None => self.validate(),
+        }
+    }
+
+    /// Runs validation now, cancelling any deferred validation.
+    ///
+    /// Returns the result, which is [`ValidationResult::Valid`] if no
+    /// validator is set.
+    pub fn validate_now(&mut self) -> ValidationResult {
+        if let Some(debouncer) = &mut self.validation_debounce {
+            debouncer.reset();
+        }
+        self.validate();
+        self.validation_message
+            .clone()
+            .unwrap_or(ValidationResult::Valid)
+    }
+
     /// Runs validation and updates the validation message.
     fn validate(&mut self) {
         if let Some(ref validator) = self.validator {
@@ -306,16 +1022,39 @@ impl TextInput {
         self.text[..byte_idx].chars().count()
     }
 
+    /// Returns the byte index where the cursor's line starts.
+    ///
+    /// Single-line inputs have one line, starting at 0.
+    fn line_start(&self) -> usize {
+        if !self.multiline {
+            return 0;
+        }
+        self.text[..self.cursor].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    /// Returns the byte index where the cursor's line ends, before its line
+    /// break.
+    fn line_end(&self) -> usize {
+        if !self.multiline {
+            return self.text.len();
+        }
+        self.text[self.cursor..]
+            .find('\n')
+            .map_or(self.text.len(), |i| self.cursor + i)
+    }
+
     /// Finds the previous word boundary from the current cursor.
+    ///
+    /// The search stops at the start of the line; from there, the cursor
+    /// moves as [`TextInputMsg::CursorLeft`] would.
     fn prev_word_boundary(&self) -> usize {
-        let chars: Vec<(usize, char)> = self.text.char_indices().collect();
-        let char_pos = self.byte_to_char_index(self.cursor);
-
-        if char_pos == 0 {
-            return 0;
+        let start = self.line_start();
+        if self.cursor == start {
+            return self.prev_char_position().unwrap_or(self.cursor);
         }
+        let chars: Vec<(usize, char)> = self.text[start..self.cursor].char_indices().collect();
 
-        let mut pos = char_pos - 1;
+        let mut pos = chars.len() - 1;
 
         // Skip whitespace
         while pos > 0 && chars[pos].1.is_whitespace() {
@@ -327,20 +1066,22 @@ impl TextInput {
             pos -= 1;
         }
 
-        self.char_to_byte_index(pos)
+        start + chars[pos].0
     }
 
     /// Finds the next word boundary from the current cursor.
+    ///
+    /// The search stops at the end of the line; from there, the cursor
+    /// moves as [`TextInputMsg::CursorRight`] would.
     fn next_word_boundary(&self) -> usize {
-        let chars: Vec<(usize, char)> = self.text.char_indices().collect();
-        let char_pos = self.byte_to_char_index(self.cursor);
-        let len = chars.len();
-
-        if char_pos >= len {
-            return self.text.len();
+        let end = self.line_end();
+        if self.cursor == end {
+            return self.next_char_position().unwrap_or(self.cursor);
         }
+        let chars: Vec<(usize, char)> = self.text[self.cursor..end].char_indices().collect();
+        let len = chars.len();
 
-        let mut pos = char_pos;
+        let mut pos = 0;
 
         // Skip current word characters
         while pos < len && !chars[pos].1.is_whitespace() {
@@ -352,23 +1093,40 @@ impl TextInput {
             pos += 1;
         }
 
-        self.char_to_byte_index(pos)
+        chars.get(pos).map_or(end, |&(i, _)| self.cursor + i)
+    }
+
+    /// Returns true if the cursor must not move across `c`.
+    fn blocks_cursor(&self, c: char) -> bool {
+        c == '\n' && self.multiline && !self.line_wrap_cursor
+    }
+
+    /// Returns the position one character left of the cursor, or `None` at
+    /// the start of the text or of a line the cursor may not leave.
+    fn prev_char_position(&self) -> Option<usize> {
+        let prev = self.text[..self.cursor].chars().next_back()?;
+        (!self.blocks_cursor(prev)).then(|| self.cursor - prev.len_utf8())
+    }
+
+    /// Returns the position one character right of the cursor, or `None` at
+    /// the end of the text or of a line the cursor may not leave.
+    fn next_char_position(&self) -> Option<usize> {
+        let next = self.text[self.cursor..].chars().next()?;
+        (!self.blocks_cursor(next)).then(|| self.cursor + next.len_utf8())
     }
 
     /// Moves cursor left by one character.
     fn move_cursor_left(&mut self) {
-        if self.cursor > 0 {
-            let char_pos = self.byte_to_char_index(self.cursor);
-            self.cursor = self.char_to_byte_index(char_pos.saturating_sub(1));
+        if let Some(position) = self.prev_char_position() {
+            self.cursor = position;
         }
         self.selection = None;
     }
 
     /// Moves cursor right by one character.
     fn move_cursor_right(&mut self) {
-        if self.cursor < self.text.len() {
-            let char_pos = self.byte_to_char_index(self.cursor);
-            self.cursor = self.char_to_byte_index(char_pos + 1);
+        if let Some(position) = self.next_char_position() {
+            self.cursor = position;
         }
         self.selection = None;
     }
@@ -396,6 +1154,7 @@ impl TextInput {
         } else {
             self.selection = Some(new_cursor..anchor);
         }
+        self.selection_unpublished = true;
     }
 
     /// Deletes the current selection and returns the deleted text.
@@ -404,28 +1163,45 @@ impl TextInput {
             let deleted = self.text[range.clone()].to_string();
             self.text.replace_range(range.clone(), "");
             self.cursor = range.start;
-            self.validate();
+            self.validate_edit();
             Some(deleted)
         } else {
             None
         }
     }
 
+    /// Normalizes line breaks in pasted text.
+    ///
+    /// Multiline inputs keep line breaks as `\n`; single-line inputs drop them.
+    fn filter_pasted(&self, text: &str) -> String {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        if self.multiline {
+            text
+        } else {
+            text.replace('\n', "")
+        }
+    }
+
+    /// Returns how many more characters fit within the maximum length,
+    /// counting selected text as replaced, or `None` without a maximum.
+    fn remaining_length(&self) -> Option<usize> {
+        let max = self.max_length?;
+        let selection_chars = self
+            .selection
+            .as_ref()
+            .map(|r| self.text[r.clone()].chars().count())
+            .unwrap_or(0);
+        Some(max.saturating_sub(self.char_count() - selection_chars))
+    }
+
     /// Inserts text at the cursor position.
     fn insert_text(&mut self, text: &str) -> bool {
         // Check max length
-        if let Some(max) = self.max_length {
-            let current_chars = self.char_count();
-            let insert_chars = text.chars().count();
-            let selection_chars = self
-                .selection
-                .as_ref()
-                .map(|r| self.text[r.clone()].chars().count())
-                .unwrap_or(0);
-
-            if current_chars - selection_chars + insert_chars > max {
-                return false;
-            }
+        if self
+            .remaining_length()
+            .is_some_and(|room| text.chars().count() > room)
+        {
+            return false;
         }
 
         // Delete selection first if any
@@ -434,7 +1210,7 @@ impl TextInput {
         // Insert new text
         self.text.insert_str(self.cursor, text);
         self.cursor += text.len();
-        self.validate();
+        self.validate_edit();
         true
     }
 }
@@ -444,6 +1220,13 @@ impl Component for TextInput {
     type Action = TextInputAction;
 
     fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        if !msg.is_select() {
+            self.publish_selection();
+        }
+        if self.read_only && msg.is_edit() {
+            return None;
+        }
+
         match msg {
             TextInputMsg::InsertChar(c) => {
                 if self.insert_text(&c.to_string()) {
@@ -461,7 +1244,7 @@ impl Component for TextInput {
                     let new_cursor = self.char_to_byte_index(char_pos - 1);
                     self.text.drain(new_cursor..self.cursor);
                     self.cursor = new_cursor;
-                    self.validate();
+                    self.validate_edit();
                     Some(TextInputAction::Changed(self.text.clone()))
                 } else {
                     None
@@ -475,7 +1258,7 @@ impl Component for TextInput {
                     let char_pos = self.byte_to_char_index(self.cursor);
                     let end = self.char_to_byte_index(char_pos + 1);
                     self.text.drain(self.cursor..end);
-                    self.validate();
+                    self.validate_edit();
                     Some(TextInputAction::Changed(self.text.clone()))
                 } else {
                     None
@@ -490,12 +1273,12 @@ impl Component for TextInput {
                 None
             }
             TextInputMsg::CursorHome => {
-                self.cursor = 0;
+                self.cursor = self.line_start();
                 self.selection = None;
                 None
             }
             TextInputMsg::CursorEnd => {
-                self.cursor = self.text.len();
+                self.cursor = self.line_end();
                 self.selection = None;
                 None
             }
@@ -510,27 +1293,23 @@ impl Component for TextInput {
                 None
             }
             TextInputMsg::SelectLeft => {
-                if self.cursor > 0 {
-                    let char_pos = self.byte_to_char_index(self.cursor);
-                    let new_cursor = self.char_to_byte_index(char_pos.saturating_sub(1));
+                if let Some(new_cursor) = self.prev_char_position() {
                     self.extend_selection(new_cursor);
                 }
                 None
             }
             TextInputMsg::SelectRight => {
-                if self.cursor < self.text.len() {
-                    let char_pos = self.byte_to_char_index(self.cursor);
-                    let new_cursor = self.char_to_byte_index(char_pos + 1);
+                if let Some(new_cursor) = self.next_char_position() {
                     self.extend_selection(new_cursor);
                 }
                 None
             }
             TextInputMsg::SelectHome => {
-                self.extend_selection(0);
+                self.extend_selection(self.line_start());
                 None
             }
             TextInputMsg::SelectEnd => {
-                self.extend_selection(self.text.len());
+                self.extend_selection(self.line_end());
                 None
             }
             TextInputMsg::SelectWordLeft => {
@@ -547,6 +1326,7 @@ impl Component for TextInput {
                 if !self.text.is_empty() {
                     self.selection = Some(0..self.text.len());
                     self.cursor = self.text.len();
+                    self.selection_unpublished = true;
                 }
                 None
             }
@@ -554,11 +1334,59 @@ impl Component for TextInput {
                 self.selection = None;
                 None
             }
-            TextInputMsg::Cut => self.delete_selection().map(TextInputAction::CutToClipboard),
-            TextInputMsg::Copy => self
-                .selected_text()
-                .map(|t| TextInputAction::CopiedToClipboard(t.to_string())),
+            TextInputMsg::Cut => {
+                let cut = self.delete_selection()?;
+                self.write_clipboard(&cut);
+                Some(TextInputAction::CutToClipboard(cut))
+            }
+            TextInputMsg::Copy => {
+                let copied = self.selected_text()?.to_string();
+                self.write_clipboard(&copied);
+                Some(TextInputAction::CopiedToClipboard(copied))
+            }
+            TextInputMsg::PasteFromClipboard => {
+                let text = match self.clipboard.as_ref()?.get_text() {
+                    Ok(text) => text,
+                    Err(e) => {
+                        tracing::debug!(error = %e, "Nothing to paste from clipboard");
+                        return None;
+                    }
+                };
+                self.update(TextInputMsg::Paste(text))
+            }
+            TextInputMsg::PasteFromPrimary => {
+                if !self.primary_selection {
+                    return None;
+                }
+                let text = match self
+                    .clipboard
+                    .as_ref()?
+                    .get_text_from(ClipboardKind::Primary)
+                {
+                    Ok(text) => text,
+                    Err(e) => {
+                        tracing::debug!(error = %e, "Nothing to paste from primary selection");
+                        return None;
+                    }
+                };
+                // Insert at the cursor without replacing the selection
+                self.selection = None;
+                self.update(TextInputMsg::Paste(text))
+            }
+            TextInputMsg::Paste(text) if self.submit_on_newline && text.contains(['\n', '\r']) => {
+                let text = text.replace("\r\n", "\n").replace('\r', "\n");
+                let mut lines = text.split('\n');
+                let first = lines.next().unwrap_or_default();
+                self.queued_lines.extend(lines.map(str::to_string));
+                // A trailing line break ends the last line rather than
+                // starting an empty one
+                if self.queued_lines.back().is_some_and(String::is_empty) {
+                    self.queued_lines.pop_back();
+                }
+                self.submit_line(first)
+            }
             TextInputMsg::Paste(text) => {
+                let text = self.filter_pasted(&text);
                 if self.insert_text(&text) {
                     Some(TextInputAction::Changed(self.text.clone()))
                 } else {
@@ -573,9 +1401,39 @@ impl Component for TextInput {
                 self.text.clear();
                 self.cursor = 0;
                 self.selection = None;
-                self.validate();
+                self.validate_edit();
                 Some(TextInputAction::Changed(String::new()))
             }
+            TextInputMsg::Submit => {
+                if let Some(history) = &mut self.history {
+                    history.push(&self.text);
+                }
+                Some(TextInputAction::Submit(self.text.clone()))
+            }
+            TextInputMsg::Cancel => {
+                self.selection = None;
+                Some(TextInputAction::Cancel)
+            }
+            TextInputMsg::HistoryPrev => {
+                let entry = self.history.as_mut()?.prev(&self.text)?.to_string();
+                self.set_text(entry);
+                Some(TextInputAction::Changed(self.text.clone()))
+            }
+            TextInputMsg::HistoryNext => {
+                let entry = self.history.as_mut()?.next()?;
+                self.set_text(entry);
+                Some(TextInputAction::Changed(self.text.clone()))
+            }
+            TextInputMsg::Tick => {
+                if let Some(debouncer) = self.validation_debounce.as_mut() {
+                    let now = debouncer.clock().now();
+                    if debouncer.try_emit(now).is_some() {
+                        self.validate();
+                    }
+                }
+                let line = self.queued_lines.pop_front()?;
+                self.submit_line(&line)
+            }
         }
     }
 }
@@ -594,9 +1452,10 @@ impl Focusable for TextInput {
     }
 
     fn on_blur(&mut self) {
-        // Validate on blur
-        self.validate();
-        // Clear selection on blur
+        // Validate on blur, even if validation is debounced
+        self.validate_now();
+        // Publish the finished selection, then clear it
+        self.publish_selection();
         self.selection = None;
     }
 }
@@ -606,7 +1465,9 @@ impl Renderable for TextInput {
         let theme = self.theme.as_ref().cloned().unwrap_or_default();
 
         // Determine styles
-        let text_style = if self.focused {
+        let text_style = if self.read_only {
+            theme.muted_style()
+        } else if self.focused {
             theme.input_focused_style()
         } else {
             theme.input_normal_style()
@@ -614,14 +1475,34 @@ impl Renderable for TextInput {
 
         let border_style = if self.focused {
             theme.border_focused_style()
+        } else if self.read_only {
+            theme.border_disabled_style()
+        } else if self.hovered {
+            theme.border_hover_style()
         } else {
             theme.border_style()
         };
+        let border_type = if self.read_only {
+            theme.borders().disabled
+        } else if self.hovered {
+            theme.borders().hover
+        } else {
+            theme.components().input.border_type
+        };
+
+        // Apply per-component overrides
+        let (text_style, border_style) = match self.id {
+            Some(ref id) => (
+                theme.resolve_style(id, text_style),
+                theme.resolve_border_style(id, border_style),
+            ),
+            None => (text_style, border_style),
+        };
 
         // Build block with border
         let mut block = Block::default()
             .borders(Borders::ALL)
-            .border_type(theme.components().input.border_type)
+            .border_type(border_type)
             .border_style(border_style);
 
         // Add error indicator to title if validation failed
@@ -639,76 +1520,252 @@ impl Renderable for TextInput {
         let inner_area = block.inner(area);
         frame.render_widget(block, area);
 
+        // Pad inside the border, keeping at least one column to type into
+        let padding = self.padding.min(inner_area.width.saturating_sub(1) / 2);
+        let inner_area = Rect {
+            x: inner_area.x + padding,
+            width: inner_area.width - padding * 2,
+            ..inner_area
+        };
+
+        // Split off space for the affixes; the prefix takes priority
+        let affix_style = theme.muted_style();
+        let prefix_width = self.prefix.as_deref().map_or(0, affix_width);
+        let suffix_width = self.suffix.as_deref().map_or(0, affix_width);
+        let prefix_width = prefix_width.min(inner_area.width);
+        let suffix_width = suffix_width.min(inner_area.width - prefix_width);
+        let [prefix_area, text_area, suffix_area] = Layout::horizontal([
+            Constraint::Length(prefix_width),
+            Constraint::Fill(1),
+            Constraint::Length(suffix_width),
+        ])
+        .areas(inner_area);
+
+        if let Some(ref prefix) = self.prefix {
+            frame.render_widget(
+                Paragraph::new(prefix.as_str()).style(affix_style),
+                prefix_area,
+            );
+        }
+        if let Some(ref suffix) = self.suffix {
+            frame.render_widget(
+                Paragraph::new(suffix.as_str()).style(affix_style),
+                suffix_area,
+            );
+        }
+
+        // Offset aligned text, or scroll it so the cursor stays visible
+        let lines = self.line_ranges();
+        let cursor_row = lines
+            .iter()
+            .position(|line| line.contains(&self.cursor) || line.end == self.cursor)
+            .unwrap_or(0);
+        let cursor_line = lines[cursor_row].clone();
+        let cursor_column = display_width(&self.text[cursor_line.start..self.cursor]) as u16;
+        let (offset, scroll) = self.text_layout(
+            &self.text[cursor_line.clone()],
+            cursor_column,
+            text_area.width,
+        );
+        let row_scroll = cursor_row.saturating_sub(usize::from(text_area.height.max(1)) - 1);
+
         // Render text content
         if self.text.is_empty() {
-            // Show placeholder
+            // Show placeholder, leaving the cursor column as for text
             if let Some(ref placeholder) = self.placeholder {
                 let placeholder_style = theme.input_placeholder_style();
-                let paragraph = Paragraph::new(placeholder.as_str()).style(placeholder_style);
-                frame.render_widget(paragraph, inner_area);
+                let placeholder_area = match self.alignment {
+                    Alignment::Left => text_area,
+                    _ => Rect {
+                        width: text_area.width.saturating_sub(1),
+                        ..text_area
+                    },
+                };
+                let paragraph = Paragraph::new(placeholder.as_str())
+                    .style(placeholder_style)
+                    .alignment(self.alignment);
+                frame.render_widget(paragraph, placeholder_area);
             }
         } else {
-            // Build spans with selection highlighting
-            let spans = self.build_text_spans(&theme);
-            let paragraph = Paragraph::new(Line::from(spans)).style(text_style);
-            frame.render_widget(paragraph, inner_area);
-        }
-
-        // Render cursor if focused
-        if self.focused && inner_area.width > 0 {
-            let cursor_char_pos = self.byte_to_char_index(self.cursor);
-            let cursor_x = inner_area.x + cursor_char_pos as u16;
-
-            if cursor_x < inner_area.x + inner_area.width {
-                // Get character at cursor or space if at end
-                let cursor_char = if self.cursor < self.text.len() {
-                    self.text[self.cursor..].chars().next().unwrap_or(' ')
+            // Draw each line on its own row, with selection highlighting
+            let rows = lines.iter().skip(row_scroll).zip(text_area.rows());
+            for (line, row) in rows {
+                // Lines share the cursor line's scroll, aligning only when
+                // nothing is scrolled
+                let (offset, scroll) = if scroll > 0 || line == &cursor_line {
+                    (offset, scroll)
                 } else {
-                    ' '
+                    let text = &self.text[line.clone()];
+                    self.text_layout(text, 0, text_area.width)
+                };
+                let paragraph = Paragraph::new(self.build_text_line(line.clone(), &theme))
+                    .style(text_style)
+                    .scroll((0, scroll));
+                let aligned_area = Rect {
+                    x: row.x + offset,
+                    width: row.width - offset,
+                    ..row
                 };
+                frame.render_widget(paragraph, aligned_area);
+            }
+        }
 
-                let cursor_style = theme.input_cursor_style();
-                let cursor_span = Span::styled(cursor_char.to_string(), cursor_style);
-                let cursor_area = Rect::new(cursor_x, inner_area.y, 1, 1);
-                frame.render_widget(Paragraph::new(cursor_span), cursor_area);
+        // Render cursor if focused
+        if self.focused && !text_area.is_empty() {
+            let cursor_x = text_area.x + offset + cursor_column - scroll;
+            let cursor_y = text_area.y + (cursor_row - row_scroll) as u16;
+            if self.cursor_shape.is_some() {
+                frame.set_cursor_position((cursor_x, cursor_y));
+                return;
             }
+
+            // Get character at cursor or space if at the end of the line
+            let cursor_char = match self.text[self.cursor..].chars().next() {
+                Some(c) if c != '\n' => c,
+                _ => ' ',
+            };
+
+            // Cover the whole of a wide character, within the text area
+            let cursor_text = cursor_char.to_string();
+            let cursor_width = (display_width(&cursor_text).max(1) as u16)
+                .min(text_area.right().saturating_sub(cursor_x).max(1));
+            let cursor_style = theme.input_cursor_style();
+            let cursor_span = Span::styled(cursor_text, cursor_style);
+            let cursor_area = Rect::new(cursor_x, cursor_y, cursor_width, 1);
+            frame.render_widget(Paragraph::new(cursor_span), cursor_area);
         }
     }
 }
 
+impl Measurable for TextInput {
+    /// Measures the text or placeholder, whichever is wider, plus the
+    /// affixes, a cell for the cursor, the padding and the border, and at
+    /// least the [minimum width](TextInput::with_min_width). Inputs are
+    /// three rows high.
+    fn measure(&self, max: Size) -> Size {
+        let content = display_width(&self.text)
+            .max(self.placeholder.as_deref().map_or(0, display_width))
+            .saturating_add(1);
+        let content = u16::try_from(content).unwrap_or(u16::MAX);
+        let affixes = self
+            .prefix
+            .as_deref()
+            .map_or(0, affix_width)
+            .saturating_add(self.suffix.as_deref().map_or(0, affix_width));
+        let width = content
+            .saturating_add(affixes)
+            .saturating_add(self.padding.saturating_mul(2))
+            .saturating_add(2)
+            .max(self.min_width);
+        Size::new(width.min(max.width), 3.min(max.height))
+    }
+}
+
+/// Returns the display width of an affix, saturated to fit a `u16`.
+fn affix_width(affix: &str) -> u16 {
+    u16::try_from(Span::raw(affix).width()).unwrap_or(u16::MAX)
+}
+
 impl TextInput {
-    /// Builds text spans with selection highlighting.
-    fn build_text_spans(&self, theme: &Theme) -> Vec<Span<'_>> {
-        let mut spans = Vec::new();
+    /// Returns the byte ranges of the lines drawn, without line breaks.
+    ///
+    /// Single-line inputs draw all of their text on one line.
+    fn line_ranges(&self) -> Vec<Range<usize>> {
+        if !self.multiline {
+            return std::iter::once(0..self.text.len()).collect();
+        }
+        let mut start = 0;
+        let mut lines = Vec::new();
+        for (i, _) in self.text.match_indices('\n') {
+            lines.push(start..i);
+            start = i + 1;
+        }
+        lines.push(start..self.text.len());
+        lines
+    }
+
+    /// Returns how far a line is offset from the left of a text area of the
+    /// given width, and how many columns it is scrolled, with the cursor at
+    /// `cursor` columns into the line.
+    ///
+    /// Widths are terminal columns, so wide characters such as CJK or emoji
+    /// count twice. A line that fits, plus a column for the cursor after it,
+    /// is offset according to the alignment and never scrolled. Longer lines
+    /// fill the area and scroll just enough to keep the cursor visible;
+    /// right-aligned lines start scrolled to their end.
+    fn text_layout(&self, line: &str, cursor: u16, width: u16) -> (u16, u16) {
+        let content = u16::try_from(display_width(line))
+            .unwrap_or(u16::MAX)
+            .saturating_add(1);
+
+        if content <= width {
+            let offset = match self.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => (width - content) / 2,
+                Alignment::Right => width - content,
+            };
+            return (offset, 0);
+        }
+
+        let scroll = match self.alignment {
+            Alignment::Right => cursor.min(content - width),
+            _ => cursor.saturating_sub(width.saturating_sub(1)),
+        };
+        // Scroll by whole characters, as a wide one cannot be cut in half
+        let boundaries = line.chars().scan(0u16, |column, c| {
+            *column = column.saturating_add(c.width().unwrap_or(0) as u16);
+            Some(*column)
+        });
+        let scroll = std::iter::once(0)
+            .chain(boundaries)
+            .find(|&column| column >= scroll)
+            .unwrap_or(scroll);
+        (0, scroll)
+    }
+
+    /// Builds the text of the line at `line` with selection highlighting.
+    fn build_text_line(&self, line: Range<usize>, theme: &Theme) -> Line<'_> {
         let selection_style = Style::default()
             .add_modifier(theme.components().input.selection_modifier)
             .bg(theme.colors().primary);
 
-        match &self.selection {
+        // The part of the selection on this line
+        let selected = self
+            .selection
+            .as_ref()
+            .map(|range| {
+                range.start.clamp(line.start, line.end)..range.end.clamp(line.start, line.end)
+            })
+            .filter(|range| !range.is_empty());
+
+        let mut spans = Vec::new();
+        match selected {
             Some(range) => {
                 // Before selection
-                if range.start > 0 {
-                    spans.push(Span::raw(&self.text[..range.start]));
+                if range.start > line.start {
+                    spans.push(Span::raw(&self.text[line.start..range.start]));
                 }
                 // Selection
                 spans.push(Span::styled(&self.text[range.clone()], selection_style));
                 // After selection
-                if range.end < self.text.len() {
-                    spans.push(Span::raw(&self.text[range.end..]));
+                if range.end < line.end {
+                    spans.push(Span::raw(&self.text[range.end..line.end]));
                 }
             }
             None => {
-                spans.push(Span::raw(&self.text));
+                spans.push(Span::raw(&self.text[line]));
             }
         }
 
-        spans
+        Line::from(spans)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clipboard::MemoryClipboard;
+    use crate::event::ManualClock;
 
     #[test]
     fn test_new_text_input() {
@@ -851,8 +1908,525 @@ mod tests {
     }
 
     #[test]
-    fn test_max_length() {
-        let mut input = TextInput::new().with_max_length(5);
+    fn test_paste_strips_newlines_when_single_line() {
+        let mut input = TextInput::new();
+        input.update(TextInputMsg::Paste("one\ntwo\r\nthree".to_string()));
+        assert_eq!(input.text(), "onetwothree");
+    }
+
+    #[test]
+    fn test_line_wrap_cursor_at_line_boundaries() {
+        let lines = |wrap: bool| {
+            let mut input = TextInput::new()
+                .with_multiline(true)
+                .with_line_wrap_cursor(wrap);
+            input.update(TextInputMsg::Paste("ab\ncd".to_string()));
+            input
+        };
+
+        // Left at the start of the second line
+        let mut wrapping = lines(true);
+        assert!(wrapping.line_wrap_cursor());
+        wrapping.cursor = 3;
+        wrapping.update(TextInputMsg::CursorLeft);
+        assert_eq!(wrapping.cursor(), 2);
+
+        let mut clamped = lines(false);
+        clamped.cursor = 3;
+        clamped.update(TextInputMsg::CursorLeft);
+        assert_eq!(clamped.cursor(), 3);
+        clamped.update(TextInputMsg::SelectLeft);
+        assert_eq!(clamped.selected_text(), None);
+
+        // Right at the end of the first line
+        wrapping.cursor = 2;
+        wrapping.update(TextInputMsg::CursorRight);
+        assert_eq!(wrapping.cursor(), 3);
+
+        clamped.cursor = 2;
+        clamped.update(TextInputMsg::CursorRight);
+        assert_eq!(clamped.cursor(), 2);
+
+        // Selection still moves within the line and wraps when allowed
+        clamped.cursor = 0;
+        clamped.update(TextInputMsg::SelectRight);
+        clamped.update(TextInputMsg::SelectRight);
+        clamped.update(TextInputMsg::SelectRight);
+        assert_eq!(clamped.selected_text(), Some("ab"));
+        wrapping.cursor = 1;
+        wrapping.update(TextInputMsg::SelectRight);
+        wrapping.update(TextInputMsg::SelectRight);
+        assert_eq!(wrapping.selected_text(), Some("b\n"));
+    }
+
+    #[test]
+    fn test_home_and_end_stop_at_line_boundaries() {
+        let mut input = TextInput::new().with_multiline(true);
+        input.update(TextInputMsg::Paste("one\ntwo\nthree".to_string()));
+        input.cursor = 5;
+
+        input.update(TextInputMsg::CursorHome);
+        assert_eq!(input.cursor(), 4);
+        input.update(TextInputMsg::CursorHome);
+        assert_eq!(input.cursor(), 4);
+        input.update(TextInputMsg::CursorEnd);
+        assert_eq!(input.cursor(), 7);
+        input.update(TextInputMsg::CursorEnd);
+        assert_eq!(input.cursor(), 7);
+
+        input.update(TextInputMsg::SelectHome);
+        assert_eq!(input.selected_text(), Some("two"));
+        input.cursor = 9;
+        input.selection = None;
+        input.update(TextInputMsg::SelectEnd);
+        assert_eq!(input.selected_text(), Some("hree"));
+
+        // Single-line inputs still move to either end of the text
+        let mut input = TextInput::new();
+        input.set_text("one two");
+        input.cursor = 3;
+        input.update(TextInputMsg::CursorHome);
+        assert_eq!(input.cursor(), 0);
+        input.update(TextInputMsg::CursorEnd);
+        assert_eq!(input.cursor(), 7);
+    }
+
+    #[test]
+    fn test_word_moves_stop_at_line_boundaries() {
+        let lines = |wrap: bool| {
+            let mut input = TextInput::new()
+                .with_multiline(true)
+                .with_line_wrap_cursor(wrap);
+            input.update(TextInputMsg::Paste("ab cd\nef gh".to_string()));
+            input
+        };
+
+        // Word left from inside the second line stops at its start
+        let mut wrapping = lines(true);
+        wrapping.cursor = 8;
+        wrapping.update(TextInputMsg::CursorWordLeft);
+        assert_eq!(wrapping.cursor(), 6);
+        // From the line start, it wraps like Left
+        wrapping.update(TextInputMsg::CursorWordLeft);
+        assert_eq!(wrapping.cursor(), 5);
+        wrapping.update(TextInputMsg::CursorWordLeft);
+        assert_eq!(wrapping.cursor(), 3);
+
+        // Word right stops at the line end, then wraps like Right
+        wrapping.update(TextInputMsg::CursorWordRight);
+        assert_eq!(wrapping.cursor(), 5);
+        wrapping.update(TextInputMsg::CursorWordRight);
+        assert_eq!(wrapping.cursor(), 6);
+
+        let mut clamped = lines(false);
+        clamped.cursor = 6;
+        clamped.update(TextInputMsg::CursorWordLeft);
+        assert_eq!(clamped.cursor(), 6);
+        clamped.cursor = 3;
+        clamped.update(TextInputMsg::SelectWordRight);
+        clamped.update(TextInputMsg::SelectWordRight);
+        assert_eq!(clamped.selected_text(), Some("cd"));
+    }
+
+    #[test]
+    fn test_cursor_and_scroll_use_display_width() {
+        let cursor_column = |buffer: &ratatui::buffer::Buffer| {
+            let cursor_bg = Theme::default().input_cursor_style().bg;
+            (0..buffer.area.width).find(|&x| Some(buffer[(x, 1)].bg) == cursor_bg)
+        };
+        let mut input = TextInput::new();
+        input.set_text("日本");
+        input.set_focused(true);
+
+        // Two wide characters fill four columns before the cursor
+        let buffer = render_to_buffer(&input, 8);
+        assert_eq!(cursor_column(&buffer), Some(5));
+        assert_eq!(row_text(&buffer, 1), "│日 本   │");
+
+        // Over a wide character, the cursor draws the whole character
+        input.update(TextInputMsg::CursorLeft);
+        let buffer = render_to_buffer(&input, 8);
+        assert_eq!(cursor_column(&buffer), Some(3));
+        assert_eq!(buffer[(3, 1)].symbol(), "本");
+
+        // Text scrolls as soon as its columns no longer fit, by whole
+        // characters
+        input.set_text("日本語");
+        let buffer = render_to_buffer(&input, 8);
+        assert_eq!(cursor_column(&buffer), Some(5));
+        assert_eq!(row_text(&buffer, 1), "│本 語   │");
+    }
+
+    #[test]
+    fn test_multiline_renders_each_line() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut input = TextInput::new().with_multiline(true);
+        input.update(TextInputMsg::Paste("one\ntwo\nthree\nfour".to_string()));
+        input.set_focused(true);
+        input.cursor = 6;
+
+        let mut terminal = Terminal::new(TestBackend::new(9, 5)).unwrap();
+        let mut draw = |input: &TextInput| {
+            let frame = terminal
+                .draw(|frame| input.render(frame, Rect::new(0, 0, 9, 5)))
+                .unwrap();
+            frame.buffer.clone()
+        };
+
+        let buffer = draw(&input);
+        assert_eq!(row_text(&buffer, 1), "│one    │");
+        assert_eq!(row_text(&buffer, 2), "│two    │");
+        assert_eq!(row_text(&buffer, 3), "│three  │");
+        // The cursor is drawn on its own line
+        let cursor_bg = Theme::default().input_cursor_style().bg;
+        assert_eq!(Some(buffer[(3, 2)].bg), cursor_bg);
+
+        // Rows scroll to keep the cursor line visible
+        input.cursor = input.text().len();
+        let buffer = draw(&input);
+        assert_eq!(row_text(&buffer, 1), "│two    │");
+        assert_eq!(row_text(&buffer, 3), "│four   │");
+        assert_eq!(Some(buffer[(5, 3)].bg), cursor_bg);
+    }
+
+    #[test]
+    fn test_paste_keeps_newlines_when_multiline() {
+        let mut input = TextInput::new().with_multiline(true);
+        assert!(input.is_multiline());
+        input.update(TextInputMsg::Paste("one\r\ntwo\rthree".to_string()));
+        assert_eq!(input.text(), "one\ntwo\nthree");
+    }
+
+    #[test]
+    fn test_paste_submits_each_line() {
+        let mut input = TextInput::new()
+            .with_submit_on_newline(true)
+            .with_history(10);
+        input.update(TextInputMsg::Paste("> ".to_string()));
+
+        let actions = input.update_many(TextInputMsg::Paste("a\nb\nc".to_string()));
+        assert_eq!(
+            actions,
+            [
+                TextInputAction::Submit("> a".to_string()),
+                TextInputAction::Submit("b".to_string()),
+                TextInputAction::Submit("c".to_string()),
+            ]
+        );
+        assert!(input.text().is_empty());
+        assert!(!input.has_queued_lines());
+
+        // Submitted lines are recorded in history
+        input.update(TextInputMsg::HistoryPrev);
+        assert_eq!(input.text(), "c");
+
+        // A paste without line breaks is inserted as usual
+        input.update(TextInputMsg::Clear);
+        assert_eq!(
+            input.update_many(TextInputMsg::Paste("d".to_string())),
+            [TextInputAction::Changed("d".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_pasted_lines_cut_to_max_length() {
+        let mut input = TextInput::new()
+            .with_submit_on_newline(true)
+            .with_max_length(4);
+        input.update(TextInputMsg::Paste("> ".to_string()));
+
+        let actions = input.update_many(TextInputMsg::Paste("abc\nlonger\nok".to_string()));
+        assert_eq!(
+            actions,
+            [
+                TextInputAction::Submit("> ab".to_string()),
+                TextInputAction::Submit("long".to_string()),
+                TextInputAction::Submit("ok".to_string()),
+            ]
+        );
+        assert!(input.text().is_empty());
+    }
+
+    #[test]
+    fn test_paste_lines_queued_for_update() {
+        let mut input = TextInput::new().with_submit_on_newline(true);
+
+        // A single update submits the first line and queues the rest
+        assert_eq!(
+            input.update(TextInputMsg::Paste("a\r\nb\n".to_string())),
+            Some(TextInputAction::Submit("a".to_string()))
+        );
+        assert!(input.has_queued_lines());
+        assert_eq!(
+            input.update(TextInputMsg::Tick),
+            Some(TextInputAction::Submit("b".to_string()))
+        );
+
+        // The trailing line break does not submit an empty line
+        assert!(!input.has_queued_lines());
+        assert_eq!(input.update(TextInputMsg::Tick), None);
+    }
+
+    #[test]
+    fn test_handle_paste_event() {
+        let mut input = TextInput::new();
+        let event = AppEvent::<String>::Paste("pasted\ntext".to_string());
+
+        let action = input.handle_event(&event);
+        assert!(matches!(action, Some(TextInputAction::Changed(ref t)) if t == "pastedtext"));
+        assert_eq!(input.text(), "pastedtext");
+
+        assert!(input.handle_event(&AppEvent::<String>::Tick).is_none());
+    }
+
+    #[test]
+    fn test_clipboard_copy_paste_round_trip() {
+        let clipboard = Arc::new(MemoryClipboard::new());
+        let mut source = TextInput::new().with_clipboard(clipboard.clone());
+        source.set_text("hello world");
+        source.update(TextInputMsg::SelectWordLeft);
+        source.update(TextInputMsg::Copy);
+        assert_eq!(clipboard.get_text().unwrap(), "world");
+        assert_eq!(source.text(), "hello world");
+
+        let mut target = TextInput::new().with_clipboard(clipboard);
+        let action = target.update(TextInputMsg::PasteFromClipboard);
+        assert!(matches!(action, Some(TextInputAction::Changed(ref t)) if t == "world"));
+        assert_eq!(target.text(), "world");
+    }
+
+    #[test]
+    fn test_clipboard_cut_removes_selection() {
+        let clipboard = Arc::new(MemoryClipboard::new());
+        let mut input = TextInput::new().with_clipboard(clipboard.clone());
+        input.set_text("hello");
+        input.update(TextInputMsg::SelectAll);
+        input.update(TextInputMsg::Cut);
+
+        assert!(input.text().is_empty());
+        assert_eq!(clipboard.get_text().unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_paste_from_clipboard_without_clipboard() {
+        let mut input = TextInput::new();
+        assert!(input.update(TextInputMsg::PasteFromClipboard).is_none());
+
+        let mut input =
+            TextInput::new().with_clipboard(Arc::new(crate::clipboard::MemoryClipboard::new()));
+        assert!(input.update(TextInputMsg::PasteFromClipboard).is_none());
+    }
+
+    #[test]
+    fn test_selection_updates_primary_not_clipboard() {
+        let clipboard = Arc::new(MemoryClipboard::new());
+        let mut input = TextInput::new()
+            .with_clipboard(clipboard.clone())
+            .with_primary_selection(true);
+        assert!(input.uses_primary_selection());
+        input.set_text("hello world");
+
+        // Publishing waits until the selection is finished
+        input.update(TextInputMsg::SelectLeft);
+        input.update(TextInputMsg::SelectWordLeft);
+        assert!(clipboard.get_text_from(ClipboardKind::Primary).is_err());
+        input.on_blur();
+        assert_eq!(
+            clipboard.get_text_from(ClipboardKind::Primary).unwrap(),
+            "world"
+        );
+        assert!(clipboard.get_text().is_err());
+
+        // Copying writes the clipboard and leaves the primary selection alone
+        input.update(TextInputMsg::SelectAll);
+        input.update(TextInputMsg::Copy);
+        clipboard
+            .set_text_in(ClipboardKind::Primary, "selected")
+            .unwrap();
+        assert_eq!(clipboard.get_text().unwrap(), "hello world");
+        assert_eq!(
+            clipboard.get_text_from(ClipboardKind::Primary).unwrap(),
+            "selected"
+        );
+
+        // Without the option, selecting does not touch the primary selection
+        let mut plain = TextInput::new().with_clipboard(clipboard.clone());
+        plain.set_text("other");
+        plain.update(TextInputMsg::SelectAll);
+        plain.update(TextInputMsg::Copy);
+        assert_eq!(
+            clipboard.get_text_from(ClipboardKind::Primary).unwrap(),
+            "selected"
+        );
+    }
+
+    #[test]
+    fn test_middle_click_pastes_primary() {
+        use crate::event::MouseEvent;
+
+        let clipboard = Arc::new(MemoryClipboard::new());
+        clipboard.set_text("copied").unwrap();
+        clipboard
+            .set_text_in(ClipboardKind::Primary, " there")
+            .unwrap();
+        let middle_click = AppEvent::<String>::Mouse(MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Middle),
+            3,
+            1,
+        ));
+
+        let mut input = TextInput::new().with_clipboard(clipboard.clone());
+        input.set_text("hi");
+        assert!(input.handle_event(&middle_click).is_none());
+
+        let mut input = input.with_primary_selection(true);
+        let action = input.handle_event(&middle_click);
+        assert!(matches!(action, Some(TextInputAction::Changed(ref t)) if t == "hi there"));
+
+        // Pasting a selection inserts it at the cursor instead of replacing it
+        input.update(TextInputMsg::SelectWordLeft);
+        input.handle_event(&middle_click);
+        assert_eq!(input.text(), "hi therethere");
+        assert_eq!(input.selection(), None);
+    }
+
+    fn submit(input: &mut TextInput, text: &str) {
+        input.set_text(text);
+        input.update(TextInputMsg::Submit);
+    }
+
+    #[test]
+    fn test_submit_action() {
+        let mut input = TextInput::new();
+        input.set_text("go");
+        let action = input.update(TextInputMsg::Submit);
+        assert!(matches!(action, Some(TextInputAction::Submit(ref t)) if t == "go"));
+        assert!(input.history().is_empty());
+    }
+
+    #[test]
+    fn test_history_recall_order() {
+        let mut input = TextInput::new().with_history(10);
+        submit(&mut input, "one");
+        submit(&mut input, "two");
+        submit(&mut input, "three");
+        input.update(TextInputMsg::Clear);
+
+        input.update(TextInputMsg::HistoryPrev);
+        assert_eq!(input.text(), "three");
+        assert_eq!(input.cursor(), 5);
+        input.update(TextInputMsg::HistoryPrev);
+        assert_eq!(input.text(), "two");
+        input.update(TextInputMsg::HistoryPrev);
+        assert_eq!(input.text(), "one");
+
+        // Past the oldest entry is a no-op
+        assert!(input.update(TextInputMsg::HistoryPrev).is_none());
+        assert_eq!(input.text(), "one");
+
+        input.update(TextInputMsg::HistoryNext);
+        assert_eq!(input.text(), "two");
+    }
+
+    #[test]
+    fn test_history_restores_draft() {
+        let mut input = TextInput::new().with_history(10);
+        submit(&mut input, "ls");
+        input.set_text("git sta");
+
+        input.update(TextInputMsg::HistoryPrev);
+        assert_eq!(input.text(), "ls");
+        input.update(TextInputMsg::HistoryNext);
+        assert_eq!(input.text(), "git sta");
+
+        // Past the draft is a no-op
+        assert!(input.update(TextInputMsg::HistoryNext).is_none());
+        assert_eq!(input.text(), "git sta");
+    }
+
+    #[test]
+    fn test_history_dedup_and_capacity() {
+        let mut input = TextInput::new().with_history(2);
+        submit(&mut input, "a");
+        submit(&mut input, "a");
+        submit(&mut input, "");
+        assert_eq!(input.history(), vec!["a"]);
+
+        submit(&mut input, "b");
+        submit(&mut input, "c");
+        assert_eq!(input.history(), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_history_empty_or_disabled() {
+        let mut input = TextInput::new().with_history(5);
+        input.set_text("draft");
+        assert!(input.update(TextInputMsg::HistoryPrev).is_none());
+        assert!(input.update(TextInputMsg::HistoryNext).is_none());
+        assert_eq!(input.text(), "draft");
+
+        let mut input = TextInput::new();
+        assert!(input.update(TextInputMsg::HistoryPrev).is_none());
+    }
+
+    fn debounced_input(clock: &ManualClock) -> TextInput {
+        TextInput::new()
+            .with_validator(|text| {
+                if text.len() < 3 {
+                    ValidationResult::Invalid("Too short".to_string())
+                } else {
+                    ValidationResult::Valid
+                }
+            })
+            .with_validation_debounce_clock(Duration::from_millis(200), Arc::new(clock.clone()))
+    }
+
+    #[test]
+    fn test_debounced_validation_waits_for_pause() {
+        let clock = ManualClock::new();
+        let mut input = debounced_input(&clock);
+
+        for c in "ab".chars() {
+            input.update(TextInputMsg::InsertChar(c));
+            clock.advance(Duration::from_millis(50));
+            input.update(TextInputMsg::Tick);
+        }
+        assert!(input.is_validation_pending());
+        assert!(input.is_valid());
+
+        clock.advance(Duration::from_millis(200));
+        input.update(TextInputMsg::Tick);
+        assert!(!input.is_validation_pending());
+        assert!(!input.is_valid());
+
+        // The previous message persists while the next edit is debounced
+        input.update(TextInputMsg::InsertChar('c'));
+        input.update(TextInputMsg::Tick);
+        assert!(!input.is_valid());
+
+        clock.advance(Duration::from_millis(200));
+        input.update(TextInputMsg::Tick);
+        assert!(input.is_valid());
+    }
+
+    #[test]
+    fn test_debounced_validation_runs_on_blur() {
+        let clock = ManualClock::new();
+        let mut input = debounced_input(&clock);
+
+        input.update(TextInputMsg::InsertChar('a'));
+        assert!(input.is_valid());
+
+        input.on_blur();
+        assert!(!input.is_valid());
+        assert!(!input.is_validation_pending());
+    }
+
+    #[test]
+    fn test_max_length() {
+        let mut input = TextInput::new().with_max_length(5);
 
         input.update(TextInputMsg::Paste("hello".to_string()));
         assert_eq!(input.text(), "hello");
@@ -906,6 +2480,31 @@ mod tests {
         // Placeholder is used during rendering, not stored in text
     }
 
+    #[test]
+    fn test_measure() {
+        let max = Size::new(80, 24);
+
+        // An empty input fits the cursor inside its border
+        assert_eq!(TextInput::new().measure(max), Size::new(3, 3));
+
+        // The wider of text and placeholder, plus affixes
+        let mut input = TextInput::new()
+            .with_placeholder("Search")
+            .with_prefix("$ ")
+            .with_min_width(5);
+        assert_eq!(input.measure(max), Size::new(6 + 1 + 2 + 2, 3));
+        input.set_text("a longer query");
+        assert_eq!(input.measure(max), Size::new(14 + 1 + 2 + 2, 3));
+
+        assert_eq!(input.with_min_width(40).measure(max).width, 40);
+        assert_eq!(
+            TextInput::new()
+                .with_min_width(40)
+                .measure(Size::new(20, 1)),
+            Size::new(20, 1)
+        );
+    }
+
     #[test]
     fn test_focusable() {
         let mut input = TextInput::new();
@@ -917,4 +2516,387 @@ mod tests {
         input.set_focused(false);
         assert!(!input.is_focused());
     }
+
+    #[test]
+    fn test_read_only_rejects_edits() {
+        let clipboard = Arc::new(MemoryClipboard::new());
+        clipboard.set_text("pasted").unwrap();
+        let mut input = TextInput::new()
+            .with_read_only(true)
+            .with_clipboard(clipboard)
+            .with_history(10);
+        input.set_text("fixed");
+        assert!(input.is_read_only());
+
+        let edits = [
+            TextInputMsg::InsertChar('x'),
+            TextInputMsg::Backspace,
+            TextInputMsg::Delete,
+            TextInputMsg::Paste("more".to_string()),
+            TextInputMsg::PasteFromClipboard,
+            TextInputMsg::Clear,
+            TextInputMsg::SetText("other".to_string()),
+            TextInputMsg::HistoryPrev,
+        ];
+        for msg in edits {
+            assert!(msg.is_edit());
+            assert!(input.update(msg).is_none());
+            assert_eq!(input.text(), "fixed");
+        }
+
+        input.update(TextInputMsg::SelectAll);
+        assert!(input.update(TextInputMsg::Cut).is_none());
+        assert_eq!(input.text(), "fixed");
+    }
+
+    #[test]
+    fn test_read_only_allows_navigation_and_copy() {
+        let mut input = TextInput::new().with_read_only(true);
+        input.set_text("hello world");
+
+        input.update(TextInputMsg::CursorHome);
+        assert_eq!(input.cursor(), 0);
+        input.update(TextInputMsg::SelectWordRight);
+        assert_eq!(input.selected_text(), Some("hello "));
+
+        let action = input.update(TextInputMsg::Copy);
+        assert!(matches!(action, Some(TextInputAction::CopiedToClipboard(ref s)) if s == "hello "));
+    }
+
+    #[test]
+    fn test_read_only_toggle_restores_editing() {
+        let mut input = TextInput::new().with_read_only(true);
+        assert!(input.update(TextInputMsg::InsertChar('a')).is_none());
+
+        input.set_read_only(false);
+        assert!(!input.is_read_only());
+        assert!(input.update(TextInputMsg::InsertChar('a')).is_some());
+        assert_eq!(input.text(), "a");
+    }
+
+    fn render_to_buffer(input: &TextInput, width: u16) -> ratatui::buffer::Buffer {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(width, 3)).unwrap();
+        terminal
+            .draw(|frame| input.render(frame, Rect::new(0, 0, width, 3)))
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    fn row_text(buffer: &ratatui::buffer::Buffer, y: u16) -> String {
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_border_follows_hover_and_focus() {
+        let theme = Theme::dark();
+        let mut input = TextInput::new().with_theme(theme.clone());
+        let border = |input: &TextInput| render_to_buffer(input, 10)[(0, 0)].style();
+
+        assert_eq!(border(&input).fg, theme.border_style().fg);
+
+        input.set_hovered(true);
+        assert!(input.is_hovered());
+        assert_eq!(border(&input).fg, theme.border_hover_style().fg);
+        assert!(border(&input).add_modifier.contains(Modifier::BOLD));
+
+        // Focus takes precedence over hover
+        input.set_focused(true);
+        assert_eq!(border(&input).fg, theme.border_focused_style().fg);
+    }
+
+    #[test]
+    fn test_hover_uses_hover_border_type() {
+        use crate::theme::ThemeBuilder;
+        use ratatui::widgets::BorderType;
+
+        let theme = ThemeBuilder::new()
+            .hover_border_type(BorderType::Double)
+            .disabled_border_type(BorderType::Thick)
+            .build();
+        let mut input = TextInput::new().with_theme(theme);
+        let corner = |input: &TextInput| render_to_buffer(input, 10)[(0, 0)].symbol().to_string();
+
+        assert_eq!(corner(&input), "╭");
+        input.set_hovered(true);
+        assert_eq!(corner(&input), "╔");
+        input.set_focused(true);
+        assert_eq!(corner(&input), "╔");
+        // A read-only input uses the disabled border type, even when hovered
+        input.set_read_only(true);
+        assert_eq!(corner(&input), "┏");
+        input.set_hovered(false);
+        assert_eq!(corner(&input), "┏");
+    }
+
+    #[test]
+    fn test_alignment_places_text_and_cursor() {
+        let cursor_column = |buffer: &ratatui::buffer::Buffer| {
+            let cursor_bg = Theme::default().input_cursor_style().bg;
+            (0..buffer.area.width).find(|&x| Some(buffer[(x, 1)].bg) == cursor_bg)
+        };
+        let mut input = TextInput::new().with_alignment(Alignment::Right);
+        assert_eq!(input.alignment(), Alignment::Right);
+        input.set_focused(true);
+        input.set_text("42");
+
+        // Cursor at the end sits in the last column, after the text
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(row_text(&buffer, 1), "│     42 │");
+        assert_eq!(cursor_column(&buffer), Some(8));
+
+        input.update(TextInputMsg::CursorHome);
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(cursor_column(&buffer), Some(6));
+
+        let mut input = input.with_alignment(Alignment::Center);
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(row_text(&buffer, 1), "│  42    │");
+        assert_eq!(cursor_column(&buffer), Some(3));
+
+        input = input.with_alignment(Alignment::Left);
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(row_text(&buffer, 1), "│42      │");
+        assert_eq!(cursor_column(&buffer), Some(1));
+    }
+
+    #[test]
+    fn test_right_alignment_scrolls_from_end() {
+        let mut input = TextInput::new().with_alignment(Alignment::Right);
+        input.set_focused(true);
+        input.set_text("1234567890");
+
+        // Eight columns: the tail and the cursor are visible
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(row_text(&buffer, 1), "│4567890 │");
+
+        // Moving left within view keeps the end anchored
+        input.update(TextInputMsg::CursorLeft);
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(row_text(&buffer, 1), "│4567890 │");
+
+        // Moving past the left edge scrolls back
+        input.update(TextInputMsg::CursorHome);
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(row_text(&buffer, 1), "│12345678│");
+    }
+
+    #[test]
+    fn test_padding_reduces_usable_width() {
+        let mut input = TextInput::new().with_padding(2);
+        assert_eq!(input.padding(), 2);
+        assert_eq!(input.measure(Size::new(80, 3)), Size::new(1 + 4 + 2, 3));
+        input.set_focused(true);
+        input.set_text("abcdef");
+
+        // Ten columns less the border and padding leave four: three
+        // characters and the cursor
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(row_text(&buffer, 1), "│  def   │");
+
+        // Padding wider than the input is reduced to leave room to type
+        let input = input.with_padding(20);
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(row_text(&buffer, 1), "│   f    │");
+    }
+
+    #[test]
+    fn test_affixes_excluded_from_text() {
+        let mut input = TextInput::new().with_prefix("$ ").with_suffix(" USD");
+        input.update(TextInputMsg::Paste("12.00".to_string()));
+
+        assert_eq!(input.text(), "12.00");
+        assert_eq!(input.prefix(), Some("$ "));
+        assert_eq!(input.suffix(), Some(" USD"));
+        assert_eq!(input.cursor(), 5);
+
+        let buffer = render_to_buffer(&input, 20);
+        assert_eq!(row_text(&buffer, 1), "│$ 12.00        USD│");
+    }
+
+    #[test]
+    fn test_cursor_offset_by_prefix() {
+        let theme = Theme::default();
+        let mut input = TextInput::new()
+            .with_prefix("https://")
+            .with_theme(theme.clone());
+        input.set_focused(true);
+        input.set_text("abc");
+        input.update(TextInputMsg::CursorHome);
+        input.update(TextInputMsg::CursorRight);
+
+        let buffer = render_to_buffer(&input, 20);
+        // Border (1) + prefix (8) + one character
+        assert_eq!(buffer[(10, 1)].symbol(), "b");
+        assert_eq!(buffer[(10, 1)].bg, theme.input_cursor_style().bg.unwrap());
+        assert_eq!(buffer[(1, 1)].fg, theme.muted_style().fg.unwrap());
+    }
+
+    #[test]
+    fn test_terminal_cursor_position() {
+        use ratatui::backend::TestBackend;
+        use ratatui::layout::Position;
+        use ratatui::Terminal;
+
+        let theme = Theme::default();
+        let mut input = TextInput::new()
+            .with_prefix("> ")
+            .with_cursor_style(CursorShape::SteadyBar)
+            .with_theme(theme.clone());
+        assert_eq!(input.cursor_style(), Some(CursorShape::SteadyBar));
+        input.set_text("abc");
+        input.update(TextInputMsg::CursorLeft);
+
+        let mut terminal = Terminal::new(TestBackend::new(20, 3)).unwrap();
+        let mut draw = |input: &TextInput| {
+            terminal.set_cursor_position(Position::ORIGIN).unwrap();
+            terminal
+                .draw(|frame| input.render(frame, Rect::new(0, 0, 20, 3)))
+                .unwrap();
+            let cursor = terminal.get_cursor_position().unwrap();
+            (cursor, terminal.backend().buffer()[(5, 1)].clone())
+        };
+
+        // Blurred inputs leave the cursor alone
+        let (cursor, _) = draw(&input);
+        assert_eq!(cursor, Position::ORIGIN);
+
+        // Border (1) + prefix (2) + two characters; the cell is not restyled
+        input.set_focused(true);
+        let (cursor, cell) = draw(&input);
+        assert_eq!(cursor, Position::new(5, 1));
+        assert_eq!(cell.symbol(), "c");
+        assert_ne!(cell.bg, theme.input_cursor_style().bg.unwrap());
+    }
+
+    #[test]
+    fn test_text_scrolls_after_prefix() {
+        let mut input = TextInput::new().with_prefix("> ");
+        input.set_focused(true);
+        input.set_text("abcdefgh");
+
+        // Inner width 8: 2 for the prefix leaves 6 for text and the cursor
+        let buffer = render_to_buffer(&input, 10);
+        assert_eq!(row_text(&buffer, 1), "│> defgh │");
+    }
+
+    #[test]
+    fn test_prefix_wider_than_field() {
+        let mut input = TextInput::new()
+            .with_prefix("a very long prefix")
+            .with_suffix("!");
+        input.set_focused(true);
+        input.set_text("text");
+
+        let buffer = render_to_buffer(&input, 8);
+        assert_eq!(row_text(&buffer, 1), "│a very│");
+        assert_eq!(input.text(), "text");
+    }
+
+    #[test]
+    fn test_msg_from_key() {
+        use terminput::{KeyEventKind, KeyEventState};
+
+        let key = |code, modifiers| KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        };
+
+        assert!(matches!(
+            TextInputMsg::from_key(&key(KeyCode::Char('x'), KeyModifiers::NONE)),
+            Some(TextInputMsg::InsertChar('x'))
+        ));
+        assert!(matches!(
+            TextInputMsg::from_key(&key(KeyCode::Char('v'), KeyModifiers::CTRL)),
+            Some(TextInputMsg::PasteFromClipboard)
+        ));
+        assert!(matches!(
+            TextInputMsg::from_key(&key(
+                KeyCode::Left,
+                KeyModifiers::CTRL | KeyModifiers::SHIFT
+            )),
+            Some(TextInputMsg::SelectWordLeft)
+        ));
+        assert!(TextInputMsg::from_key(&key(KeyCode::Tab, KeyModifiers::NONE)).is_none());
+        assert!(TextInputMsg::from_key(&key(KeyCode::Char('q'), KeyModifiers::CTRL)).is_none());
+    }
+
+    #[test]
+    fn test_handle_key_routes_editing_keys() {
+        use terminput::KeyEventState;
+
+        let key = |code, modifiers| KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        };
+        let plain = |code| key(code, KeyModifiers::NONE);
+
+        let mut input = TextInput::new();
+        for c in "hello world".chars() {
+            input.handle_key(&plain(KeyCode::Char(c)));
+        }
+        assert_eq!(input.text(), "hello world");
+
+        // Backspace deletes one character
+        assert_eq!(
+            input.handle_key(&plain(KeyCode::Backspace)),
+            Some(TextInputAction::Changed("hello worl".to_string()))
+        );
+        input.handle_key(&key(KeyCode::Left, KeyModifiers::CTRL));
+        assert_eq!(input.cursor(), 6);
+        input.handle_key(&plain(KeyCode::Home));
+        input.handle_key(&key(KeyCode::Right, KeyModifiers::SHIFT));
+        assert_eq!(input.selected_text(), Some("h"));
+        input.handle_key(&plain(KeyCode::Delete));
+        input.handle_key(&plain(KeyCode::End));
+        assert_eq!(input.text(), "ello worl");
+        assert_eq!(input.cursor(), 9);
+
+        input.handle_key(&key(KeyCode::Char('a'), KeyModifiers::CTRL));
+        assert_eq!(input.selected_text(), Some("ello worl"));
+        assert_eq!(
+            input.handle_key(&plain(KeyCode::Esc)),
+            Some(TextInputAction::Cancel)
+        );
+        assert_eq!(input.selected_text(), None);
+        assert_eq!(input.text(), "ello worl");
+
+        assert_eq!(
+            input.handle_key(&plain(KeyCode::Enter)),
+            Some(TextInputAction::Submit("ello worl".to_string()))
+        );
+
+        // Releases and unmapped keys are ignored
+        let release = KeyEvent {
+            kind: KeyEventKind::Release,
+            ..plain(KeyCode::Char('x'))
+        };
+        assert_eq!(input.handle_key(&release), None);
+        assert_eq!(input.handle_key(&plain(KeyCode::Tab)), None);
+        assert_eq!(input.text(), "ello worl");
+    }
+
+    #[test]
+    fn test_focused_input_renders_into_tiny_areas() {
+        use crate::test_util::{render, to_lines};
+
+        let mut input = TextInput::new();
+        input.set_focused(true);
+        input.set_text("abc");
+        assert!(to_lines(&render(&input, Rect::new(0, 0, 0, 0))).is_empty());
+        assert_eq!(to_lines(&render(&input, Rect::new(0, 0, 1, 1))), ["╭"]);
+        // Too short for a text row inside the border, so no cursor is drawn
+        assert_eq!(
+            to_lines(&render(&input, Rect::new(0, 0, 8, 2))),
+            ["╭──────╮", "╰──────╯"]
+        );
+    }
 }
@@ -0,0 +1,192 @@
+//! ThemePreview component showing a theme's palette and sample widgets.
+//!
+//! A [`ThemePreview`] draws a labeled color swatch for every
+//! [`ColorRole`] alongside a title, a button and a text input styled with
+//! the theme, so users can compare themes in a picker before applying one.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::{Measurable, ThemePreview};
+//! use tuilib::theme::Theme;
+//! use ratatui::layout::Size;
+//!
+//! let previews: Vec<ThemePreview> = [Theme::dark(), Theme::light()]
+//!     .into_iter()
+//!     .map(ThemePreview::new)
+//!     .collect();
+//!
+//! assert_eq!(previews[1].theme().name(), Theme::light().name());
+//! assert_eq!(previews[0].measure(Size::new(80, 24)), Size::new(40, 15));
+//! ```
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use super::modal::Button;
+use super::{Focusable, Measurable, Renderable, TextInput};
+use crate::theme::{ColorRole, Theme};
+
+/// Width of a color swatch in cells.
+const SWATCH_WIDTH: u16 = 2;
+
+/// Width of the swatch column: swatch, space, longest role name, gap.
+const SWATCH_COLUMN_WIDTH: u16 = SWATCH_WIDTH + 1 + 14 + 1;
+
+/// Minimum width of the sample widget column.
+const SAMPLE_COLUMN_WIDTH: u16 = 20;
+
+/// A preview of a theme's colors and how widgets look with it.
+///
+/// The palette swatches fill the left column, one role per row in
+/// [`ColorRole::ALL`] order, and the sample widgets fill the right column.
+/// The whole preview is framed by a border titled with the theme's name.
+#[derive(Debug, Clone)]
+pub struct ThemePreview {
+    /// The theme being previewed.
+    theme: Theme,
+    /// Title shown above the sample widgets.
+    sample_title: String,
+}
+
+impl ThemePreview {
+    /// Creates a preview of the given theme.
+    pub fn new(theme: Theme) -> Self {
+        Self {
+            theme,
+            sample_title: "Sample".to_string(),
+        }
+    }
+
+    /// Sets the title shown above the sample widgets.
+    pub fn with_sample_title(mut self, title: impl Into<String>) -> Self {
+        self.sample_title = title.into();
+        self
+    }
+
+    /// Returns the theme being previewed.
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Renders one swatch row per palette role.
+    fn render_swatches(&self, frame: &mut Frame, area: Rect) {
+        let label_style = self.theme.primary_text_style();
+        for (row, role) in (area.top()..area.bottom()).zip(ColorRole::ALL) {
+            let swatch = " ".repeat(SWATCH_WIDTH as usize);
+            let line = Line::from(vec![
+                Span::styled(swatch, Style::default().bg(self.theme.color(role))),
+                Span::raw(" "),
+                Span::styled(role.name(), label_style),
+            ]);
+            let row_area = Rect::new(area.x, row, area.width, 1);
+            frame.render_widget(Paragraph::new(line), row_area);
+        }
+    }
+
+    /// Renders the sample title, status colors, button and input.
+    fn render_samples(&self, frame: &mut Frame, area: Rect) {
+        let theme = &self.theme;
+        let [title_area, status_area, button_area, input_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ])
+        .areas(area);
+
+        let title = Paragraph::new(Span::styled(
+            self.sample_title.as_str(),
+            theme.heading_style(),
+        ));
+        frame.render_widget(title, title_area);
+
+        let status = Line::from(vec![
+            Span::styled("error", theme.error_text_style()),
+            Span::raw(" "),
+            Span::styled("warn", theme.warning_text_style()),
+            Span::raw(" "),
+            Span::styled("ok", theme.success_text_style()),
+            Span::raw(" "),
+            Span::styled("info", theme.info_text_style()),
+        ]);
+        frame.render_widget(Paragraph::new(status), status_area);
+
+        let mut button = Button::new("theme-preview-button", "Button").with_theme(theme.clone());
+        button.set_focused(true);
+        let button_width = button.measure(button_area.as_size()).width;
+        button.render(
+            frame,
+            Rect {
+                width: button_width,
+                ..button_area
+            },
+        );
+
+        let input = TextInput::new()
+            .with_placeholder("Input")
+            .with_theme(theme.clone());
+        input.render(frame, input_area);
+    }
+}
+
+impl Renderable for ThemePreview {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let theme = &self.theme;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(theme.borders().default)
+            .border_style(theme.border_style())
+            .title(Span::styled(theme.name(), theme.heading_style()))
+            .style(Style::default().bg(theme.colors().background));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [swatch_area, sample_area] =
+            Layout::horizontal([Constraint::Length(SWATCH_COLUMN_WIDTH), Constraint::Fill(1)])
+                .areas(inner);
+        self.render_swatches(frame, swatch_area);
+        self.render_samples(frame, sample_area);
+    }
+}
+
+impl Measurable for ThemePreview {
+    fn measure(&self, max: Size) -> Size {
+        let width = SWATCH_COLUMN_WIDTH + SAMPLE_COLUMN_WIDTH + 2;
+        let height = ColorRole::ALL.len() as u16 + 2;
+        Size::new(width.min(max.width), height.min(max.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{render, to_lines};
+
+    #[test]
+    fn test_swatches_use_palette_colors() {
+        for theme in [Theme::dark(), Theme::light(), Theme::high_contrast()] {
+            let preview = ThemePreview::new(theme.clone());
+            let buffer = render(&preview, Rect::new(0, 0, 40, 15));
+
+            for (row, role) in (1..).zip(ColorRole::ALL) {
+                for x in 1..1 + SWATCH_WIDTH {
+                    assert_eq!(buffer[(x, row)].bg, theme.color(role), "{role} swatch");
+                }
+                let label: String = to_lines(&buffer)[row as usize].chars().skip(4).collect();
+                assert!(label.starts_with(role.name()), "{role} label: {label}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_renders_samples_and_title() {
+        let preview = ThemePreview::new(Theme::dark()).with_sample_title("Widgets");
+        let lines = to_lines(&render(&preview, Rect::new(0, 0, 40, 15)));
+
+        assert!(lines[0].contains(Theme::dark().name()));
+        assert!(lines[1].contains("Widgets"));
+        assert!(lines[4].contains("Button"));
+        assert!(lines[7].contains("Input"));
+    }
+}
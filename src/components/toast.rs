@@ -0,0 +1,354 @@
+//! Toast notifications that dismiss themselves.
+//!
+//! A [`ToastStack`] holds short-lived messages such as "Saved!", each with a
+//! [`Severity`] and a time to live. Call [`ToastStack::tick`] with the time
+//! elapsed since the last tick (for example on every
+//! [`AppEvent::Tick`](crate::event::AppEvent::Tick)) to expire old toasts,
+//! and render the stack over the rest of the UI to show them in a corner.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use tuilib::components::{Severity, ToastStack};
+//!
+//! let mut toasts = ToastStack::new();
+//! toasts.push("Saved!", Severity::Success, Duration::from_secs(2));
+//! toasts.push("Disk almost full", Severity::Warning, Duration::from_secs(5));
+//!
+//! let expired = toasts.tick(Duration::from_secs(3));
+//! assert_eq!(expired.len(), 1);
+//! assert_eq!(expired[0].message(), "Saved!");
+//! assert_eq!(toasts.len(), 1);
+//! ```
+
+use std::time::Duration;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use super::Renderable;
+use crate::theme::Theme;
+
+/// Default width of a toast, in cells.
+const DEFAULT_WIDTH: u16 = 40;
+
+/// Default maximum number of toasts drawn at once.
+const DEFAULT_MAX_VISIBLE: usize = 5;
+
+/// Height of a single toast: one line of text inside a border.
+const TOAST_HEIGHT: u16 = 3;
+
+/// How important a toast is, which determines its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// Neutral information.
+    Info,
+    /// A successful operation.
+    Success,
+    /// Something that may need attention.
+    Warning,
+    /// A failed operation.
+    Error,
+}
+
+impl Severity {
+    /// Returns the theme style for this severity.
+    pub fn style(&self, theme: &Theme) -> Style {
+        match self {
+            Severity::Info => theme.info_text_style(),
+            Severity::Success => theme.success_text_style(),
+            Severity::Warning => theme.warning_text_style(),
+            Severity::Error => theme.error_text_style(),
+        }
+    }
+}
+
+/// The corner of the screen toasts are drawn in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastPosition {
+    /// The top-left corner.
+    TopLeft,
+    /// The top-right corner.
+    #[default]
+    TopRight,
+    /// The bottom-left corner.
+    BottomLeft,
+    /// The bottom-right corner.
+    BottomRight,
+}
+
+/// A single notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toast {
+    /// Order in which the toast was pushed.
+    id: u64,
+    message: String,
+    severity: Severity,
+    /// Time left before the toast expires.
+    remaining: Duration,
+}
+
+impl Toast {
+    /// Returns the toast's message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the toast's severity.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the time left before the toast expires.
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+}
+
+/// A stack of auto-dismissing toasts.
+#[derive(Debug, Clone)]
+pub struct ToastStack {
+    /// Active toasts, oldest first.
+    toasts: Vec<Toast>,
+    /// ID assigned to the next toast.
+    next_id: u64,
+    /// Where toasts are drawn.
+    position: ToastPosition,
+    /// Width of each toast.
+    width: u16,
+    /// Maximum number of toasts drawn at once.
+    max_visible: usize,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl Default for ToastStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToastStack {
+    /// Creates an empty stack drawn in the top-right corner.
+    pub fn new() -> Self {
+        Self {
+            toasts: Vec::new(),
+            next_id: 0,
+            position: ToastPosition::default(),
+            width: DEFAULT_WIDTH,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            theme: None,
+        }
+    }
+
+    /// Sets the corner toasts are drawn in.
+    pub fn with_position(mut self, position: ToastPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the width of each toast (default 40).
+    pub fn with_width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the maximum number of toasts drawn at once (default 5).
+    ///
+    /// Older toasts beyond the limit stay in the stack but are not drawn.
+    pub fn with_max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Adds a toast that expires after `duration`.
+    pub fn push(&mut self, message: impl Into<String>, severity: Severity, duration: Duration) {
+        self.toasts.push(Toast {
+            id: self.next_id,
+            message: message.into(),
+            severity,
+            remaining: duration,
+        });
+        self.next_id += 1;
+    }
+
+    /// Advances time by `elapsed`, removing and returning expired toasts.
+    ///
+    /// Expired toasts are returned in the order they expired; toasts that
+    /// expired at the same moment are returned oldest first.
+    pub fn tick(&mut self, elapsed: Duration) -> Vec<Toast> {
+        let mut expired = Vec::new();
+        self.toasts.retain_mut(|toast| {
+            if toast.remaining <= elapsed {
+                expired.push(toast.clone());
+                false
+            } else {
+                toast.remaining -= elapsed;
+                true
+            }
+        });
+        expired.sort_by_key(|t| (t.remaining, t.id));
+        expired
+    }
+
+    /// Removes all toasts.
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Returns the active toasts, newest first.
+    pub fn toasts(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter().rev()
+    }
+
+    /// Returns the number of active toasts.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Returns true if there are no active toasts.
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+impl Renderable for ToastStack {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let width = self.width.min(area.width);
+        let fits = (area.height / TOAST_HEIGHT) as usize;
+        let count = self.toasts.len().min(self.max_visible).min(fits);
+        if count == 0 || width < 2 {
+            return;
+        }
+
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+        let stack_height = count as u16 * TOAST_HEIGHT;
+        let x = match self.position {
+            ToastPosition::TopLeft | ToastPosition::BottomLeft => area.x,
+            ToastPosition::TopRight | ToastPosition::BottomRight => area.right() - width,
+        };
+        let y = match self.position {
+            ToastPosition::TopLeft | ToastPosition::TopRight => area.y,
+            ToastPosition::BottomLeft | ToastPosition::BottomRight => area.bottom() - stack_height,
+        };
+
+        // Newest toast on top
+        for (index, toast) in self.toasts().take(count).enumerate() {
+            let toast_area = Rect::new(x, y + index as u16 * TOAST_HEIGHT, width, TOAST_HEIGHT);
+            let style = toast.severity.style(&theme);
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(theme.components().modal.border_type)
+                .border_style(style);
+
+            frame.render_widget(Clear, toast_area);
+            frame.render_widget(
+                Paragraph::new(toast.message.as_str())
+                    .style(theme.primary_text_style())
+                    .block(block),
+                toast_area,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(stack: &ToastStack) -> Vec<&str> {
+        stack.toasts().map(|t| t.message()).collect()
+    }
+
+    fn render_lines(stack: &ToastStack, width: u16, height: u16) -> Vec<String> {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| stack.render(frame, Rect::new(0, 0, width, height)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| (0..width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_push_newest_first() {
+        let mut stack = ToastStack::new();
+        assert!(stack.is_empty());
+
+        stack.push("one", Severity::Info, Duration::from_secs(1));
+        stack.push("two", Severity::Error, Duration::from_secs(1));
+        assert_eq!(stack.len(), 2);
+        assert_eq!(messages(&stack), vec!["two", "one"]);
+    }
+
+    #[test]
+    fn test_expiry_order() {
+        let mut stack = ToastStack::new();
+        stack.push("long", Severity::Info, Duration::from_secs(10));
+        stack.push("short", Severity::Success, Duration::from_secs(2));
+        stack.push("medium", Severity::Warning, Duration::from_secs(5));
+        stack.push("medium too", Severity::Error, Duration::from_secs(5));
+
+        assert!(stack.tick(Duration::from_secs(1)).is_empty());
+
+        let expired = stack.tick(Duration::from_secs(1));
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].message(), "short");
+
+        // One large tick expires several toasts, earliest first
+        let expired = stack.tick(Duration::from_secs(4));
+        let names: Vec<&str> = expired.iter().map(|t| t.message()).collect();
+        assert_eq!(names, vec!["medium", "medium too"]);
+
+        assert_eq!(messages(&stack), vec!["long"]);
+        assert_eq!(
+            stack.toasts().next().unwrap().remaining(),
+            Duration::from_secs(4)
+        );
+
+        stack.tick(Duration::from_secs(4));
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_render_stacks_in_corner() {
+        let mut stack = ToastStack::new().with_width(8);
+        stack.push("old", Severity::Info, Duration::from_secs(1));
+        stack.push("new", Severity::Success, Duration::from_secs(1));
+
+        let lines = render_lines(&stack, 12, 7);
+        assert_eq!(lines[1], "    ║new   ║");
+        assert_eq!(lines[4], "    ║old   ║");
+        assert_eq!(lines[6], "            ");
+    }
+
+    #[test]
+    fn test_render_bottom_left_and_limits() {
+        let theme = Theme::default();
+        let mut stack = ToastStack::new()
+            .with_width(6)
+            .with_position(ToastPosition::BottomLeft)
+            .with_theme(theme.clone());
+        stack.push("a", Severity::Info, Duration::from_secs(1));
+        stack.push("b", Severity::Error, Duration::from_secs(1));
+
+        // Only one toast fits in four rows; the newest is drawn
+        let lines = render_lines(&stack, 10, 4);
+        assert_eq!(lines[0], "          ");
+        assert_eq!(lines[2], "║b   ║    ");
+
+        let lines = render_lines(&stack.clone().with_max_visible(0), 10, 4);
+        assert!(lines.iter().all(|l| l.trim().is_empty()));
+    }
+}
@@ -0,0 +1,271 @@
+//! Toggle component for switching a setting on or off.
+//!
+//! A [`Toggle`] draws a small switch, `[ ●]` when on and `[● ]` when off,
+//! with the knob in the theme's primary color while on. Optional labels
+//! describe the current state beside the switch.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::{Component, Toggle, ToggleAction, ToggleMsg};
+//!
+//! let mut wifi = Toggle::new().with_labels("On", "Off");
+//! assert!(!wifi.is_on());
+//!
+//! let action = wifi.update(ToggleMsg::Toggle);
+//! assert_eq!(action, Some(ToggleAction::Changed(true)));
+//! assert_eq!(wifi.label(), Some("On"));
+//! ```
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use terminput::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{Component, Focusable, Measurable, Renderable};
+use crate::focus::FocusId;
+use crate::text::display_width;
+use crate::theme::Theme;
+
+/// Width of the switch, including its brackets.
+const SWITCH_WIDTH: u16 = 4;
+
+/// Messages that the Toggle component can handle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToggleMsg {
+    /// Flip the switch.
+    Toggle,
+    /// Turn the switch on or off.
+    Set(bool),
+}
+
+impl ToggleMsg {
+    /// Maps a key press to a Toggle message.
+    ///
+    /// Space flips the switch, Right turns it on and Left turns it off.
+    /// Other keys return `None`.
+    pub fn from_key(key: &KeyEvent) -> Option<Self> {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CTRL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+
+        let msg = match key.code {
+            KeyCode::Char(' ') => ToggleMsg::Toggle,
+            KeyCode::Right => ToggleMsg::Set(true),
+            KeyCode::Left => ToggleMsg::Set(false),
+            _ => return None,
+        };
+        Some(msg)
+    }
+}
+
+/// Actions emitted by the Toggle component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToggleAction {
+    /// The switch changed to the given state.
+    Changed(bool),
+}
+
+/// An on/off switch.
+#[derive(Debug, Clone, Default)]
+pub struct Toggle {
+    /// Optional identifier for focus management.
+    id: Option<FocusId>,
+    /// Whether the switch is on.
+    on: bool,
+    /// Labels shown for the on and off states.
+    labels: Option<(String, String)>,
+    /// Whether the toggle has focus.
+    focused: bool,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl Toggle {
+    /// Creates a toggle that is off and has no labels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the toggle's identifier.
+    pub fn with_id(mut self, id: impl Into<FocusId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Returns the toggle's identifier, if set.
+    pub fn id(&self) -> Option<&FocusId> {
+        self.id.as_ref()
+    }
+
+    /// Sets the initial state.
+    pub fn with_on(mut self, on: bool) -> Self {
+        self.on = on;
+        self
+    }
+
+    /// Sets the labels shown beside the switch when it is on and off.
+    pub fn with_labels(mut self, on: impl Into<String>, off: impl Into<String>) -> Self {
+        self.labels = Some((on.into(), off.into()));
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns true if the switch is on.
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+
+    /// Turns the switch on or off.
+    pub fn set_on(&mut self, on: bool) {
+        self.on = on;
+    }
+
+    /// Returns the label for the current state, if labels are set.
+    pub fn label(&self) -> Option<&str> {
+        self.labels
+            .as_ref()
+            .map(|(on, off)| if self.on { on.as_str() } else { off.as_str() })
+    }
+
+    /// Sets the state, returning an action if it changed.
+    fn change(&mut self, on: bool) -> Option<ToggleAction> {
+        let changed = self.on != on;
+        self.on = on;
+        changed.then_some(ToggleAction::Changed(on))
+    }
+}
+
+impl Component for Toggle {
+    type Message = ToggleMsg;
+    type Action = ToggleAction;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        match msg {
+            ToggleMsg::Toggle => self.change(!self.on),
+            ToggleMsg::Set(on) => self.change(on),
+        }
+    }
+}
+
+impl Focusable for Toggle {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+impl Renderable for Toggle {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+
+        let bracket_style = if self.focused {
+            theme.border_focused_style().add_modifier(Modifier::BOLD)
+        } else {
+            theme.border_style()
+        };
+        let (knob_style, label_style) = if self.on {
+            (
+                Style::default().fg(theme.colors().primary),
+                theme.primary_text_style(),
+            )
+        } else {
+            (theme.muted_style(), theme.secondary_text_style())
+        };
+        let (left, right) = if self.on { (" ", "●") } else { ("●", " ") };
+
+        let mut spans = vec![
+            Span::styled("[", bracket_style),
+            Span::styled(left, knob_style),
+            Span::styled(right, knob_style),
+            Span::styled("]", bracket_style),
+        ];
+        if let Some(label) = self.label() {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(label, label_style));
+        }
+
+        let row = Rect::new(area.x, area.y, area.width, 1);
+        frame.render_widget(Paragraph::new(Line::from(spans)), row);
+    }
+}
+
+impl Measurable for Toggle {
+    fn measure(&self, max: Size) -> Size {
+        let label_width = self
+            .labels
+            .as_ref()
+            .map_or(0, |(on, off)| display_width(on).max(display_width(off)) + 1);
+        let width = u16::try_from(SWITCH_WIDTH as usize + label_width).unwrap_or(u16::MAX);
+        Size::new(width.min(max.width), 1.min(max.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{render, to_lines};
+
+    #[test]
+    fn test_toggle_flips_and_reports_changes() {
+        let mut toggle = Toggle::new();
+        assert_eq!(
+            toggle.update(ToggleMsg::Toggle),
+            Some(ToggleAction::Changed(true))
+        );
+        assert!(toggle.is_on());
+        assert_eq!(toggle.update(ToggleMsg::Set(true)), None);
+        assert_eq!(
+            toggle.update(ToggleMsg::Toggle),
+            Some(ToggleAction::Changed(false))
+        );
+        assert!(!toggle.is_on());
+    }
+
+    #[test]
+    fn test_toggle_renders_state_and_labels() {
+        let mut toggle = Toggle::new().with_labels("On", "Off");
+        assert_eq!(toggle.measure(Size::new(80, 1)), Size::new(8, 1));
+
+        let buffer = render(&toggle, Rect::new(0, 0, 10, 1));
+        assert_eq!(to_lines(&buffer), vec!["[● ] Off  "]);
+
+        toggle.set_on(true);
+        let buffer = render(&toggle, Rect::new(0, 0, 10, 1));
+        assert_eq!(to_lines(&buffer), vec!["[ ●] On   "]);
+        assert_eq!(buffer[(2, 0)].fg, Theme::default().colors().primary);
+
+        let plain = Toggle::new().with_on(true);
+        assert_eq!(plain.label(), None);
+        assert_eq!(
+            to_lines(&render(&plain, Rect::new(0, 0, 4, 1))),
+            vec!["[ ●]"]
+        );
+    }
+
+    #[test]
+    fn test_toggle_focused_styling() {
+        let theme = Theme::default();
+        let mut toggle = Toggle::new();
+        let buffer = render(&toggle, Rect::new(0, 0, 4, 1));
+        assert_eq!(buffer[(0, 0)].fg, theme.colors().border);
+
+        toggle.set_focused(true);
+        let buffer = render(&toggle, Rect::new(0, 0, 4, 1));
+        assert_eq!(buffer[(0, 0)].fg, theme.colors().border_focused);
+        assert!(buffer[(3, 0)].modifier.contains(Modifier::BOLD));
+    }
+}
@@ -0,0 +1,315 @@
+//! Tooltip overlay anchored to another component.
+//!
+//! A [`Tooltip`] draws a small bordered box next to an anchor [`Rect`],
+//! usually the area a component was rendered into. It is placed below the
+//! anchor when there is room, otherwise above it, otherwise to its left, and
+//! is always clamped to the area it is rendered in. Text is word-wrapped to
+//! the tooltip's maximum width and the box sizes itself to fit.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::prelude::Rect;
+//! use tuilib::components::{Tooltip, TooltipPlacement};
+//!
+//! let anchor = Rect::new(2, 1, 10, 1);
+//! let tooltip = Tooltip::new(anchor, "Press Enter to save").with_max_width(12);
+//!
+//! let frame = Rect::new(0, 0, 40, 10);
+//! assert_eq!(tooltip.placement(frame), Some(TooltipPlacement::Below));
+//! assert_eq!(tooltip.area(frame), Some(Rect::new(2, 2, 10, 5)));
+//! ```
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use super::modal::Overlay;
+use super::Renderable;
+use crate::text::{display_width, wrap_text};
+use crate::theme::Theme;
+
+/// Default maximum width of a tooltip, in cells, including its border.
+const DEFAULT_MAX_WIDTH: u16 = 40;
+
+/// Width and height taken up by the border.
+const BORDER_SIZE: u16 = 2;
+
+/// Where a tooltip is drawn relative to its anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TooltipPlacement {
+    /// Directly below the anchor.
+    Below,
+    /// Directly above the anchor.
+    Above,
+    /// To the left of the anchor.
+    Left,
+}
+
+/// A bordered hint box anchored to a rectangle.
+#[derive(Debug, Clone)]
+pub struct Tooltip {
+    /// The area the tooltip points at.
+    anchor: Rect,
+    /// The text to display.
+    text: String,
+    /// Maximum width, including the border.
+    max_width: u16,
+    /// Whether to draw a shadow behind the tooltip.
+    show_shadow: bool,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl Tooltip {
+    /// Creates a tooltip for the given anchor area.
+    pub fn new(anchor: Rect, text: impl Into<String>) -> Self {
+        Self {
+            anchor,
+            text: text.into(),
+            max_width: DEFAULT_MAX_WIDTH,
+            show_shadow: true,
+            theme: None,
+        }
+    }
+
+    /// Sets the maximum width, including the border (default 40).
+    pub fn with_max_width(mut self, max_width: u16) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets whether to draw a shadow behind the tooltip (default true).
+    pub fn with_shadow(mut self, show_shadow: bool) -> Self {
+        self.show_shadow = show_shadow;
+        self
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the anchor area.
+    pub fn anchor(&self) -> Rect {
+        self.anchor
+    }
+
+    /// Moves the tooltip to a new anchor area.
+    pub fn set_anchor(&mut self, anchor: Rect) {
+        self.anchor = anchor;
+    }
+
+    /// Returns the tooltip text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the wrapped lines drawn inside the tooltip within `bounds`.
+    ///
+    /// Lines that do not fit in the bounds' height are dropped.
+    pub fn lines(&self, bounds: Rect) -> Vec<String> {
+        let width = self.max_width.min(bounds.width).saturating_sub(BORDER_SIZE);
+        let height = bounds.height.saturating_sub(BORDER_SIZE);
+        if width == 0 || height == 0 {
+            return Vec::new();
+        }
+
+        let mut lines = wrap_text(&self.text, width as usize);
+        lines.truncate(height as usize);
+        lines
+    }
+
+    /// Returns the size of the tooltip, including its border, within `bounds`.
+    ///
+    /// Returns `None` if the bounds are too small to draw anything.
+    fn size(&self, bounds: Rect) -> Option<(u16, u16, Vec<String>)> {
+        let lines = self.lines(bounds);
+        if lines.is_empty() {
+            return None;
+        }
+
+        let text_width = lines.iter().map(|l| display_width(l)).max().unwrap_or(0);
+        let width = (text_width as u16).max(1) + BORDER_SIZE;
+        let height = lines.len() as u16 + BORDER_SIZE;
+        Some((width, height, lines))
+    }
+
+    /// Returns the side of the anchor the tooltip is drawn on within `bounds`.
+    ///
+    /// Below is preferred, then above, then left. If none of them has room,
+    /// the tooltip is drawn below and pushed back inside the bounds.
+    pub fn placement(&self, bounds: Rect) -> Option<TooltipPlacement> {
+        self.layout(bounds).map(|(placement, _, _)| placement)
+    }
+
+    /// Returns the area the tooltip occupies within `bounds`.
+    ///
+    /// The area never extends outside `bounds`. Returns `None` if the bounds
+    /// are too small to draw a tooltip.
+    pub fn area(&self, bounds: Rect) -> Option<Rect> {
+        self.layout(bounds).map(|(_, area, _)| area)
+    }
+
+    /// Computes the placement, area and wrapped lines of the tooltip.
+    fn layout(&self, bounds: Rect) -> Option<(TooltipPlacement, Rect, Vec<String>)> {
+        let (width, height, lines) = self.size(bounds)?;
+
+        // Keep a coordinate range of `len` cells inside `min..max`
+        let clamp =
+            |pos: u16, len: u16, min: u16, max: u16| pos.min(max.saturating_sub(len)).max(min);
+
+        let anchor_x = clamp(self.anchor.x, width, bounds.x, bounds.right());
+        let anchor_y = clamp(self.anchor.y, height, bounds.y, bounds.bottom());
+
+        let (placement, x, y) = if self.anchor.bottom().saturating_add(height) <= bounds.bottom()
+            && self.anchor.bottom() >= bounds.y
+        {
+            (TooltipPlacement::Below, anchor_x, self.anchor.bottom())
+        } else if self.anchor.y >= bounds.y.saturating_add(height) {
+            (TooltipPlacement::Above, anchor_x, self.anchor.y - height)
+        } else if self.anchor.x >= bounds.x.saturating_add(width) {
+            (TooltipPlacement::Left, self.anchor.x - width, anchor_y)
+        } else {
+            let y = clamp(self.anchor.bottom(), height, bounds.y, bounds.bottom());
+            (TooltipPlacement::Below, anchor_x, y)
+        };
+
+        // Anchors partly outside the bounds can still push the box out
+        let x = clamp(x, width, bounds.x, bounds.right());
+        let y = clamp(y, height, bounds.y, bounds.bottom());
+        Some((placement, Rect::new(x, y, width, height), lines))
+    }
+}
+
+impl Renderable for Tooltip {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let Some((_, tooltip_area, lines)) = self.layout(area) else {
+            return;
+        };
+
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+        Overlay::new()
+            .with_shadow(self.show_shadow)
+            .with_theme(theme.clone())
+            .render_shadow(frame, tooltip_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border_style());
+        let text: Vec<Line> = lines.into_iter().map(Line::from).collect();
+
+        frame.render_widget(Clear, tooltip_area);
+        frame.render_widget(
+            Paragraph::new(text)
+                .style(theme.primary_text_style())
+                .block(block),
+            tooltip_area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: 40,
+        height: 12,
+    };
+
+    fn contains(outer: Rect, inner: Rect) -> bool {
+        inner.x >= outer.x
+            && inner.y >= outer.y
+            && inner.right() <= outer.right()
+            && inner.bottom() <= outer.bottom()
+    }
+
+    #[test]
+    fn test_sizes_to_wrapped_text() {
+        let tooltip = Tooltip::new(Rect::new(0, 0, 5, 1), "one two three").with_max_width(9);
+        assert_eq!(tooltip.lines(FRAME), vec!["one two", "three"]);
+        assert_eq!(tooltip.area(FRAME), Some(Rect::new(0, 1, 9, 4)));
+    }
+
+    #[test]
+    fn test_placement_flips_near_edges() {
+        // Plenty of room: below
+        let tooltip = Tooltip::new(Rect::new(5, 2, 6, 1), "hint");
+        assert_eq!(tooltip.placement(FRAME), Some(TooltipPlacement::Below));
+        assert_eq!(tooltip.area(FRAME), Some(Rect::new(5, 3, 6, 3)));
+
+        // Bottom edge: above
+        let tooltip = Tooltip::new(Rect::new(5, 11, 6, 1), "hint");
+        assert_eq!(tooltip.placement(FRAME), Some(TooltipPlacement::Above));
+        assert_eq!(tooltip.area(FRAME), Some(Rect::new(5, 8, 6, 3)));
+
+        // Right edge: below, shifted left to stay inside
+        let tooltip = Tooltip::new(Rect::new(38, 2, 2, 1), "hint");
+        assert_eq!(tooltip.placement(FRAME), Some(TooltipPlacement::Below));
+        assert_eq!(tooltip.area(FRAME), Some(Rect::new(34, 3, 6, 3)));
+
+        // Anchor fills the height: left
+        let tooltip = Tooltip::new(Rect::new(20, 0, 10, 12), "hint");
+        assert_eq!(tooltip.placement(FRAME), Some(TooltipPlacement::Left));
+        assert_eq!(tooltip.area(FRAME), Some(Rect::new(14, 0, 6, 3)));
+
+        // Bottom-right corner: above, shifted left
+        let tooltip = Tooltip::new(Rect::new(39, 11, 1, 1), "hint");
+        assert_eq!(tooltip.placement(FRAME), Some(TooltipPlacement::Above));
+        assert_eq!(tooltip.area(FRAME), Some(Rect::new(34, 8, 6, 3)));
+    }
+
+    #[test]
+    fn test_never_exceeds_bounds() {
+        let text = "a very long tooltip text that cannot possibly fit in the small frame";
+        let bounds = Rect::new(2, 1, 12, 6);
+        for x in 0..16 {
+            for y in 0..9 {
+                let tooltip = Tooltip::new(Rect::new(x, y, 3, 1), text);
+                let area = tooltip.area(bounds).unwrap();
+                assert!(contains(bounds, area), "{area:?} outside {bounds:?}");
+            }
+        }
+
+        // No room left of a full-size anchor: falls back inside the bounds
+        let tooltip = Tooltip::new(bounds, text);
+        assert!(contains(bounds, tooltip.area(bounds).unwrap()));
+    }
+
+    #[test]
+    fn test_zero_size_frame() {
+        let tooltip = Tooltip::new(Rect::new(0, 0, 1, 1), "hint");
+        assert_eq!(tooltip.area(Rect::new(0, 0, 0, 0)), None);
+        assert_eq!(tooltip.area(Rect::new(0, 0, 2, 10)), None);
+
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(0, 0)).unwrap();
+        terminal
+            .draw(|frame| tooltip.render(frame, frame.area()))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_render_with_shadow() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let tooltip = Tooltip::new(Rect::new(0, 0, 4, 1), "hi");
+        let mut terminal = Terminal::new(TestBackend::new(10, 6)).unwrap();
+        terminal
+            .draw(|frame| tooltip.render(frame, frame.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..10).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert_eq!(row(1), "┌──┐      ");
+        assert_eq!(row(2), "│hi│░░    ");
+        assert_eq!(row(3), "└──┘░░    ");
+        assert_eq!(row(4), "  ░░░░    ");
+    }
+}
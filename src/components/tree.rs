@@ -0,0 +1,610 @@
+//! Tree component for hierarchical data.
+//!
+//! A [`Tree`] holds nodes that can be expanded and collapsed, such as a file
+//! explorer or an outline. Navigation works on the flattened list of visible
+//! nodes, and rendering indents each node by its depth with expand markers
+//! (`▸` collapsed, `▾` expanded).
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::{Component, Tree, TreeAction, TreeMsg};
+//!
+//! let mut tree = Tree::new();
+//! let src = tree.add_root("src", "src/");
+//! let main = tree.add_child(src, "main.rs", "src/main.rs");
+//! tree.add_root("Cargo.toml", "Cargo.toml");
+//!
+//! // Children are hidden until their parent is expanded
+//! assert_eq!(tree.visible_ids().len(), 2);
+//! tree.update(TreeMsg::Expand);
+//! assert_eq!(tree.visible_ids().len(), 3);
+//!
+//! tree.update(TreeMsg::Down);
+//! let action = tree.update(TreeMsg::Select);
+//! assert!(matches!(action, Some(TreeAction::Selected(id)) if id == main));
+//! assert_eq!(tree.node(main).map(|n| *n.value()), Some("src/main.rs"));
+//! ```
+
+use std::cell::Cell;
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use super::{Component, Focusable, Renderable, ScrollState};
+use crate::focus::FocusId;
+use crate::theme::Theme;
+
+/// Identifies a node within a [`Tree`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Returns the node's index in insertion order.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// A node in a [`Tree`].
+#[derive(Debug, Clone)]
+pub struct TreeNode<T> {
+    label: String,
+    value: T,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    expanded: bool,
+}
+
+impl<T> TreeNode<T> {
+    /// Returns the label shown for this node.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the value stored in this node.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the parent node, or `None` for a root.
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    /// Returns the node's children in order.
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    /// Returns true if the node has children.
+    pub fn has_children(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    /// Returns true if the node is expanded.
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+}
+
+/// Messages that the Tree component can handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeMsg {
+    /// Move the selection to the previous visible node.
+    Up,
+    /// Move the selection to the next visible node.
+    Down,
+    /// Expand the selected node.
+    Expand,
+    /// Collapse the selected node, or move to its parent if already collapsed.
+    Collapse,
+    /// Toggle the selected node between expanded and collapsed.
+    Toggle,
+    /// Choose the selected node.
+    Select,
+}
+
+/// Actions emitted by the Tree component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeAction {
+    /// A node was chosen.
+    Selected(NodeId),
+    /// A node was expanded or collapsed.
+    Toggled(NodeId),
+}
+
+/// A visible row in the flattened tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct VisibleNode {
+    id: NodeId,
+    depth: usize,
+}
+
+/// A tree of expandable nodes with keyboard navigation.
+#[derive(Debug, Clone)]
+pub struct Tree<T> {
+    /// Optional identifier for focus management and style overrides.
+    id: Option<FocusId>,
+    /// All nodes, indexed by [`NodeId`].
+    nodes: Vec<TreeNode<T>>,
+    /// Top-level nodes in order.
+    roots: Vec<NodeId>,
+    /// Nodes currently visible, in display order.
+    visible: Vec<VisibleNode>,
+    /// Index of the selected node in `visible`.
+    selected: usize,
+    /// Rows shown when rendering; adjusted to keep the selection visible.
+    scroll: Cell<ScrollState>,
+    /// Whether the tree is focused.
+    focused: bool,
+    /// Optional theme for styling.
+    theme: Option<Theme>,
+}
+
+impl<T> Default for Tree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Tree<T> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            nodes: Vec::new(),
+            roots: Vec::new(),
+            visible: Vec::new(),
+            selected: 0,
+            scroll: Cell::new(ScrollState::default()),
+            focused: false,
+            theme: None,
+        }
+    }
+
+    /// Sets the tree's identifier.
+    ///
+    /// The ID is used to look up per-component style overrides on the theme.
+    pub fn with_id(mut self, id: impl Into<FocusId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Returns the tree's identifier, if set.
+    pub fn id(&self) -> Option<&FocusId> {
+        self.id.as_ref()
+    }
+
+    /// Sets the theme for styling.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Adds a top-level node and returns its ID.
+    pub fn add_root(&mut self, label: impl Into<String>, value: T) -> NodeId {
+        let id = self.push_node(label.into(), value, None);
+        self.roots.push(id);
+        self.rebuild();
+        id
+    }
+
+    /// Adds a child under `parent` and returns its ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` does not belong to this tree.
+    pub fn add_child(&mut self, parent: NodeId, label: impl Into<String>, value: T) -> NodeId {
+        assert!(parent.0 < self.nodes.len(), "unknown parent {:?}", parent);
+        let id = self.push_node(label.into(), value, Some(parent));
+        self.nodes[parent.0].children.push(id);
+        self.rebuild();
+        id
+    }
+
+    fn push_node(&mut self, label: String, value: T, parent: Option<NodeId>) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(TreeNode {
+            label,
+            value,
+            parent,
+            children: Vec::new(),
+            expanded: false,
+        });
+        id
+    }
+
+    /// Returns the node with the given ID.
+    pub fn node(&self, id: NodeId) -> Option<&TreeNode<T>> {
+        self.nodes.get(id.0)
+    }
+
+    /// Returns the top-level nodes.
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    /// Returns the total number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the tree has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the IDs of the visible nodes in display order.
+    pub fn visible_ids(&self) -> Vec<NodeId> {
+        self.visible.iter().map(|v| v.id).collect()
+    }
+
+    /// Returns the depth of a node, where roots have depth zero.
+    pub fn depth(&self, id: NodeId) -> usize {
+        std::iter::successors(self.node(id).and_then(|n| n.parent), |p| {
+            self.nodes[p.0].parent
+        })
+        .count()
+    }
+
+    /// Returns the selected node, if the tree is not empty.
+    pub fn selected(&self) -> Option<NodeId> {
+        self.visible.get(self.selected).map(|v| v.id)
+    }
+
+    /// Selects a node, expanding its ancestors so it is visible.
+    pub fn select(&mut self, id: NodeId) {
+        if id.0 >= self.nodes.len() {
+            return;
+        }
+        let mut ancestor = self.nodes[id.0].parent;
+        while let Some(parent) = ancestor {
+            self.nodes[parent.0].expanded = true;
+            ancestor = self.nodes[parent.0].parent;
+        }
+        self.rebuild();
+        self.rebuild_selecting(id);
+    }
+
+    /// Expands a node, returning true if it changed.
+    pub fn expand(&mut self, id: NodeId) -> bool {
+        self.set_expanded(id, true)
+    }
+
+    /// Collapses a node, returning true if it changed.
+    ///
+    /// If the selection was inside the collapsed subtree, it moves to the
+    /// collapsed node.
+    pub fn collapse(&mut self, id: NodeId) -> bool {
+        self.set_expanded(id, false)
+    }
+
+    /// Toggles a node, returning true if it changed.
+    pub fn toggle(&mut self, id: NodeId) -> bool {
+        let expanded = self.node(id).is_some_and(|n| n.expanded);
+        self.set_expanded(id, !expanded)
+    }
+
+    fn set_expanded(&mut self, id: NodeId, expanded: bool) -> bool {
+        match self.nodes.get_mut(id.0) {
+            Some(node) if node.has_children() && node.expanded != expanded => {
+                node.expanded = expanded;
+                let selected = self.selected();
+                self.rebuild();
+                if let Some(selected) = selected {
+                    self.rebuild_selecting(selected);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Recomputes the visible list, keeping the selection index in range.
+    fn rebuild(&mut self) {
+        let mut visible = Vec::new();
+        let mut stack: Vec<VisibleNode> = self
+            .roots
+            .iter()
+            .rev()
+            .map(|&id| VisibleNode { id, depth: 0 })
+            .collect();
+
+        while let Some(entry) = stack.pop() {
+            visible.push(entry);
+            let node = &self.nodes[entry.id.0];
+            if node.expanded {
+                stack.extend(node.children.iter().rev().map(|&id| VisibleNode {
+                    id,
+                    depth: entry.depth + 1,
+                }));
+            }
+        }
+
+        self.visible = visible;
+        self.selected = self.selected.min(self.visible.len().saturating_sub(1));
+    }
+
+    /// Selects `id`, or its nearest visible ancestor if it is hidden.
+    fn rebuild_selecting(&mut self, id: NodeId) {
+        let mut target = Some(id);
+        while let Some(candidate) = target {
+            if let Some(index) = self.visible.iter().position(|v| v.id == candidate) {
+                self.selected = index;
+                return;
+            }
+            target = self.nodes[candidate.0].parent;
+        }
+    }
+}
+
+impl<T> Component for Tree<T> {
+    type Message = TreeMsg;
+    type Action = TreeAction;
+
+    fn update(&mut self, msg: Self::Message) -> Option<Self::Action> {
+        let selected = self.selected()?;
+        match msg {
+            TreeMsg::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                None
+            }
+            TreeMsg::Down => {
+                if self.selected + 1 < self.visible.len() {
+                    self.selected += 1;
+                }
+                None
+            }
+            TreeMsg::Expand => self
+                .expand(selected)
+                .then_some(TreeAction::Toggled(selected)),
+            TreeMsg::Collapse => {
+                if self.collapse(selected) {
+                    Some(TreeAction::Toggled(selected))
+                } else {
+                    // Already collapsed: move to the parent
+                    if let Some(parent) = self.nodes[selected.0].parent {
+                        self.rebuild_selecting(parent);
+                    }
+                    None
+                }
+            }
+            TreeMsg::Toggle => self
+                .toggle(selected)
+                .then_some(TreeAction::Toggled(selected)),
+            TreeMsg::Select => Some(TreeAction::Selected(selected)),
+        }
+    }
+}
+
+impl<T> Focusable for Tree<T> {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+impl<T> Renderable for Tree<T> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.height == 0 {
+            return;
+        }
+
+        let theme = self.theme.as_ref().cloned().unwrap_or_default();
+        let (item_style, selected_style) = match self.id {
+            Some(ref id) => (
+                theme.resolve_style(id, theme.list_item_style()),
+                theme.resolve_style(id, theme.list_selected_style()),
+            ),
+            None => (theme.list_item_style(), theme.list_selected_style()),
+        };
+
+        // Scroll just enough to keep the selection on screen
+        let height = area.height as usize;
+        let mut scroll = self.scroll.get();
+        scroll.set_total(self.visible.len());
+        scroll.set_viewport(height);
+        scroll.ensure_visible(self.selected);
+        self.scroll.set(scroll);
+
+        let lines: Vec<Line> = self
+            .visible
+            .iter()
+            .enumerate()
+            .skip(scroll.offset())
+            .take(height)
+            .map(|(index, entry)| {
+                let node = &self.nodes[entry.id.0];
+                let marker = match (node.has_children(), node.expanded) {
+                    (false, _) => "  ",
+                    (true, false) => "▸ ",
+                    (true, true) => "▾ ",
+                };
+                let style = if index == self.selected {
+                    selected_style
+                } else {
+                    item_style
+                };
+                Line::from(vec![
+                    Span::raw("  ".repeat(entry.depth)),
+                    Span::raw(marker),
+                    Span::raw(node.label.as_str()),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds:
+    /// ```text
+    /// a
+    /// ├ a1
+    /// │ └ a1x
+    /// └ a2
+    /// b
+    /// ```
+    fn sample_tree() -> (Tree<&'static str>, [NodeId; 5]) {
+        let mut tree = Tree::new();
+        let a = tree.add_root("a", "a");
+        let a1 = tree.add_child(a, "a1", "a1");
+        let a1x = tree.add_child(a1, "a1x", "a1x");
+        let a2 = tree.add_child(a, "a2", "a2");
+        let b = tree.add_root("b", "b");
+        (tree, [a, a1, a1x, a2, b])
+    }
+
+    fn render_lines(tree: &Tree<&'static str>, width: u16, height: u16) -> Vec<String> {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| tree.render(frame, Rect::new(0, 0, width, height)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_collapsed_by_default() {
+        let (tree, [a, _, _, _, b]) = sample_tree();
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.visible_ids(), vec![a, b]);
+        assert_eq!(tree.selected(), Some(a));
+    }
+
+    #[test]
+    fn test_flatten_across_expand_collapse() {
+        let (mut tree, [a, a1, a1x, a2, b]) = sample_tree();
+
+        assert!(tree.expand(a));
+        assert_eq!(tree.visible_ids(), vec![a, a1, a2, b]);
+
+        assert!(tree.expand(a1));
+        assert_eq!(tree.visible_ids(), vec![a, a1, a1x, a2, b]);
+        assert_eq!(tree.depth(a1x), 2);
+
+        // Collapsing hides the subtree but remembers nested expansion
+        assert!(tree.collapse(a));
+        assert_eq!(tree.visible_ids(), vec![a, b]);
+        assert!(tree.expand(a));
+        assert_eq!(tree.visible_ids(), vec![a, a1, a1x, a2, b]);
+
+        // Leaves cannot be expanded
+        assert!(!tree.expand(b));
+    }
+
+    #[test]
+    fn test_navigation_and_select() {
+        let (mut tree, [a, a1, _, _, b]) = sample_tree();
+
+        assert!(tree.update(TreeMsg::Up).is_none());
+        assert_eq!(tree.selected(), Some(a));
+
+        assert_eq!(tree.update(TreeMsg::Toggle), Some(TreeAction::Toggled(a)));
+        tree.update(TreeMsg::Down);
+        assert_eq!(tree.update(TreeMsg::Select), Some(TreeAction::Selected(a1)));
+
+        tree.update(TreeMsg::Down);
+        tree.update(TreeMsg::Down);
+        tree.update(TreeMsg::Down);
+        assert_eq!(tree.selected(), Some(b));
+    }
+
+    #[test]
+    fn test_collapsing_ancestor_moves_selection() {
+        let (mut tree, [a, a1, a1x, _, _]) = sample_tree();
+        tree.select(a1x);
+        assert!(tree.node(a).unwrap().is_expanded());
+        assert!(tree.node(a1).unwrap().is_expanded());
+        assert_eq!(tree.selected(), Some(a1x));
+
+        tree.collapse(a);
+        assert_eq!(tree.selected(), Some(a));
+    }
+
+    #[test]
+    fn test_collapse_moves_to_parent_when_collapsed() {
+        let (mut tree, [a, a1, a1x, _, _]) = sample_tree();
+        tree.select(a1x);
+
+        assert!(tree.update(TreeMsg::Collapse).is_none());
+        assert_eq!(tree.selected(), Some(a1));
+        assert_eq!(
+            tree.update(TreeMsg::Collapse),
+            Some(TreeAction::Toggled(a1))
+        );
+        assert_eq!(tree.selected(), Some(a1));
+        assert!(tree.update(TreeMsg::Collapse).is_none());
+        assert_eq!(tree.selected(), Some(a));
+    }
+
+    #[test]
+    fn test_collapsing_sibling_keeps_selection() {
+        let (mut tree, [a, _, _, _, b]) = sample_tree();
+        tree.expand(a);
+        tree.select(b);
+
+        tree.collapse(a);
+        assert_eq!(tree.selected(), Some(b));
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let mut tree: Tree<()> = Tree::new();
+        assert!(tree.is_empty());
+        assert!(tree.selected().is_none());
+        assert!(tree.update(TreeMsg::Down).is_none());
+    }
+
+    #[test]
+    fn test_render_indents_with_markers() {
+        let (mut tree, [a, _, _, _, _]) = sample_tree();
+        tree.expand(a);
+
+        assert_eq!(
+            render_lines(&tree, 12, 4),
+            vec!["▾ a", "  ▸ a1", "    a2", "  b"]
+        );
+    }
+
+    #[test]
+    fn test_render_scrolls_to_selection() {
+        let (mut tree, [_, _, a1x, _, b]) = sample_tree();
+        tree.select(a1x);
+
+        assert_eq!(render_lines(&tree, 12, 2), vec!["  ▾ a1", "      a1x"]);
+
+        tree.select(b);
+        assert_eq!(render_lines(&tree, 12, 2), vec!["    a2", "  b"]);
+
+        tree.select(tree.roots()[0]);
+        assert_eq!(render_lines(&tree, 12, 2), vec!["▾ a", "  ▾ a1"]);
+    }
+
+    #[test]
+    fn test_tree_renders_into_tiny_areas() {
+        let (tree, _) = sample_tree();
+        assert!(render_lines(&tree, 0, 0).is_empty());
+        assert_eq!(render_lines(&tree, 1, 1), ["▸"]);
+    }
+}
@@ -0,0 +1,222 @@
+//! Built-in validators for [`TextInput`](super::TextInput).
+//!
+//! Each constructor returns a [`ValidatorFn`] that can be passed to
+//! [`TextInput::with_validator`](super::TextInput::with_validator), and
+//! [`all`] combines several validators into one.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::components::{validators, ValidationResult};
+//!
+//! let port = validators::all([
+//!     validators::non_empty(),
+//!     validators::numeric_range(1.0, 65535.0),
+//! ]);
+//!
+//! assert_eq!(port("8080"), ValidationResult::Valid);
+//! assert!(!port("").is_valid());
+//! assert!(!port("70000").is_valid());
+//! ```
+
+use std::fmt;
+
+use regex_automata::meta::Regex;
+
+use super::{ValidationResult, ValidatorFn};
+
+/// Error returned by [`regex`] when the pattern cannot be compiled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternError(String);
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid pattern: {}", self.0)
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// Requires the text to match a regular expression.
+///
+/// The pattern is compiled once when the validator is created. Matching
+/// uses search semantics, so anchor the pattern with `^` and `$` to require
+/// the whole text to match.
+///
+/// # Errors
+///
+/// Returns [`PatternError`] if the pattern is not a valid regular expression.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::components::validators;
+///
+/// let hex = validators::regex(r"^[0-9a-f]+$").unwrap();
+/// assert!(hex("c0ffee").is_valid());
+/// assert!(!hex("coffee").is_valid());
+/// ```
+pub fn regex(pattern: &str) -> Result<ValidatorFn, PatternError> {
+    let compiled = Regex::new(pattern).map_err(|e| PatternError(e.to_string()))?;
+    let message = format!("Must match {}", pattern);
+    Ok(Box::new(move |text| {
+        if compiled.is_match(text) {
+            ValidationResult::Valid
+        } else {
+            ValidationResult::Invalid(message.clone())
+        }
+    }))
+}
+
+/// Requires the text to contain at least one non-whitespace character.
+pub fn non_empty() -> ValidatorFn {
+    Box::new(|text| {
+        if text.trim().is_empty() {
+            ValidationResult::Invalid("Field is required".to_string())
+        } else {
+            ValidationResult::Valid
+        }
+    })
+}
+
+/// Requires the text to be at most `max` characters long.
+pub fn max_len(max: usize) -> ValidatorFn {
+    Box::new(move |text| {
+        if text.chars().count() > max {
+            ValidationResult::Invalid(format!("Must be at most {} characters", max))
+        } else {
+            ValidationResult::Valid
+        }
+    })
+}
+
+/// Requires the text to be a number between `min` and `max` (inclusive).
+pub fn numeric_range(min: f64, max: f64) -> ValidatorFn {
+    Box::new(move |text| match text.trim().parse::<f64>() {
+        Ok(value) if (min..=max).contains(&value) => ValidationResult::Valid,
+        Ok(_) => ValidationResult::Invalid(format!("Must be between {} and {}", min, max)),
+        Err(_) => ValidationResult::Invalid("Must be a number".to_string()),
+    })
+}
+
+/// Combines validators, running them in order.
+///
+/// Returns the first [`ValidationResult::Invalid`], skipping the remaining
+/// validators. If none fail, the first [`ValidationResult::Warning`] is
+/// returned, or [`ValidationResult::Valid`] if there are no warnings.
+pub fn all(validators: impl IntoIterator<Item = ValidatorFn>) -> ValidatorFn {
+    let validators: Vec<ValidatorFn> = validators.into_iter().collect();
+    Box::new(move |text| {
+        let mut warning = None;
+        for validator in &validators {
+            match validator(text) {
+                ValidationResult::Valid => {}
+                invalid @ ValidationResult::Invalid(_) => return invalid,
+                w @ ValidationResult::Warning(_) => {
+                    warning.get_or_insert(w);
+                }
+            }
+        }
+        warning.unwrap_or(ValidationResult::Valid)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_regex() {
+        let validator = regex(r"^\d{3}-\d{4}$").unwrap();
+        assert_eq!(validator("555-1234"), ValidationResult::Valid);
+        assert_eq!(
+            validator("5551234"),
+            ValidationResult::Invalid(r"Must match ^\d{3}-\d{4}$".to_string())
+        );
+    }
+
+    #[test]
+    fn test_regex_invalid_pattern() {
+        let err = regex("(unclosed").err().unwrap();
+        assert!(err.to_string().starts_with("Invalid pattern"));
+    }
+
+    #[test]
+    fn test_non_empty() {
+        let validator = non_empty();
+        assert!(validator("x").is_valid());
+        assert!(!validator("").is_valid());
+        assert!(!validator("   ").is_valid());
+    }
+
+    #[test]
+    fn test_max_len() {
+        let validator = max_len(3);
+        assert!(validator("abc").is_valid());
+        assert!(validator("日本語").is_valid());
+        assert_eq!(
+            validator("abcd"),
+            ValidationResult::Invalid("Must be at most 3 characters".to_string())
+        );
+    }
+
+    #[test]
+    fn test_numeric_range() {
+        let validator = numeric_range(0.0, 10.0);
+        assert!(validator("0").is_valid());
+        assert!(validator(" 7.5 ").is_valid());
+        assert!(validator("10").is_valid());
+        assert_eq!(
+            validator("11"),
+            ValidationResult::Invalid("Must be between 0 and 10".to_string())
+        );
+        assert_eq!(
+            validator("ten"),
+            ValidationResult::Invalid("Must be a number".to_string())
+        );
+    }
+
+    #[test]
+    fn test_all_short_circuits_on_invalid() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let counting: ValidatorFn = Box::new(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            ValidationResult::Valid
+        });
+
+        let validator = all([non_empty(), max_len(2), counting]);
+        assert_eq!(
+            validator(""),
+            ValidationResult::Invalid("Field is required".to_string())
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        assert!(validator("ok").is_valid());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_all_preserves_warnings() {
+        let warn: ValidatorFn = Box::new(|text| {
+            if text.len() < 8 {
+                ValidationResult::Warning("Weak password".to_string())
+            } else {
+                ValidationResult::Valid
+            }
+        });
+
+        let validator = all([warn, max_len(10)]);
+        assert_eq!(
+            validator("short"),
+            ValidationResult::Warning("Weak password".to_string())
+        );
+        assert_eq!(
+            validator("long enough"),
+            ValidationResult::Invalid("Must be at most 10 characters".to_string())
+        );
+        assert_eq!(validator("password1"), ValidationResult::Valid);
+    }
+}
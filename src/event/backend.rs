@@ -0,0 +1,289 @@
+//! Terminal backends for the event loop.
+//!
+//! A [`Backend`] is where [`EventLoop::run_with_backend`] reads input from
+//! and what it hands over while suspended. [`CrosstermTerminal`] reads the
+//! real terminal through crossterm, and [`ChannelBackend`] reads events sent
+//! from code, so tests can drive an app through the same path as real input.
+//!
+//! Input arrives as a [`TerminalEvent`], which does not depend on the
+//! terminal library in use.
+//!
+//! [`EventLoop::run_with_backend`]: super::EventLoop::run_with_backend
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+
+use terminput::KeyEvent;
+use tokio::sync::mpsc;
+
+use super::mouse::MouseEvent;
+use super::terminal::{resume_terminal, suspend_terminal, TerminalEventStream, TerminalOptions};
+
+/// A terminal input event, independent of the terminal library.
+///
+/// Keys use terminput's [`KeyEvent`], the same type
+/// [`InputMatcher`](crate::input::InputMatcher) matches against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalEvent {
+    /// A key was pressed, repeated or released.
+    Key(KeyEvent),
+
+    /// A mouse event.
+    Mouse(MouseEvent),
+
+    /// The terminal was resized to the given width and height.
+    Resize(u16, u16),
+
+    /// Text was pasted into the terminal (requires bracketed paste).
+    Paste(String),
+
+    /// The terminal gained focus.
+    FocusGained,
+
+    /// The terminal lost focus.
+    FocusLost,
+}
+
+impl TryFrom<crossterm::event::Event> for TerminalEvent {
+    type Error = terminput::UnsupportedEvent;
+
+    /// Converts a crossterm event, failing for keys terminput cannot
+    /// represent.
+    fn try_from(event: crossterm::event::Event) -> Result<Self, Self::Error> {
+        use crossterm::event::Event;
+
+        Ok(match event {
+            Event::Key(key) => TerminalEvent::Key(terminput_crossterm::to_terminput_key(key)?),
+            Event::Mouse(mouse) => TerminalEvent::Mouse(mouse.into()),
+            Event::Resize(width, height) => TerminalEvent::Resize(width, height),
+            Event::Paste(text) => TerminalEvent::Paste(text),
+            Event::FocusGained => TerminalEvent::FocusGained,
+            Event::FocusLost => TerminalEvent::FocusLost,
+        })
+    }
+}
+
+/// A future returned by a [`Backend`].
+pub type BackendFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A source of terminal input that the event loop can suspend.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::event::{Backend, BackendFuture, TerminalEvent};
+///
+/// /// A backend without any input.
+/// struct NoInput;
+///
+/// impl Backend for NoInput {
+///     fn next_event(&mut self) -> BackendFuture<'_, Option<std::io::Result<TerminalEvent>>> {
+///         Box::pin(async { None })
+///     }
+///
+///     fn suspend(&mut self) -> BackendFuture<'_, std::io::Result<()>> {
+///         Box::pin(async { Ok(()) })
+///     }
+///
+///     fn resume(&mut self) -> std::io::Result<()> {
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Backend {
+    /// Waits for the next input event.
+    ///
+    /// Returns `None` once the backend has no more input. The returned
+    /// future must be cancel-safe, as the event loop polls it in
+    /// `tokio::select!`.
+    fn next_event(&mut self) -> BackendFuture<'_, Option<io::Result<TerminalEvent>>>;
+
+    /// Stops reading input and returns the terminal to normal mode.
+    fn suspend(&mut self) -> BackendFuture<'_, io::Result<()>>;
+
+    /// Re-enters TUI mode after [`suspend`](Self::suspend).
+    fn resume(&mut self) -> io::Result<()>;
+}
+
+/// The real terminal, read and suspended through crossterm.
+///
+/// This is the backend [`EventLoop::run`](super::EventLoop::run) uses.
+/// Terminal modes are written to `W`, which is usually stdout.
+pub struct CrosstermTerminal<W = io::Stdout> {
+    /// Where terminal mode escape sequences are written.
+    writer: W,
+    /// The options the terminal was set up with.
+    options: TerminalOptions,
+    /// Input from the terminal, or `None` while suspended.
+    events: Option<TerminalEventStream>,
+    /// Whether raw mode was enabled before suspending.
+    raw_mode: bool,
+}
+
+impl<W: io::Write> CrosstermTerminal<W> {
+    /// Creates a backend for a terminal set up with `options`.
+    pub fn new(writer: W, options: TerminalOptions) -> Self {
+        Self {
+            writer,
+            options,
+            events: Some(TerminalEventStream::new()),
+            raw_mode: false,
+        }
+    }
+}
+
+impl<W: io::Write + Send> Backend for CrosstermTerminal<W> {
+    /// Waits for the next terminal event, or forever while suspended.
+    ///
+    /// Events terminput cannot represent are skipped.
+    fn next_event(&mut self) -> BackendFuture<'_, Option<io::Result<TerminalEvent>>> {
+        Box::pin(async move {
+            let Some(events) = &mut self.events else {
+                return std::future::pending().await;
+            };
+            loop {
+                match events.next().await? {
+                    Ok(event) => match TerminalEvent::try_from(event) {
+                        Ok(event) => return Some(Ok(event)),
+                        Err(e) => tracing::trace!(error = %e, "Skipping terminal event"),
+                    },
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+        })
+    }
+
+    /// Stops reading input and returns the terminal to normal mode, so that
+    /// a child process such as `$EDITOR` receives every keystroke.
+    fn suspend(&mut self) -> BackendFuture<'_, io::Result<()>> {
+        Box::pin(async move {
+            if let Some(events) = self.events.take() {
+                events.close().await;
+            }
+            self.raw_mode = suspend_terminal(&mut self.writer, &self.options)?;
+            Ok(())
+        })
+    }
+
+    /// Re-enters TUI mode and reads input through a new stream.
+    fn resume(&mut self) -> io::Result<()> {
+        resume_terminal(&mut self.writer, &self.options, self.raw_mode)?;
+        self.events = Some(TerminalEventStream::new());
+        Ok(())
+    }
+}
+
+impl<W> std::fmt::Debug for CrosstermTerminal<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CrosstermTerminal")
+            .field("options", &self.options)
+            .field("suspended", &self.events.is_none())
+            .finish()
+    }
+}
+
+/// A backend fed with events sent from code.
+///
+/// Pair it with a ratatui `TestBackend` terminal, such as one from
+/// [`setup_test_terminal`](super::setup_test_terminal), to drive an app in
+/// tests without a real terminal. Suspending changes no terminal modes.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::event::{ChannelBackend, TerminalEvent};
+///
+/// let (backend, input) = ChannelBackend::new();
+/// input.send(TerminalEvent::Resize(80, 24)).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ChannelBackend {
+    /// Events waiting to be read.
+    rx: mpsc::UnboundedReceiver<TerminalEvent>,
+}
+
+impl ChannelBackend {
+    /// Creates a backend and the sender that feeds it.
+    ///
+    /// The backend has no more input once every sender is dropped.
+    pub fn new() -> (Self, mpsc::UnboundedSender<TerminalEvent>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        (Self { rx }, tx)
+    }
+}
+
+impl Backend for ChannelBackend {
+    fn next_event(&mut self) -> BackendFuture<'_, Option<io::Result<TerminalEvent>>> {
+        Box::pin(async move { self.rx.recv().await.map(Ok) })
+    }
+
+    fn suspend(&mut self) -> BackendFuture<'_, io::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn resume(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_terminal_event_from_crossterm() {
+        use crossterm::event::{Event, KeyCode, KeyModifiers};
+
+        let key = Event::Key(crossterm::event::KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::CONTROL,
+        ));
+        let Ok(TerminalEvent::Key(key)) = TerminalEvent::try_from(key) else {
+            panic!("expected a key event");
+        };
+        assert_eq!(key.code, terminput::KeyCode::Char('a'));
+        assert_eq!(key.modifiers, terminput::KeyModifiers::CTRL);
+
+        assert_eq!(
+            TerminalEvent::try_from(Event::Resize(80, 24)).unwrap(),
+            TerminalEvent::Resize(80, 24)
+        );
+        assert_eq!(
+            TerminalEvent::try_from(Event::FocusLost).unwrap(),
+            TerminalEvent::FocusLost
+        );
+    }
+
+    #[tokio::test]
+    async fn test_suspend_rebuilds_terminal_events() {
+        let mut terminal = CrosstermTerminal::new(Vec::new(), TerminalOptions::new());
+        if let Some(events) = terminal.events.as_mut() {
+            events.set_poll_timeout(Duration::from_millis(99));
+        }
+
+        terminal.suspend().await.unwrap();
+        assert!(terminal.events.is_none());
+        terminal.resume().unwrap();
+
+        // The old stream was dropped and a fresh one created after resuming
+        let events = terminal.events.as_ref().unwrap();
+        assert_eq!(events.poll_timeout(), Duration::from_millis(10));
+        let output = String::from_utf8(terminal.writer).unwrap();
+        assert!(output.find("\x1b[?1049l").unwrap() < output.find("\x1b[?1049h").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_channel_backend_ends_with_senders() {
+        let (mut backend, input) = ChannelBackend::new();
+        input.send(TerminalEvent::FocusGained).unwrap();
+        drop(input);
+
+        assert_eq!(
+            backend.next_event().await.unwrap().unwrap(),
+            TerminalEvent::FocusGained
+        );
+        assert!(backend.next_event().await.is_none());
+    }
+}
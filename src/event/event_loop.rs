@@ -4,13 +4,22 @@
 //! for TUI applications, integrating terminal events, tick timing, and shutdown signals.
 
 use std::future::Future;
-use std::time::Duration;
-
-use tokio::sync::mpsc;
-use tracing::{debug, trace};
-
-use super::shutdown::ShutdownSignal;
-use super::terminal::TerminalEventStream;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{mpsc, watch};
+use tokio::time::Interval;
+use tokio_stream::{Stream, StreamExt, StreamMap};
+use tracing::{debug, trace, Instrument};
+
+use super::backend::{Backend, ChannelBackend, CrosstermTerminal, TerminalEvent};
+use super::metrics::FrameMetrics;
+use super::mouse::MouseEvent;
+use super::render::RenderScheduler;
+use super::shutdown::{is_suspend_key, ShutdownSignal, SuspendHandler, SuspendSignal};
+use super::terminal::TerminalOptions;
+use super::timing::{Clock, Debouncer, SystemClock};
 use crate::input::Action;
 
 /// Application event types that flow through the event loop.
@@ -32,8 +41,17 @@ use crate::input::Action;
 /// ```
 #[derive(Debug, Clone)]
 pub enum AppEvent<M = String> {
-    /// A terminal event from crossterm.
-    Terminal(crossterm::event::Event),
+    /// A terminal event, such as a key press.
+    Terminal(TerminalEvent),
+
+    /// A mouse event from the terminal.
+    Mouse(MouseEvent),
+
+    /// The terminal was resized to the given width and height.
+    Resize(u16, u16),
+
+    /// Text was pasted into the terminal (requires bracketed paste).
+    Paste(String),
 
     /// A matched keybinding action.
     Action(Action),
@@ -44,6 +62,31 @@ pub enum AppEvent<M = String> {
     /// A render tick event (fires at the configured frame rate).
     Tick,
 
+    /// A redraw was requested through the [`RenderScheduler`].
+    ///
+    /// Delivered at most once per tick, after the tick itself.
+    Render,
+
+    /// The terminal has been restored to normal mode after the handler
+    /// returned [`ControlFlow::Suspend`].
+    ///
+    /// The handler may run blocking work that needs the terminal (such as
+    /// spawning `$EDITOR`) while handling this event.
+    Suspended,
+
+    /// The terminal has re-entered TUI mode after a suspension.
+    ///
+    /// The screen is blank at this point; clear the `Terminal` so the
+    /// scheduled [`AppEvent::Render`] redraws everything.
+    Resumed,
+
+    /// No input has arrived for the configured
+    /// [`idle_timeout`](EventLoopConfig::idle_timeout).
+    ///
+    /// Delivered once, after a tick, each time the application goes idle.
+    /// The next [input](AppEvent::is_input) starts the timeout again.
+    Idle,
+
     /// A shutdown signal was received.
     Shutdown,
 }
@@ -54,6 +97,93 @@ impl<M> AppEvent<M> {
         matches!(self, AppEvent::Terminal(_))
     }
 
+    /// Converts a terminal event into an application event.
+    ///
+    /// Mouse, resize, and paste events become [`AppEvent::Mouse`],
+    /// [`AppEvent::Resize`], and [`AppEvent::Paste`]; everything else is
+    /// wrapped in [`AppEvent::Terminal`].
+    pub fn from_terminal(event: TerminalEvent) -> Self {
+        match event {
+            TerminalEvent::Mouse(mouse) => AppEvent::Mouse(mouse),
+            TerminalEvent::Resize(width, height) => AppEvent::Resize(width, height),
+            TerminalEvent::Paste(text) => AppEvent::Paste(text),
+            other => AppEvent::Terminal(other),
+        }
+    }
+
+    /// Creates a key event from a terminput key.
+    ///
+    /// The key is delivered the same way as one read from the terminal, so
+    /// headless tests can feed synthetic input through the app's normal
+    /// key handling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+    /// use tuilib::event::AppEvent;
+    ///
+    /// let key = KeyEvent {
+    ///     code: KeyCode::Char('q'),
+    ///     modifiers: KeyModifiers::NONE,
+    ///     kind: KeyEventKind::Press,
+    ///     state: KeyEventState::NONE,
+    /// };
+    /// let event = AppEvent::<String>::from_key(key);
+    /// assert!(event.is_terminal());
+    /// assert_eq!(event.key(), Some(key));
+    /// ```
+    pub fn from_key(key: terminput::KeyEvent) -> Self {
+        AppEvent::Terminal(TerminalEvent::Key(key))
+    }
+
+    /// Returns the key if this is a key event.
+    pub fn key(&self) -> Option<terminput::KeyEvent> {
+        match self {
+            AppEvent::Terminal(TerminalEvent::Key(key)) => Some(*key),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this is a mouse event.
+    pub fn is_mouse(&self) -> bool {
+        matches!(self, AppEvent::Mouse(_))
+    }
+
+    /// Returns the mouse event if this is a mouse event.
+    pub fn mouse(&self) -> Option<&MouseEvent> {
+        match self {
+            AppEvent::Mouse(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this is a resize event.
+    pub fn is_resize(&self) -> bool {
+        matches!(self, AppEvent::Resize(_, _))
+    }
+
+    /// Returns the new `(width, height)` if this is a resize event.
+    pub fn resize(&self) -> Option<(u16, u16)> {
+        match self {
+            AppEvent::Resize(width, height) => Some((*width, *height)),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this is a paste event.
+    pub fn is_paste(&self) -> bool {
+        matches!(self, AppEvent::Paste(_))
+    }
+
+    /// Returns the pasted text if this is a paste event.
+    pub fn paste(&self) -> Option<&str> {
+        match self {
+            AppEvent::Paste(text) => Some(text),
+            _ => None,
+        }
+    }
+
     /// Returns true if this is an action event.
     pub fn is_action(&self) -> bool {
         matches!(self, AppEvent::Action(_))
@@ -69,11 +199,48 @@ impl<M> AppEvent<M> {
         matches!(self, AppEvent::Tick)
     }
 
+    /// Returns true if this is a render event.
+    pub fn is_render(&self) -> bool {
+        matches!(self, AppEvent::Render)
+    }
+
+    /// Returns true if this is a suspended event.
+    pub fn is_suspended(&self) -> bool {
+        matches!(self, AppEvent::Suspended)
+    }
+
+    /// Returns true if this is a resumed event.
+    pub fn is_resumed(&self) -> bool {
+        matches!(self, AppEvent::Resumed)
+    }
+
+    /// Returns true if this is an idle event.
+    pub fn is_idle(&self) -> bool {
+        matches!(self, AppEvent::Idle)
+    }
+
     /// Returns true if this is a shutdown event.
     pub fn is_shutdown(&self) -> bool {
         matches!(self, AppEvent::Shutdown)
     }
 
+    /// Returns true if this event is input that ends an idle period.
+    ///
+    /// Terminal events, mouse events, resizes, pastes, actions and messages
+    /// are input. Events the loop generates itself, such as ticks and
+    /// renders, are not.
+    pub fn is_input(&self) -> bool {
+        matches!(
+            self,
+            AppEvent::Terminal(_)
+                | AppEvent::Mouse(_)
+                | AppEvent::Resize(_, _)
+                | AppEvent::Paste(_)
+                | AppEvent::Action(_)
+                | AppEvent::Message(_)
+        )
+    }
+
     /// Returns the action if this is an action event.
     pub fn action(&self) -> Option<&Action> {
         match self {
@@ -94,7 +261,7 @@ impl<M> AppEvent<M> {
 /// Control flow signal returned by event handlers.
 ///
 /// Handlers return this to indicate whether the event loop should continue
-/// running or exit.
+/// running or exit, and whether the UI needs to be redrawn.
 ///
 /// # Examples
 ///
@@ -104,10 +271,14 @@ impl<M> AppEvent<M> {
 /// // Continue processing events
 /// let continue_running = ControlFlow::Continue;
 ///
+/// // Continue and schedule a redraw on the next tick
+/// let redraw = ControlFlow::Redraw;
+///
 /// // Exit the event loop
 /// let should_exit = ControlFlow::Exit;
 ///
 /// assert!(!continue_running.should_exit());
+/// assert!(redraw.should_continue() && redraw.needs_redraw());
 /// assert!(should_exit.should_exit());
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -116,6 +287,15 @@ pub enum ControlFlow {
     #[default]
     Continue,
 
+    /// Continue running and mark the UI as needing a redraw.
+    Redraw,
+
+    /// Suspend the TUI, restoring the terminal to normal mode.
+    ///
+    /// The loop then delivers [`AppEvent::Suspended`] followed by
+    /// [`AppEvent::Resumed`].
+    Suspend,
+
     /// Exit the event loop.
     Exit,
 }
@@ -128,7 +308,155 @@ impl ControlFlow {
 
     /// Returns true if the event loop should continue.
     pub fn should_continue(&self) -> bool {
-        matches!(self, ControlFlow::Continue)
+        !self.should_exit()
+    }
+
+    /// Returns true if the handler requested a redraw.
+    pub fn needs_redraw(&self) -> bool {
+        matches!(self, ControlFlow::Redraw)
+    }
+
+    /// Returns true if the handler requested a suspension.
+    pub fn should_suspend(&self) -> bool {
+        matches!(self, ControlFlow::Suspend)
+    }
+}
+
+/// The result of an event handler registered with
+/// [`EventLoop::add_handler`].
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::event::{AppEvent, HandlerOutcome};
+///
+/// let mut quit_on_shutdown = |event: &AppEvent| {
+///     if event.is_shutdown() {
+///         HandlerOutcome::Exit
+///     } else {
+///         HandlerOutcome::Continue
+///     }
+/// };
+/// assert_eq!(quit_on_shutdown(&AppEvent::Tick), HandlerOutcome::Continue);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HandlerOutcome {
+    /// Pass the event on to the next handler.
+    #[default]
+    Continue,
+
+    /// Stop the event here; later handlers do not see it.
+    Consumed,
+
+    /// Stop the event here and mark the UI as needing a redraw.
+    Redraw,
+
+    /// Stop the event here and exit the event loop.
+    Exit,
+}
+
+/// A handler registered with [`EventLoop::add_handler`].
+pub type EventHandler<M> = Box<dyn FnMut(&AppEvent<M>) -> HandlerOutcome + Send>;
+
+/// A frame whose handler took longer than the configured frame budget.
+///
+/// Passed to observers registered with [`EventLoop::on_frame_overrun`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameOverrun {
+    /// Time spent handling the tick, including any render it triggered.
+    pub handler_time: Duration,
+    /// The configured frame budget.
+    pub budget: Duration,
+}
+
+impl FrameOverrun {
+    /// Returns how far the handler went over the budget.
+    pub fn overrun(&self) -> Duration {
+        self.handler_time.saturating_sub(self.budget)
+    }
+}
+
+/// An observer registered with [`EventLoop::on_frame_overrun`].
+pub type OverrunObserver = Box<dyn FnMut(&FrameOverrun) + Send>;
+
+/// Async cleanup registered with [`EventLoop::on_shutdown`].
+type ShutdownHook = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>;
+
+/// An event stream registered with [`EventLoop::add_source`].
+type EventSource<M> = Pin<Box<dyn Stream<Item = AppEvent<M>> + Send>>;
+
+/// Shortest tick interval the loop will use.
+const MIN_TICK_RATE: Duration = Duration::from_millis(1);
+
+/// A handle for changing the tick rate of a running event loop.
+///
+/// Obtained from [`EventLoop::tick_rate_handle`]. Clones control the same
+/// loop, so the rate can be changed from the event handler or from async
+/// tasks, for example to tick slowly while idle and quickly during an
+/// animation.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tuilib::event::{EventLoop, EventLoopConfig};
+///
+/// let event_loop: EventLoop<String> = EventLoop::new(EventLoopConfig::default());
+/// let tick_rate = event_loop.tick_rate_handle();
+///
+/// tick_rate.set(Duration::from_millis(250));
+/// assert_eq!(event_loop.tick_rate(), Duration::from_millis(250));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TickRateHandle {
+    tx: Arc<watch::Sender<Duration>>,
+}
+
+impl TickRateHandle {
+    /// Sets the tick rate.
+    ///
+    /// A running loop restarts its tick interval, so the next tick comes one
+    /// new interval later. Rates below one millisecond are raised to one
+    /// millisecond.
+    pub fn set(&self, rate: Duration) {
+        self.tx.send_if_modified(|current| {
+            let rate = rate.max(MIN_TICK_RATE);
+            let changed = *current != rate;
+            *current = rate;
+            changed
+        });
+    }
+
+    /// Returns the current tick rate.
+    pub fn get(&self) -> Duration {
+        *self.tx.borrow()
+    }
+}
+
+/// Creates a tick interval that skips missed ticks.
+///
+/// With `delay_first`, the first tick comes one interval from now instead of
+/// immediately.
+fn new_tick_interval(rate: Duration, delay_first: bool) -> Interval {
+    let rate = rate.max(MIN_TICK_RATE);
+    let mut interval = if delay_first {
+        tokio::time::interval_at(tokio::time::Instant::now() + rate, rate)
+    } else {
+        tokio::time::interval(rate)
+    };
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    interval
+}
+
+/// Debouncer holding back terminal sizes while a resize is in progress.
+type ResizeDebouncer = Debouncer<(u16, u16), Arc<dyn Clock + Send + Sync>>;
+
+/// Waits for a held resize to settle, or forever if resizes are not
+/// debounced.
+async fn settled_resize(debouncer: &mut Option<ResizeDebouncer>) -> Option<(u16, u16)> {
+    match debouncer {
+        Some(debouncer) => debouncer.next().await,
+        None => std::future::pending().await,
     }
 }
 
@@ -163,6 +491,32 @@ pub struct EventLoopConfig {
 
     /// Whether to handle SIGINT/SIGTERM for graceful shutdown.
     pub handle_signals: bool,
+
+    /// Whether to capture mouse events from the terminal.
+    pub capture_mouse: bool,
+
+    /// Whether to enable the Kitty keyboard protocol, which reports key
+    /// releases.
+    pub keyboard_enhancement: bool,
+
+    /// Whether to wrap each tick's handling in a `frame` tracing span.
+    pub frame_spans: bool,
+
+    /// Whether Ctrl+Z and SIGTSTP suspend the application to the shell.
+    pub handle_suspend: bool,
+
+    /// Maximum time the handler may spend on a tick before it is reported.
+    pub frame_budget: Option<Duration>,
+
+    /// Whether to drop the next tick after a frame exceeds the budget.
+    pub skip_late_ticks: bool,
+
+    /// How long without input before an [`AppEvent::Idle`] is delivered.
+    pub idle_timeout: Option<Duration>,
+
+    /// How long the terminal size must stay unchanged before a resize is
+    /// delivered.
+    pub resize_debounce: Option<Duration>,
 }
 
 impl EventLoopConfig {
@@ -221,6 +575,138 @@ impl EventLoopConfig {
         self.handle_signals = handle;
         self
     }
+
+    /// Sets whether to capture mouse events.
+    ///
+    /// This is applied by [`setup_terminal_with`](super::setup_terminal_with)
+    /// and [`restore_terminal_with`](super::restore_terminal_with) when given
+    /// [`terminal_options`](Self::terminal_options).
+    ///
+    /// # Arguments
+    ///
+    /// * `capture` - Whether to enable mouse capture
+    pub fn capture_mouse(mut self, capture: bool) -> Self {
+        self.capture_mouse = capture;
+        self
+    }
+
+    /// Sets whether to enable the Kitty keyboard protocol.
+    ///
+    /// Like [`capture_mouse`](Self::capture_mouse), this is applied through
+    /// [`terminal_options`](Self::terminal_options), including when the
+    /// terminal is suspended and resumed. See
+    /// [`TerminalOptions::keyboard_enhancement`].
+    pub fn keyboard_enhancement(mut self, enabled: bool) -> Self {
+        self.keyboard_enhancement = enabled;
+        self
+    }
+
+    /// Sets whether to emit a tracing span for each frame.
+    ///
+    /// When enabled, each tick (and any render it triggers) is handled inside
+    /// a `frame` debug span, followed by a trace event with the frame and
+    /// handler times.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to emit frame spans
+    pub fn frame_spans(mut self, enabled: bool) -> Self {
+        self.frame_spans = enabled;
+        self
+    }
+
+    /// Sets whether to handle Ctrl+Z / SIGTSTP by suspending to the shell.
+    ///
+    /// When enabled, [`EventLoop::run`] restores the terminal and stops the
+    /// process. Once the shell continues it (e.g. with `fg`), the terminal is
+    /// re-initialized and the handler receives [`AppEvent::Resumed`] followed
+    /// by a scheduled [`AppEvent::Render`]. The Ctrl+Z key event itself is
+    /// not delivered to the handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - Whether to handle suspend requests
+    pub fn handle_suspend(mut self, handle: bool) -> Self {
+        self.handle_suspend = handle;
+        self
+    }
+
+    /// Sets the time budget for handling a tick.
+    ///
+    /// When a tick (and any render it triggers) takes longer than the
+    /// budget, the loop logs a warning with the overrun, counts it in the
+    /// [`FrameMetrics`], and notifies any observers registered with
+    /// [`EventLoop::on_frame_overrun`].
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - Maximum handler time per tick
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::event::EventLoopConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = EventLoopConfig::new()
+    ///     .tick_rate(Duration::from_millis(16))
+    ///     .frame_budget(Duration::from_millis(12))
+    ///     .skip_late_ticks(true);
+    /// ```
+    pub fn frame_budget(mut self, budget: Duration) -> Self {
+        self.frame_budget = Some(budget);
+        self
+    }
+
+    /// Sets whether to drop the next tick after a frame exceeds the budget.
+    ///
+    /// Dropping the tick gives the loop a full tick interval to catch up on
+    /// input and messages before the next frame. Has no effect without a
+    /// [`frame_budget`](Self::frame_budget).
+    ///
+    /// # Arguments
+    ///
+    /// * `skip` - Whether to drop the tick following an overrun
+    pub fn skip_late_ticks(mut self, skip: bool) -> Self {
+        self.skip_late_ticks = skip;
+        self
+    }
+
+    /// Delivers [`AppEvent::Idle`] once no input has arrived for `timeout`.
+    ///
+    /// Idleness is checked on each tick, so the event arrives up to one
+    /// tick interval after the timeout. Useful for dimming the screen or
+    /// pausing work after a period of inactivity.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - How long without input counts as idle
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Coalesces bursts of [`AppEvent::Resize`] into a single event.
+    ///
+    /// While the terminal is being resized, each new size is held back
+    /// until no further resize arrives for `delay`; only the final size is
+    /// then delivered. This avoids an expensive relayout for every
+    /// intermediate size while a window is dragged.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - How long the size must settle before it is delivered
+    pub fn resize_debounce(mut self, delay: Duration) -> Self {
+        self.resize_debounce = Some(delay);
+        self
+    }
+
+    /// Returns the terminal setup options implied by this configuration.
+    pub fn terminal_options(&self) -> TerminalOptions {
+        TerminalOptions::new()
+            .mouse_capture(self.capture_mouse)
+            .keyboard_enhancement(self.keyboard_enhancement)
+    }
 }
 
 impl Default for EventLoopConfig {
@@ -230,6 +716,14 @@ impl Default for EventLoopConfig {
             debounce_delay: Duration::from_millis(50),
             channel_buffer_size: 256,
             handle_signals: true,
+            capture_mouse: true,
+            keyboard_enhancement: false,
+            frame_spans: false,
+            handle_suspend: false,
+            frame_budget: None,
+            skip_late_ticks: false,
+            idle_timeout: None,
+            resize_debounce: None,
         }
     }
 }
@@ -269,6 +763,30 @@ pub struct EventLoop<M = String> {
     config: EventLoopConfig,
     tx: mpsc::Sender<AppEvent<M>>,
     rx: mpsc::Receiver<AppEvent<M>>,
+    scheduler: RenderScheduler,
+    metrics: Arc<Mutex<FrameMetrics>>,
+    /// Handlers run in order before the handler passed to `run`.
+    handlers: Vec<EventHandler<M>>,
+    /// Extra event streams, keyed by registration order.
+    sources: StreamMap<usize, EventSource<M>>,
+    /// Key given to the next source.
+    next_source: usize,
+    /// Observers notified when a frame exceeds the budget.
+    overrun_observers: Vec<OverrunObserver>,
+    /// Set when the tick after an overrun should be dropped.
+    skip_next_tick: bool,
+    /// Current tick rate, shared with [`TickRateHandle`]s.
+    tick_rate: TickRateHandle,
+    /// Source of time for idle detection.
+    clock: Arc<dyn Clock + Send + Sync>,
+    /// When the last input arrived, or the loop started.
+    last_input: Instant,
+    /// Whether [`AppEvent::Idle`] has been delivered since the last input.
+    idle: bool,
+    /// Holds back resizes until the size settles, if enabled.
+    resize: Option<ResizeDebouncer>,
+    /// Cleanup run once after the loop exits, in registration order.
+    shutdown_hooks: Vec<ShutdownHook>,
 }
 
 impl<M> EventLoop<M>
@@ -290,8 +808,41 @@ where
     /// let event_loop: EventLoop<String> = EventLoop::new(config);
     /// ```
     pub fn new(config: EventLoopConfig) -> Self {
+        Self::new_with_clock(config, Arc::new(SystemClock))
+    }
+
+    /// Creates an event loop that reads time for idle detection and frame
+    /// metrics from `clock`.
+    ///
+    /// Use a [`ManualClock`](super::ManualClock) to test
+    /// [`idle_timeout`](EventLoopConfig::idle_timeout) or
+    /// [`metrics`](Self::metrics) without waiting.
+    pub fn new_with_clock(config: EventLoopConfig, clock: Arc<dyn Clock + Send + Sync>) -> Self {
         let (tx, rx) = mpsc::channel(config.channel_buffer_size);
-        Self { config, tx, rx }
+        let tick_rate = TickRateHandle {
+            tx: Arc::new(watch::Sender::new(config.tick_rate.max(MIN_TICK_RATE))),
+        };
+        let resize = config
+            .resize_debounce
+            .map(|delay| Debouncer::with_clock(delay, clock.clone()));
+        Self {
+            config,
+            tx,
+            rx,
+            scheduler: RenderScheduler::new(),
+            metrics: Arc::new(Mutex::new(FrameMetrics::default())),
+            handlers: Vec::new(),
+            sources: StreamMap::new(),
+            next_source: 0,
+            overrun_observers: Vec::new(),
+            skip_next_tick: false,
+            tick_rate,
+            last_input: clock.now(),
+            resize,
+            clock,
+            idle: false,
+            shutdown_hooks: Vec::new(),
+        }
     }
 
     /// Returns a sender that can be used to send events to the loop.
@@ -315,73 +866,552 @@ where
     }
 
     /// Returns a reference to the configuration.
+    ///
+    /// The configured tick rate is the rate the loop started with; see
+    /// [`tick_rate`](Self::tick_rate) for the current one.
     pub fn config(&self) -> &EventLoopConfig {
         &self.config
     }
 
-    /// Runs the event loop until exit is signaled.
-    ///
-    /// This method will block until the handler returns `ControlFlow::Exit`
-    /// or a shutdown signal is received.
-    ///
-    /// # Arguments
+    /// Returns the current tick rate.
+    pub fn tick_rate(&self) -> Duration {
+        self.tick_rate.get()
+    }
+
+    /// Changes the tick rate.
     ///
-    /// * `handler` - Async function that processes events and returns control flow
+    /// While the loop is running, use a [`TickRateHandle`] from
+    /// [`tick_rate_handle`](Self::tick_rate_handle) instead.
+    pub fn set_tick_rate(&self, rate: Duration) {
+        self.tick_rate.set(rate);
+    }
+
+    /// Returns a handle for changing the tick rate while the loop runs.
     ///
-    /// # Returns
+    /// The loop picks up a new rate straight away, even while waiting for
+    /// the next tick.
+    pub fn tick_rate_handle(&self) -> TickRateHandle {
+        self.tick_rate.clone()
+    }
+
+    /// Returns a handle to the loop's render scheduler.
     ///
-    /// Returns `Ok(())` on clean shutdown, or an error if something went wrong.
+    /// Use [`RenderScheduler::force_redraw`] from async tasks to request an
+    /// [`AppEvent::Render`] on the next tick.
     ///
     /// # Examples
     ///
-    /// ```rust,ignore
-    /// use tuilib::event::{EventLoop, EventLoopConfig, AppEvent, ControlFlow};
+    /// ```rust
+    /// use tuilib::event::{EventLoop, EventLoopConfig};
     ///
-    /// let mut event_loop: EventLoop<String> = EventLoop::new(EventLoopConfig::default());
+    /// let event_loop: EventLoop<String> = EventLoop::new(EventLoopConfig::default());
+    /// let scheduler = event_loop.render_scheduler();
     ///
-    /// event_loop.run(|event| async move {
-    ///     match event {
-    ///         AppEvent::Shutdown => ControlFlow::Exit,
-    ///         AppEvent::Tick => {
-    ///             // Render the UI
-    ///             ControlFlow::Continue
-    ///         }
-    ///         _ => ControlFlow::Continue,
-    ///     }
-    /// }).await?;
+    /// scheduler.force_redraw();
+    /// assert!(scheduler.is_dirty());
     /// ```
-    pub async fn run<F, Fut>(&mut self, mut handler: F) -> std::io::Result<()>
-    where
-        F: FnMut(AppEvent<M>) -> Fut,
-        Fut: Future<Output = ControlFlow>,
-    {
-        debug!(
-            tick_rate_ms = ?self.config.tick_rate.as_millis(),
-            "Starting event loop"
-        );
+    pub fn render_scheduler(&self) -> RenderScheduler {
+        self.scheduler.clone()
+    }
 
-        // Create terminal event stream
-        let mut terminal_events = TerminalEventStream::new();
+    /// Returns a snapshot of the frame timing statistics.
+    ///
+    /// Metrics are updated on every tick while the loop is running.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::event::{EventLoop, EventLoopConfig};
+    ///
+    /// let event_loop: EventLoop<String> = EventLoop::new(EventLoopConfig::default());
+    /// assert_eq!(event_loop.metrics().frame_count(), 0);
+    /// ```
+    pub fn metrics(&self) -> FrameMetrics {
+        self.metrics
+            .lock()
+            .map(|m| m.clone())
+            .unwrap_or_else(|e| e.into_inner().clone())
+    }
 
-        // Create tick interval
-        let mut tick_interval = tokio::time::interval(self.config.tick_rate);
-        tick_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    /// Returns a shared handle to the live frame metrics.
+    ///
+    /// Unlike [`metrics`](Self::metrics), the handle can be read from inside
+    /// the event handler while the loop is running.
+    pub fn metrics_handle(&self) -> Arc<Mutex<FrameMetrics>> {
+        self.metrics.clone()
+    }
 
-        // Create shutdown signal handler
-        let mut shutdown = if self.config.handle_signals {
-            Some(ShutdownSignal::new()?)
-        } else {
+    /// Registers a handler that sees events before the handler passed to
+    /// [`run`](Self::run).
+    ///
+    /// Handlers run in registration order. Each can let the event continue
+    /// to the next handler, or stop it by consuming it or exiting the loop;
+    /// the `run` handler only receives events that every registered handler
+    /// let through. This lets independent subsystems, such as a log pane or
+    /// a key recorder, handle their own events.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::event::{AppEvent, EventLoop, EventLoopConfig, HandlerOutcome};
+    ///
+    /// let mut event_loop: EventLoop<String> = EventLoop::new(EventLoopConfig::default());
+    /// event_loop.add_handler(Box::new(|event| match event {
+    ///     AppEvent::Shutdown => HandlerOutcome::Exit,
+    ///     _ => HandlerOutcome::Continue,
+    /// }));
+    /// assert_eq!(event_loop.handler_count(), 1);
+    /// ```
+    pub fn add_handler(&mut self, handler: EventHandler<M>) {
+        self.handlers.push(handler);
+    }
+
+    /// Returns the number of registered handlers.
+    pub fn handler_count(&self) -> usize {
+        self.handlers.len()
+    }
+
+    /// Adds a stream of events that the loop polls alongside terminal input,
+    /// ticks, and the [`sender`](Self::sender) channel.
+    ///
+    /// Any number of sources can be added, such as a websocket or a file
+    /// watcher. A source is dropped once it ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::event::{AppEvent, EventLoop, EventLoopConfig};
+    ///
+    /// let mut event_loop: EventLoop<String> = EventLoop::new(EventLoopConfig::default());
+    /// event_loop.add_source(tokio_stream::iter(vec![AppEvent::Message("hi".to_string())]));
+    /// assert_eq!(event_loop.source_count(), 1);
+    /// ```
+    pub fn add_source<S>(&mut self, source: S)
+    where
+        S: Stream<Item = AppEvent<M>> + Send + 'static,
+    {
+        self.sources.insert(self.next_source, Box::pin(source));
+        self.next_source += 1;
+    }
+
+    /// Returns the number of sources that have not ended yet.
+    pub fn source_count(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Registers an observer called whenever a tick exceeds the
+    /// [frame budget](EventLoopConfig::frame_budget).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tuilib::event::{EventLoop, EventLoopConfig};
+    ///
+    /// let mut event_loop: EventLoop<String> =
+    ///     EventLoop::new(EventLoopConfig::new().frame_budget(Duration::from_millis(8)));
+    /// event_loop.on_frame_overrun(Box::new(|overrun| {
+    ///     eprintln!("frame over budget by {:?}", overrun.overrun());
+    /// }));
+    /// ```
+    pub fn on_frame_overrun(&mut self, observer: OverrunObserver) {
+        self.overrun_observers.push(observer);
+    }
+
+    /// Registers async cleanup to run when the loop exits.
+    ///
+    /// Once the handler returns [`ControlFlow::Exit`], whether in response
+    /// to a shutdown signal or on its own, the loop awaits each hook in
+    /// registration order before [`run`](Self::run) returns. Hooks also run
+    /// when the loop fails with an error. They run at most once, even if the
+    /// loop is run again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::event::{EventLoop, EventLoopConfig};
+    ///
+    /// let mut event_loop: EventLoop<String> = EventLoop::new(EventLoopConfig::default());
+    /// event_loop.on_shutdown(|| async {
+    ///     // Flush files, save state, ...
+    /// });
+    /// ```
+    pub fn on_shutdown<F, Fut>(&mut self, hook: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.shutdown_hooks.push(Box::new(move || Box::pin(hook())));
+    }
+
+    /// Runs and removes the registered shutdown hooks.
+    async fn run_shutdown_hooks(&mut self) {
+        let hooks = std::mem::take(&mut self.shutdown_hooks);
+        if !hooks.is_empty() {
+            debug!(hooks = hooks.len(), "Running shutdown hooks");
+        }
+        for hook in hooks {
+            hook().await;
+        }
+    }
+
+    /// Passes an event through the registered handlers and then `handler`.
+    async fn deliver<F, Fut>(&mut self, handler: &mut F, event: AppEvent<M>) -> ControlFlow
+    where
+        F: FnMut(AppEvent<M>) -> Fut,
+        Fut: Future<Output = ControlFlow>,
+    {
+        for registered in &mut self.handlers {
+            match registered(&event) {
+                HandlerOutcome::Continue => {}
+                HandlerOutcome::Consumed => return ControlFlow::Continue,
+                HandlerOutcome::Redraw => return ControlFlow::Redraw,
+                HandlerOutcome::Exit => return ControlFlow::Exit,
+            }
+        }
+        handler(event).await
+    }
+
+    /// Holds back a resize while the size is still changing.
+    ///
+    /// Returns the event unchanged unless it is a resize and
+    /// [`resize_debounce`](EventLoopConfig::resize_debounce) is set, in
+    /// which case the size is kept until it settles.
+    fn hold_resize(&mut self, event: AppEvent<M>) -> Option<AppEvent<M>> {
+        match (&mut self.resize, event) {
+            (Some(debouncer), AppEvent::Resize(width, height)) => {
+                trace!(width, height, "Resize held until the size settles");
+                debouncer.push((width, height));
+                None
+            }
+            (_, event) => Some(event),
+        }
+    }
+
+    /// Passes an event to the handler, recording frame metrics for ticks.
+    ///
+    /// Input restarts the idle timeout, and a tick after it has passed is
+    /// followed by [`AppEvent::Idle`].
+    async fn dispatch<F, Fut>(&mut self, handler: &mut F, event: AppEvent<M>) -> ControlFlow
+    where
+        F: FnMut(AppEvent<M>) -> Fut,
+        Fut: Future<Output = ControlFlow>,
+    {
+        if !event.is_tick() {
+            if event.is_input() {
+                self.last_input = self.clock.now();
+                self.idle = false;
+            }
+            return self.dispatch_inner(handler, event).await;
+        }
+
+        let control = self.dispatch_tick(handler, event).await;
+        if control.should_exit() || self.idle {
+            return control;
+        }
+        let timed_out = self
+            .config
+            .idle_timeout
+            .is_some_and(|timeout| self.clock.now().duration_since(self.last_input) >= timeout);
+        if !timed_out {
+            return control;
+        }
+
+        debug!("Event loop idle");
+        self.idle = true;
+        match self.dispatch_inner(handler, AppEvent::Idle).await {
+            ControlFlow::Continue => control,
+            idle_control => idle_control,
+        }
+    }
+
+    /// Passes a tick to the handler, recording frame metrics.
+    async fn dispatch_tick<F, Fut>(&mut self, handler: &mut F, event: AppEvent<M>) -> ControlFlow
+    where
+        F: FnMut(AppEvent<M>) -> Fut,
+        Fut: Future<Output = ControlFlow>,
+    {
+        let started = self.clock.now();
+        let control = if self.config.frame_spans {
+            let frame = self.metrics.lock().map(|m| m.frame_count()).unwrap_or(0);
+            self.dispatch_inner(handler, event)
+                .instrument(tracing::debug_span!("frame", frame))
+                .await
+        } else {
+            self.dispatch_inner(handler, event).await
+        };
+        let handler_time = self.clock.now().saturating_duration_since(started);
+        let overrun = self
+            .config
+            .frame_budget
+            .filter(|&budget| handler_time > budget)
+            .map(|budget| FrameOverrun {
+                handler_time,
+                budget,
+            });
+
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.record_frame(started, handler_time);
+            if overrun.is_some() {
+                metrics.record_overrun();
+            }
+            if self.config.frame_spans {
+                trace!(
+                    handler_us = handler_time.as_micros() as u64,
+                    avg_frame_us = metrics.average_frame_time().map(|t| t.as_micros() as u64),
+                    fps = metrics.fps(),
+                    "Frame complete"
+                );
+            }
+        }
+
+        if let Some(overrun) = overrun {
+            tracing::warn!(
+                handler_us = handler_time.as_micros() as u64,
+                budget_us = overrun.budget.as_micros() as u64,
+                overrun_us = overrun.overrun().as_micros() as u64,
+                "Frame exceeded budget"
+            );
+            for observer in &mut self.overrun_observers {
+                observer(&overrun);
+            }
+            self.skip_next_tick = self.config.skip_late_ticks;
+        }
+
+        control
+    }
+
+    /// Suspends the TUI around an [`AppEvent::Suspended`] dispatch.
+    ///
+    /// Ticks are not polled while suspended, and the terminal event stream
+    /// is dropped so that it cannot take input meant for a child process;
+    /// a new stream is created on resuming. Shutdown signals received in
+    /// the meantime are delivered after resuming. Without a `terminal`
+    /// (headless mode) no terminal modes are changed.
+    async fn suspend<F, Fut, B>(
+        &mut self,
+        handler: &mut F,
+        mut terminal: Option<&mut B>,
+    ) -> std::io::Result<ControlFlow>
+    where
+        F: FnMut(AppEvent<M>) -> Fut,
+        Fut: Future<Output = ControlFlow>,
+        B: Backend,
+    {
+        debug!("Suspending event loop");
+        if let Some(terminal) = terminal.as_deref_mut() {
+            terminal.suspend().await?;
+        }
+
+        let control = self.dispatch_inner(handler, AppEvent::Suspended).await;
+
+        if let Some(terminal) = terminal {
+            terminal.resume()?;
+        }
+        debug!("Resuming event loop");
+
+        if control.should_exit() {
+            return Ok(control);
+        }
+
+        self.scheduler.mark_dirty();
+        Ok(self.dispatch_inner(handler, AppEvent::Resumed).await)
+    }
+
+    /// Stops the process in response to Ctrl+Z or SIGTSTP.
+    ///
+    /// Blocks until the process is continued, then schedules a redraw and
+    /// returns the [`AppEvent::Resumed`] event to deliver.
+    fn suspend_process(&self, handler: &mut SuspendHandler) -> AppEvent<M> {
+        debug!("Suspending process");
+        if let Err(e) = handler.suspend() {
+            tracing::error!(error = %e, "Suspend error");
+        }
+        debug!("Process continued");
+        self.scheduler.mark_dirty();
+        AppEvent::Resumed
+    }
+
+    /// Passes an event to the handler and applies render scheduling.
+    ///
+    /// A [`ControlFlow::Redraw`] result marks the scheduler dirty. After a
+    /// tick, a pending redraw is delivered as a single [`AppEvent::Render`].
+    async fn dispatch_inner<F, Fut>(&mut self, handler: &mut F, event: AppEvent<M>) -> ControlFlow
+    where
+        F: FnMut(AppEvent<M>) -> Fut,
+        Fut: Future<Output = ControlFlow>,
+    {
+        let is_tick = event.is_tick();
+
+        let control = self.deliver(handler, event).await;
+        if control.should_exit() {
+            return control;
+        }
+        if control.needs_redraw() {
+            self.scheduler.mark_dirty();
+        }
+
+        if is_tick && self.scheduler.take_dirty() {
+            trace!("Render event");
+            let control = self.deliver(handler, AppEvent::Render).await;
+            if control.needs_redraw() {
+                self.scheduler.mark_dirty();
+            }
+            return control;
+        }
+
+        control
+    }
+
+    /// Runs the event loop until exit is signaled.
+    ///
+    /// This method will block until the handler returns `ControlFlow::Exit`
+    /// or a shutdown signal is received. Events pass through the handlers
+    /// registered with [`add_handler`](Self::add_handler) first, and hooks
+    /// registered with [`on_shutdown`](Self::on_shutdown) run before it
+    /// returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Async function that processes events and returns control flow
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on clean shutdown, or an error if something went wrong.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use tuilib::event::{EventLoop, EventLoopConfig, AppEvent, ControlFlow};
+    ///
+    /// let mut event_loop: EventLoop<String> = EventLoop::new(EventLoopConfig::default());
+    ///
+    /// event_loop.run(|event| async move {
+    ///     match event {
+    ///         AppEvent::Shutdown => ControlFlow::Exit,
+    ///         AppEvent::Tick => {
+    ///             // Render the UI
+    ///             ControlFlow::Continue
+    ///         }
+    ///         _ => ControlFlow::Continue,
+    ///     }
+    /// }).await?;
+    /// ```
+    pub async fn run<F, Fut>(&mut self, handler: F) -> std::io::Result<()>
+    where
+        F: FnMut(AppEvent<M>) -> Fut,
+        Fut: Future<Output = ControlFlow>,
+    {
+        let terminal = CrosstermTerminal::new(std::io::stdout(), self.config.terminal_options());
+        self.run_with_backend(terminal, handler).await
+    }
+
+    /// Runs the event loop with input from the given backend.
+    ///
+    /// This is [`run`](Self::run) with the terminal replaced by `backend`.
+    /// With a [`ChannelBackend`], tests can send key and resize events that
+    /// reach the handler exactly as terminal input would.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+    /// use tuilib::event::{
+    ///     AppEvent, ChannelBackend, ControlFlow, EventLoop, EventLoopConfig, TerminalEvent,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> std::io::Result<()> {
+    ///     let config = EventLoopConfig::new().handle_signals(false).handle_suspend(false);
+    ///     let mut event_loop: EventLoop<String> = EventLoop::new(config);
+    ///     let (backend, input) = ChannelBackend::new();
+    ///     input
+    ///         .send(TerminalEvent::Key(KeyEvent {
+    ///             code: KeyCode::Char('q'),
+    ///             modifiers: KeyModifiers::NONE,
+    ///             kind: KeyEventKind::Press,
+    ///             state: KeyEventState::NONE,
+    ///         }))
+    ///         .unwrap();
+    ///
+    ///     event_loop
+    ///         .run_with_backend(backend, |event: AppEvent<String>| async move {
+    ///             match event.key() {
+    ///                 Some(key) if key.code == KeyCode::Char('q') => ControlFlow::Exit,
+    ///                 _ => ControlFlow::Continue,
+    ///             }
+    ///         })
+    ///         .await
+    /// }
+    /// ```
+    pub async fn run_with_backend<B, F, Fut>(
+        &mut self,
+        mut backend: B,
+        mut handler: F,
+    ) -> std::io::Result<()>
+    where
+        B: Backend,
+        F: FnMut(AppEvent<M>) -> Fut,
+        Fut: Future<Output = ControlFlow>,
+    {
+        let result = self.run_backend(&mut backend, &mut handler).await;
+        self.run_shutdown_hooks().await;
+        result
+    }
+
+    /// Runs the loop with input from `terminal` until the handler exits.
+    async fn run_backend<B, F, Fut>(
+        &mut self,
+        terminal: &mut B,
+        handler: &mut F,
+    ) -> std::io::Result<()>
+    where
+        B: Backend,
+        F: FnMut(AppEvent<M>) -> Fut,
+        Fut: Future<Output = ControlFlow>,
+    {
+        self.last_input = self.clock.now();
+        self.idle = false;
+        let mut tick_rate = self.tick_rate.tx.subscribe();
+        debug!(
+            tick_rate_ms = ?tick_rate.borrow().as_millis(),
+            "Starting event loop"
+        );
+
+        // Create tick interval
+        let mut tick_interval = new_tick_interval(*tick_rate.borrow_and_update(), false);
+
+        // Create shutdown signal handler
+        let mut shutdown = if self.config.handle_signals {
+            Some(ShutdownSignal::new()?)
+        } else {
+            None
+        };
+
+        // Create suspend (Ctrl+Z) handling
+        let mut suspend = if self.config.handle_suspend {
+            Some((
+                SuspendSignal::new()?,
+                SuspendHandler::new(self.config.terminal_options()),
+            ))
+        } else {
             None
         };
 
         loop {
+            let mut settled = false;
             let event = tokio::select! {
                 // Terminal events
-                Some(term_event) = terminal_events.next() => {
+                Some(term_event) = terminal.next_event() => {
                     match term_event {
                         Ok(event) => {
                             trace!(?event, "Terminal event received");
-                            AppEvent::Terminal(event)
+                            match suspend {
+                                Some((_, ref mut handler)) if is_suspend_key(&event) => {
+                                    self.suspend_process(handler)
+                                }
+                                _ => AppEvent::from_terminal(event),
+                            }
                         }
                         Err(e) => {
                             tracing::error!(error = %e, "Terminal event error");
@@ -396,12 +1426,33 @@ where
                     AppEvent::Tick
                 }
 
+                // Tick rate changes
+                Ok(()) = tick_rate.changed() => {
+                    let rate = *tick_rate.borrow_and_update();
+                    debug!(tick_rate_ms = ?rate.as_millis(), "Tick rate changed");
+                    tick_interval = new_tick_interval(rate, true);
+                    continue;
+                }
+
                 // Channel messages
                 Some(msg) = self.rx.recv() => {
                     trace!("Channel message received");
                     msg
                 }
 
+                // Registered sources
+                Some((source, event)) = self.sources.next(), if !self.sources.is_empty() => {
+                    trace!(source, "Source event received");
+                    event
+                }
+
+                // Resizes that have settled
+                Some((width, height)) = settled_resize(&mut self.resize) => {
+                    trace!(width, height, "Resize settled");
+                    settled = true;
+                    AppEvent::Resize(width, height)
+                }
+
                 // Shutdown signal
                 _ = async {
                     if let Some(ref mut s) = shutdown {
@@ -413,9 +1464,39 @@ where
                     debug!("Shutdown signal received");
                     AppEvent::Shutdown
                 }
+
+                // Suspend signal
+                _ = async {
+                    if let Some((ref mut s, _)) = suspend {
+                        s.recv().await
+                    } else {
+                        std::future::pending::<()>().await
+                    }
+                } => {
+                    match suspend {
+                        Some((_, ref mut handler)) => self.suspend_process(handler),
+                        None => continue,
+                    }
+                }
+            };
+            let event = if settled {
+                event
+            } else {
+                match self.hold_resize(event) {
+                    Some(event) => event,
+                    None => continue,
+                }
             };
 
-            let control = handler(event).await;
+            let mut control = self.dispatch(handler, event).await;
+            if std::mem::take(&mut self.skip_next_tick) {
+                trace!("Dropping tick after frame overrun");
+                tick_interval.reset();
+            }
+            while control.should_suspend() {
+                control = self.suspend(handler, Some(&mut *terminal)).await?;
+                tick_interval.reset();
+            }
 
             if control.should_exit() {
                 debug!("Event loop exiting");
@@ -435,14 +1516,27 @@ where
         F: FnMut(AppEvent<M>) -> Fut,
         Fut: Future<Output = ControlFlow>,
     {
+        let result = self.run_headless_loop(&mut handler).await;
+        self.run_shutdown_hooks().await;
+        result
+    }
+
+    /// Runs the loop without terminal input until the handler exits.
+    async fn run_headless_loop<F, Fut>(&mut self, handler: &mut F) -> std::io::Result<()>
+    where
+        F: FnMut(AppEvent<M>) -> Fut,
+        Fut: Future<Output = ControlFlow>,
+    {
+        self.last_input = self.clock.now();
+        self.idle = false;
+        let mut tick_rate = self.tick_rate.tx.subscribe();
         debug!(
-            tick_rate_ms = ?self.config.tick_rate.as_millis(),
+            tick_rate_ms = ?tick_rate.borrow().as_millis(),
             "Starting headless event loop"
         );
 
         // Create tick interval
-        let mut tick_interval = tokio::time::interval(self.config.tick_rate);
-        tick_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut tick_interval = new_tick_interval(*tick_rate.borrow_and_update(), false);
 
         // Create shutdown signal handler
         let mut shutdown = if self.config.handle_signals {
@@ -452,6 +1546,7 @@ where
         };
 
         loop {
+            let mut settled = false;
             let event = tokio::select! {
                 // Tick events
                 _ = tick_interval.tick() => {
@@ -459,12 +1554,33 @@ where
                     AppEvent::Tick
                 }
 
+                // Tick rate changes
+                Ok(()) = tick_rate.changed() => {
+                    let rate = *tick_rate.borrow_and_update();
+                    debug!(tick_rate_ms = ?rate.as_millis(), "Tick rate changed");
+                    tick_interval = new_tick_interval(rate, true);
+                    continue;
+                }
+
                 // Channel messages
                 Some(msg) = self.rx.recv() => {
                     trace!("Channel message received");
                     msg
                 }
 
+                // Registered sources
+                Some((source, event)) = self.sources.next(), if !self.sources.is_empty() => {
+                    trace!(source, "Source event received");
+                    event
+                }
+
+                // Resizes that have settled
+                Some((width, height)) = settled_resize(&mut self.resize) => {
+                    trace!(width, height, "Resize settled");
+                    settled = true;
+                    AppEvent::Resize(width, height)
+                }
+
                 // Shutdown signal
                 _ = async {
                     if let Some(ref mut s) = shutdown {
@@ -477,8 +1593,24 @@ where
                     AppEvent::Shutdown
                 }
             };
+            let event = if settled {
+                event
+            } else {
+                match self.hold_resize(event) {
+                    Some(event) => event,
+                    None => continue,
+                }
+            };
 
-            let control = handler(event).await;
+            let mut control = self.dispatch(handler, event).await;
+            if std::mem::take(&mut self.skip_next_tick) {
+                trace!("Dropping tick after frame overrun");
+                tick_interval.reset();
+            }
+            while control.should_suspend() {
+                control = self.suspend(handler, None::<&mut ChannelBackend>).await?;
+                tick_interval.reset();
+            }
 
             if control.should_exit() {
                 debug!("Headless event loop exiting");
@@ -494,6 +1626,10 @@ impl<M> std::fmt::Debug for EventLoop<M> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("EventLoop")
             .field("config", &self.config)
+            .field("handlers", &self.handlers.len())
+            .field("sources", &self.sources.len())
+            .field("overrun_observers", &self.overrun_observers.len())
+            .field("shutdown_hooks", &self.shutdown_hooks.len())
             .finish()
     }
 }
@@ -513,6 +1649,17 @@ mod tests {
         assert!(!exit_flow.should_continue());
     }
 
+    #[test]
+    fn test_control_flow_redraw() {
+        let redraw = ControlFlow::Redraw;
+        assert!(redraw.needs_redraw());
+        assert!(redraw.should_continue());
+        assert!(!redraw.should_exit());
+        assert!(!ControlFlow::Continue.needs_redraw());
+        assert!(ControlFlow::Suspend.should_suspend());
+        assert!(ControlFlow::Suspend.should_continue());
+    }
+
     #[test]
     fn test_control_flow_default() {
         let default = ControlFlow::default();
@@ -523,9 +1670,14 @@ mod tests {
     fn test_event_loop_config_default() {
         let config = EventLoopConfig::default();
         assert_eq!(config.tick_rate, Duration::from_millis(16));
+        assert_eq!(config.idle_timeout, None);
+        assert_eq!(config.resize_debounce, None);
         assert_eq!(config.debounce_delay, Duration::from_millis(50));
         assert_eq!(config.channel_buffer_size, 256);
         assert!(config.handle_signals);
+        assert!(config.capture_mouse);
+        assert!(!config.frame_spans);
+        assert!(!config.handle_suspend);
     }
 
     #[test]
@@ -580,22 +1732,487 @@ mod tests {
         assert!(!shutdown_event.is_tick());
     }
 
+    #[test]
+    fn test_handle_suspend_config() {
+        let config = EventLoopConfig::new().handle_suspend(true);
+        assert!(config.handle_suspend);
+    }
+
+    #[test]
+    fn test_capture_mouse_config() {
+        let config = EventLoopConfig::new().capture_mouse(false);
+        assert!(!config.capture_mouse);
+        assert!(!config.terminal_options().mouse_capture);
+        assert!(EventLoopConfig::default().terminal_options().mouse_capture);
+
+        let config = EventLoopConfig::new().keyboard_enhancement(true);
+        assert!(config.terminal_options().keyboard_enhancement);
+        assert!(
+            !EventLoopConfig::default()
+                .terminal_options()
+                .keyboard_enhancement
+        );
+    }
+
+    #[test]
+    fn test_from_terminal_maps_mouse_events() {
+        use super::super::mouse::{MouseButton, MouseEventKind};
+        use crossterm::event::{Event, KeyModifiers};
+
+        let event = Event::Mouse(crossterm::event::MouseEvent {
+            kind: crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::Left),
+            column: 4,
+            row: 2,
+            modifiers: KeyModifiers::NONE,
+        });
+        let event = AppEvent::<String>::from_terminal(event.try_into().unwrap());
+        assert!(event.is_mouse());
+        let mouse = event.mouse().unwrap();
+        assert_eq!(mouse.kind, MouseEventKind::Down(MouseButton::Left));
+        assert_eq!((mouse.column, mouse.row), (4, 2));
+
+        let focus = AppEvent::<String>::from_terminal(TerminalEvent::FocusGained);
+        assert!(focus.is_terminal());
+        assert!(focus.mouse().is_none());
+    }
+
+    #[test]
+    fn test_from_terminal_maps_resize_events() {
+        let event = AppEvent::<String>::from_terminal(TerminalEvent::Resize(120, 40));
+        assert!(event.is_resize());
+        assert!(!event.is_terminal());
+        assert_eq!(event.resize(), Some((120, 40)));
+
+        assert_eq!(AppEvent::<String>::Tick.resize(), None);
+    }
+
+    #[test]
+    fn test_from_terminal_maps_paste_events() {
+        let event = AppEvent::<String>::from_terminal(TerminalEvent::Paste("a\nb".to_string()));
+        assert!(event.is_paste());
+        assert_eq!(event.paste(), Some("a\nb"));
+        assert!(AppEvent::<String>::Tick.paste().is_none());
+    }
+
     #[tokio::test]
-    async fn test_event_loop_send_receive() {
+    async fn test_resize_event_through_loop() {
         let mut event_loop: EventLoop<String> = EventLoop::new(
             EventLoopConfig::new()
                 .handle_signals(false)
-                .tick_rate(Duration::from_millis(100)),
+                .tick_rate(Duration::from_secs(60)),
         );
 
         let sender = event_loop.sender();
-
-        // Send a message before running
         sender
-            .send(AppEvent::Message("test".to_string()))
+            .send(AppEvent::from_terminal(TerminalEvent::Resize(80, 24)))
             .await
             .unwrap();
-        sender.send(AppEvent::Shutdown).await.unwrap();
+
+        let mut received = None;
+
+        event_loop
+            .run_headless(|event| {
+                received = event.resize();
+                let done = event.is_resize();
+                async move {
+                    if done {
+                        ControlFlow::Exit
+                    } else {
+                        ControlFlow::Continue
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(received, Some((80, 24)));
+    }
+
+    #[tokio::test]
+    async fn test_resize_debounce_delivers_final_size() {
+        let mut event_loop: EventLoop<String> = EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_millis(10))
+                .resize_debounce(Duration::from_millis(30)),
+        );
+
+        // A burst of resizes, as from dragging the window edge
+        let sender = event_loop.sender();
+        for width in 80..=90 {
+            sender.send(AppEvent::Resize(width, 24)).await.unwrap();
+        }
+        sender
+            .send(AppEvent::Message("after burst".to_string()))
+            .await
+            .unwrap();
+
+        let mut events = Vec::new();
+        let mut ticks_since_resize = None;
+        event_loop
+            .run_headless(|event| {
+                if let Some(size) = event.resize() {
+                    events.push(format!("resize {size:?}"));
+                    ticks_since_resize = Some(0);
+                } else if let Some(message) = event.message() {
+                    events.push(message.clone());
+                } else if event.is_tick() {
+                    ticks_since_resize = ticks_since_resize.map(|ticks| ticks + 1);
+                }
+                // Keep running a while to catch any late resize
+                let done = ticks_since_resize.is_some_and(|ticks| ticks >= 5);
+                async move {
+                    if done {
+                        ControlFlow::Exit
+                    } else {
+                        ControlFlow::Continue
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        // Other events are not held back behind the pending resize
+        assert_eq!(events, ["after burst", "resize (90, 24)"]);
+    }
+
+    /// Runs a headless loop for `ticks` ticks, returning the number of
+    /// render events delivered.
+    async fn count_renders(
+        event_loop: &mut EventLoop<String>,
+        ticks: usize,
+        mut on_tick: impl FnMut(usize) -> ControlFlow,
+    ) -> usize {
+        let mut tick_count = 0;
+        let mut renders = 0;
+
+        event_loop
+            .run_headless(|event| {
+                let control = if event.is_render() {
+                    renders += 1;
+                    ControlFlow::Continue
+                } else if event.is_tick() {
+                    tick_count += 1;
+                    if tick_count > ticks {
+                        ControlFlow::Exit
+                    } else {
+                        on_tick(tick_count)
+                    }
+                } else {
+                    ControlFlow::Continue
+                };
+                async move { control }
+            })
+            .await
+            .unwrap();
+
+        renders
+    }
+
+    fn render_test_loop() -> EventLoop<String> {
+        EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_millis(5)),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_no_render_without_dirty_signal() {
+        let mut event_loop = render_test_loop();
+        let renders = count_renders(&mut event_loop, 5, |_| ControlFlow::Continue).await;
+        assert_eq!(renders, 0);
+    }
+
+    #[tokio::test]
+    async fn test_redraw_triggers_single_render() {
+        let mut event_loop = render_test_loop();
+        let renders = count_renders(&mut event_loop, 5, |tick| {
+            if tick == 2 {
+                ControlFlow::Redraw
+            } else {
+                ControlFlow::Continue
+            }
+        })
+        .await;
+        assert_eq!(renders, 1);
+    }
+
+    #[tokio::test]
+    async fn test_force_redraw_coalesces() {
+        let mut event_loop = render_test_loop();
+        let scheduler = event_loop.render_scheduler();
+        scheduler.force_redraw();
+        scheduler.force_redraw();
+        scheduler.force_redraw();
+
+        let renders = count_renders(&mut event_loop, 3, |_| ControlFlow::Continue).await;
+        assert_eq!(renders, 1);
+        assert!(!scheduler.is_dirty());
+    }
+
+    #[tokio::test]
+    async fn test_frame_metrics() {
+        use crate::event::ManualClock;
+
+        let clock = ManualClock::new();
+        let mut event_loop: EventLoop<String> = EventLoop::new_with_clock(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .frame_spans(true)
+                .tick_rate(Duration::from_millis(1)),
+            Arc::new(clock.clone()),
+        );
+
+        // Each tick's handler takes 20ms on the loop's clock
+        let mut ticks = 0;
+        event_loop
+            .run_headless(|event| {
+                if event.is_tick() {
+                    ticks += 1;
+                    clock.advance(Duration::from_millis(20));
+                }
+                let done = ticks >= 10;
+                async move {
+                    if done {
+                        ControlFlow::Exit
+                    } else {
+                        ControlFlow::Continue
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        let metrics = event_loop.metrics();
+        assert_eq!(metrics.frame_count(), 10);
+        assert_eq!(metrics.max_handler_time(), Some(Duration::from_millis(20)));
+        assert_eq!(
+            metrics.average_handler_time(),
+            Some(Duration::from_millis(20))
+        );
+        assert_eq!(
+            metrics.average_frame_time(),
+            Some(Duration::from_millis(20))
+        );
+
+        // Back-to-back 20ms frames are 50 FPS
+        let fps = metrics.fps().unwrap();
+        assert!((fps - 50.0).abs() < 1e-6, "fps: {fps}");
+    }
+
+    #[tokio::test]
+    async fn test_frame_budget_overrun() {
+        let mut event_loop: EventLoop<String> = EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_millis(5))
+                .frame_budget(Duration::from_millis(2))
+                .skip_late_ticks(true),
+        );
+        let overruns = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&overruns);
+        event_loop.on_frame_overrun(Box::new(move |overrun| {
+            seen.lock().unwrap().push(*overrun);
+        }));
+
+        let mut tick_starts = Vec::new();
+        event_loop
+            .run_headless(|event| {
+                if event.is_tick() {
+                    tick_starts.push(Instant::now());
+                }
+                let ticks = tick_starts.len();
+                async move {
+                    match ticks {
+                        0 => ControlFlow::Continue,
+                        1 | 2 => {
+                            // Deliberately slow frames
+                            tokio::time::sleep(Duration::from_millis(10)).await;
+                            ControlFlow::Continue
+                        }
+                        _ => ControlFlow::Exit,
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        let overruns = overruns.lock().unwrap();
+        assert_eq!(overruns.len(), 2);
+        for overrun in overruns.iter() {
+            assert_eq!(overrun.budget, Duration::from_millis(2));
+            assert!(overrun.overrun() >= Duration::from_millis(8), "{overrun:?}");
+        }
+        assert_eq!(event_loop.metrics().overrun_count(), 2);
+
+        // The tick due during the slow frame was dropped, so the next one
+        // came a full interval after the frame finished
+        let gap = tick_starts[1] - tick_starts[0];
+        assert!(gap >= Duration::from_millis(15), "{gap:?}");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_tick_rate_changes_while_running() {
+        let mut event_loop: EventLoop<String> = EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_millis(40)),
+        );
+        let tick_rate = event_loop.tick_rate_handle();
+
+        let mut tick_starts = Vec::new();
+        event_loop
+            .run_headless(|event| {
+                if event.is_tick() {
+                    tick_starts.push(tokio::time::Instant::now());
+                    if tick_starts.len() == 3 {
+                        tick_rate.set(Duration::from_millis(5));
+                    }
+                }
+                let done = tick_starts.len() >= 9;
+                async move {
+                    if done {
+                        ControlFlow::Exit
+                    } else {
+                        ControlFlow::Continue
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        // Time is paused, so ticks come exactly one interval apart
+        let gaps: Vec<Duration> = tick_starts.windows(2).map(|w| w[1] - w[0]).collect();
+        let ms = Duration::from_millis;
+        assert_eq!(
+            gaps,
+            [ms(40), ms(40), ms(5), ms(5), ms(5), ms(5), ms(5), ms(5)]
+        );
+
+        assert_eq!(event_loop.tick_rate(), Duration::from_millis(5));
+        assert_eq!(event_loop.config().tick_rate, Duration::from_millis(40));
+        event_loop.set_tick_rate(Duration::ZERO);
+        assert_eq!(tick_rate.get(), Duration::from_millis(1));
+    }
+
+    #[tokio::test]
+    async fn test_idle_after_timeout_without_input() {
+        use crate::event::ManualClock;
+
+        let clock = ManualClock::new();
+        let mut event_loop: EventLoop<String> = EventLoop::new_with_clock(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_millis(1))
+                .idle_timeout(Duration::from_secs(1)),
+            Arc::new(clock.clone()),
+        );
+        let sender = event_loop.sender();
+
+        let mut ticks = 0;
+        let mut idle_after = Vec::new();
+        let mut input_after = None;
+        event_loop
+            .run_headless(|event| {
+                match event {
+                    AppEvent::Tick => {
+                        ticks += 1;
+                        match ticks {
+                            2 => clock.advance(Duration::from_millis(999)),
+                            3 => clock.advance(Duration::from_millis(1)),
+                            5 => sender.try_send(AppEvent::Message("key".into())).unwrap(),
+                            // Ticks alone never reset the timeout
+                            9 => clock.advance(Duration::from_secs(1)),
+                            _ => {}
+                        }
+                    }
+                    AppEvent::Idle => idle_after.push(ticks),
+                    AppEvent::Message(_) => input_after = Some(ticks),
+                    _ => {}
+                }
+                let done = ticks >= 12;
+                async move {
+                    if done {
+                        ControlFlow::Exit
+                    } else {
+                        ControlFlow::Continue
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        // Idle is delivered once per quiet period, and input starts a new one
+        assert!(input_after.is_some_and(|tick| tick < 9), "{input_after:?}");
+        assert_eq!(idle_after, [3, 9]);
+    }
+
+    #[test]
+    fn test_app_event_is_input() {
+        assert!(AppEvent::Message("m".to_string()).is_input());
+        assert!(AppEvent::<String>::Paste("p".to_string()).is_input());
+        assert!(AppEvent::<String>::Resize(80, 24).is_input());
+        assert!(!AppEvent::<String>::Tick.is_input());
+        assert!(!AppEvent::<String>::Render.is_input());
+        assert!(!AppEvent::<String>::Idle.is_input());
+        assert!(AppEvent::<String>::Idle.is_idle());
+    }
+
+    #[tokio::test]
+    async fn test_suspend_delivers_suspended_then_resumed() {
+        let mut event_loop: EventLoop<String> = EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_secs(60)),
+        );
+        event_loop
+            .sender()
+            .send(AppEvent::Message("edit".to_string()))
+            .await
+            .unwrap();
+
+        let mut seen = Vec::new();
+        event_loop
+            .run_headless(|event| {
+                let control = match &event {
+                    AppEvent::Message(_) => ControlFlow::Suspend,
+                    AppEvent::Resumed => ControlFlow::Exit,
+                    _ => ControlFlow::Continue,
+                };
+                if !event.is_tick() {
+                    seen.push(event);
+                }
+                async move { control }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(seen.len(), 3);
+        assert!(seen[0].is_message());
+        assert!(seen[1].is_suspended());
+        assert!(seen[2].is_resumed());
+
+        // A redraw is scheduled for after the resume
+        assert!(event_loop.render_scheduler().is_dirty());
+    }
+
+    #[tokio::test]
+    async fn test_event_loop_send_receive() {
+        let mut event_loop: EventLoop<String> = EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_millis(100)),
+        );
+
+        let sender = event_loop.sender();
+
+        // Send a message before running
+        sender
+            .send(AppEvent::Message("test".to_string()))
+            .await
+            .unwrap();
+        sender.send(AppEvent::Shutdown).await.unwrap();
 
         let mut received = Vec::new();
 
@@ -643,6 +2260,225 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_registered_handlers_pipeline() {
+        let mut event_loop: EventLoop<String> = EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_secs(60)),
+        );
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let first = log.clone();
+        event_loop.add_handler(Box::new(move |event| match event.message() {
+            Some(msg) => {
+                first.lock().unwrap().push(format!("first:{msg}"));
+                match msg.as_str() {
+                    "eaten" => HandlerOutcome::Consumed,
+                    "dirty" => HandlerOutcome::Redraw,
+                    _ => HandlerOutcome::Continue,
+                }
+            }
+            None => HandlerOutcome::Continue,
+        }));
+        let second = log.clone();
+        event_loop.add_handler(Box::new(move |event| match event.message() {
+            Some(msg) => {
+                second.lock().unwrap().push(format!("second:{msg}"));
+                if msg == "quit" {
+                    HandlerOutcome::Exit
+                } else {
+                    HandlerOutcome::Continue
+                }
+            }
+            None => HandlerOutcome::Continue,
+        }));
+        assert_eq!(event_loop.handler_count(), 2);
+
+        let sender = event_loop.sender();
+        for msg in ["hello", "eaten", "dirty", "quit", "never"] {
+            sender
+                .send(AppEvent::Message(msg.to_string()))
+                .await
+                .unwrap();
+        }
+
+        let run_log = log.clone();
+        event_loop
+            .run_headless(|event| {
+                if let Some(msg) = event.message() {
+                    run_log.lock().unwrap().push(format!("run:{msg}"));
+                }
+                async { ControlFlow::Continue }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "first:hello",
+                "second:hello",
+                "run:hello",
+                "first:eaten",
+                "first:dirty",
+                "first:quit",
+                "second:quit",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drive_app_on_test_backend() {
+        use ratatui::layout::Rect;
+        use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+
+        use crate::event::setup_test_terminal;
+
+        let key = |c| {
+            TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers: KeyModifiers::NONE,
+                kind: KeyEventKind::Press,
+                state: KeyEventState::NONE,
+            })
+        };
+
+        let mut terminal = setup_test_terminal(20, 1).unwrap();
+        let mut event_loop: EventLoop<String> = EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .handle_suspend(false)
+                .tick_rate(Duration::from_secs(60)),
+        );
+        let (backend, input) = ChannelBackend::new();
+        for event in [key('+'), key('+'), TerminalEvent::Resize(10, 1), key('q')] {
+            input.send(event).unwrap();
+        }
+
+        let mut count = 0;
+        event_loop
+            .run_with_backend(backend, |event| {
+                if let Some((width, height)) = event.resize() {
+                    terminal.backend_mut().resize(width, height);
+                }
+                let control = match event.key().map(|key| key.code) {
+                    Some(KeyCode::Char('q')) => ControlFlow::Exit,
+                    Some(KeyCode::Char('+')) => {
+                        count += 1;
+                        ControlFlow::Continue
+                    }
+                    _ => ControlFlow::Continue,
+                };
+                let text = format!("count: {count}");
+                terminal
+                    .draw(|frame| frame.render_widget(text.as_str(), frame.area()))
+                    .unwrap();
+                async move { control }
+            })
+            .await
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer.area, Rect::new(0, 0, 10, 1));
+        assert_eq!(crate::test_util::to_lines(buffer), vec!["count: 2  "]);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_hooks_run_once_before_exit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut event_loop: EventLoop<String> = EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_secs(60)),
+        );
+        let cleanups = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&cleanups);
+        event_loop.on_shutdown(move || async move {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // A shutdown signal arriving while the user is already exiting
+        let sender = event_loop.sender();
+        sender.send(AppEvent::Shutdown).await.unwrap();
+        sender.send(AppEvent::Shutdown).await.unwrap();
+
+        let mut shutdowns = 0;
+        event_loop
+            .run_headless(|event| {
+                if event.is_shutdown() {
+                    shutdowns += 1;
+                }
+                async { ControlFlow::Exit }
+            })
+            .await
+            .unwrap();
+        assert_eq!(cleanups.load(Ordering::SeqCst), 1);
+
+        // Running the loop again does not repeat the cleanup
+        event_loop
+            .run_headless(|_| async { ControlFlow::Exit })
+            .await
+            .unwrap();
+        assert_eq!(shutdowns, 1);
+        assert_eq!(cleanups.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_custom_source_events() {
+        let mut event_loop: EventLoop<String> = EventLoop::new(
+            EventLoopConfig::new()
+                .handle_signals(false)
+                .tick_rate(Duration::from_secs(60)),
+        );
+        // Sends shutdown when dropped, which the loop does once the source
+        // it belongs to has ended
+        struct ShutdownOnDrop(mpsc::Sender<AppEvent<String>>);
+        impl Drop for ShutdownOnDrop {
+            fn drop(&mut self) {
+                self.0.try_send(AppEvent::Shutdown).unwrap();
+            }
+        }
+
+        // A second source shuts the loop down once the first has ended
+        let (tx, rx) = mpsc::channel(1);
+        let guard = ShutdownOnDrop(tx);
+        event_loop.add_source(
+            tokio_stream::iter(vec![AppEvent::Message("from source".to_string())]).map(
+                move |event| {
+                    let _ = &guard;
+                    event
+                },
+            ),
+        );
+        event_loop.add_source(tokio_stream::wrappers::ReceiverStream::new(rx));
+        assert_eq!(event_loop.source_count(), 2);
+
+        let mut received = Vec::new();
+        event_loop
+            .run_headless(|event| {
+                let exit = event.is_shutdown();
+                if let Some(msg) = event.message() {
+                    received.push(msg.clone());
+                }
+                async move {
+                    if exit {
+                        ControlFlow::Exit
+                    } else {
+                        ControlFlow::Continue
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(received, vec!["from source"]);
+        // The finished source was dropped
+        assert_eq!(event_loop.source_count(), 1);
+    }
+
     #[test]
     fn test_event_loop_debug() {
         let event_loop: EventLoop<String> = EventLoop::new(EventLoopConfig::default());
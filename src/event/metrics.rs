@@ -0,0 +1,222 @@
+//! Frame timing statistics.
+//!
+//! This module provides [`FrameMetrics`], a rolling window of frame timings
+//! collected by the [`EventLoop`](super::EventLoop) on every tick. It tracks
+//! both the time between consecutive ticks (the effective frame time) and
+//! the time spent inside the handler for each tick.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::{Duration, Instant};
+//! use tuilib::event::FrameMetrics;
+//!
+//! let mut metrics = FrameMetrics::new(60);
+//! let start = Instant::now();
+//!
+//! metrics.record_frame(start, Duration::from_millis(2));
+//! metrics.record_frame(start + Duration::from_millis(20), Duration::from_millis(4));
+//!
+//! assert_eq!(metrics.frame_count(), 2);
+//! assert_eq!(metrics.average_frame_time(), Some(Duration::from_millis(20)));
+//! assert_eq!(metrics.max_handler_time(), Some(Duration::from_millis(4)));
+//! assert_eq!(metrics.fps(), Some(50.0));
+//! ```
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// The default number of frames kept in the rolling window.
+const DEFAULT_WINDOW: usize = 60;
+
+/// Rolling frame-time and handler-time statistics.
+#[derive(Debug, Clone)]
+pub struct FrameMetrics {
+    /// Maximum number of samples kept.
+    window: usize,
+    /// Time between consecutive frames.
+    frame_times: VecDeque<Duration>,
+    /// Time spent in the handler for each frame.
+    handler_times: VecDeque<Duration>,
+    /// When the previous frame started.
+    last_frame: Option<Instant>,
+    /// Total number of frames recorded.
+    frame_count: u64,
+    /// Total number of frames whose handler exceeded the frame budget.
+    overrun_count: u64,
+}
+
+impl Default for FrameMetrics {
+    fn default() -> Self {
+        Self::new(DEFAULT_WINDOW)
+    }
+}
+
+impl FrameMetrics {
+    /// Creates empty metrics keeping the last `window` frames.
+    ///
+    /// A window of zero is treated as one.
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            window,
+            frame_times: VecDeque::with_capacity(window),
+            handler_times: VecDeque::with_capacity(window),
+            last_frame: None,
+            frame_count: 0,
+            overrun_count: 0,
+        }
+    }
+
+    /// Returns the size of the rolling window.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Records a frame that started at `started` and spent `handler_time`
+    /// in the handler.
+    ///
+    /// The frame time is measured from the start of the previous frame, so
+    /// the first recorded frame contributes only a handler time.
+    pub fn record_frame(&mut self, started: Instant, handler_time: Duration) {
+        if let Some(last) = self.last_frame {
+            push_sample(
+                &mut self.frame_times,
+                self.window,
+                started.saturating_duration_since(last),
+            );
+        }
+        push_sample(&mut self.handler_times, self.window, handler_time);
+        self.last_frame = Some(started);
+        self.frame_count += 1;
+    }
+
+    /// Records that the last frame's handler exceeded the frame budget.
+    pub fn record_overrun(&mut self) {
+        self.overrun_count += 1;
+    }
+
+    /// Returns the total number of frames that exceeded the frame budget.
+    pub fn overrun_count(&self) -> u64 {
+        self.overrun_count
+    }
+
+    /// Returns the total number of frames recorded.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Returns the average time between frames over the window.
+    pub fn average_frame_time(&self) -> Option<Duration> {
+        average(&self.frame_times)
+    }
+
+    /// Returns the longest time between frames over the window.
+    pub fn max_frame_time(&self) -> Option<Duration> {
+        self.frame_times.iter().max().copied()
+    }
+
+    /// Returns the average handler time over the window.
+    pub fn average_handler_time(&self) -> Option<Duration> {
+        average(&self.handler_times)
+    }
+
+    /// Returns the longest handler time over the window.
+    pub fn max_handler_time(&self) -> Option<Duration> {
+        self.handler_times.iter().max().copied()
+    }
+
+    /// Returns the effective frames per second over the window.
+    ///
+    /// Returns `None` until at least two frames have been recorded.
+    pub fn fps(&self) -> Option<f64> {
+        self.average_frame_time()
+            .filter(|t| !t.is_zero())
+            .map(|t| 1.0 / t.as_secs_f64())
+    }
+
+    /// Clears all recorded samples.
+    pub fn reset(&mut self) {
+        self.frame_times.clear();
+        self.handler_times.clear();
+        self.last_frame = None;
+        self.frame_count = 0;
+        self.overrun_count = 0;
+    }
+}
+
+/// Appends a sample, dropping the oldest once the window is full.
+fn push_sample(samples: &mut VecDeque<Duration>, window: usize, sample: Duration) {
+    if samples.len() == window {
+        samples.pop_front();
+    }
+    samples.push_back(sample);
+}
+
+/// Returns the mean of the samples, or `None` if there are none.
+fn average(samples: &VecDeque<Duration>) -> Option<Duration> {
+    if samples.is_empty() {
+        return None;
+    }
+    let total: Duration = samples.iter().sum();
+    Some(total / samples.len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_metrics() {
+        let metrics = FrameMetrics::default();
+        assert_eq!(metrics.window(), 60);
+        assert_eq!(metrics.frame_count(), 0);
+        assert!(metrics.average_frame_time().is_none());
+        assert!(metrics.max_handler_time().is_none());
+        assert!(metrics.fps().is_none());
+    }
+
+    #[test]
+    fn test_first_frame_has_no_frame_time() {
+        let mut metrics = FrameMetrics::new(10);
+        metrics.record_frame(Instant::now(), Duration::from_millis(3));
+
+        assert_eq!(metrics.frame_count(), 1);
+        assert!(metrics.average_frame_time().is_none());
+        assert_eq!(
+            metrics.average_handler_time(),
+            Some(Duration::from_millis(3))
+        );
+    }
+
+    #[test]
+    fn test_rolling_window() {
+        let mut metrics = FrameMetrics::new(2);
+        let start = Instant::now();
+
+        metrics.record_frame(start, Duration::from_millis(1));
+        metrics.record_frame(start + Duration::from_millis(100), Duration::from_millis(9));
+        metrics.record_frame(start + Duration::from_millis(110), Duration::from_millis(2));
+        metrics.record_frame(start + Duration::from_millis(120), Duration::from_millis(2));
+
+        // The 100ms frame and 9ms handler time have left the window
+        assert_eq!(metrics.max_frame_time(), Some(Duration::from_millis(10)));
+        assert_eq!(metrics.max_handler_time(), Some(Duration::from_millis(2)));
+        assert_eq!(metrics.fps(), Some(100.0));
+        assert_eq!(metrics.frame_count(), 4);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut metrics = FrameMetrics::new(0);
+        assert_eq!(metrics.window(), 1);
+
+        metrics.record_frame(Instant::now(), Duration::from_millis(1));
+        metrics.record_overrun();
+        assert_eq!(metrics.overrun_count(), 1);
+        metrics.reset();
+        assert_eq!(metrics.frame_count(), 0);
+        assert_eq!(metrics.overrun_count(), 0);
+        assert!(metrics.average_handler_time().is_none());
+    }
+}
@@ -87,13 +87,76 @@
 //!     sender.send(AppEvent::Message("Data loaded".to_string())).await.ok();
 //! });
 //! ```
+//!
+//! ## Rendering Only on Change
+//!
+//! Return [`ControlFlow::Redraw`] from the handler (or call
+//! [`RenderScheduler::force_redraw`] from another task) and draw on
+//! [`AppEvent::Render`] instead of every tick. Redraw requests are coalesced
+//! to at most one render per tick.
+//!
+//! ```rust,ignore
+//! event_loop.run(|event| async move {
+//!     match event {
+//!         AppEvent::Terminal(_) => {
+//!             // Update state, then ask for a redraw
+//!             ControlFlow::Redraw
+//!         }
+//!         AppEvent::Render => {
+//!             // terminal.draw(...)
+//!             ControlFlow::Continue
+//!         }
+//!         AppEvent::Shutdown => ControlFlow::Exit,
+//!         _ => ControlFlow::Continue,
+//!     }
+//! }).await?;
+//! ```
+//!
+//! ## Suspending for Subprocesses
+//!
+//! Return [`ControlFlow::Suspend`] to hand the terminal to another program.
+//! The loop restores the terminal, delivers [`AppEvent::Suspended`] (run the
+//! subprocess there), re-enters TUI mode, and delivers [`AppEvent::Resumed`].
+//!
+//! ```rust,ignore
+//! event_loop.run(|event| async move {
+//!     match event {
+//!         AppEvent::Action(action) if action.name() == "edit" => ControlFlow::Suspend,
+//!         AppEvent::Suspended => {
+//!             std::process::Command::new("vi").status().ok();
+//!             ControlFlow::Continue
+//!         }
+//!         AppEvent::Resumed => {
+//!             terminal.clear().ok();
+//!             ControlFlow::Redraw
+//!         }
+//!         _ => ControlFlow::Continue,
+//!     }
+//! }).await?;
+//! ```
 
+mod backend;
 mod event_loop;
+mod metrics;
+mod mouse;
+mod render;
 mod shutdown;
 mod terminal;
 mod timing;
 
-pub use event_loop::{AppEvent, ControlFlow, EventLoop, EventLoopConfig};
-pub use shutdown::ShutdownSignal;
-pub use terminal::{restore_terminal, setup_terminal, TerminalEventStream};
-pub use timing::{Debouncer, Throttle};
+pub use backend::{Backend, BackendFuture, ChannelBackend, CrosstermTerminal, TerminalEvent};
+pub use event_loop::{
+    AppEvent, ControlFlow, EventHandler, EventLoop, EventLoopConfig, FrameOverrun, HandlerOutcome,
+    OverrunObserver, TickRateHandle,
+};
+pub use metrics::FrameMetrics;
+pub use mouse::{route_click, MouseButton, MouseEvent, MouseEventKind, ACTIVATE_ACTION};
+pub use render::RenderScheduler;
+pub use shutdown::{ShutdownSignal, SuspendHandler, SuspendSignal};
+pub use terminal::{
+    enter_terminal_modes, leave_terminal_modes, restore_terminal, restore_terminal_with,
+    resume_terminal, set_cursor_shape, setup_terminal, setup_terminal_with, setup_test_terminal,
+    supports_keyboard_enhancement, suspend_terminal, with_terminal_suspended, CursorShape,
+    TerminalEventStream, TerminalOptions,
+};
+pub use timing::{Clock, Debouncer, ManualClock, SystemClock, Throttle};
@@ -0,0 +1,239 @@
+//! Mouse event types and click routing.
+//!
+//! This module provides a [`MouseEvent`] abstraction over crossterm's mouse
+//! events, along with [`route_click`] for turning a click into focus changes
+//! and an "activate" action for the clicked component.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::event::{route_click, MouseButton, MouseEvent, MouseEventKind};
+//! use tuilib::focus::{FocusId, FocusManager};
+//! use ratatui::layout::Rect;
+//!
+//! let mut focus = FocusManager::new();
+//! focus.register(FocusId::new("ok"), 0);
+//! focus.set_area(FocusId::new("ok"), Rect::new(10, 5, 8, 3));
+//!
+//! let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 12, 6);
+//! let (id, action) = route_click(&mut focus, &click).unwrap();
+//!
+//! assert_eq!(id, FocusId::new("ok"));
+//! assert_eq!(action.name(), "activate");
+//! assert_eq!(focus.current(), Some(&FocusId::new("ok")));
+//! ```
+
+use crossterm::event::KeyModifiers;
+use ratatui::layout::{Position, Rect};
+
+use crate::focus::{FocusId, FocusManager};
+use crate::input::Action;
+
+/// The name of the action emitted when a component is clicked.
+pub const ACTIVATE_ACTION: &str = "activate";
+
+/// A mouse button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    /// The left (primary) button.
+    Left,
+    /// The right (secondary) button.
+    Right,
+    /// The middle button (usually the scroll wheel).
+    Middle,
+}
+
+impl From<crossterm::event::MouseButton> for MouseButton {
+    fn from(button: crossterm::event::MouseButton) -> Self {
+        match button {
+            crossterm::event::MouseButton::Left => MouseButton::Left,
+            crossterm::event::MouseButton::Right => MouseButton::Right,
+            crossterm::event::MouseButton::Middle => MouseButton::Middle,
+        }
+    }
+}
+
+/// The kind of mouse event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseEventKind {
+    /// A button was pressed.
+    Down(MouseButton),
+    /// A button was released.
+    Up(MouseButton),
+    /// The mouse moved while a button was held.
+    Drag(MouseButton),
+    /// The mouse moved with no button held.
+    Moved,
+    /// The wheel scrolled up.
+    ScrollUp,
+    /// The wheel scrolled down.
+    ScrollDown,
+    /// The wheel scrolled left.
+    ScrollLeft,
+    /// The wheel scrolled right.
+    ScrollRight,
+}
+
+impl From<crossterm::event::MouseEventKind> for MouseEventKind {
+    fn from(kind: crossterm::event::MouseEventKind) -> Self {
+        use crossterm::event::MouseEventKind as Kind;
+        match kind {
+            Kind::Down(button) => MouseEventKind::Down(button.into()),
+            Kind::Up(button) => MouseEventKind::Up(button.into()),
+            Kind::Drag(button) => MouseEventKind::Drag(button.into()),
+            Kind::Moved => MouseEventKind::Moved,
+            Kind::ScrollUp => MouseEventKind::ScrollUp,
+            Kind::ScrollDown => MouseEventKind::ScrollDown,
+            Kind::ScrollLeft => MouseEventKind::ScrollLeft,
+            Kind::ScrollRight => MouseEventKind::ScrollRight,
+        }
+    }
+}
+
+/// A mouse event at a terminal cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MouseEvent {
+    /// What happened.
+    pub kind: MouseEventKind,
+    /// The column (x coordinate) of the event.
+    pub column: u16,
+    /// The row (y coordinate) of the event.
+    pub row: u16,
+    /// Keyboard modifiers held during the event.
+    pub modifiers: KeyModifiers,
+}
+
+impl MouseEvent {
+    /// Creates a mouse event with no modifiers.
+    pub fn new(kind: MouseEventKind, column: u16, row: u16) -> Self {
+        Self {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    /// Sets the modifiers held during the event.
+    pub fn with_modifiers(mut self, modifiers: KeyModifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Returns the position of the event.
+    pub fn position(&self) -> Position {
+        Position::new(self.column, self.row)
+    }
+
+    /// Returns true if this is a left-button press.
+    pub fn is_click(&self) -> bool {
+        self.kind == MouseEventKind::Down(MouseButton::Left)
+    }
+
+    /// Returns true if this is a scroll event.
+    pub fn is_scroll(&self) -> bool {
+        matches!(
+            self.kind,
+            MouseEventKind::ScrollUp
+                | MouseEventKind::ScrollDown
+                | MouseEventKind::ScrollLeft
+                | MouseEventKind::ScrollRight
+        )
+    }
+
+    /// Returns true if the event occurred within the given area.
+    pub fn is_inside(&self, area: Rect) -> bool {
+        area.contains(self.position())
+    }
+}
+
+impl From<crossterm::event::MouseEvent> for MouseEvent {
+    fn from(event: crossterm::event::MouseEvent) -> Self {
+        Self {
+            kind: event.kind.into(),
+            column: event.column,
+            row: event.row,
+            modifiers: event.modifiers,
+        }
+    }
+}
+
+/// Routes a mouse click to the component under the cursor.
+///
+/// If the event is a left click on a component whose area is registered with
+/// [`FocusManager::set_area`], that component receives focus and an
+/// [`ACTIVATE_ACTION`] action is returned for it.
+///
+/// Returns `None` for other mouse events, or when no focusable component is
+/// under the cursor.
+pub fn route_click(manager: &mut FocusManager, event: &MouseEvent) -> Option<(FocusId, Action)> {
+    if !event.is_click() {
+        return None;
+    }
+
+    let id = manager.focus_at(event.column, event.row)?;
+    Some((id, Action::new(ACTIVATE_ACTION)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_crossterm() {
+        let event = crossterm::event::MouseEvent {
+            kind: crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::Right),
+            column: 3,
+            row: 4,
+            modifiers: KeyModifiers::SHIFT,
+        };
+
+        let mouse = MouseEvent::from(event);
+        assert_eq!(mouse.kind, MouseEventKind::Down(MouseButton::Right));
+        assert_eq!(mouse.position(), Position::new(3, 4));
+        assert_eq!(mouse.modifiers, KeyModifiers::SHIFT);
+        assert!(!mouse.is_click());
+    }
+
+    #[test]
+    fn test_mouse_event_helpers() {
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 5, 5);
+        assert!(click.is_click());
+        assert!(!click.is_scroll());
+        assert!(click.is_inside(Rect::new(0, 0, 10, 10)));
+        assert!(!click.is_inside(Rect::new(6, 0, 10, 10)));
+
+        let scroll = MouseEvent::new(MouseEventKind::ScrollDown, 0, 0);
+        assert!(scroll.is_scroll());
+    }
+
+    #[test]
+    fn test_route_click_focuses_component() {
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("a"), 0);
+        manager.register(FocusId::new("b"), 0);
+        manager.set_area(FocusId::new("a"), Rect::new(0, 0, 10, 3));
+        manager.set_area(FocusId::new("b"), Rect::new(0, 3, 10, 3));
+
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 2, 4);
+        let (id, action) = route_click(&mut manager, &click).unwrap();
+
+        assert_eq!(id, FocusId::new("b"));
+        assert_eq!(action.name(), ACTIVATE_ACTION);
+        assert_eq!(manager.current(), Some(&FocusId::new("b")));
+    }
+
+    #[test]
+    fn test_route_click_ignores_misses_and_other_buttons() {
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("a"), 0);
+        manager.set_area(FocusId::new("a"), Rect::new(0, 0, 10, 3));
+
+        let miss = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 20, 20);
+        assert!(route_click(&mut manager, &miss).is_none());
+
+        let right = MouseEvent::new(MouseEventKind::Down(MouseButton::Right), 1, 1);
+        assert!(route_click(&mut manager, &right).is_none());
+        assert!(manager.current().is_none());
+    }
+}
@@ -0,0 +1,101 @@
+//! Render-on-change scheduling.
+//!
+//! This module provides [`RenderScheduler`], a shared dirty flag that lets an
+//! application redraw only when something has changed instead of on every
+//! tick. The event loop checks the flag after each tick and, if it is set,
+//! delivers a single [`AppEvent::Render`](super::AppEvent::Render) to the
+//! handler, so any number of change signals between ticks coalesce into one
+//! redraw.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::event::RenderScheduler;
+//!
+//! let scheduler = RenderScheduler::new();
+//! assert!(!scheduler.is_dirty());
+//!
+//! // Any clone can mark the UI as needing a redraw
+//! let handle = scheduler.clone();
+//! handle.force_redraw();
+//! handle.force_redraw();
+//!
+//! // The flag is consumed once, no matter how many times it was set
+//! assert!(scheduler.take_dirty());
+//! assert!(!scheduler.take_dirty());
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A shared dirty flag used to request redraws.
+///
+/// Cloning a `RenderScheduler` produces another handle to the same flag, so
+/// async tasks can request a redraw without going through the event channel.
+#[derive(Debug, Clone, Default)]
+pub struct RenderScheduler {
+    dirty: Arc<AtomicBool>,
+}
+
+impl RenderScheduler {
+    /// Creates a new scheduler with no pending redraw.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the UI as needing a redraw on the next tick.
+    pub fn mark_dirty(&self) {
+        self.dirty.store(true, Ordering::Release);
+    }
+
+    /// Requests a redraw from outside the event handler.
+    ///
+    /// This is equivalent to [`mark_dirty`](Self::mark_dirty) and is intended
+    /// for async tasks holding a clone of the scheduler.
+    pub fn force_redraw(&self) {
+        self.mark_dirty();
+    }
+
+    /// Returns true if a redraw is pending.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.load(Ordering::Acquire)
+    }
+
+    /// Clears the dirty flag, returning whether a redraw was pending.
+    pub fn take_dirty(&self) -> bool {
+        self.dirty.swap(false, Ordering::AcqRel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_is_clean() {
+        let scheduler = RenderScheduler::new();
+        assert!(!scheduler.is_dirty());
+        assert!(!scheduler.take_dirty());
+    }
+
+    #[test]
+    fn test_mark_dirty_coalesces() {
+        let scheduler = RenderScheduler::new();
+        scheduler.mark_dirty();
+        scheduler.mark_dirty();
+
+        assert!(scheduler.is_dirty());
+        assert!(scheduler.take_dirty());
+        assert!(!scheduler.is_dirty());
+    }
+
+    #[test]
+    fn test_clones_share_flag() {
+        let scheduler = RenderScheduler::new();
+        let handle = scheduler.clone();
+
+        handle.force_redraw();
+        assert!(scheduler.take_dirty());
+        assert!(!handle.is_dirty());
+    }
+}
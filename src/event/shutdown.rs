@@ -1,9 +1,15 @@
 //! Graceful shutdown signal handling.
 //!
 //! This module provides utilities for handling SIGINT and SIGTERM signals
-//! to enable graceful application shutdown.
+//! to enable graceful application shutdown, and SIGTSTP (Ctrl+Z) to
+//! suspend the application to the shell.
 
 use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use super::backend::TerminalEvent;
+use super::terminal::{resume_terminal, suspend_terminal, TerminalOptions};
 
 /// A signal handler for graceful shutdown.
 ///
@@ -125,9 +131,260 @@ impl std::fmt::Debug for ShutdownSignal {
     }
 }
 
+/// A signal listener for suspend requests (SIGTSTP).
+///
+/// Note that while the terminal is in raw mode, pressing Ctrl+Z produces a
+/// key event rather than SIGTSTP; this listener covers signals sent by other
+/// means such as `kill -TSTP`.
+#[cfg(unix)]
+pub struct SuspendSignal {
+    sigtstp: tokio::signal::unix::Signal,
+}
+
+#[cfg(unix)]
+impl SuspendSignal {
+    /// Creates a new suspend signal listener.
+    ///
+    /// Registering the listener replaces the default SIGTSTP behavior, so the
+    /// process no longer stops on its own; use a [`SuspendHandler`] to stop it.
+    pub fn new() -> io::Result<Self> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let sigtstp = signal(SignalKind::from_raw(signal_hook::consts::SIGTSTP))?;
+        Ok(Self { sigtstp })
+    }
+
+    /// Waits for a SIGTSTP signal.
+    pub async fn recv(&mut self) {
+        self.sigtstp.recv().await;
+        tracing::debug!("SIGTSTP received");
+    }
+}
+
+#[cfg(unix)]
+impl std::fmt::Debug for SuspendSignal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SuspendSignal").finish()
+    }
+}
+
+/// Windows has no job control; the listener never fires.
+#[cfg(windows)]
+#[derive(Debug)]
+pub struct SuspendSignal;
+
+#[cfg(windows)]
+impl SuspendSignal {
+    /// Creates a new suspend signal listener.
+    pub fn new() -> io::Result<Self> {
+        Ok(Self)
+    }
+
+    /// Waits forever, since suspend signals do not exist on Windows.
+    pub async fn recv(&mut self) {
+        std::future::pending::<()>().await
+    }
+}
+
+/// Type alias for suspend lifecycle hooks.
+type SuspendHook = Box<dyn FnMut() -> io::Result<()> + Send>;
+
+/// Suspends the process to the shell, restoring the terminal around the stop.
+///
+/// [`suspend`](Self::suspend) runs three steps in order:
+///
+/// 1. Restore the terminal to normal mode
+/// 2. Stop the process with the default SIGTSTP behavior (returns once the
+///    shell sends SIGCONT, e.g. via `fg`)
+/// 3. Re-enter TUI mode
+///
+/// Each step can be replaced with [`SuspendHandler::with_hooks`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+/// use tuilib::event::SuspendHandler;
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let (a, b, c) = (log.clone(), log.clone(), log.clone());
+///
+/// let mut handler = SuspendHandler::with_hooks(
+///     move || Ok(a.lock().unwrap().push("restore")),
+///     move || Ok(b.lock().unwrap().push("stop")),
+///     move || Ok(c.lock().unwrap().push("setup")),
+/// );
+/// handler.suspend().unwrap();
+///
+/// assert_eq!(*log.lock().unwrap(), ["restore", "stop", "setup"]);
+/// ```
+pub struct SuspendHandler {
+    on_suspend: SuspendHook,
+    stop: SuspendHook,
+    on_resume: SuspendHook,
+}
+
+impl SuspendHandler {
+    /// Creates a handler that restores and re-enters the terminal on stdout.
+    pub fn new(options: TerminalOptions) -> Self {
+        let raw_mode = Arc::new(AtomicBool::new(false));
+        let raw_mode_resume = raw_mode.clone();
+
+        Self::with_hooks(
+            move || {
+                let was_raw = suspend_terminal(&mut io::stdout(), &options)?;
+                raw_mode.store(was_raw, Ordering::Release);
+                Ok(())
+            },
+            stop_process,
+            move || {
+                resume_terminal(
+                    &mut io::stdout(),
+                    &options,
+                    raw_mode_resume.load(Ordering::Acquire),
+                )
+            },
+        )
+    }
+
+    /// Creates a handler with custom hooks for each step.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_suspend` - Restores the terminal before stopping
+    /// * `stop` - Stops the process until it is continued
+    /// * `on_resume` - Re-initializes the terminal after continuing
+    pub fn with_hooks<S, P, R>(on_suspend: S, stop: P, on_resume: R) -> Self
+    where
+        S: FnMut() -> io::Result<()> + Send + 'static,
+        P: FnMut() -> io::Result<()> + Send + 'static,
+        R: FnMut() -> io::Result<()> + Send + 'static,
+    {
+        Self {
+            on_suspend: Box::new(on_suspend),
+            stop: Box::new(stop),
+            on_resume: Box::new(on_resume),
+        }
+    }
+
+    /// Restores the terminal, stops the process, and re-enters TUI mode.
+    ///
+    /// The terminal is re-entered even if stopping fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from any of the steps.
+    pub fn suspend(&mut self) -> io::Result<()> {
+        (self.on_suspend)()?;
+        let stopped = (self.stop)();
+        (self.on_resume)()?;
+        stopped
+    }
+}
+
+impl std::fmt::Debug for SuspendHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SuspendHandler").finish()
+    }
+}
+
+/// Stops the current process as the default SIGTSTP handler would.
+///
+/// Returns once the process receives SIGCONT.
+#[cfg(unix)]
+fn stop_process() -> io::Result<()> {
+    signal_hook::low_level::emulate_default_handler(signal_hook::consts::SIGTSTP)
+}
+
+/// Windows has no job control, so stopping is a no-op.
+#[cfg(windows)]
+fn stop_process() -> io::Result<()> {
+    Ok(())
+}
+
+/// Returns true if a terminal event is the Ctrl+Z suspend key.
+pub(crate) fn is_suspend_key(event: &TerminalEvent) -> bool {
+    use terminput::{KeyCode, KeyEventKind, KeyModifiers};
+
+    matches!(
+        event,
+        TerminalEvent::Key(key)
+            if key.kind == KeyEventKind::Press
+                && key.code == KeyCode::Char('z')
+                && key.modifiers.contains(KeyModifiers::CTRL)
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
+
+    fn recording_handler(fail_stop: bool) -> (SuspendHandler, Arc<Mutex<Vec<&'static str>>>) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (a, b, c) = (log.clone(), log.clone(), log.clone());
+        let handler = SuspendHandler::with_hooks(
+            move || {
+                a.lock().unwrap().push("restore");
+                Ok(())
+            },
+            move || {
+                b.lock().unwrap().push("stop");
+                if fail_stop {
+                    Err(io::Error::other("stop failed"))
+                } else {
+                    Ok(())
+                }
+            },
+            move || {
+                c.lock().unwrap().push("setup");
+                Ok(())
+            },
+        );
+        (handler, log)
+    }
+
+    #[test]
+    fn test_suspend_handler_hook_order() {
+        let (mut handler, log) = recording_handler(false);
+        handler.suspend().unwrap();
+        assert_eq!(*log.lock().unwrap(), ["restore", "stop", "setup"]);
+    }
+
+    #[test]
+    fn test_suspend_handler_resumes_after_stop_error() {
+        let (mut handler, log) = recording_handler(true);
+        assert!(handler.suspend().is_err());
+        assert_eq!(*log.lock().unwrap(), ["restore", "stop", "setup"]);
+    }
+
+    #[test]
+    fn test_is_suspend_key() {
+        use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+
+        let key = |modifiers| {
+            TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Char('z'),
+                modifiers,
+                kind: KeyEventKind::Press,
+                state: KeyEventState::NONE,
+            })
+        };
+        assert!(is_suspend_key(&key(KeyModifiers::CTRL)));
+        assert!(!is_suspend_key(&key(KeyModifiers::NONE)));
+        assert!(!is_suspend_key(&TerminalEvent::FocusLost));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_suspend_signal_creation() {
+        let mut signal = SuspendSignal::new().unwrap();
+        assert!(format!("{:?}", signal).contains("SuspendSignal"));
+
+        let result =
+            tokio::time::timeout(std::time::Duration::from_millis(10), signal.recv()).await;
+        assert!(result.is_err());
+    }
 
     #[cfg(unix)]
     #[tokio::test]
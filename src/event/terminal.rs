@@ -4,6 +4,11 @@
 
 use std::time::Duration;
 
+use tokio::task::JoinHandle;
+
+/// A poll of the terminal running on the blocking thread pool.
+type PendingPoll = JoinHandle<std::io::Result<Option<crossterm::event::Event>>>;
+
 /// An async stream of terminal events.
 ///
 /// Wraps crossterm's event polling in an async-friendly interface
@@ -26,14 +31,14 @@ use std::time::Duration;
 pub struct TerminalEventStream {
     /// Polling timeout for non-blocking event checks.
     poll_timeout: Duration,
+    /// A poll left running by a cancelled call to `next`.
+    pending: Option<PendingPoll>,
 }
 
 impl TerminalEventStream {
     /// Creates a new terminal event stream.
     pub fn new() -> Self {
-        Self {
-            poll_timeout: Duration::from_millis(10),
-        }
+        Self::with_timeout(Duration::from_millis(10))
     }
 
     /// Creates a terminal event stream with a custom poll timeout.
@@ -44,12 +49,15 @@ impl TerminalEventStream {
     pub fn with_timeout(timeout: Duration) -> Self {
         Self {
             poll_timeout: timeout,
+            pending: None,
         }
     }
 
     /// Attempts to get the next terminal event.
     ///
-    /// This method is cancel-safe and can be used in tokio::select!
+    /// This method is cancel-safe and can be used in tokio::select!: a poll
+    /// interrupted by cancellation is picked up again by the next call, so
+    /// no event is lost.
     ///
     /// # Returns
     ///
@@ -60,14 +68,17 @@ impl TerminalEventStream {
         loop {
             // Use spawn_blocking to poll in a thread pool
             let timeout = self.poll_timeout;
-            let result = tokio::task::spawn_blocking(move || {
-                if crossterm::event::poll(timeout)? {
-                    crossterm::event::read().map(Some)
-                } else {
-                    Ok(None)
-                }
-            })
-            .await;
+            let poll = self.pending.get_or_insert_with(|| {
+                tokio::task::spawn_blocking(move || {
+                    if crossterm::event::poll(timeout)? {
+                        crossterm::event::read().map(Some)
+                    } else {
+                        Ok(None)
+                    }
+                })
+            });
+            let result = poll.await;
+            self.pending = None;
 
             match result {
                 Ok(Ok(Some(event))) => return Some(Ok(event)),
@@ -96,6 +107,17 @@ impl TerminalEventStream {
     pub fn set_poll_timeout(&mut self, timeout: Duration) {
         self.poll_timeout = timeout;
     }
+
+    /// Closes the stream, waiting for any poll still in progress.
+    ///
+    /// Once this returns the terminal is no longer read, so another process
+    /// can take over its input. An event read by the last poll is
+    /// discarded.
+    pub async fn close(mut self) {
+        if let Some(poll) = self.pending.take() {
+            let _ = poll.await;
+        }
+    }
 }
 
 impl Default for TerminalEventStream {
@@ -108,16 +130,301 @@ impl std::fmt::Debug for TerminalEventStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TerminalEventStream")
             .field("poll_timeout", &self.poll_timeout)
+            .field("polling", &self.pending.is_some())
             .finish()
     }
 }
 
+/// Options controlling which terminal features are enabled during setup.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::event::TerminalOptions;
+///
+/// // Leave mouse events to the terminal (e.g. for native text selection)
+/// let options = TerminalOptions::new().mouse_capture(false);
+/// assert!(!options.mouse_capture);
+/// assert!(options.bracketed_paste);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalOptions {
+    /// Whether to capture mouse events.
+    pub mouse_capture: bool,
+    /// Whether to enable bracketed paste, delivering pastes as a single event.
+    pub bracketed_paste: bool,
+    /// Whether to enable the Kitty keyboard protocol, which reports key
+    /// releases.
+    pub keyboard_enhancement: bool,
+}
+
+impl TerminalOptions {
+    /// Creates options with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to capture mouse events.
+    pub fn mouse_capture(mut self, capture: bool) -> Self {
+        self.mouse_capture = capture;
+        self
+    }
+
+    /// Sets whether to enable bracketed paste.
+    pub fn bracketed_paste(mut self, enabled: bool) -> Self {
+        self.bracketed_paste = enabled;
+        self
+    }
+
+    /// Sets whether to enable the Kitty keyboard protocol.
+    ///
+    /// When enabled, the terminal is asked to disambiguate escape codes and
+    /// report key repeats and releases, which
+    /// [`InputMatcher`](crate::input::InputMatcher) chords need. Only enable
+    /// it if [`supports_keyboard_enhancement`] returns `true`; the legacy
+    /// Windows console cannot enable it at all and setup fails there.
+    /// Disabled by default.
+    pub fn keyboard_enhancement(mut self, enabled: bool) -> Self {
+        self.keyboard_enhancement = enabled;
+        self
+    }
+}
+
+impl Default for TerminalOptions {
+    fn default() -> Self {
+        Self {
+            mouse_capture: true,
+            bracketed_paste: true,
+            keyboard_enhancement: false,
+        }
+    }
+}
+
+/// The shape of the terminal cursor.
+///
+/// Terminals that do not support changing the cursor shape ignore it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CursorShape {
+    /// The shape configured by the user in their terminal.
+    #[default]
+    Default,
+    /// A blinking block.
+    BlinkingBlock,
+    /// A steady block.
+    SteadyBlock,
+    /// A blinking underline.
+    BlinkingUnderline,
+    /// A steady underline.
+    SteadyUnderline,
+    /// A blinking vertical bar.
+    BlinkingBar,
+    /// A steady vertical bar.
+    SteadyBar,
+}
+
+impl From<CursorShape> for crossterm::cursor::SetCursorStyle {
+    fn from(shape: CursorShape) -> Self {
+        use crossterm::cursor::SetCursorStyle;
+
+        match shape {
+            CursorShape::Default => SetCursorStyle::DefaultUserShape,
+            CursorShape::BlinkingBlock => SetCursorStyle::BlinkingBlock,
+            CursorShape::SteadyBlock => SetCursorStyle::SteadyBlock,
+            CursorShape::BlinkingUnderline => SetCursorStyle::BlinkingUnderScore,
+            CursorShape::SteadyUnderline => SetCursorStyle::SteadyUnderScore,
+            CursorShape::BlinkingBar => SetCursorStyle::BlinkingBar,
+            CursorShape::SteadyBar => SetCursorStyle::SteadyBar,
+        }
+    }
+}
+
+/// Writes the escape sequence (DECSCUSR) that sets the cursor shape.
+///
+/// Rendering can only position the cursor, so applications call this after
+/// drawing, typically with the focused input's
+/// [`cursor_style`](crate::components::TextInput::cursor_style).
+/// [`leave_terminal_modes`] restores the default shape.
+///
+/// # Errors
+///
+/// Returns an IO error if writing fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::event::{set_cursor_shape, CursorShape};
+///
+/// let mut output = Vec::new();
+/// set_cursor_shape(&mut output, CursorShape::SteadyBar).unwrap();
+/// assert_eq!(output, b"\x1b[6 q");
+/// ```
+pub fn set_cursor_shape<W: std::io::Write>(
+    writer: &mut W,
+    shape: CursorShape,
+) -> std::io::Result<()> {
+    crossterm::execute!(writer, crossterm::cursor::SetCursorStyle::from(shape))
+}
+
+/// Returns `true` if the terminal supports the Kitty keyboard protocol.
+///
+/// Terminals with enhanced keyboard support can report key releases, which
+/// lets the [`InputMatcher`](crate::input::InputMatcher) recognise keys held
+/// down together as a [`KeyChord`](crate::input::KeyChord). Detecting
+/// support does not turn the protocol on: set
+/// [`TerminalOptions::keyboard_enhancement`] so that
+/// [`enter_terminal_modes`] enables it. The terminal is queried, so call
+/// this after raw mode is enabled; any error is treated as no support.
+pub fn supports_keyboard_enhancement() -> bool {
+    crossterm::terminal::supports_keyboard_enhancement().unwrap_or(false)
+}
+
+/// Writes the escape sequences that switch the terminal into TUI mode.
+///
+/// This enters the alternate screen and enables the features selected in
+/// `options`. It does not enable raw mode, which is a property of the TTY
+/// rather than something written to the output stream.
+///
+/// # Errors
+///
+/// Returns an IO error if writing fails.
+pub fn enter_terminal_modes<W: std::io::Write>(
+    writer: &mut W,
+    options: &TerminalOptions,
+) -> std::io::Result<()> {
+    crossterm::execute!(writer, crossterm::terminal::EnterAlternateScreen)?;
+    if options.keyboard_enhancement {
+        // Pushed after entering the alternate screen, which keeps its own
+        // stack of flags in Kitty
+        crossterm::execute!(
+            writer,
+            crossterm::event::PushKeyboardEnhancementFlags(
+                crossterm::event::KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                    | crossterm::event::KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+            )
+        )?;
+    }
+    if options.mouse_capture {
+        crossterm::execute!(writer, crossterm::event::EnableMouseCapture)?;
+    }
+    if options.bracketed_paste {
+        crossterm::execute!(writer, crossterm::event::EnableBracketedPaste)?;
+    }
+    Ok(())
+}
+
+/// Writes the escape sequences that undo [`enter_terminal_modes`].
+///
+/// The cursor shape is also reset to the user's default.
+///
+/// # Errors
+///
+/// Returns an IO error if writing fails.
+pub fn leave_terminal_modes<W: std::io::Write>(
+    writer: &mut W,
+    options: &TerminalOptions,
+) -> std::io::Result<()> {
+    if options.bracketed_paste {
+        crossterm::execute!(writer, crossterm::event::DisableBracketedPaste)?;
+    }
+    if options.mouse_capture {
+        crossterm::execute!(writer, crossterm::event::DisableMouseCapture)?;
+    }
+    if options.keyboard_enhancement {
+        crossterm::execute!(writer, crossterm::event::PopKeyboardEnhancementFlags)?;
+    }
+    set_cursor_shape(writer, CursorShape::Default)?;
+    crossterm::execute!(writer, crossterm::terminal::LeaveAlternateScreen)?;
+    Ok(())
+}
+
+/// Temporarily returns the terminal to normal mode.
+///
+/// Leaves the modes entered by [`enter_terminal_modes`], shows the cursor,
+/// and disables raw mode if it is enabled. Returns whether raw mode was
+/// enabled, which should be passed to [`resume_terminal`].
+///
+/// # Errors
+///
+/// Returns an IO error if writing fails or raw mode cannot be changed.
+pub fn suspend_terminal<W: std::io::Write>(
+    writer: &mut W,
+    options: &TerminalOptions,
+) -> std::io::Result<bool> {
+    leave_terminal_modes(writer, options)?;
+    crossterm::execute!(writer, crossterm::cursor::Show)?;
+    let raw_mode = crossterm::terminal::is_raw_mode_enabled()?;
+    if raw_mode {
+        crossterm::terminal::disable_raw_mode()?;
+    }
+    Ok(raw_mode)
+}
+
+/// Re-enters TUI mode after [`suspend_terminal`].
+///
+/// Raw mode is re-enabled only if it was enabled before suspending. The
+/// alternate screen starts out blank, so applications should clear their
+/// `Terminal` (e.g. `terminal.clear()`) and redraw after resuming.
+///
+/// # Errors
+///
+/// Returns an IO error if writing fails or raw mode cannot be changed.
+pub fn resume_terminal<W: std::io::Write>(
+    writer: &mut W,
+    options: &TerminalOptions,
+    raw_mode: bool,
+) -> std::io::Result<()> {
+    if raw_mode {
+        crossterm::terminal::enable_raw_mode()?;
+    }
+    enter_terminal_modes(writer, options)?;
+    crossterm::execute!(writer, crossterm::cursor::Hide)?;
+    Ok(())
+}
+
+/// Runs a closure with the terminal temporarily restored to normal mode.
+///
+/// This is useful for handing the terminal to a subprocess such as
+/// `$EDITOR`. The terminal is re-entered even if the closure's result is an
+/// error.
+///
+/// # Errors
+///
+/// Returns an IO error if suspending or resuming the terminal fails.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use tuilib::event::{with_terminal_suspended, TerminalOptions};
+///
+/// let status = with_terminal_suspended(&mut std::io::stdout(), &TerminalOptions::new(), || {
+///     std::process::Command::new("vi").arg("notes.txt").status()
+/// })?;
+/// terminal.clear()?;
+/// ```
+pub fn with_terminal_suspended<W, T, F>(
+    writer: &mut W,
+    options: &TerminalOptions,
+    f: F,
+) -> std::io::Result<T>
+where
+    W: std::io::Write,
+    F: FnOnce() -> T,
+{
+    let raw_mode = suspend_terminal(writer, options)?;
+    let result = f();
+    resume_terminal(writer, options, raw_mode)?;
+    Ok(result)
+}
+
 /// Sets up the terminal for a TUI application.
 ///
 /// This function performs the standard terminal setup sequence:
 /// - Enables raw mode (disabling line buffering and echoing)
 /// - Switches to the alternate screen buffer
 /// - Enables mouse capture
+/// - Enables bracketed paste
+///
+/// Use [`setup_terminal_with`] to control which features are enabled.
 ///
 /// # Returns
 ///
@@ -136,14 +443,30 @@ impl std::fmt::Debug for TerminalEventStream {
 /// // Use terminal for rendering...
 /// ```
 pub fn setup_terminal(
+) -> std::io::Result<ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>> {
+    setup_terminal_with(TerminalOptions::default())
+}
+
+/// Sets up the terminal for a TUI application with the given options.
+///
+/// # Errors
+///
+/// Returns an IO error if terminal setup fails.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use tuilib::event::{setup_terminal_with, EventLoopConfig};
+///
+/// let config = EventLoopConfig::new().capture_mouse(false);
+/// let mut terminal = setup_terminal_with(config.terminal_options())?;
+/// ```
+pub fn setup_terminal_with(
+    options: TerminalOptions,
 ) -> std::io::Result<ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>> {
     crossterm::terminal::enable_raw_mode()?;
     let mut stdout = std::io::stdout();
-    crossterm::execute!(
-        stdout,
-        crossterm::terminal::EnterAlternateScreen,
-        crossterm::event::EnableMouseCapture
-    )?;
+    enter_terminal_modes(&mut stdout, &options)?;
     let backend = ratatui::backend::CrosstermBackend::new(stdout);
     ratatui::Terminal::new(backend)
 }
@@ -154,6 +477,7 @@ pub fn setup_terminal(
 /// - Disables raw mode
 /// - Leaves the alternate screen buffer
 /// - Disables mouse capture
+/// - Disables bracketed paste
 /// - Shows the cursor
 ///
 /// # Arguments
@@ -175,17 +499,58 @@ pub fn setup_terminal(
 /// ```
 pub fn restore_terminal(
     terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+) -> std::io::Result<()> {
+    restore_terminal_with(terminal, TerminalOptions::default())
+}
+
+/// Restores a terminal that was set up with [`setup_terminal_with`].
+///
+/// Pass the same options used during setup so that exactly the enabled
+/// features are disabled again.
+///
+/// # Errors
+///
+/// Returns an IO error if terminal restoration fails.
+pub fn restore_terminal_with(
+    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    options: TerminalOptions,
 ) -> std::io::Result<()> {
     crossterm::terminal::disable_raw_mode()?;
-    crossterm::execute!(
-        terminal.backend_mut(),
-        crossterm::terminal::LeaveAlternateScreen,
-        crossterm::event::DisableMouseCapture
-    )?;
+    leave_terminal_modes(terminal.backend_mut(), &options)?;
     terminal.show_cursor()?;
     Ok(())
 }
 
+/// Creates an in-memory terminal of the given size.
+///
+/// The terminal uses ratatui's `TestBackend`, so application rendering can
+/// be driven and inspected in tests without a real terminal. Pair it with
+/// [`EventLoop::run_with_backend`](super::EventLoop::run_with_backend) and a
+/// [`ChannelBackend`](super::ChannelBackend) to feed keys and resizes through
+/// the same path as terminal input.
+///
+/// # Errors
+///
+/// Returns an IO error if the terminal cannot be created.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::event::setup_test_terminal;
+///
+/// let mut terminal = setup_test_terminal(20, 2).unwrap();
+/// terminal
+///     .draw(|frame| frame.render_widget("hello", frame.area()))
+///     .unwrap();
+/// assert_eq!(terminal.backend().buffer()[(0, 0)].symbol(), "h");
+/// ```
+pub fn setup_test_terminal(
+    width: u16,
+    height: u16,
+) -> std::io::Result<ratatui::Terminal<ratatui::backend::TestBackend>> {
+    ratatui::Terminal::new(ratatui::backend::TestBackend::new(width, height))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,4 +587,153 @@ mod tests {
         assert!(debug_str.contains("TerminalEventStream"));
         assert!(debug_str.contains("poll_timeout"));
     }
+
+    const ENABLE_MOUSE: &str = "\x1b[?1000h";
+    const DISABLE_MOUSE: &str = "\x1b[?1000l";
+
+    #[test]
+    fn test_enter_modes_enables_mouse_capture() {
+        let mut buffer = Vec::new();
+        enter_terminal_modes(&mut buffer, &TerminalOptions::new()).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert!(output.contains("\x1b[?1049h"));
+        assert!(output.contains(ENABLE_MOUSE));
+    }
+
+    #[test]
+    fn test_enter_modes_without_mouse_capture() {
+        let mut buffer = Vec::new();
+        let options = TerminalOptions::new().mouse_capture(false);
+        enter_terminal_modes(&mut buffer, &options).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert!(output.contains("\x1b[?1049h"));
+        assert!(!output.contains(ENABLE_MOUSE));
+    }
+
+    #[test]
+    fn test_leave_modes_restores_mouse_capture() {
+        let mut buffer = Vec::new();
+        leave_terminal_modes(&mut buffer, &TerminalOptions::new()).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert!(output.contains(DISABLE_MOUSE));
+        assert!(output.contains("\x1b[?1049l"));
+
+        let mut buffer = Vec::new();
+        let options = TerminalOptions::new().mouse_capture(false);
+        leave_terminal_modes(&mut buffer, &options).unwrap();
+        assert!(!String::from_utf8(buffer).unwrap().contains(DISABLE_MOUSE));
+    }
+
+    #[test]
+    fn test_cursor_shapes() {
+        let cases = [
+            (CursorShape::Default, "\x1b[0 q"),
+            (CursorShape::BlinkingBlock, "\x1b[1 q"),
+            (CursorShape::SteadyUnderline, "\x1b[4 q"),
+            (CursorShape::BlinkingBar, "\x1b[5 q"),
+        ];
+        for (shape, expected) in cases {
+            let mut buffer = Vec::new();
+            set_cursor_shape(&mut buffer, shape).unwrap();
+            assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+        }
+
+        // Leaving TUI mode restores the default shape
+        let mut buffer = Vec::new();
+        leave_terminal_modes(&mut buffer, &TerminalOptions::new()).unwrap();
+        assert!(String::from_utf8(buffer).unwrap().contains("\x1b[0 q"));
+    }
+
+    #[test]
+    fn test_bracketed_paste_modes() {
+        let mut buffer = Vec::new();
+        enter_terminal_modes(&mut buffer, &TerminalOptions::new()).unwrap();
+        assert!(String::from_utf8(buffer).unwrap().contains("\x1b[?2004h"));
+
+        let mut buffer = Vec::new();
+        leave_terminal_modes(&mut buffer, &TerminalOptions::new()).unwrap();
+        assert!(String::from_utf8(buffer).unwrap().contains("\x1b[?2004l"));
+
+        let mut buffer = Vec::new();
+        let options = TerminalOptions::new().bracketed_paste(false);
+        enter_terminal_modes(&mut buffer, &options).unwrap();
+        assert!(!String::from_utf8(buffer).unwrap().contains("\x1b[?2004h"));
+    }
+
+    #[test]
+    fn test_keyboard_enhancement_modes() {
+        const PUSH: &str = "\x1b[>3u";
+        const POP: &str = "\x1b[<1u";
+
+        let mut buffer = Vec::new();
+        enter_terminal_modes(&mut buffer, &TerminalOptions::new()).unwrap();
+        leave_terminal_modes(&mut buffer, &TerminalOptions::new()).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(!output.contains(PUSH) && !output.contains(POP));
+
+        // Flags are pushed inside the alternate screen and popped before
+        // leaving it
+        let options = TerminalOptions::new().keyboard_enhancement(true);
+        let mut buffer = Vec::new();
+        enter_terminal_modes(&mut buffer, &options).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.find("\x1b[?1049h").unwrap() < output.find(PUSH).unwrap());
+
+        let mut buffer = Vec::new();
+        leave_terminal_modes(&mut buffer, &options).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.find(POP).unwrap() < output.find("\x1b[?1049l").unwrap());
+
+        // Suspending pops the flags and resuming pushes them again
+        let buffer = SharedBuffer::default();
+        let mut writer = buffer.clone();
+        with_terminal_suspended(&mut writer, &options, || {
+            buffer.0.borrow_mut().extend_from_slice(b"<editor>");
+        })
+        .unwrap();
+        let output = String::from_utf8(buffer.0.borrow().clone()).unwrap();
+        let closure = output.find("<editor>").unwrap();
+        assert!(output[..closure].contains(POP));
+        assert!(output[closure..].contains(PUSH));
+    }
+
+    /// A writer that shares its buffer so a closure can append to it.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(std::rc::Rc<std::cell::RefCell<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_suspend_brackets_closure() {
+        let buffer = SharedBuffer::default();
+        let mut writer = buffer.clone();
+
+        let result = with_terminal_suspended(&mut writer, &TerminalOptions::new(), || {
+            buffer.0.borrow_mut().extend_from_slice(b"<editor>");
+            42
+        })
+        .unwrap();
+        assert_eq!(result, 42);
+
+        let output = String::from_utf8(buffer.0.borrow().clone()).unwrap();
+        let leave = output.find("\x1b[?1049l").unwrap();
+        let closure = output.find("<editor>").unwrap();
+        let enter = output.find("\x1b[?1049h").unwrap();
+        assert!(leave < closure && closure < enter, "{output:?}");
+
+        // Mouse capture is disabled while suspended and restored afterwards
+        assert!(output[..closure].contains(DISABLE_MOUSE));
+        assert!(output[closure..].contains(ENABLE_MOUSE));
+    }
 }
@@ -2,9 +2,100 @@
 //!
 //! This module provides utilities to control the rate of event processing,
 //! preventing rapid duplicate events from overwhelming the UI.
+//!
+//! Both [`Debouncer`] and [`Throttle`] can carry a payload: push values in
+//! as they arrive and take them out with `try_emit` (or await `next`) once
+//! the timing rules allow. Time is read from a [`Clock`], which defaults to
+//! the system clock and can be replaced with a [`ManualClock`] in tests.
+//!
+//! # Example
+//!
+//! Debounced search-as-you-type:
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use tuilib::event::{Debouncer, ManualClock};
+//!
+//! let clock = ManualClock::new();
+//! let mut search = Debouncer::with_clock(Duration::from_millis(300), clock.clone());
+//!
+//! search.push("r".to_string());
+//! search.push("ru".to_string());
+//! search.push("rus".to_string());
+//! assert_eq!(search.try_emit(clock.now()), None);
+//!
+//! clock.advance(Duration::from_millis(300));
+//! assert_eq!(search.try_emit(clock.now()), Some("rus".to_string()));
+//! ```
 
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+/// A source of the current time.
+///
+/// Implement this to control time in tests; [`SystemClock`] is used by
+/// default.
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+/// A [`Clock`] that reads the system's monotonic clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A [`Clock`] that only moves when told to.
+///
+/// Clones share the same time, so a test can keep one handle and give
+/// another to a [`Debouncer`] or [`Throttle`].
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl ManualClock {
+    /// Creates a manual clock starting at the current instant.
+    pub fn new() -> Self {
+        Self {
+            now: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Returns the clock's current instant.
+    pub fn now(&self) -> Instant {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) += duration;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        ManualClock::now(self)
+    }
+}
+
 /// A debouncer that delays processing until events stop arriving.
 ///
 /// Debouncing is useful for events that fire rapidly but only the final
@@ -15,13 +106,19 @@ use std::time::{Duration, Instant};
 /// The debouncer only allows an event to proceed if a specified delay
 /// has passed since the last event. Each new event resets the timer.
 ///
+/// With a payload type `T`, values given to [`push`](Debouncer::push) are
+/// held back until the delay passes with no newer value, and only the
+/// latest one is emitted by [`try_emit`](Debouncer::try_emit) or
+/// [`next`](Debouncer::next).
+///
 /// # Examples
 ///
 /// ```rust
-/// use tuilib::event::Debouncer;
+/// use tuilib::event::{Debouncer, ManualClock};
 /// use std::time::Duration;
 ///
-/// let mut debouncer = Debouncer::new(Duration::from_millis(100));
+/// let clock = ManualClock::new();
+/// let mut debouncer: Debouncer<(), _> = Debouncer::with_clock(Duration::from_millis(100), clock.clone());
 ///
 /// // First call - allowed immediately
 /// assert!(debouncer.should_process());
@@ -29,14 +126,16 @@ use std::time::{Duration, Instant};
 /// // Immediate second call - blocked (still within delay)
 /// assert!(!debouncer.should_process());
 ///
-/// // After waiting the delay duration, calls are allowed again
-/// std::thread::sleep(Duration::from_millis(110));
+/// // After the delay has passed, calls are allowed again
+/// clock.advance(Duration::from_millis(110));
 /// assert!(debouncer.should_process());
 /// ```
 #[derive(Debug, Clone)]
-pub struct Debouncer {
+pub struct Debouncer<T = (), C = SystemClock> {
     delay: Duration,
     last_event: Option<Instant>,
+    pending: Option<T>,
+    clock: C,
 }
 
 impl Debouncer {
@@ -55,9 +154,79 @@ impl Debouncer {
     /// let debouncer = Debouncer::new(Duration::from_millis(50));
     /// ```
     pub fn new(delay: Duration) -> Self {
+        Self::with_clock(delay, SystemClock)
+    }
+}
+
+impl<T> Debouncer<T> {
+    /// Creates a debouncer for values of type `T` using the system clock.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::event::Debouncer;
+    /// use std::time::Duration;
+    ///
+    /// let debouncer = Debouncer::<String>::for_values(Duration::from_millis(300));
+    /// assert!(!debouncer.is_pending());
+    /// ```
+    pub fn for_values(delay: Duration) -> Self {
+        Self::with_clock(delay, SystemClock)
+    }
+}
+
+impl<T, C: Clock> Debouncer<T, C> {
+    /// Creates a debouncer that reads time from `clock`.
+    pub fn with_clock(delay: Duration, clock: C) -> Self {
         Self {
             delay,
             last_event: None,
+            pending: None,
+            clock,
+        }
+    }
+
+    /// Records a new value, replacing any value still waiting to be emitted.
+    ///
+    /// Each push restarts the quiet period.
+    pub fn push(&mut self, value: T) {
+        self.pending = Some(value);
+        self.last_event = Some(self.clock.now());
+    }
+
+    /// Emits the latest value if the delay has passed since it was pushed.
+    pub fn try_emit(&mut self, now: Instant) -> Option<T> {
+        let last = self.last_event?;
+        if self.pending.is_some() && now.saturating_duration_since(last) >= self.delay {
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if a value is waiting to be emitted.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Returns the clock used by this debouncer.
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Waits for the quiet period to end and emits the latest value.
+    ///
+    /// Returns `None` immediately if nothing is pending. This is intended for
+    /// use with [`SystemClock`] in a `tokio::select!`; it is cancel-safe, and
+    /// pushing a new value simply restarts the wait on the next call.
+    pub async fn next(&mut self) -> Option<T> {
+        loop {
+            self.pending.as_ref()?;
+            let remaining = self.remaining();
+            if remaining.is_zero() {
+                return self.try_emit(self.clock.now());
+            }
+            tokio::time::sleep(remaining).await;
         }
     }
 
@@ -83,7 +252,7 @@ impl Debouncer {
     /// }
     /// ```
     pub fn should_process(&mut self) -> bool {
-        let now = Instant::now();
+        let now = self.clock.now();
 
         match self.last_event {
             Some(last) if now.duration_since(last) < self.delay => false,
@@ -94,11 +263,12 @@ impl Debouncer {
         }
     }
 
-    /// Resets the debouncer state.
+    /// Resets the debouncer state, discarding any pending value.
     ///
     /// After calling this, the next `should_process()` will return `true`.
     pub fn reset(&mut self) {
         self.last_event = None;
+        self.pending = None;
     }
 
     /// Returns the debounce delay duration.
@@ -115,7 +285,8 @@ impl Debouncer {
     ///
     /// Returns `None` if no event has been processed yet.
     pub fn time_since_last(&self) -> Option<Duration> {
-        self.last_event.map(|last| last.elapsed())
+        self.last_event
+            .map(|last| self.clock.now().saturating_duration_since(last))
     }
 
     /// Returns the remaining time until the next event can be processed.
@@ -124,7 +295,7 @@ impl Debouncer {
     pub fn remaining(&self) -> Duration {
         match self.last_event {
             Some(last) => {
-                let elapsed = last.elapsed();
+                let elapsed = self.clock.now().saturating_duration_since(last);
                 if elapsed >= self.delay {
                     Duration::ZERO
                 } else {
@@ -153,13 +324,19 @@ impl Default for Debouncer {
 /// processed immediately, and then subsequent events are only processed
 /// if the minimum interval has passed.
 ///
+/// With a payload type `T`, [`push`](Throttle::push) emits a value straight
+/// away when the interval allows and otherwise keeps the latest value, which
+/// [`try_emit`](Throttle::try_emit) or [`next`](Throttle::next) release once
+/// the interval has elapsed.
+///
 /// # Examples
 ///
 /// ```rust
-/// use tuilib::event::Throttle;
+/// use tuilib::event::{ManualClock, Throttle};
 /// use std::time::Duration;
 ///
-/// let mut throttle = Throttle::new(Duration::from_millis(100));
+/// let clock = ManualClock::new();
+/// let mut throttle: Throttle<(), _> = Throttle::with_clock(Duration::from_millis(100), clock.clone());
 ///
 /// // First call - allowed immediately
 /// assert!(throttle.should_process());
@@ -167,14 +344,16 @@ impl Default for Debouncer {
 /// // Immediate second call - blocked
 /// assert!(!throttle.should_process());
 ///
-/// // After waiting, calls are allowed again
-/// std::thread::sleep(Duration::from_millis(110));
+/// // After the interval has passed, calls are allowed again
+/// clock.advance(Duration::from_millis(110));
 /// assert!(throttle.should_process());
 /// ```
 #[derive(Debug, Clone)]
-pub struct Throttle {
+pub struct Throttle<T = (), C = SystemClock> {
     interval: Duration,
     last_allowed: Option<Instant>,
+    pending: Option<T>,
+    clock: C,
 }
 
 impl Throttle {
@@ -194,10 +373,7 @@ impl Throttle {
     /// let throttle = Throttle::new(Duration::from_millis(100));
     /// ```
     pub fn new(interval: Duration) -> Self {
-        Self {
-            interval,
-            last_allowed: None,
-        }
+        Self::with_clock(interval, SystemClock)
     }
 
     /// Creates a throttle from a rate (events per second).
@@ -223,6 +399,80 @@ impl Throttle {
         let interval = Duration::from_secs(1) / events_per_second;
         Self::new(interval)
     }
+}
+
+impl<T> Throttle<T> {
+    /// Creates a throttle for values of type `T` using the system clock.
+    pub fn for_values(interval: Duration) -> Self {
+        Self::with_clock(interval, SystemClock)
+    }
+}
+
+impl<T, C: Clock> Throttle<T, C> {
+    /// Creates a throttle that reads time from `clock`.
+    pub fn with_clock(interval: Duration, clock: C) -> Self {
+        Self {
+            interval,
+            last_allowed: None,
+            pending: None,
+            clock,
+        }
+    }
+
+    /// Offers a value to the throttle.
+    ///
+    /// Returns the value immediately if the interval has elapsed since the
+    /// last emission. Otherwise it is held (replacing any older held value)
+    /// and `None` is returned.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        let now = self.clock.now();
+        if self.is_ready(now) {
+            self.pending = None;
+            self.last_allowed = Some(now);
+            Some(value)
+        } else {
+            self.pending = Some(value);
+            None
+        }
+    }
+
+    /// Emits the held value if the interval has elapsed.
+    pub fn try_emit(&mut self, now: Instant) -> Option<T> {
+        if self.pending.is_some() && self.is_ready(now) {
+            self.last_allowed = Some(now);
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if a value is being held.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Waits for the interval to elapse and emits the held value.
+    ///
+    /// Returns `None` immediately if nothing is held. This is intended for
+    /// use with [`SystemClock`] in a `tokio::select!` and is cancel-safe.
+    pub async fn next(&mut self) -> Option<T> {
+        loop {
+            self.pending.as_ref()?;
+            let remaining = self.remaining();
+            if remaining.is_zero() {
+                return self.try_emit(self.clock.now());
+            }
+            tokio::time::sleep(remaining).await;
+        }
+    }
+
+    /// Returns true if the interval has elapsed at `now`.
+    fn is_ready(&self, now: Instant) -> bool {
+        match self.last_allowed {
+            Some(last) => now.saturating_duration_since(last) >= self.interval,
+            None => true,
+        }
+    }
 
     /// Checks if enough time has passed to process a new event.
     ///
@@ -232,7 +482,7 @@ impl Throttle {
     ///
     /// `true` if the event should be processed, `false` if it should be skipped.
     pub fn should_process(&mut self) -> bool {
-        let now = Instant::now();
+        let now = self.clock.now();
 
         match self.last_allowed {
             Some(last) if now.duration_since(last) < self.interval => false,
@@ -243,11 +493,12 @@ impl Throttle {
         }
     }
 
-    /// Resets the throttle state.
+    /// Resets the throttle state, discarding any held value.
     ///
     /// After calling this, the next `should_process()` will return `true`.
     pub fn reset(&mut self) {
         self.last_allowed = None;
+        self.pending = None;
     }
 
     /// Returns the throttle interval.
@@ -264,7 +515,8 @@ impl Throttle {
     ///
     /// Returns `None` if no event has been allowed yet.
     pub fn time_since_last(&self) -> Option<Duration> {
-        self.last_allowed.map(|last| last.elapsed())
+        self.last_allowed
+            .map(|last| self.clock.now().saturating_duration_since(last))
     }
 
     /// Returns the remaining time until the next event can be processed.
@@ -273,7 +525,7 @@ impl Throttle {
     pub fn remaining(&self) -> Duration {
         match self.last_allowed {
             Some(last) => {
-                let elapsed = last.elapsed();
+                let elapsed = self.clock.now().saturating_duration_since(last);
                 if elapsed >= self.interval {
                     Duration::ZERO
                 } else {
@@ -299,7 +551,6 @@ impl Default for Throttle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::thread;
 
     #[test]
     fn test_debouncer_creation() {
@@ -328,9 +579,13 @@ mod tests {
 
     #[test]
     fn test_debouncer_after_delay_allowed() {
-        let mut debouncer = Debouncer::new(Duration::from_millis(10));
+        let clock = ManualClock::new();
+        let mut debouncer: Debouncer<(), _> =
+            Debouncer::with_clock(Duration::from_millis(10), clock.clone());
         assert!(debouncer.should_process());
-        thread::sleep(Duration::from_millis(15));
+        clock.advance(Duration::from_millis(9));
+        assert!(!debouncer.should_process());
+        clock.advance(Duration::from_millis(1));
         assert!(debouncer.should_process());
     }
 
@@ -352,12 +607,13 @@ mod tests {
 
     #[test]
     fn test_debouncer_time_since_last() {
-        let mut debouncer = Debouncer::new(Duration::from_millis(100));
+        let clock = ManualClock::new();
+        let mut debouncer: Debouncer<(), _> =
+            Debouncer::with_clock(Duration::from_millis(100), clock.clone());
         assert!(debouncer.time_since_last().is_none());
         debouncer.should_process();
-        thread::sleep(Duration::from_millis(5));
-        let elapsed = debouncer.time_since_last().unwrap();
-        assert!(elapsed >= Duration::from_millis(5));
+        clock.advance(Duration::from_millis(5));
+        assert_eq!(debouncer.time_since_last(), Some(Duration::from_millis(5)));
     }
 
     #[test]
@@ -411,9 +667,13 @@ mod tests {
 
     #[test]
     fn test_throttle_after_interval_allowed() {
-        let mut throttle = Throttle::new(Duration::from_millis(10));
+        let clock = ManualClock::new();
+        let mut throttle: Throttle<(), _> =
+            Throttle::with_clock(Duration::from_millis(10), clock.clone());
         assert!(throttle.should_process());
-        thread::sleep(Duration::from_millis(15));
+        clock.advance(Duration::from_millis(9));
+        assert!(!throttle.should_process());
+        clock.advance(Duration::from_millis(1));
         assert!(throttle.should_process());
     }
 
@@ -435,12 +695,13 @@ mod tests {
 
     #[test]
     fn test_throttle_time_since_last() {
-        let mut throttle = Throttle::new(Duration::from_millis(100));
+        let clock = ManualClock::new();
+        let mut throttle: Throttle<(), _> =
+            Throttle::with_clock(Duration::from_millis(100), clock.clone());
         assert!(throttle.time_since_last().is_none());
         throttle.should_process();
-        thread::sleep(Duration::from_millis(5));
-        let elapsed = throttle.time_since_last().unwrap();
-        assert!(elapsed >= Duration::from_millis(5));
+        clock.advance(Duration::from_millis(5));
+        assert_eq!(throttle.time_since_last(), Some(Duration::from_millis(5)));
     }
 
     #[test]
@@ -488,4 +749,89 @@ mod tests {
         let cloned = throttle.clone();
         assert_eq!(throttle.interval(), cloned.interval());
     }
+
+    #[test]
+    fn test_debouncer_emits_only_last_value() {
+        let clock = ManualClock::new();
+        let mut debouncer = Debouncer::with_clock(Duration::from_millis(100), clock.clone());
+
+        for value in 1..=5 {
+            debouncer.push(value);
+            clock.advance(Duration::from_millis(30));
+            assert_eq!(debouncer.try_emit(clock.now()), None);
+        }
+
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(debouncer.try_emit(clock.now()), Some(5));
+        assert_eq!(debouncer.try_emit(clock.now()), None);
+        assert!(!debouncer.is_pending());
+    }
+
+    #[test]
+    fn test_debouncer_reset_discards_pending() {
+        let clock = ManualClock::new();
+        let mut debouncer = Debouncer::with_clock(Duration::from_millis(10), clock.clone());
+        debouncer.push("query");
+        debouncer.reset();
+
+        clock.advance(Duration::from_millis(20));
+        assert_eq!(debouncer.try_emit(clock.now()), None);
+    }
+
+    #[test]
+    fn test_throttle_emits_first_then_suppresses() {
+        let clock = ManualClock::new();
+        let mut throttle = Throttle::with_clock(Duration::from_millis(100), clock.clone());
+
+        assert_eq!(throttle.push(1), Some(1));
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(throttle.push(2), None);
+        assert_eq!(throttle.push(3), None);
+        assert_eq!(throttle.try_emit(clock.now()), None);
+
+        clock.advance(Duration::from_millis(90));
+        assert_eq!(throttle.try_emit(clock.now()), Some(3));
+
+        // The trailing emission starts a new interval
+        assert_eq!(throttle.push(4), None);
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(throttle.push(5), Some(5));
+        assert!(!throttle.is_pending());
+    }
+
+    #[test]
+    fn test_manual_clock_drives_unit_api() {
+        let clock = ManualClock::new();
+        let mut throttle: Throttle<(), _> =
+            Throttle::with_clock(Duration::from_millis(50), clock.clone());
+
+        assert!(throttle.should_process());
+        assert!(!throttle.should_process());
+        assert_eq!(throttle.remaining(), Duration::from_millis(50));
+
+        clock.advance(Duration::from_millis(50));
+        assert!(throttle.should_process());
+    }
+
+    #[tokio::test]
+    async fn test_debouncer_next() {
+        let mut debouncer = Debouncer::for_values(Duration::from_millis(10));
+        assert_eq!(debouncer.next().await, None);
+
+        debouncer.push("a");
+        debouncer.push("ab");
+        assert_eq!(debouncer.next().await, Some("ab"));
+    }
+
+    #[tokio::test]
+    async fn test_throttle_next() {
+        let mut throttle = Throttle::for_values(Duration::from_millis(10));
+        assert_eq!(throttle.push(1), Some(1));
+        assert_eq!(throttle.push(2), None);
+
+        let start = Instant::now();
+        assert_eq!(throttle.next().await, Some(2));
+        assert!(start.elapsed() >= Duration::from_millis(5));
+        assert_eq!(throttle.next().await, None);
+    }
 }
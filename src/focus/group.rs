@@ -0,0 +1,126 @@
+//! Focus groups for composite widgets.
+//!
+//! A [`FocusGroup`] bundles several focusable children, such as the day,
+//! month, and year fields of a date picker, into a single Tab stop. Once
+//! [registered](super::FocusManager::register_group) with a
+//! [`FocusManager`](super::FocusManager), Tab moves between groups and other
+//! components, while
+//! [`navigate_within`](super::FocusManager::navigate_within) (usually bound
+//! to the arrow keys) moves between the children of the active group.
+
+use super::{FocusId, FocusRing};
+
+/// A set of focusable children that acts as one Tab stop.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::focus::{FocusGroup, FocusId};
+///
+/// let group = FocusGroup::new(FocusId::new("date"))
+///     .with_child(FocusId::new("day"))
+///     .with_child(FocusId::new("month"))
+///     .with_child(FocusId::new("year"));
+///
+/// assert_eq!(group.len(), 3);
+/// assert!(group.contains(&FocusId::new("month")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FocusGroup {
+    id: FocusId,
+    ring: FocusRing,
+}
+
+impl FocusGroup {
+    /// Creates an empty group with the given ID.
+    ///
+    /// The ID is registered in the outer focus order in place of the
+    /// children.
+    pub fn new(id: FocusId) -> Self {
+        Self {
+            id,
+            ring: FocusRing::new(),
+        }
+    }
+
+    /// Adds a child after the existing ones.
+    pub fn with_child(mut self, id: FocusId) -> Self {
+        let order = i32::try_from(self.ring.len()).unwrap_or(i32::MAX);
+        self.ring.register(id, order);
+        self
+    }
+
+    /// Registers a child with an explicit order within the group.
+    pub fn register(&mut self, id: FocusId, order: i32) {
+        self.ring.register(id, order);
+    }
+
+    /// Unregisters a child, returning `true` if it was in the group.
+    pub fn unregister(&mut self, id: &FocusId) -> bool {
+        self.ring.unregister(id)
+    }
+
+    /// Returns the group's ID.
+    pub fn id(&self) -> &FocusId {
+        &self.id
+    }
+
+    /// Returns the number of children.
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// Returns `true` if the group has no children.
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+
+    /// Returns `true` if the given ID is a child of this group.
+    pub fn contains(&self, id: &FocusId) -> bool {
+        self.ring.contains(id)
+    }
+
+    /// Returns the focused child, if any.
+    pub fn current(&self) -> Option<&FocusId> {
+        self.ring.current()
+    }
+
+    /// Returns an iterator over the children in focus order.
+    pub fn children(&self) -> impl Iterator<Item = &FocusId> {
+        self.ring.iter()
+    }
+
+    /// Focuses the first child, returning its ID.
+    pub(crate) fn enter(&mut self) -> Option<FocusId> {
+        self.ring.clear_focus();
+        self.ring.next()
+    }
+
+    /// Returns the underlying focus ring.
+    pub(crate) fn ring_mut(&mut self) -> &mut FocusRing {
+        &mut self.ring
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_children_keep_insertion_order() {
+        let mut group = FocusGroup::new(FocusId::new("picker"))
+            .with_child(FocusId::new("b"))
+            .with_child(FocusId::new("a"));
+        group.register(FocusId::new("first"), -1);
+
+        let children: Vec<&str> = group.children().map(FocusId::as_str).collect();
+        assert_eq!(children, vec!["first", "b", "a"]);
+        assert_eq!(group.id(), &FocusId::new("picker"));
+        assert!(group.current().is_none());
+
+        assert_eq!(group.enter(), Some(FocusId::new("first")));
+        assert!(group.unregister(&FocusId::new("b")));
+        assert_eq!(group.len(), 2);
+        assert!(!group.is_empty());
+    }
+}
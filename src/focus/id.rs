@@ -3,10 +3,15 @@
 //! The [`FocusId`] type provides a unique identifier for focusable components
 //! in the TUI application. It supports creation from strings, static strings,
 //! and other common types.
+//!
+//! IDs are cloned constantly during focus navigation, so cloning never copies
+//! the string: static IDs are borrowed, and dynamic IDs share a reference
+//! counted buffer.
 
-use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 
 /// Unique identifier for focusable components.
 ///
@@ -43,8 +48,20 @@ use std::hash::Hash;
 /// assert_eq!(id1, id2);
 /// assert_ne!(id1, id3);
 /// ```
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct FocusId(Cow<'static, str>);
+///
+/// IDs compare, hash and order by their string value, regardless of how they
+/// were created.
+#[derive(Clone)]
+pub struct FocusId(Repr);
+
+/// Storage for a [`FocusId`]'s string.
+#[derive(Clone)]
+enum Repr {
+    /// A string literal; cloning copies the pointer.
+    Static(&'static str),
+    /// A dynamic string; cloning bumps the reference count.
+    Shared(Arc<str>),
+}
 
 impl FocusId {
     /// Creates a new `FocusId` from a static string.
@@ -65,7 +82,7 @@ impl FocusId {
     /// assert_eq!(id.as_str(), "my-component");
     /// ```
     pub const fn new(id: &'static str) -> Self {
-        Self(Cow::Borrowed(id))
+        Self(Repr::Static(id))
     }
 
     /// Returns the identifier as a string slice.
@@ -79,49 +96,84 @@ impl FocusId {
     /// assert_eq!(id.as_str(), "button-1");
     /// ```
     pub fn as_str(&self) -> &str {
-        &self.0
+        match &self.0 {
+            Repr::Static(s) => s,
+            Repr::Shared(s) => s,
+        }
+    }
+}
+
+impl PartialEq for FocusId {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for FocusId {}
+
+impl Hash for FocusId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialOrd for FocusId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FocusId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
     }
 }
 
 impl fmt::Debug for FocusId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "FocusId({:?})", self.0)
+        write!(f, "FocusId({:?})", self.as_str())
     }
 }
 
 impl fmt::Display for FocusId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.as_str())
     }
 }
 
 impl From<&'static str> for FocusId {
     fn from(s: &'static str) -> Self {
-        Self(Cow::Borrowed(s))
+        Self(Repr::Static(s))
     }
 }
 
 impl From<String> for FocusId {
     fn from(s: String) -> Self {
-        Self(Cow::Owned(s))
+        Self(Repr::Shared(Arc::from(s)))
+    }
+}
+
+impl From<Arc<str>> for FocusId {
+    fn from(s: Arc<str>) -> Self {
+        Self(Repr::Shared(s))
     }
 }
 
 impl AsRef<str> for FocusId {
     fn as_ref(&self) -> &str {
-        &self.0
+        self.as_str()
     }
 }
 
 impl PartialEq<str> for FocusId {
     fn eq(&self, other: &str) -> bool {
-        self.0.as_ref() == other
+        self.as_str() == other
     }
 }
 
 impl PartialEq<&str> for FocusId {
     fn eq(&self, other: &&str) -> bool {
-        self.0.as_ref() == *other
+        self.as_str() == *other
     }
 }
 
@@ -196,4 +248,52 @@ mod tests {
         let s: &str = id.as_ref();
         assert_eq!(s, "ref-test");
     }
+
+    #[test]
+    fn test_static_and_dynamic_ids_are_equal() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |id: &FocusId| {
+            let mut hasher = DefaultHasher::new();
+            id.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let static_id = FocusId::new("field");
+        let dynamic_id = FocusId::from(format!("fi{}", "eld"));
+        assert_eq!(static_id, dynamic_id);
+        assert_eq!(hash(&static_id), hash(&dynamic_id));
+
+        let mut set = HashSet::new();
+        set.insert(dynamic_id);
+        assert!(set.contains(&static_id));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut ids = [
+            FocusId::from(String::from("b")),
+            FocusId::new("c"),
+            FocusId::new("a"),
+        ];
+        ids.sort();
+        let names: Vec<&str> = ids.iter().map(FocusId::as_str).collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+        assert!(FocusId::new("a") < FocusId::from(String::from("b")));
+    }
+
+    #[test]
+    fn test_clone_shares_string() {
+        let id = FocusId::from(String::from("dynamic"));
+        let clones: Vec<FocusId> = (0..100).map(|_| id.clone()).collect();
+
+        // Every clone points at the same backing string
+        for clone in &clones {
+            assert_eq!(clone.as_str().as_ptr(), id.as_str().as_ptr());
+        }
+
+        static NAME: &str = "static";
+        let static_id = FocusId::new(NAME);
+        assert_eq!(static_id.clone().as_str().as_ptr(), NAME.as_ptr());
+    }
 }
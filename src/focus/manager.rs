@@ -4,7 +4,15 @@
 //! TUI application. It handles Tab/Shift+Tab navigation, focus traps for modals,
 //! and focus restoration.
 
-use super::{FocusId, FocusRing, FocusTrap};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use ratatui::layout::{Position, Rect};
+use terminput::KeyCode;
+
+use super::{FocusGroup, FocusId, FocusRing, FocusTrap};
+use crate::components::Focusable;
 use tracing::{debug, instrument};
 
 /// Focus navigation direction.
@@ -16,6 +24,17 @@ pub enum FocusDirection {
     Previous,
 }
 
+/// The axis a component is laid out along, for arrow-key navigation.
+///
+/// See [`FocusManager::register_with_axis`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// A row of components, navigated with Left and Right.
+    Horizontal,
+    /// A column of components, navigated with Up and Down.
+    Vertical,
+}
+
 /// Result of a focus navigation operation.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FocusResult {
@@ -32,6 +51,75 @@ pub enum FocusResult {
     NoFocusables,
 }
 
+impl FocusResult {
+    /// Describes the move from one focused component to another.
+    fn transition(from: Option<FocusId>, to: Option<FocusId>) -> Self {
+        match to {
+            Some(to) if from.as_ref() == Some(&to) => FocusResult::Unchanged(to),
+            Some(to) => FocusResult::Moved { from, to },
+            None => FocusResult::NoFocusables,
+        }
+    }
+}
+
+/// Result of restoring previously saved focus.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FocusRestore {
+    /// The saved component was focused again.
+    Restored(FocusId),
+    /// The saved component is no longer registered, so the nearest remaining
+    /// one was focused instead.
+    Fallback {
+        /// The ID that was saved.
+        saved: FocusId,
+        /// The ID that received focus.
+        focused: FocusId,
+    },
+    /// The saved component is no longer registered and nothing else can
+    /// receive focus.
+    Lost(FocusId),
+}
+
+impl FocusRestore {
+    /// Returns the ID that received focus, if any.
+    pub fn focused(&self) -> Option<&FocusId> {
+        match self {
+            FocusRestore::Restored(id) | FocusRestore::Fallback { focused: id, .. } => Some(id),
+            FocusRestore::Lost(_) => None,
+        }
+    }
+
+    /// Returns `true` if a different component than the saved one was focused.
+    pub fn is_fallback(&self) -> bool {
+        matches!(self, FocusRestore::Fallback { .. })
+    }
+}
+
+/// A component shared with a [`FocusManager`] so it can be told about focus
+/// changes.
+///
+/// See [`FocusManager::bind`].
+pub type SharedFocusable = Arc<Mutex<dyn Focusable + Send>>;
+
+/// Components bound to focus IDs.
+#[derive(Clone, Default)]
+struct Bindings(HashMap<FocusId, SharedFocusable>);
+
+impl fmt::Debug for Bindings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+/// A saved focus position.
+#[derive(Debug, Clone)]
+struct SavedFocus {
+    /// The focused component.
+    id: FocusId,
+    /// Its order when saved, used to find the nearest replacement.
+    order: Option<i32>,
+}
+
 /// Main focus management interface.
 ///
 /// The `FocusManager` coordinates focus navigation across all focusable components
@@ -41,6 +129,8 @@ pub enum FocusResult {
 /// - Programmatic focus control
 /// - Focus traps for modal dialogs
 /// - Focus restoration when traps are popped
+/// - Focus groups that act as a single Tab stop
+/// - Arrow-key navigation along rows and columns
 ///
 /// # Basic Usage
 ///
@@ -95,7 +185,20 @@ pub enum FocusResult {
 pub struct FocusManager {
     ring: FocusRing,
     traps: Vec<FocusTrap>,
-    restoration_stack: Vec<FocusId>,
+    restoration_stack: Vec<SavedFocus>,
+    /// Outcome of the last focus restoration.
+    last_restore: Option<FocusRestore>,
+    /// Screen areas of components, with the sequence number of the call
+    /// that recorded each.
+    areas: HashMap<FocusId, (Rect, u64)>,
+    /// Sequence number given to the next recorded area.
+    next_area_seq: u64,
+    /// Groups registered in the main ring, keyed by group ID.
+    groups: HashMap<FocusId, FocusGroup>,
+    /// Axes of components registered with one.
+    axes: HashMap<FocusId, Axis>,
+    /// Components notified when they gain or lose focus.
+    bindings: Bindings,
 }
 
 impl Default for FocusManager {
@@ -120,6 +223,12 @@ impl FocusManager {
             ring: FocusRing::new(),
             traps: Vec::new(),
             restoration_stack: Vec::new(),
+            last_restore: None,
+            areas: HashMap::new(),
+            next_area_seq: 0,
+            groups: HashMap::new(),
+            axes: HashMap::new(),
+            bindings: Bindings::default(),
         }
     }
 
@@ -132,7 +241,8 @@ impl FocusManager {
 
     /// Returns the total number of registered focusable components.
     ///
-    /// This only counts the main focus ring, not trap contents.
+    /// This only counts the main focus ring, not trap contents. Each group
+    /// counts as one component.
     pub fn len(&self) -> usize {
         self.ring.len()
     }
@@ -167,6 +277,61 @@ impl FocusManager {
         self.ring.register(id, order);
     }
 
+    /// Registers a focusable component laid out along an axis.
+    ///
+    /// The component takes part in Tab navigation as usual. In addition,
+    /// [`navigate_along`](Self::navigate_along) moves between it and its
+    /// neighbors in focus order that share the same axis, so a toolbar can
+    /// be walked with Left/Right and a list with Up/Down. Entries that are
+    /// consecutive in focus order and share an axis form one row or column,
+    /// however far apart their order values are. A row only ends at an
+    /// entry registered without that axis, so two rows on the same axis
+    /// need such an entry between them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{Axis, FocusDirection, FocusId, FocusManager};
+    ///
+    /// let mut manager = FocusManager::new();
+    /// manager.register_with_axis(FocusId::new("bold"), 0, Axis::Horizontal);
+    /// manager.register_with_axis(FocusId::new("italic"), 1, Axis::Horizontal);
+    /// manager.register(FocusId::new("editor"), 2);
+    ///
+    /// manager.focus_next();
+    /// manager.navigate_along(Axis::Horizontal, FocusDirection::Next);
+    /// assert_eq!(manager.current(), Some(&FocusId::new("italic")));
+    /// ```
+    pub fn register_with_axis(&mut self, id: FocusId, order: i32, axis: Axis) {
+        self.ring.register(id.clone(), order);
+        self.axes.insert(id, axis);
+    }
+
+    /// Returns the axis a component was registered with.
+    pub fn axis(&self, id: &FocusId) -> Option<Axis> {
+        self.axes.get(id).copied()
+    }
+
+    /// Registers many components at once.
+    ///
+    /// Cheaper than calling [`register`](Self::register) repeatedly, since
+    /// the focus order is only sorted once. Duplicate IDs update the order of
+    /// the existing entry. Entries with equal order are arranged as described
+    /// for [`FocusRing::register_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{FocusId, FocusManager};
+    ///
+    /// let mut manager = FocusManager::new();
+    /// manager.register_all((0..3).map(|i| (FocusId::from(format!("field-{i}")), i)));
+    /// assert_eq!(manager.len(), 3);
+    /// ```
+    pub fn register_all(&mut self, entries: impl IntoIterator<Item = (FocusId, i32)>) {
+        self.ring.register_all(entries);
+    }
+
     /// Unregisters a component from the focus manager.
     ///
     /// # Arguments
@@ -177,7 +342,125 @@ impl FocusManager {
     ///
     /// `true` if the component was found and removed.
     pub fn unregister(&mut self, id: &FocusId) -> bool {
-        self.ring.unregister(id)
+        let from = self.current().cloned();
+        self.areas.remove(id);
+        self.axes.remove(id);
+        let removed = if let Some(group) = self.groups.values_mut().find(|g| g.contains(id)) {
+            group.unregister(id)
+        } else {
+            self.groups.remove(id);
+            self.ring.unregister(id)
+        };
+        self.notify_change(from);
+        removed
+    }
+
+    /// Registers a group of components as a single Tab stop.
+    ///
+    /// The group's ID takes `order` in the main focus order. Tabbing onto
+    /// the group focuses its first child, and
+    /// [`navigate_within`](Self::navigate_within) moves between its
+    /// children. Registering a group with an existing ID replaces it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{FocusDirection, FocusGroup, FocusId, FocusManager};
+    ///
+    /// let mut manager = FocusManager::new();
+    /// manager.register(FocusId::new("name"), 0);
+    /// manager.register_group(
+    ///     FocusGroup::new(FocusId::new("date"))
+    ///         .with_child(FocusId::new("day"))
+    ///         .with_child(FocusId::new("month")),
+    ///     1,
+    /// );
+    ///
+    /// manager.focus_next();
+    /// assert_eq!(manager.focus_next(), Some(FocusId::new("day")));
+    ///
+    /// manager.navigate_within(FocusDirection::Next);
+    /// assert_eq!(manager.current(), Some(&FocusId::new("month")));
+    /// assert_eq!(manager.active_group(), Some(&FocusId::new("date")));
+    /// ```
+    pub fn register_group(&mut self, group: FocusGroup, order: i32) {
+        let id = group.id().clone();
+        self.ring.register(id.clone(), order);
+        self.groups.insert(id, group);
+    }
+
+    /// Returns a registered group by ID.
+    pub fn group(&self, id: &FocusId) -> Option<&FocusGroup> {
+        self.groups.get(id)
+    }
+
+    /// Returns the ID of the group containing the focused component.
+    ///
+    /// Always `None` while a trap is active, since groups live in the main
+    /// focus order.
+    pub fn active_group(&self) -> Option<&FocusId> {
+        if self.has_trap() {
+            return None;
+        }
+        self.ring
+            .current()
+            .filter(|id| self.groups.contains_key(*id))
+    }
+
+    /// Records the screen area occupied by a component.
+    ///
+    /// Areas are used for mouse hit-testing by [`focus_at`](Self::focus_at).
+    /// Call this during rendering so areas stay in sync with the layout.
+    pub fn set_area(&mut self, id: FocusId, area: Rect) {
+        self.areas.insert(id, (area, self.next_area_seq));
+        self.next_area_seq += 1;
+    }
+
+    /// Returns the recorded screen area of a component.
+    pub fn area(&self, id: &FocusId) -> Option<Rect> {
+        self.areas.get(id).map(|(area, _)| *area)
+    }
+
+    /// Returns the focusable component at a screen position, if any.
+    ///
+    /// Only components focusable in the current context (the active trap,
+    /// if any) are considered. When areas overlap, the smallest wins, so
+    /// nested components take precedence over their containers. Between
+    /// areas of the same size, the one recorded last wins, as it usually
+    /// belongs to the component drawn on top.
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<FocusId> {
+        let position = Position::new(x, y);
+        self.areas
+            .iter()
+            .filter(|(id, (area, _))| area.contains(position) && self.contains(id))
+            .min_by_key(|(_, (area, seq))| (area.area(), std::cmp::Reverse(*seq)))
+            .map(|(id, _)| id.clone())
+    }
+
+    /// Focuses the component at a screen position.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the focused component, or `None` if no focusable component
+    /// is at that position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{FocusId, FocusManager};
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut manager = FocusManager::new();
+    /// manager.register(FocusId::new("button"), 0);
+    /// manager.set_area(FocusId::new("button"), Rect::new(0, 0, 10, 3));
+    ///
+    /// assert_eq!(manager.focus_at(4, 1), Some(FocusId::new("button")));
+    /// assert_eq!(manager.current(), Some(&FocusId::new("button")));
+    /// assert_eq!(manager.focus_at(20, 20), None);
+    /// ```
+    pub fn focus_at(&mut self, x: u16, y: u16) -> Option<FocusId> {
+        let id = self.hit_test(x, y)?;
+        self.focus(&id).then_some(id)
     }
 
     /// Navigates focus in the given direction.
@@ -207,11 +490,7 @@ impl FocusManager {
     #[instrument(skip(self), fields(direction = ?direction, has_trap = self.has_trap()))]
     pub fn navigate(&mut self, direction: FocusDirection) -> FocusResult {
         // Get current focus before navigation (must be done separately to avoid borrow issues)
-        let from = if let Some(trap) = self.traps.last() {
-            trap.current().cloned()
-        } else {
-            self.ring.current().cloned()
-        };
+        let from = self.current().cloned();
 
         // Navigate in the appropriate ring
         let to = if let Some(trap) = self.traps.last_mut() {
@@ -220,23 +499,19 @@ impl FocusManager {
                 FocusDirection::Previous => trap.prev(),
             }
         } else {
-            match direction {
+            let to = match direction {
                 FocusDirection::Next => self.ring.next(),
                 FocusDirection::Previous => self.ring.prev(),
-            }
-        };
-
-        let result = match to {
-            Some(to_id) => {
-                if from.as_ref() == Some(&to_id) {
-                    FocusResult::Unchanged(to_id)
-                } else {
-                    FocusResult::Moved { from, to: to_id }
-                }
-            }
-            None => FocusResult::NoFocusables,
+            };
+            // Entering a group focuses its first child
+            to.map(|id| match self.groups.get_mut(&id) {
+                Some(group) => group.enter().unwrap_or(id),
+                None => id,
+            })
         };
+        self.notify_change(from.clone());
 
+        let result = FocusResult::transition(from, to);
         debug!(?result, "Focus navigation completed");
         result
     }
@@ -271,6 +546,112 @@ impl FocusManager {
         }
     }
 
+    /// Navigates between the children of the active group.
+    ///
+    /// Navigation wraps around within the group and never leaves it. When
+    /// no group is active, focus stays where it is.
+    ///
+    /// # Returns
+    ///
+    /// A `FocusResult` indicating what happened; `Unchanged` when no group
+    /// is active.
+    #[instrument(skip(self), fields(direction = ?direction))]
+    pub fn navigate_within(&mut self, direction: FocusDirection) -> FocusResult {
+        let from = self.current().cloned();
+        let group = match self.active_group().cloned() {
+            Some(group_id) => self.groups.get_mut(&group_id),
+            None => None,
+        };
+        let Some(group) = group else {
+            return match from {
+                Some(id) => FocusResult::Unchanged(id),
+                None => FocusResult::NoFocusables,
+            };
+        };
+
+        let to = match direction {
+            FocusDirection::Next => group.ring_mut().next(),
+            FocusDirection::Previous => group.ring_mut().prev(),
+        };
+        self.notify_change(from.clone());
+        let result = match to {
+            Some(to) if from.as_ref() == Some(&to) => FocusResult::Unchanged(to),
+            Some(to) => FocusResult::Moved { from, to },
+            None => match from {
+                Some(id) => FocusResult::Unchanged(id),
+                None => FocusResult::NoFocusables,
+            },
+        };
+
+        debug!(?result, "Group navigation completed");
+        result
+    }
+
+    /// Navigates between neighbors along an axis, wrapping around.
+    ///
+    /// Focus moves within the run of consecutive components in focus order
+    /// that were registered with `axis`, starting from the focused one. When
+    /// the focused component is not on that axis, or a trap is active, focus
+    /// stays where it is.
+    ///
+    /// # Returns
+    ///
+    /// A `FocusResult` indicating what happened; `Unchanged` when focus
+    /// cannot move along the axis.
+    #[instrument(skip(self), fields(axis = ?axis, direction = ?direction))]
+    pub fn navigate_along(&mut self, axis: Axis, direction: FocusDirection) -> FocusResult {
+        let from = self.current().cloned();
+        let Some(current) = from.clone() else {
+            return FocusResult::NoFocusables;
+        };
+        if self.has_trap() || self.axis(&current) != Some(axis) {
+            return FocusResult::Unchanged(current);
+        }
+
+        // The run of same-axis entries around the current one
+        let ids: Vec<&FocusId> = self.ring.iter().collect();
+        let Some(index) = ids.iter().position(|id| **id == current) else {
+            return FocusResult::Unchanged(current);
+        };
+        let on_axis = |id: &&FocusId| self.axis(id) == Some(axis);
+        let start = ids[..index]
+            .iter()
+            .rposition(|id| !on_axis(id))
+            .map_or(0, |i| i + 1);
+        let end = ids[index..]
+            .iter()
+            .position(|id| !on_axis(id))
+            .map_or(ids.len(), |i| index + i);
+        let len = end - start;
+        let offset = match direction {
+            FocusDirection::Next => 1,
+            FocusDirection::Previous => len - 1,
+        };
+        let to = ids[start + (index - start + offset) % len].clone();
+
+        self.focus_quietly(&to);
+        self.notify_change(from.clone());
+        let result = FocusResult::transition(from, Some(to));
+
+        debug!(?result, "Axis navigation completed");
+        result
+    }
+
+    /// Navigates along an axis in response to an arrow key.
+    ///
+    /// Left and Right move along [`Axis::Horizontal`]; Up and Down move
+    /// along [`Axis::Vertical`]. Returns `None` for other keys.
+    pub fn navigate_arrow(&mut self, key: KeyCode) -> Option<FocusResult> {
+        let (axis, direction) = match key {
+            KeyCode::Left => (Axis::Horizontal, FocusDirection::Previous),
+            KeyCode::Right => (Axis::Horizontal, FocusDirection::Next),
+            KeyCode::Up => (Axis::Vertical, FocusDirection::Previous),
+            KeyCode::Down => (Axis::Vertical, FocusDirection::Next),
+            _ => return None,
+        };
+        Some(self.navigate_along(axis, direction))
+    }
+
     /// Focuses a specific component by ID.
     ///
     /// If a focus trap is active, the ID must be within the trap.
@@ -298,21 +679,84 @@ impl FocusManager {
     /// assert!(!manager.focus(&FocusId::new("nonexistent")));
     /// ```
     pub fn focus(&mut self, id: &FocusId) -> bool {
+        let from = self.current().cloned();
+        let focused = self.focus_quietly(id);
+        self.notify_change(from);
+        focused
+    }
+
+    /// Focuses a specific component by ID, reporting the transition.
+    ///
+    /// Works like [`focus`](Self::focus), but returns where focus moved
+    /// from and to. If the ID cannot be focused, focus stays where it is and
+    /// `Unchanged` (or `NoFocusables` when nothing is focused) is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{FocusId, FocusManager, FocusResult};
+    ///
+    /// let mut manager = FocusManager::new();
+    /// manager.register(FocusId::new("a"), 0);
+    /// manager.register(FocusId::new("b"), 0);
+    /// manager.focus(&FocusId::new("a"));
+    ///
+    /// assert_eq!(
+    ///     manager.focus_with_result(&FocusId::new("b")),
+    ///     FocusResult::Moved {
+    ///         from: Some(FocusId::new("a")),
+    ///         to: FocusId::new("b"),
+    ///     }
+    /// );
+    /// ```
+    pub fn focus_with_result(&mut self, id: &FocusId) -> FocusResult {
+        let from = self.current().cloned();
+        self.focus_quietly(id);
+        self.notify_change(from.clone());
+        FocusResult::transition(from, self.current().cloned())
+    }
+
+    /// Focuses a component without notifying bound components.
+    fn focus_quietly(&mut self, id: &FocusId) -> bool {
         if let Some(trap) = self.traps.last_mut() {
             trap.focus(id)
         } else {
-            self.ring.focus(id)
+            self.focus_in_ring(id)
+        }
+    }
+
+    /// Focuses an ID in the main ring, descending into groups.
+    ///
+    /// Focusing a group focuses its first child; focusing a child also
+    /// makes its group the current Tab stop.
+    fn focus_in_ring(&mut self, id: &FocusId) -> bool {
+        if let Some(group) = self.groups.values_mut().find(|g| g.contains(id)) {
+            let group_id = group.id().clone();
+            return group.ring_mut().focus(id) && self.ring.focus(&group_id);
+        }
+        if !self.ring.focus(id) {
+            return false;
+        }
+        if let Some(group) = self.groups.get_mut(id) {
+            group.enter();
         }
+        true
     }
 
     /// Returns the currently focused component's ID.
     ///
     /// If a focus trap is active, returns the focused item within the trap.
+    /// If a group is focused, returns its focused child.
     pub fn current(&self) -> Option<&FocusId> {
         if let Some(trap) = self.traps.last() {
             trap.current()
         } else {
-            self.ring.current()
+            let current = self.ring.current()?;
+            // An empty group is focused as a whole
+            match self.groups.get(current) {
+                Some(group) => group.current().or(Some(current)),
+                None => Some(current),
+            }
         }
     }
 
@@ -320,22 +764,31 @@ impl FocusManager {
     ///
     /// If a focus trap is active, clears focus within the trap.
     pub fn clear_focus(&mut self) {
+        let from = self.current().cloned();
         if let Some(trap) = self.traps.last_mut() {
             trap.ring_mut().clear_focus();
         } else {
             self.ring.clear_focus();
         }
+        self.notify_change(from);
     }
 
     /// Pushes a new focus trap onto the stack.
     ///
     /// The current focus is automatically saved and will be restored when
-    /// the trap is popped.
+    /// the trap is popped. If nothing in the trap is focused yet, its
+    /// [initial](FocusTrap::with_initial) component is focused, falling back
+    /// to the first.
     ///
     /// # Arguments
     ///
     /// * `trap` - The focus trap to activate
     ///
+    /// # Returns
+    ///
+    /// The focus transition into the trap; `NoFocusables` if the trap is
+    /// empty.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -352,25 +805,35 @@ impl FocusManager {
     /// assert!(manager.has_trap());
     /// ```
     #[instrument(skip(self, trap), fields(trap_items = trap.len()))]
-    pub fn push_trap(&mut self, mut trap: FocusTrap) {
+    pub fn push_trap(&mut self, mut trap: FocusTrap) -> FocusResult {
+        let from = self.current().cloned();
+
         // Save current focus for restoration
-        let current = self.current().cloned();
-        if let Some(ref id) = current {
-            debug!(?id, "Saving current focus for restoration");
-            self.restoration_stack.push(id.clone());
+        if let Some(saved) = self.saved_focus() {
+            debug!(id = ?saved.id, "Saving current focus for restoration");
+            self.restoration_stack.push(saved);
         }
 
-        // Focus first item in trap if nothing is focused
+        // Focus the initial item, or the first, if nothing is focused
         if trap.current().is_none() && !trap.is_empty() {
-            trap.next();
+            let focused_initial = trap.initial().cloned().is_some_and(|id| trap.focus(&id));
+            if !focused_initial {
+                trap.next();
+            }
         }
 
         debug!(trap_count = self.traps.len() + 1, "Focus trap pushed");
         self.traps.push(trap);
+        self.notify_change(from.clone());
+        FocusResult::transition(from, self.current().cloned())
     }
 
     /// Pops the topmost focus trap and restores previous focus.
     ///
+    /// If the previously focused component was unregistered while the trap
+    /// was active, the nearest remaining component is focused instead; see
+    /// [`last_restore`](Self::last_restore) for the outcome.
+    ///
     /// # Returns
     ///
     /// The popped trap, or `None` if no trap was active.
@@ -393,26 +856,51 @@ impl FocusManager {
     /// assert!(!manager.has_trap());
     /// assert_eq!(manager.current(), Some(&FocusId::new("main")));
     /// ```
-    #[instrument(skip(self))]
     pub fn pop_trap(&mut self) -> Option<FocusTrap> {
+        self.pop_trap_with_result().map(|(trap, _)| trap)
+    }
+
+    /// Pops the topmost focus trap, reporting the focus transition.
+    ///
+    /// Works like [`pop_trap`](Self::pop_trap), but also returns where focus
+    /// moved from and to as it was restored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{FocusId, FocusManager, FocusResult, FocusTrap};
+    ///
+    /// let mut manager = FocusManager::new();
+    /// manager.register(FocusId::new("main"), 0);
+    /// manager.focus_next();
+    ///
+    /// let mut trap = FocusTrap::new();
+    /// trap.register(FocusId::new("modal"), 0);
+    /// manager.push_trap(trap);
+    ///
+    /// let (_, result) = manager.pop_trap_with_result().unwrap();
+    /// assert_eq!(
+    ///     result,
+    ///     FocusResult::Moved {
+    ///         from: Some(FocusId::new("modal")),
+    ///         to: FocusId::new("main"),
+    ///     }
+    /// );
+    /// ```
+    #[instrument(skip(self))]
+    pub fn pop_trap_with_result(&mut self) -> Option<(FocusTrap, FocusResult)> {
+        let from = self.current().cloned();
         let trap = self.traps.pop()?;
 
-        // Restore previous focus
-        if let Some(saved_id) = self.restoration_stack.pop() {
-            debug!(?saved_id, "Restoring saved focus");
-            if self.traps.is_empty() {
-                // Restoring to main ring
-                self.ring.focus(&saved_id);
-            } else {
-                // Restoring to outer trap
-                if let Some(outer) = self.traps.last_mut() {
-                    outer.focus(&saved_id);
-                }
-            }
-        }
+        // Restore previous focus in the main ring or the outer trap
+        self.last_restore = self
+            .restoration_stack
+            .pop()
+            .map(|saved| self.restore(saved));
+        self.notify_change(from.clone());
 
         debug!(remaining_traps = self.traps.len(), "Focus trap popped");
-        Some(trap)
+        Some((trap, FocusResult::transition(from, self.current().cloned())))
     }
 
     /// Saves the current focus to the restoration stack.
@@ -436,38 +924,167 @@ impl FocusManager {
     /// assert_eq!(manager.current(), Some(&FocusId::new("a")));
     /// ```
     pub fn save_focus(&mut self) {
-        if let Some(id) = self.current().cloned() {
-            self.restoration_stack.push(id);
+        if let Some(saved) = self.saved_focus() {
+            self.restoration_stack.push(saved);
         }
     }
 
     /// Restores focus from the restoration stack.
     ///
+    /// If the saved component has since been unregistered, the next
+    /// remaining component by focus order is focused instead, or the first
+    /// if none come after it.
+    ///
     /// # Returns
     ///
-    /// The restored focus ID, or `None` if the stack was empty.
-    pub fn restore_focus(&mut self) -> Option<FocusId> {
-        let id = self.restoration_stack.pop()?;
-        self.focus(&id);
-        Some(id)
+    /// The outcome of the restoration, or `None` if the stack was empty.
+    pub fn restore_focus(&mut self) -> Option<FocusRestore> {
+        let saved = self.restoration_stack.pop()?;
+        let from = self.current().cloned();
+        let restore = self.restore(saved);
+        self.notify_change(from);
+        self.last_restore = Some(restore.clone());
+        Some(restore)
+    }
+
+    /// Returns the outcome of the last [`pop_trap`](Self::pop_trap) or
+    /// [`restore_focus`](Self::restore_focus) that restored focus.
+    pub fn last_restore(&self) -> Option<&FocusRestore> {
+        self.last_restore.as_ref()
+    }
+
+    /// Captures the current focus and its order in the current context.
+    fn saved_focus(&self) -> Option<SavedFocus> {
+        let id = self.current()?.clone();
+        let ring = self.traps.last().map_or(&self.ring, FocusTrap::ring);
+        let order = ring
+            .current_index()
+            .and_then(|index| ring.entries().nth(index))
+            .map(|(_, order)| order);
+        Some(SavedFocus { id, order })
+    }
+
+    /// Focuses a saved component, falling back to the nearest remaining one.
+    fn restore(&mut self, saved: SavedFocus) -> FocusRestore {
+        debug!(id = ?saved.id, "Restoring saved focus");
+        if self.focus_quietly(&saved.id) {
+            return FocusRestore::Restored(saved.id);
+        }
+
+        let ring = self.traps.last().map_or(&self.ring, FocusTrap::ring);
+        let fallback = saved
+            .order
+            .and_then(|order| ring.entries().find(|&(_, o)| o >= order))
+            .or_else(|| ring.entries().next())
+            .map(|(id, _)| id.clone());
+
+        match fallback {
+            Some(id) if self.focus_quietly(&id) => {
+                let focused = self.current().cloned().unwrap_or(id);
+                debug!(saved = ?saved.id, ?focused, "Saved focus is gone, focused nearest");
+                FocusRestore::Fallback {
+                    saved: saved.id,
+                    focused,
+                }
+            }
+            _ => FocusRestore::Lost(saved.id),
+        }
     }
 
     /// Returns `true` if the given ID is focusable in the current context.
     ///
-    /// If a trap is active, checks within the trap.
+    /// If a trap is active, checks within the trap. Children of groups are
+    /// contained as well as the groups themselves.
     pub fn contains(&self, id: &FocusId) -> bool {
         if let Some(trap) = self.traps.last() {
             trap.contains(id)
         } else {
-            self.ring.contains(id)
+            self.ring.contains(id) || self.groups.values().any(|g| g.contains(id))
         }
     }
 
-    /// Clears all registrations and traps.
+    /// Clears all registrations, traps and bindings.
+    ///
+    /// A bound component that had focus is blurred first.
     pub fn clear(&mut self) {
+        let from = self.current().cloned();
         self.ring.clear();
         self.traps.clear();
         self.restoration_stack.clear();
+        self.last_restore = None;
+        self.areas.clear();
+        self.groups.clear();
+        self.axes.clear();
+        self.notify_change(from);
+        self.bindings.0.clear();
+    }
+
+    /// Binds a component to a focus ID.
+    ///
+    /// Whenever focus moves, the component losing it gets
+    /// [`set_focused(false)`](Focusable::set_focused) followed by
+    /// [`on_blur`](Focusable::on_blur), and the component gaining it gets
+    /// [`set_focused(true)`](Focusable::set_focused) followed by
+    /// [`on_focus`](Focusable::on_focus). Binding is separate from
+    /// [`register`](Self::register); unbound IDs are navigated as before.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use tuilib::components::{FocusWrapper, Focusable};
+    /// use tuilib::focus::{FocusId, FocusManager};
+    ///
+    /// let button = Arc::new(Mutex::new(FocusWrapper::new("OK")));
+    ///
+    /// let mut manager = FocusManager::new();
+    /// manager.register(FocusId::new("ok"), 0);
+    /// manager.bind(FocusId::new("ok"), button.clone());
+    ///
+    /// manager.focus_next();
+    /// assert!(button.lock().unwrap().is_focused());
+    /// ```
+    pub fn bind(&mut self, id: FocusId, component: SharedFocusable) {
+        self.bindings.0.insert(id, component);
+    }
+
+    /// Removes the component bound to a focus ID, returning it.
+    pub fn unbind(&mut self, id: &FocusId) -> Option<SharedFocusable> {
+        self.bindings.0.remove(id)
+    }
+
+    /// Returns `true` if a component is bound to the ID.
+    pub fn is_bound(&self, id: &FocusId) -> bool {
+        self.bindings.0.contains_key(id)
+    }
+
+    /// Blurs the component that had focus and focuses the one that has it
+    /// now, if they differ.
+    fn notify_change(&self, from: Option<FocusId>) {
+        let to = self.current();
+        if from.as_ref() == to {
+            return;
+        }
+        let notify = |id: &FocusId, focused: bool| {
+            let Some(component) = self.bindings.0.get(id) else {
+                return;
+            };
+            let mut component = component.lock().unwrap_or_else(|e| e.into_inner());
+            component.set_focused(focused);
+            if focused {
+                component.on_focus();
+            } else {
+                component.on_blur();
+            }
+        };
+        if let Some(from) = &from {
+            debug!(id = ?from, "Blurring component");
+            notify(from, false);
+        }
+        if let Some(to) = to {
+            debug!(id = ?to, "Focusing component");
+            notify(to, true);
+        }
     }
 }
 
@@ -502,6 +1119,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_register_all() {
+        let mut manager = FocusManager::new();
+        manager.register_all([
+            (FocusId::new("b"), 1),
+            (FocusId::new("a"), 0),
+            (FocusId::new("b"), 2),
+        ]);
+        assert_eq!(manager.len(), 2);
+
+        assert_eq!(manager.focus_next(), Some(FocusId::new("a")));
+        assert_eq!(manager.focus_next(), Some(FocusId::new("b")));
+    }
+
     #[test]
     fn test_navigate_previous() {
         let mut manager = FocusManager::new();
@@ -593,6 +1224,124 @@ mod tests {
         assert_eq!(manager.current(), Some(&FocusId::new("main")));
     }
 
+    #[test]
+    fn test_focus_trap_initial() {
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("main"), 0);
+        manager.focus_next();
+
+        let dialog = |initial: &'static str| {
+            let mut trap = FocusTrap::new().with_initial(FocusId::new(initial));
+            trap.register(FocusId::new("delete"), 0);
+            trap.register(FocusId::new("cancel"), 1);
+            trap
+        };
+
+        manager.push_trap(dialog("cancel"));
+        assert_eq!(manager.current(), Some(&FocusId::new("cancel")));
+        manager.focus_next();
+        assert_eq!(manager.current(), Some(&FocusId::new("delete")));
+        manager.pop_trap();
+
+        // An initial ID outside the trap falls back to the first item
+        manager.push_trap(dialog("missing"));
+        assert_eq!(manager.current(), Some(&FocusId::new("delete")));
+        manager.pop_trap();
+        assert_eq!(manager.current(), Some(&FocusId::new("main")));
+    }
+
+    fn two_groups() -> FocusManager {
+        let mut manager = FocusManager::new();
+        manager.register_group(
+            FocusGroup::new(FocusId::new("date"))
+                .with_child(FocusId::new("day"))
+                .with_child(FocusId::new("month")),
+            0,
+        );
+        manager.register_group(
+            FocusGroup::new(FocusId::new("time"))
+                .with_child(FocusId::new("hour"))
+                .with_child(FocusId::new("minute")),
+            1,
+        );
+        manager
+    }
+
+    #[test]
+    fn test_tab_hops_between_groups() {
+        let mut manager = two_groups();
+        assert_eq!(manager.len(), 2);
+        assert_eq!(manager.focus_next(), Some(FocusId::new("day")));
+        assert_eq!(manager.active_group(), Some(&FocusId::new("date")));
+
+        // Moving within a group does not change the Tab stop
+        manager.navigate_within(FocusDirection::Next);
+        assert_eq!(manager.current(), Some(&FocusId::new("month")));
+
+        // Tab leaves the group and enters the next one at its first child
+        let result = manager.navigate(FocusDirection::Next);
+        assert_eq!(
+            result,
+            FocusResult::Moved {
+                from: Some(FocusId::new("month")),
+                to: FocusId::new("hour"),
+            }
+        );
+        assert_eq!(manager.active_group(), Some(&FocusId::new("time")));
+
+        assert_eq!(manager.focus_prev(), Some(FocusId::new("day")));
+    }
+
+    #[test]
+    fn test_navigation_within_group_wraps() {
+        let mut manager = two_groups();
+        manager.focus_next();
+        manager.focus_next();
+        assert_eq!(manager.current(), Some(&FocusId::new("hour")));
+
+        manager.navigate_within(FocusDirection::Next);
+        assert_eq!(manager.current(), Some(&FocusId::new("minute")));
+        manager.navigate_within(FocusDirection::Next);
+        assert_eq!(manager.current(), Some(&FocusId::new("hour")));
+        manager.navigate_within(FocusDirection::Previous);
+        assert_eq!(manager.current(), Some(&FocusId::new("minute")));
+        assert_eq!(manager.active_group(), Some(&FocusId::new("time")));
+    }
+
+    #[test]
+    fn test_groups_with_plain_components() {
+        let mut manager = two_groups();
+        manager.register(FocusId::new("submit"), 2);
+
+        // Focusing a child directly activates its group
+        assert!(manager.focus(&FocusId::new("minute")));
+        assert_eq!(manager.active_group(), Some(&FocusId::new("time")));
+        assert!(manager.contains(&FocusId::new("minute")));
+
+        assert_eq!(manager.focus_next(), Some(FocusId::new("submit")));
+        assert!(manager.active_group().is_none());
+        assert_eq!(
+            manager.navigate_within(FocusDirection::Next),
+            FocusResult::Unchanged(FocusId::new("submit"))
+        );
+
+        // Focus restored after a trap returns to the group child
+        manager.focus(&FocusId::new("month"));
+        let mut trap = FocusTrap::new();
+        trap.register(FocusId::new("ok"), 0);
+        manager.push_trap(trap);
+        assert!(manager.active_group().is_none());
+        manager.pop_trap();
+        assert_eq!(manager.current(), Some(&FocusId::new("month")));
+
+        // Unregistering a child leaves the group; unregistering the group removes it
+        assert!(manager.unregister(&FocusId::new("month")));
+        assert_eq!(manager.group(&FocusId::new("date")).unwrap().len(), 1);
+        assert!(manager.unregister(&FocusId::new("date")));
+        assert!(manager.group(&FocusId::new("date")).is_none());
+        assert_eq!(manager.len(), 2);
+    }
+
     #[test]
     fn test_nested_traps() {
         let mut manager = FocusManager::new();
@@ -636,10 +1385,59 @@ mod tests {
         assert_eq!(manager.current(), Some(&FocusId::new("b")));
 
         let restored = manager.restore_focus();
-        assert_eq!(restored, Some(FocusId::new("a")));
+        assert_eq!(restored, Some(FocusRestore::Restored(FocusId::new("a"))));
         assert_eq!(manager.current(), Some(&FocusId::new("a")));
     }
 
+    #[test]
+    fn test_pop_trap_falls_back_when_saved_focus_unregistered() {
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("a"), 0);
+        manager.register(FocusId::new("b"), 1);
+        manager.register(FocusId::new("c"), 2);
+        manager.focus(&FocusId::new("b"));
+
+        let mut trap = FocusTrap::new();
+        trap.register(FocusId::new("modal"), 0);
+        manager.push_trap(trap);
+        assert!(manager.unregister(&FocusId::new("b")));
+
+        manager.pop_trap();
+        // The next component by order takes focus
+        assert_eq!(manager.current(), Some(&FocusId::new("c")));
+        assert_eq!(
+            manager.last_restore(),
+            Some(&FocusRestore::Fallback {
+                saved: FocusId::new("b"),
+                focused: FocusId::new("c"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_restore_focus_fallback_wraps_to_first() {
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("a"), 0);
+        manager.register(FocusId::new("b"), 1);
+        manager.focus(&FocusId::new("b"));
+        manager.save_focus();
+        manager.unregister(&FocusId::new("b"));
+
+        let restored = manager.restore_focus().unwrap();
+        assert!(restored.is_fallback());
+        assert_eq!(restored.focused(), Some(&FocusId::new("a")));
+        assert_eq!(manager.current(), Some(&FocusId::new("a")));
+
+        // Nothing left to focus
+        manager.save_focus();
+        manager.unregister(&FocusId::new("a"));
+        assert_eq!(
+            manager.restore_focus(),
+            Some(FocusRestore::Lost(FocusId::new("a")))
+        );
+        assert!(manager.current().is_none());
+    }
+
     #[test]
     fn test_clear_focus() {
         let mut manager = FocusManager::new();
@@ -720,4 +1518,263 @@ mod tests {
         assert!(!manager.focus(&FocusId::new("main")));
         assert!(manager.focus(&FocusId::new("modal")));
     }
+
+    #[test]
+    fn test_focus_at() {
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("panel"), 0);
+        manager.register(FocusId::new("button"), 0);
+        manager.set_area(FocusId::new("panel"), Rect::new(0, 0, 20, 10));
+        manager.set_area(FocusId::new("button"), Rect::new(2, 2, 6, 3));
+
+        // Nested button wins over its containing panel
+        assert_eq!(manager.focus_at(3, 3), Some(FocusId::new("button")));
+        assert_eq!(manager.current(), Some(&FocusId::new("button")));
+
+        assert_eq!(manager.focus_at(15, 8), Some(FocusId::new("panel")));
+        assert_eq!(manager.focus_at(30, 30), None);
+        assert_eq!(manager.current(), Some(&FocusId::new("panel")));
+    }
+
+    #[test]
+    fn test_hit_test_prefers_last_recorded_of_equal_areas() {
+        let mut manager = FocusManager::new();
+        let ids: Vec<FocusId> = (0..8).map(|i| FocusId::from(format!("card-{i}"))).collect();
+        for id in &ids {
+            manager.register(id.clone(), 0);
+            manager.set_area(id.clone(), Rect::new(0, 0, 4, 2));
+        }
+        assert_eq!(manager.hit_test(1, 1), Some(ids[7].clone()));
+
+        // Recording an area again, as on the next frame, brings it to the top
+        manager.set_area(ids[2].clone(), Rect::new(0, 0, 4, 2));
+        assert_eq!(manager.hit_test(1, 1), Some(ids[2].clone()));
+    }
+
+    #[test]
+    fn test_focus_at_respects_trap() {
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("main"), 0);
+        manager.set_area(FocusId::new("main"), Rect::new(0, 0, 10, 10));
+
+        let mut trap = FocusTrap::new();
+        trap.register(FocusId::new("modal"), 0);
+        manager.push_trap(trap);
+
+        assert_eq!(manager.focus_at(1, 1), None);
+        assert_eq!(manager.current(), Some(&FocusId::new("modal")));
+    }
+
+    #[test]
+    fn test_unregister_removes_area() {
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("a"), 0);
+        manager.set_area(FocusId::new("a"), Rect::new(0, 0, 5, 5));
+        assert_eq!(
+            manager.area(&FocusId::new("a")),
+            Some(Rect::new(0, 0, 5, 5))
+        );
+
+        manager.unregister(&FocusId::new("a"));
+        assert_eq!(manager.area(&FocusId::new("a")), None);
+    }
+
+    /// Records focus hooks into a shared log.
+    struct Recorder {
+        name: &'static str,
+        focused: bool,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Focusable for Recorder {
+        fn is_focused(&self) -> bool {
+            self.focused
+        }
+
+        fn set_focused(&mut self, focused: bool) {
+            self.focused = focused;
+        }
+
+        fn on_focus(&mut self) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("focus {}", self.name));
+        }
+
+        fn on_blur(&mut self) {
+            self.log.lock().unwrap().push(format!("blur {}", self.name));
+        }
+    }
+
+    fn bind_recorder(
+        manager: &mut FocusManager,
+        name: &'static str,
+        log: &Arc<Mutex<Vec<String>>>,
+    ) -> Arc<Mutex<Recorder>> {
+        let recorder = Arc::new(Mutex::new(Recorder {
+            name,
+            focused: false,
+            log: log.clone(),
+        }));
+        manager.bind(FocusId::new(name), recorder.clone());
+        recorder
+    }
+
+    #[test]
+    fn test_bound_components_notified_in_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("a"), 0);
+        manager.register(FocusId::new("b"), 0);
+        let a = bind_recorder(&mut manager, "a", &log);
+        let b = bind_recorder(&mut manager, "b", &log);
+
+        manager.focus_next();
+        manager.focus_next();
+        assert!(!a.lock().unwrap().is_focused());
+        assert!(b.lock().unwrap().is_focused());
+
+        manager.focus_prev();
+        // Focusing the current component again does nothing
+        manager.focus(&FocusId::new("a"));
+        manager.clear_focus();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["focus a", "blur a", "focus b", "blur b", "focus a", "blur a"]
+        );
+    }
+
+    #[test]
+    fn test_bound_components_notified_across_traps() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("main"), 0);
+        bind_recorder(&mut manager, "main", &log);
+        bind_recorder(&mut manager, "modal", &log);
+        manager.focus_next();
+
+        let mut trap = FocusTrap::new();
+        trap.register(FocusId::new("modal"), 0);
+        manager.push_trap(trap);
+        manager.pop_trap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "focus main",
+                "blur main",
+                "focus modal",
+                "blur modal",
+                "focus main"
+            ]
+        );
+
+        assert!(manager.unbind(&FocusId::new("main")).is_some());
+        assert!(!manager.is_bound(&FocusId::new("main")));
+        manager.clear_focus();
+        assert_eq!(log.lock().unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_focus_operations_report_transitions() {
+        let mut manager = FocusManager::new();
+        manager.register(FocusId::new("a"), 0);
+        manager.register(FocusId::new("b"), 1);
+        let moved = |from: Option<&'static str>, to: &'static str| FocusResult::Moved {
+            from: from.map(FocusId::new),
+            to: FocusId::new(to),
+        };
+
+        assert_eq!(
+            manager.focus_with_result(&FocusId::new("a")),
+            moved(None, "a")
+        );
+        assert_eq!(
+            manager.focus_with_result(&FocusId::new("b")),
+            moved(Some("a"), "b")
+        );
+        assert_eq!(
+            manager.focus_with_result(&FocusId::new("b")),
+            FocusResult::Unchanged(FocusId::new("b"))
+        );
+        assert_eq!(
+            manager.focus_with_result(&FocusId::new("missing")),
+            FocusResult::Unchanged(FocusId::new("b"))
+        );
+
+        let mut trap = FocusTrap::new();
+        trap.register(FocusId::new("ok"), 0);
+        trap.register(FocusId::new("cancel"), 1);
+        assert_eq!(manager.push_trap(trap), moved(Some("b"), "ok"));
+
+        // An empty nested trap leaves nothing focused
+        assert_eq!(
+            manager.push_trap(FocusTrap::new()),
+            FocusResult::NoFocusables
+        );
+        let (trap, result) = manager.pop_trap_with_result().unwrap();
+        assert!(trap.is_empty());
+        assert_eq!(result, moved(None, "ok"));
+
+        manager.focus(&FocusId::new("cancel"));
+        let (_, result) = manager.pop_trap_with_result().unwrap();
+        assert_eq!(result, moved(Some("cancel"), "b"));
+        assert!(manager.pop_trap_with_result().is_none());
+    }
+
+    #[test]
+    fn test_arrow_navigation_stays_on_axis() {
+        let mut manager = FocusManager::new();
+        for (i, name) in ["bold", "italic", "underline"].into_iter().enumerate() {
+            manager.register_with_axis(FocusId::new(name), i as i32, Axis::Horizontal);
+        }
+        for (i, name) in ["inbox", "sent", "drafts"].into_iter().enumerate() {
+            manager.register_with_axis(FocusId::new(name), 10 + i as i32, Axis::Vertical);
+        }
+        manager.register(FocusId::new("editor"), 20);
+
+        let current = |manager: &FocusManager| manager.current().unwrap().as_str().to_string();
+
+        // Left/Right move and wrap within the row; Up/Down do nothing there
+        manager.focus_next();
+        manager.navigate_arrow(KeyCode::Right);
+        assert_eq!(current(&manager), "italic");
+        manager.navigate_arrow(KeyCode::Down);
+        assert_eq!(current(&manager), "italic");
+        manager.navigate_arrow(KeyCode::Right);
+        manager.navigate_arrow(KeyCode::Right);
+        assert_eq!(current(&manager), "bold");
+        manager.navigate_arrow(KeyCode::Left);
+        assert_eq!(current(&manager), "underline");
+
+        // Tab still follows the overall order, into the list
+        manager.focus_next();
+        assert_eq!(current(&manager), "inbox");
+        let result = manager.navigate_arrow(KeyCode::Up).unwrap();
+        assert_eq!(
+            result,
+            FocusResult::Moved {
+                from: Some(FocusId::new("inbox")),
+                to: FocusId::new("drafts"),
+            }
+        );
+        manager.navigate_arrow(KeyCode::Down);
+        assert_eq!(current(&manager), "inbox");
+        manager.navigate_arrow(KeyCode::Right);
+        assert_eq!(current(&manager), "inbox");
+
+        // Components without an axis ignore arrows
+        manager.focus(&FocusId::new("editor"));
+        assert_eq!(
+            manager.navigate_arrow(KeyCode::Left),
+            Some(FocusResult::Unchanged(FocusId::new("editor")))
+        );
+        assert_eq!(manager.navigate_arrow(KeyCode::Tab), None);
+
+        assert!(manager.unregister(&FocusId::new("sent")));
+        assert_eq!(manager.axis(&FocusId::new("sent")), None);
+        assert_eq!(manager.axis(&FocusId::new("inbox")), Some(Axis::Vertical));
+    }
 }
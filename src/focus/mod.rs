@@ -12,6 +12,7 @@
 //! - [`FocusRing`]: Ordered collection of focusable components with navigation
 //! - [`FocusManager`]: Main interface for focus management
 //! - [`FocusTrap`]: Focus restriction for modal dialogs
+//! - [`FocusGroup`]: Composite widgets that act as a single Tab stop
 //!
 //! # Basic Usage
 //!
@@ -126,12 +127,14 @@
 //! manager.register(button.id.clone(), 0);
 //! ```
 
+mod group;
 mod id;
 mod manager;
 mod ring;
 mod trap;
 
+pub use group::FocusGroup;
 pub use id::FocusId;
-pub use manager::{FocusDirection, FocusManager, FocusResult};
+pub use manager::{Axis, FocusDirection, FocusManager, FocusRestore, FocusResult, SharedFocusable};
 pub use ring::FocusRing;
 pub use trap::FocusTrap;
@@ -4,6 +4,8 @@
 //! and handles navigation between them. It supports forward (Tab) and
 //! backward (Shift+Tab) navigation with wrapping.
 
+use std::collections::HashMap;
+
 use super::FocusId;
 
 /// An entry in the focus ring with its order priority.
@@ -154,6 +156,55 @@ impl FocusRing {
         self.sort_entries();
     }
 
+    /// Registers many components at once.
+    ///
+    /// Like calling [`register`](Self::register) for each entry in turn, but
+    /// the ring is only sorted once. IDs that are already registered, or
+    /// that appear more than once in the batch, have their order updated
+    /// rather than being added twice.
+    ///
+    /// Ties are resolved once, after the whole batch: among entries with
+    /// equal order, those already registered keep their previous relative
+    /// order, followed by new IDs in batch order. Calling `register` in turn
+    /// re-sorts after every update instead, so updated entries that end up
+    /// tied can come out in a different order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{FocusId, FocusRing};
+    ///
+    /// let mut ring = FocusRing::new();
+    /// ring.register_all([
+    ///     (FocusId::new("submit"), 10),
+    ///     (FocusId::new("name"), 0),
+    ///     (FocusId::new("email"), 1),
+    /// ]);
+    ///
+    /// assert_eq!(ring.len(), 3);
+    /// assert_eq!(ring.next(), Some(FocusId::new("name")));
+    /// ```
+    pub fn register_all(&mut self, entries: impl IntoIterator<Item = (FocusId, i32)>) {
+        let mut positions: HashMap<FocusId, usize> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.id.clone(), i))
+            .collect();
+
+        for (id, order) in entries {
+            match positions.get(&id) {
+                Some(&i) => self.entries[i].order = order,
+                None => {
+                    positions.insert(id.clone(), self.entries.len());
+                    self.entries.push(FocusEntry { id, order });
+                }
+            }
+        }
+
+        self.sort_entries();
+    }
+
     /// Unregisters a component from the focus ring.
     ///
     /// If the unregistered component had focus, focus moves to the next
@@ -179,24 +230,25 @@ impl FocusRing {
     /// assert!(!ring.unregister(&FocusId::new("button"))); // Already removed
     /// ```
     pub fn unregister(&mut self, id: &FocusId) -> bool {
-        if let Some(pos) = self.entries.iter().position(|e| &e.id == id) {
-            self.entries.remove(pos);
-
-            // Adjust current index if needed
-            if let Some(current) = self.current_index {
-                if self.entries.is_empty() {
-                    self.current_index = None;
-                } else if current == pos {
-                    // Focused item was removed, adjust index
-                    self.current_index = Some(current.min(self.entries.len() - 1));
-                } else if current > pos {
-                    self.current_index = Some(current - 1);
-                }
-            }
+        let Some(pos) = self.entries.iter().position(|e| &e.id == id) else {
+            return false;
+        };
 
-            return true;
-        }
-        false
+        // Track focus by ID rather than index; if the focused entry is the
+        // one removed, focus passes to its successor, wrapping at the end
+        let focus_id = self.current_index.map(|current| {
+            let index = if current == pos {
+                (pos + 1) % self.entries.len()
+            } else {
+                current
+            };
+            self.entries[index].id.clone()
+        });
+
+        self.entries.remove(pos);
+        self.current_index =
+            focus_id.and_then(|focus_id| self.entries.iter().position(|e| e.id == focus_id));
+        true
     }
 
     /// Moves focus to the next component in the ring.
@@ -406,6 +458,46 @@ impl FocusRing {
         self.entries.iter().map(|e| &e.id)
     }
 
+    /// Returns an iterator over the IDs and their order values, in focus order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{FocusId, FocusRing};
+    ///
+    /// let mut ring = FocusRing::new();
+    /// ring.register(FocusId::new("b"), 5);
+    /// ring.register(FocusId::new("a"), -1);
+    ///
+    /// let entries: Vec<_> = ring.entries().collect();
+    /// assert_eq!(entries, vec![(&FocusId::new("a"), -1), (&FocusId::new("b"), 5)]);
+    /// ```
+    pub fn entries(&self) -> impl Iterator<Item = (&FocusId, i32)> {
+        self.entries.iter().map(|e| (&e.id, e.order))
+    }
+
+    /// Returns the position of the focused ID in focus order.
+    ///
+    /// The index matches the position of the ID in [`iter`](Self::iter) and
+    /// [`entries`](Self::entries).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{FocusId, FocusRing};
+    ///
+    /// let mut ring = FocusRing::new();
+    /// ring.register(FocusId::new("a"), 0);
+    /// ring.register(FocusId::new("b"), 0);
+    /// assert_eq!(ring.current_index(), None);
+    ///
+    /// ring.prev();
+    /// assert_eq!(ring.current_index(), Some(1));
+    /// ```
+    pub fn current_index(&self) -> Option<usize> {
+        self.current_index
+    }
+
     /// Sorts entries by order value, maintaining registration order for equal values.
     fn sort_entries(&mut self) {
         // Get the current focused ID before sorting
@@ -444,6 +536,77 @@ mod tests {
         assert!(ring.contains(&FocusId::new("b")));
     }
 
+    #[test]
+    fn test_register_all_matches_register() {
+        let batch = [
+            (FocusId::new("c"), 5),
+            (FocusId::new("a"), 0),
+            (FocusId::new("b"), 0),
+            (FocusId::from(String::from("d")), -1),
+        ];
+
+        let mut one_by_one = FocusRing::new();
+        for (id, order) in batch.clone() {
+            one_by_one.register(id, order);
+        }
+        let mut batched = FocusRing::new();
+        batched.register_all(batch);
+
+        assert_eq!(
+            batched.iter().collect::<Vec<_>>(),
+            one_by_one.iter().collect::<Vec<_>>()
+        );
+        let names: Vec<&str> = batched.iter().map(FocusId::as_str).collect();
+        assert_eq!(names, vec!["d", "a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_register_all_tied_orders() {
+        let mut ring = FocusRing::new();
+        ring.register_all([
+            (FocusId::new("a"), 0),
+            (FocusId::new("b"), 1),
+            (FocusId::new("c"), 2),
+        ]);
+        let mut one_by_one = ring.clone();
+        let batch = [
+            (FocusId::new("c"), 0),
+            (FocusId::new("b"), 0),
+            (FocusId::new("d"), 0),
+        ];
+
+        // Tied entries keep their previous ring order, then new IDs follow
+        ring.register_all(batch.clone());
+        let names: Vec<&str> = ring.iter().map(FocusId::as_str).collect();
+        assert_eq!(names, vec!["a", "b", "c", "d"]);
+
+        // Registering in turn re-sorts after moving "c" ahead of "b"
+        for (id, order) in batch {
+            one_by_one.register(id, order);
+        }
+        let names: Vec<&str> = one_by_one.iter().map(FocusId::as_str).collect();
+        assert_eq!(names, vec!["a", "c", "b", "d"]);
+    }
+
+    #[test]
+    fn test_register_all_duplicates_update() {
+        let mut ring = FocusRing::new();
+        ring.register(FocusId::new("a"), 0);
+        ring.next();
+
+        ring.register_all([
+            (FocusId::new("b"), 1),
+            (FocusId::new("a"), 2),
+            (FocusId::new("b"), -1),
+        ]);
+
+        assert_eq!(ring.len(), 2);
+        let names: Vec<&str> = ring.iter().map(FocusId::as_str).collect();
+        assert_eq!(names, vec!["b", "a"]);
+        // Focus follows the focused entry after re-sorting
+        assert_eq!(ring.current(), Some(&FocusId::new("a")));
+    }
+
     #[test]
     fn test_register_duplicate_updates_order() {
         let mut ring = FocusRing::new();
@@ -503,6 +666,49 @@ mod tests {
         assert_eq!(ring.len(), 2);
     }
 
+    #[test]
+    fn test_unregister_keeps_focus_on_same_entry() {
+        fn ring_focused_on(focused: &'static str) -> FocusRing {
+            let mut ring = FocusRing::new();
+            // Registration order differs from focus order
+            ring.register(FocusId::new("d"), 30);
+            ring.register(FocusId::new("b"), 10);
+            ring.register(FocusId::new("e"), 40);
+            ring.register(FocusId::new("a"), -5);
+            ring.register(FocusId::new("c"), 20);
+            let order: Vec<&str> = ring.iter().map(|id| id.as_str()).collect();
+            assert_eq!(order, ["a", "b", "c", "d", "e"]);
+            ring.focus(&FocusId::new(focused));
+            ring
+        }
+
+        // Removing before the focused entry
+        let mut ring = ring_focused_on("c");
+        ring.unregister(&FocusId::new("a"));
+        assert_eq!(ring.current(), Some(&FocusId::new("c")));
+
+        // Removing after the focused entry
+        let mut ring = ring_focused_on("c");
+        ring.unregister(&FocusId::new("e"));
+        assert_eq!(ring.current(), Some(&FocusId::new("c")));
+
+        // Removing the focused entry moves focus to its successor
+        let mut ring = ring_focused_on("c");
+        ring.unregister(&FocusId::new("c"));
+        assert_eq!(ring.current(), Some(&FocusId::new("d")));
+
+        // ...wrapping around when the last entry was focused
+        let mut ring = ring_focused_on("e");
+        ring.unregister(&FocusId::new("e"));
+        assert_eq!(ring.current(), Some(&FocusId::new("a")));
+
+        // Without focus, nothing gains focus
+        let mut ring = ring_focused_on("c");
+        ring.clear_focus();
+        ring.unregister(&FocusId::new("c"));
+        assert_eq!(ring.current(), None);
+    }
+
     #[test]
     fn test_unregister_all() {
         let mut ring = FocusRing::new();
@@ -662,4 +868,36 @@ mod tests {
         let ring = FocusRing::default();
         assert!(ring.is_empty());
     }
+
+    #[test]
+    fn test_entries_and_current_index() {
+        let mut ring = FocusRing::new();
+        ring.register(FocusId::new("footer"), 10);
+        ring.register(FocusId::new("search"), -5);
+        ring.register(FocusId::new("list"), 0);
+        ring.register(FocusId::new("details"), 0);
+
+        let entries: Vec<(&str, i32)> = ring.entries().map(|(id, o)| (id.as_str(), o)).collect();
+        assert_eq!(
+            entries,
+            vec![("search", -5), ("list", 0), ("details", 0), ("footer", 10)]
+        );
+        assert_eq!(ring.current_index(), None);
+
+        ring.next();
+        ring.next();
+        assert_eq!(ring.current_index(), Some(1));
+        ring.prev();
+        ring.prev();
+        assert_eq!(ring.current_index(), Some(3));
+        assert_eq!(ring.current(), Some(&FocusId::new("footer")));
+
+        // Registering before the focused entry shifts its index
+        ring.register(FocusId::new("header"), -10);
+        assert_eq!(ring.current_index(), Some(4));
+        assert_eq!(ring.current(), Some(&FocusId::new("footer")));
+
+        ring.clear_focus();
+        assert_eq!(ring.current_index(), None);
+    }
 }
@@ -43,6 +43,7 @@ use super::{FocusId, FocusRing};
 pub struct FocusTrap {
     ring: FocusRing,
     saved_focus: Option<FocusId>,
+    initial: Option<FocusId>,
 }
 
 impl Default for FocusTrap {
@@ -66,6 +67,7 @@ impl FocusTrap {
         Self {
             ring: FocusRing::new(),
             saved_focus: None,
+            initial: None,
         }
     }
 
@@ -89,9 +91,40 @@ impl FocusTrap {
         Self {
             ring: FocusRing::new(),
             saved_focus: Some(saved),
+            initial: None,
         }
     }
 
+    /// Sets the component focused when the trap is pushed.
+    ///
+    /// [`FocusManager::push_trap`](super::FocusManager::push_trap) focuses
+    /// this ID instead of the first component, which suits dialogs whose
+    /// safe choice (such as Cancel) is not first. If the ID is not in the
+    /// trap, the first component is focused.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::focus::{FocusId, FocusManager, FocusTrap};
+    ///
+    /// let mut trap = FocusTrap::new().with_initial(FocusId::new("cancel-btn"));
+    /// trap.register(FocusId::new("delete-btn"), 0);
+    /// trap.register(FocusId::new("cancel-btn"), 1);
+    ///
+    /// let mut manager = FocusManager::new();
+    /// manager.push_trap(trap);
+    /// assert_eq!(manager.current(), Some(&FocusId::new("cancel-btn")));
+    /// ```
+    pub fn with_initial(mut self, id: FocusId) -> Self {
+        self.initial = Some(id);
+        self
+    }
+
+    /// Returns the ID focused when the trap is pushed, if set.
+    pub fn initial(&self) -> Option<&FocusId> {
+        self.initial.as_ref()
+    }
+
     /// Returns `true` if the trap has no registered components.
     ///
     /// # Examples
@@ -20,10 +20,103 @@
 //! let save = Action::new("save");
 //! let undo = Action::new("undo");
 //! ```
+//!
+//! Actions can also carry typed arguments:
+//!
+//! ```rust
+//! use tuilib::input::{Action, ActionValue};
+//!
+//! let navigate = Action::new("navigate").with_arg("target", "settings");
+//! assert_eq!(navigate.arg("target").and_then(ActionValue::as_str), Some("settings"));
+//! ```
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt;
 
+/// A typed argument value attached to an [`Action`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ActionValue {
+    /// A string argument.
+    String(String),
+    /// An integer argument.
+    Int(i64),
+    /// A boolean argument.
+    Bool(bool),
+}
+
+impl ActionValue {
+    /// Returns the value as a string slice, if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ActionValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an integer, if it is one.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            ActionValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a boolean, if it is one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ActionValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ActionValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionValue::String(s) => write!(f, "{s:?}"),
+            ActionValue::Int(i) => write!(f, "{i}"),
+            ActionValue::Bool(b) => write!(f, "{b}"),
+        }
+    }
+}
+
+impl From<&str> for ActionValue {
+    fn from(value: &str) -> Self {
+        ActionValue::String(value.to_string())
+    }
+}
+
+impl From<String> for ActionValue {
+    fn from(value: String) -> Self {
+        ActionValue::String(value)
+    }
+}
+
+impl From<char> for ActionValue {
+    fn from(value: char) -> Self {
+        ActionValue::String(value.to_string())
+    }
+}
+
+impl From<i64> for ActionValue {
+    fn from(value: i64) -> Self {
+        ActionValue::Int(value)
+    }
+}
+
+impl From<i32> for ActionValue {
+    fn from(value: i32) -> Self {
+        ActionValue::Int(value.into())
+    }
+}
+
+impl From<bool> for ActionValue {
+    fn from(value: bool) -> Self {
+        ActionValue::Bool(value)
+    }
+}
+
 /// A named action that components can handle.
 ///
 /// Actions provide a semantic layer between raw input events and component
@@ -44,8 +137,13 @@ use std::fmt;
 /// let mut handlers: HashMap<Action, fn()> = HashMap::new();
 /// handlers.insert(quit, || println!("Quitting..."));
 /// ```
+///
+/// Two actions are equal only if both their names and arguments match.
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct Action(Cow<'static, str>);
+pub struct Action {
+    name: Cow<'static, str>,
+    args: BTreeMap<String, ActionValue>,
+}
 
 impl Action {
     /// Creates a new action with the given name.
@@ -63,7 +161,57 @@ impl Action {
     /// assert_eq!(action.name(), "my_action");
     /// ```
     pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
-        Self(name.into())
+        Self {
+            name: name.into(),
+            args: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the action with an argument added, replacing any existing
+    /// argument with the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::{Action, ActionValue};
+    ///
+    /// let action = Action::new("insert").with_arg("char", 'x').with_arg("count", 3);
+    /// assert_eq!(action.arg("count"), Some(&ActionValue::Int(3)));
+    /// ```
+    pub fn with_arg(mut self, key: impl Into<String>, value: impl Into<ActionValue>) -> Self {
+        self.args.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the action's arguments, sorted by key.
+    pub fn args(&self) -> &BTreeMap<String, ActionValue> {
+        &self.args
+    }
+
+    /// Returns the argument with the given key.
+    pub fn arg(&self, key: &str) -> Option<&ActionValue> {
+        self.args.get(key)
+    }
+
+    /// Returns a copy of this action under a new name, keeping its arguments.
+    ///
+    /// Useful for middleware that remaps one action to another.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::Action;
+    ///
+    /// let action = Action::new("go").with_arg("target", "home");
+    /// let remapped = action.renamed("navigate");
+    /// assert_eq!(remapped.name(), "navigate");
+    /// assert_eq!(remapped.args(), action.args());
+    /// ```
+    pub fn renamed(&self, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            args: self.args.clone(),
+        }
     }
 
     /// Returns the name of this action.
@@ -77,19 +225,28 @@ impl Action {
     /// assert_eq!(action.name(), "quit");
     /// ```
     pub fn name(&self) -> &str {
-        &self.0
+        &self.name
     }
 }
 
 impl fmt::Debug for Action {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Action(\"{}\")", self.0)
+        write!(f, "Action(\"{}\"", self.name)?;
+        if !self.args.is_empty() {
+            write!(f, ", {:?}", self.args)?;
+        }
+        write!(f, ")")
     }
 }
 
 impl fmt::Display for Action {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.name)?;
+        if !self.args.is_empty() {
+            let args: Vec<String> = self.args.iter().map(|(k, v)| format!("{k}={v}")).collect();
+            write!(f, "({})", args.join(", "))?;
+        }
+        Ok(())
     }
 }
 
@@ -188,4 +345,45 @@ mod tests {
         let s: &str = action.as_ref();
         assert_eq!(s, "test");
     }
+
+    #[test]
+    fn test_action_args() {
+        let action = Action::new("navigate")
+            .with_arg("target", "settings")
+            .with_arg("count", 2)
+            .with_arg("animate", true);
+
+        assert_eq!(action.name(), "navigate");
+        assert_eq!(action.args().len(), 3);
+        assert_eq!(
+            action.arg("target").and_then(ActionValue::as_str),
+            Some("settings")
+        );
+        assert_eq!(action.arg("count").and_then(ActionValue::as_int), Some(2));
+        assert_eq!(
+            action.arg("animate").and_then(ActionValue::as_bool),
+            Some(true)
+        );
+        assert_eq!(action.arg("count").and_then(ActionValue::as_str), None);
+        assert_eq!(action.arg("missing"), None);
+
+        // Arguments take part in equality
+        assert_ne!(action, Action::new("navigate"));
+        assert_eq!(
+            Action::new("a").with_arg("x", 1).with_arg("y", 2),
+            Action::new("a").with_arg("y", 2).with_arg("x", 1)
+        );
+    }
+
+    #[test]
+    fn test_action_args_formatting() {
+        let action = Action::new("insert")
+            .with_arg("char", 'x')
+            .with_arg("count", 3);
+        assert_eq!(action.to_string(), "insert(char=\"x\", count=3)");
+        assert_eq!(
+            format!("{:?}", action),
+            "Action(\"insert\", {\"char\": String(\"x\"), \"count\": Int(3)})"
+        );
+    }
 }
@@ -209,6 +209,7 @@ impl fmt::Display for KeyBinding {
         }
 
         let key_str = match self.key {
+            KeyCode::Char(' ') => "Space".to_string(),
             KeyCode::Char(c) => c.to_string(),
             KeyCode::F(n) => format!("F{}", n),
             KeyCode::Backspace => "Backspace".to_string(),
@@ -347,6 +348,12 @@ mod tests {
         assert_eq!(format!("{}", binding), "Ctrl+F1");
     }
 
+    #[test]
+    fn test_binding_display_space() {
+        let binding = KeyBinding::new(KeyCode::Char(' '));
+        assert_eq!(format!("{}", binding), "Space");
+    }
+
     #[test]
     fn test_binding_from_key_code() {
         let binding: KeyBinding = KeyCode::Char('q').into();
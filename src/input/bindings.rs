@@ -6,7 +6,8 @@
 //! # Overview
 //!
 //! The keybindings system supports:
-//! - Global bindings that apply everywhere
+//! - Global bindings that apply everywhere unless a context overrides them
+//! - Always-on bindings, such as quit, that no context can override
 //! - Context-scoped bindings for different UI states (modal, component-specific)
 //! - Multiple keys mapping to the same action
 //! - Both programmatic and configuration-file-based setup
@@ -43,13 +44,16 @@ use super::{Action, KeyBinding, KeySequence};
 /// Container for all keybindings organized by context.
 ///
 /// Keybindings are organized into:
-/// - Global bindings: Apply in all contexts
+/// - Always bindings: Apply everywhere and cannot be overridden
+/// - Global bindings: Apply in all contexts, as a fallback
 /// - Context bindings: Apply only within specific named contexts
 ///
-/// When looking up an action, the context bindings are checked first,
-/// then global bindings as a fallback.
+/// When looking up an action, the always bindings are checked first, then
+/// the context bindings, then global bindings as a fallback.
 #[derive(Debug, Clone, Default)]
 pub struct KeyBindings {
+    /// Bindings that apply everywhere and take precedence over contexts
+    always: HashMap<KeySequence, Action>,
     /// Global bindings that apply everywhere
     global: HashMap<KeySequence, Action>,
     /// Context-specific bindings
@@ -79,9 +83,9 @@ impl KeyBindings {
 
     /// Looks up the action for a key sequence in the given context.
     ///
-    /// If a context is provided, it first searches that context's bindings,
-    /// then falls back to global bindings. If no context is provided,
-    /// only global bindings are searched.
+    /// Always bindings are searched first. Then, if a context is provided,
+    /// that context's bindings are searched before falling back to global
+    /// bindings. If no context is provided, only global bindings follow.
     ///
     /// # Arguments
     ///
@@ -112,12 +116,57 @@ impl KeyBindings {
     /// assert!(bindings.lookup(Some("modal"), &q_seq).is_some());
     /// ```
     pub fn lookup(&self, context: Option<&str>, sequence: &KeySequence) -> Option<&Action> {
-        // First check context-specific bindings
-        if let Some(ctx_name) = context {
-            if let Some(ctx_bindings) = self.contexts.get(ctx_name) {
-                if let Some(action) = ctx_bindings.get(sequence) {
-                    return Some(action);
-                }
+        self.lookup_resolved(None, context, sequence)
+    }
+
+    /// Looks up the action for a key sequence given the active mode and
+    /// context.
+    ///
+    /// A mode is a named set of bindings, defined with
+    /// [`KeyBindingsBuilder::context`] like any other, for application-wide
+    /// states such as an editor's insert mode; the context is usually that
+    /// of the focused component. The tiers are searched in order:
+    ///
+    /// 1. Always bindings, which nothing can override
+    /// 2. The mode's bindings
+    /// 3. The context's bindings
+    /// 4. Global bindings
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::{KeyBindings, KeySequence, KeyBinding};
+    /// use terminput::{KeyCode, KeyModifiers};
+    ///
+    /// let bindings = KeyBindings::builder()
+    ///     .always(|a| a.bind("quit", "Ctrl+q"))
+    ///     .bind("help", "?")
+    ///     .context("insert", |ctx| ctx.bind("literal", "?").bind("literal", "Ctrl+q"))
+    ///     .build();
+    ///
+    /// let ctrl_q = KeySequence::single(
+    ///     KeyBinding::new(KeyCode::Char('q')).with_modifiers(KeyModifiers::CTRL),
+    /// );
+    /// let question = KeySequence::single(KeyBinding::new(KeyCode::Char('?')));
+    ///
+    /// let lookup = |seq| bindings.lookup_resolved(Some("insert"), None, seq).map(|a| a.name());
+    /// assert_eq!(lookup(&ctrl_q), Some("quit"));
+    /// assert_eq!(lookup(&question), Some("literal"));
+    /// ```
+    pub fn lookup_resolved(
+        &self,
+        mode: Option<&str>,
+        context: Option<&str>,
+        sequence: &KeySequence,
+    ) -> Option<&Action> {
+        if let Some(action) = self.always.get(sequence) {
+            return Some(action);
+        }
+
+        // Then the mode and context bindings
+        for name in [mode, context].into_iter().flatten() {
+            if let Some(action) = self.contexts.get(name).and_then(|b| b.get(sequence)) {
+                return Some(action);
             }
         }
 
@@ -133,6 +182,11 @@ impl KeyBindings {
         self.lookup(context, &sequence)
     }
 
+    /// Returns all always bindings.
+    pub fn always_bindings(&self) -> &HashMap<KeySequence, Action> {
+        &self.always
+    }
+
     /// Returns all global bindings.
     pub fn global_bindings(&self) -> &HashMap<KeySequence, Action> {
         &self.global
@@ -153,10 +207,179 @@ impl KeyBindings {
         self.global.len()
     }
 
+    /// Returns the number of always bindings.
+    pub fn always_count(&self) -> usize {
+        self.always.len()
+    }
+
     /// Returns the total number of bindings across all contexts.
     pub fn total_count(&self) -> usize {
         let context_count: usize = self.contexts.values().map(|c| c.len()).sum();
-        self.global.len() + context_count
+        self.always.len() + self.global.len() + context_count
+    }
+
+    /// Returns the key sequences bound to an action, in display order.
+    ///
+    /// With a context, this includes the context's bindings plus any global
+    /// bindings the context does not override. Shorter sequences come first,
+    /// then those with fewer modifiers, then by display string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::KeyBindings;
+    ///
+    /// let bindings = KeyBindings::builder()
+    ///     .bind_multi("up", &["k", "Up"])
+    ///     .build();
+    ///
+    /// let keys: Vec<String> = bindings
+    ///     .keys_for(None, "up")
+    ///     .iter()
+    ///     .map(|k| k.to_string())
+    ///     .collect();
+    /// assert_eq!(keys, vec!["Up", "k"]);
+    /// ```
+    pub fn keys_for(&self, context: Option<&str>, action: &str) -> Vec<&KeySequence> {
+        let mut keys: Vec<&KeySequence> = self
+            .visible_bindings(context)
+            .filter(|(_, a)| a.name() == action)
+            .map(|(sequence, _)| sequence)
+            .collect();
+        keys.sort_by_cached_key(|k| {
+            let modifiers: u32 = k.iter().map(|b| b.modifiers().bits().count_ones()).sum();
+            (k.len(), modifiers, k.to_string())
+        });
+        keys
+    }
+
+    /// Returns one help entry per action reachable in the given context.
+    ///
+    /// Entries are sorted by action name, and each lists its keys in the
+    /// order returned by [`keys_for`](Self::keys_for).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::KeyBindings;
+    ///
+    /// let bindings = KeyBindings::builder()
+    ///     .bind("quit", "q")
+    ///     .context("modal", |ctx| ctx.bind("close", "Escape"))
+    ///     .build();
+    ///
+    /// let entries = bindings.help_entries(Some("modal"));
+    /// assert_eq!(entries.len(), 2);
+    /// assert_eq!(entries[0].action.name(), "close");
+    /// assert_eq!(entries[0].keys, vec!["Esc"]);
+    /// ```
+    pub fn help_entries(&self, context: Option<&str>) -> Vec<HelpEntry> {
+        let mut actions: Vec<&Action> = self.visible_bindings(context).map(|(_, a)| a).collect();
+        actions.sort_by(|a, b| a.name().cmp(b.name()));
+        actions.dedup();
+
+        actions
+            .into_iter()
+            .map(|action| HelpEntry {
+                action: action.clone(),
+                keys: self
+                    .keys_for(context, action.name())
+                    .iter()
+                    .map(|k| k.to_string())
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Renders the help entries as Markdown, for generated documentation.
+    ///
+    /// The output starts with a `## Global` section listing the bindings in
+    /// effect outside any context, followed by a section per context in
+    /// name order. Context sections list only the entries that differ from
+    /// the global ones, so they show what the context adds or overrides.
+    /// Each section is a `| Keys | Action |` table with one row per action.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::KeyBindings;
+    ///
+    /// let bindings = KeyBindings::builder()
+    ///     .bind("quit", "q")
+    ///     .context("modal", |ctx| ctx.bind("close", "Escape"))
+    ///     .build();
+    ///
+    /// let markdown = bindings.to_markdown();
+    /// assert!(markdown.starts_with("## Global\n"));
+    /// assert!(markdown.contains("| `q` | quit |"));
+    /// assert!(markdown.contains("## modal\n"));
+    /// assert!(markdown.contains("| `Esc` | close |"));
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let global = self.help_entries(None);
+        let mut out = String::new();
+        push_markdown_section(&mut out, "Global", &global);
+
+        let mut contexts: Vec<&str> = self.context_names().collect();
+        contexts.sort_unstable();
+        for context in contexts {
+            let entries: Vec<HelpEntry> = self
+                .help_entries(Some(context))
+                .into_iter()
+                .filter(|entry| !global.contains(entry))
+                .collect();
+            out.push('\n');
+            push_markdown_section(&mut out, context, &entries);
+        }
+        out
+    }
+
+    /// Iterates over the bindings in effect for a context.
+    ///
+    /// Bindings shadowed by the always bindings or the context are skipped.
+    fn visible_bindings<'a>(
+        &'a self,
+        context: Option<&str>,
+    ) -> impl Iterator<Item = (&'a KeySequence, &'a Action)> {
+        let ctx_bindings = context.and_then(|c| self.contexts.get(c));
+        let contextual = ctx_bindings
+            .into_iter()
+            .flatten()
+            .filter(|(sequence, _)| !self.always.contains_key(*sequence));
+        let globals = self.global.iter().filter(move |(sequence, _)| {
+            !self.always.contains_key(*sequence)
+                && !ctx_bindings.is_some_and(|b| b.contains_key(*sequence))
+        });
+        self.always.iter().chain(contextual).chain(globals)
+    }
+
+    /// Removes the binding for a key sequence, returning its action.
+    ///
+    /// With a context, only that context's binding is removed, so the
+    /// sequence falls back to its global binding there, if any. Without a
+    /// context, the global binding is removed. Always bindings are never
+    /// removed. Returns `None` if nothing was bound to the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::{KeyBindings, KeyBinding, KeySequence};
+    /// use terminput::KeyCode;
+    ///
+    /// let mut bindings = KeyBindings::builder()
+    ///     .bind_multi("quit", &["q", "Escape"])
+    ///     .build();
+    ///
+    /// let q = KeySequence::single(KeyBinding::new(KeyCode::Char('q')));
+    /// assert_eq!(bindings.unbind(None, &q).unwrap().name(), "quit");
+    /// assert!(bindings.lookup(None, &q).is_none());
+    /// assert_eq!(bindings.global_count(), 1);
+    /// ```
+    pub fn unbind(&mut self, context: Option<&str>, sequence: &KeySequence) -> Option<Action> {
+        match context {
+            Some(context) => self.contexts.get_mut(context)?.remove(sequence),
+            None => self.global.remove(sequence),
+        }
     }
 
     /// Merges another KeyBindings into this one.
@@ -164,6 +387,7 @@ impl KeyBindings {
     /// Bindings from `other` will override bindings in `self` for
     /// the same key sequence.
     pub fn merge(&mut self, other: KeyBindings) {
+        self.always.extend(other.always);
         self.global.extend(other.global);
         for (ctx, bindings) in other.contexts {
             self.contexts.entry(ctx).or_default().extend(bindings);
@@ -171,6 +395,40 @@ impl KeyBindings {
     }
 }
 
+/// Appends a Markdown heading and a table of help entries to `out`.
+fn push_markdown_section(out: &mut String, title: &str, entries: &[HelpEntry]) {
+    out.push_str(&format!("## {title}\n\n"));
+    if entries.is_empty() {
+        out.push_str("No bindings.\n");
+        return;
+    }
+    out.push_str("| Keys | Action |\n| --- | --- |\n");
+    for entry in entries {
+        let keys: Vec<String> = entry.keys.iter().map(|k| markdown_code(k)).collect();
+        let action = entry.action.to_string().replace('|', "\\|");
+        out.push_str(&format!("| {} | {action} |\n", keys.join(", ")));
+    }
+}
+
+/// Formats text as an inline Markdown code span that is safe in a table.
+fn markdown_code(text: &str) -> String {
+    let text = text.replace('|', "\\|");
+    if text.contains('`') {
+        format!("`` {text} ``")
+    } else {
+        format!("`{text}`")
+    }
+}
+
+/// An action and the keys bound to it, for help screens and status lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpEntry {
+    /// The action.
+    pub action: Action,
+    /// Display strings for the keys bound to the action, e.g. `"Ctrl+s"`.
+    pub keys: Vec<String>,
+}
+
 /// Builder for creating [`KeyBindings`] with a fluent API.
 ///
 /// # Examples
@@ -190,6 +448,7 @@ impl KeyBindings {
 /// ```
 #[derive(Default)]
 pub struct KeyBindingsBuilder {
+    always: HashMap<KeySequence, Action>,
     global: HashMap<KeySequence, Action>,
     contexts: HashMap<String, HashMap<KeySequence, Action>>,
     errors: Vec<ParseKeyError>,
@@ -333,6 +592,39 @@ impl KeyBindingsBuilder {
         self
     }
 
+    /// Defines bindings that apply everywhere and that no context or mode
+    /// can override.
+    ///
+    /// Use this for actions like quit or toggling help that must always
+    /// work; ordinary [`bind`](Self::bind) bindings are only a fallback for
+    /// keys a context leaves unbound. Calling this again adds to the
+    /// existing always bindings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::{KeyBindingsBuilder, KeyBinding, KeySequence};
+    /// use terminput::KeyCode;
+    ///
+    /// let bindings = KeyBindingsBuilder::new()
+    ///     .always(|a| a.bind("toggle_help", "F1"))
+    ///     .context("editor", |ctx| ctx.bind("rename", "F1"))
+    ///     .build();
+    ///
+    /// let f1 = KeySequence::single(KeyBinding::new(KeyCode::F(1)));
+    /// let action = bindings.lookup(Some("editor"), &f1);
+    /// assert_eq!(action.map(|a| a.name()), Some("toggle_help"));
+    /// ```
+    pub fn always<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(ContextBuilder) -> ContextBuilder,
+    {
+        let always_builder = f(ContextBuilder::new());
+        self.always.extend(always_builder.bindings);
+        self.errors.extend(always_builder.errors);
+        self
+    }
+
     /// Returns any parse errors that occurred during building.
     ///
     /// This allows you to check for and report invalid key strings
@@ -352,6 +644,7 @@ impl KeyBindingsBuilder {
     /// calling `build()` if you want to handle them.
     pub fn build(self) -> KeyBindings {
         KeyBindings {
+            always: self.always,
             global: self.global,
             contexts: self.contexts,
         }
@@ -366,6 +659,7 @@ impl KeyBindingsBuilder {
     pub fn try_build(self) -> Result<KeyBindings, Vec<ParseKeyError>> {
         if self.errors.is_empty() {
             Ok(KeyBindings {
+                always: self.always,
                 global: self.global,
                 contexts: self.contexts,
             })
@@ -450,6 +744,9 @@ impl ContextBuilder {
 /// # Example TOML Configuration
 ///
 /// ```toml
+/// [always]
+/// toggle_help = "F1"
+///
 /// [global]
 /// quit = "Ctrl+q"
 /// save = "Ctrl+s"
@@ -466,6 +763,9 @@ impl ContextBuilder {
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct KeyBindingsConfig {
+    /// Keybindings that no context can override
+    #[serde(default)]
+    pub always: HashMap<String, KeyOrKeys>,
     /// Global keybindings
     #[serde(default)]
     pub global: HashMap<String, KeyOrKeys>,
@@ -511,28 +811,32 @@ impl KeyBindingsConfig {
             }
         }
 
-        // Add context bindings
+        // Add always and context bindings
+        builder = builder.always(|ctx| bind_config(ctx, self.always));
         for (ctx_name, bindings) in self.contexts {
-            builder = builder.context(&ctx_name, |mut ctx| {
-                for (action, keys) in bindings {
-                    match keys {
-                        KeyOrKeys::Single(key) => {
-                            ctx = ctx.bind(action.clone(), &key);
-                        }
-                        KeyOrKeys::Multiple(key_list) => {
-                            let key_refs: Vec<&str> = key_list.iter().map(|s| s.as_str()).collect();
-                            ctx = ctx.bind_multi(action.clone(), &key_refs);
-                        }
-                    }
-                }
-                ctx
-            });
+            builder = builder.context(&ctx_name, |ctx| bind_config(ctx, bindings));
         }
 
         builder.try_build()
     }
 }
 
+/// Adds configured bindings to a context builder.
+fn bind_config(mut ctx: ContextBuilder, bindings: HashMap<String, KeyOrKeys>) -> ContextBuilder {
+    for (action, keys) in bindings {
+        match keys {
+            KeyOrKeys::Single(key) => {
+                ctx = ctx.bind(action.clone(), &key);
+            }
+            KeyOrKeys::Multiple(key_list) => {
+                let key_refs: Vec<&str> = key_list.iter().map(|s| s.as_str()).collect();
+                ctx = ctx.bind_multi(action.clone(), &key_refs);
+            }
+        }
+    }
+    ctx
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -738,6 +1042,7 @@ mod tests {
     #[test]
     fn test_config_single_key() {
         let config = KeyBindingsConfig {
+            always: HashMap::new(),
             global: [("quit".to_string(), KeyOrKeys::Single("q".to_string()))]
                 .into_iter()
                 .collect(),
@@ -752,6 +1057,7 @@ mod tests {
     #[test]
     fn test_config_multiple_keys() {
         let config = KeyBindingsConfig {
+            always: HashMap::new(),
             global: [(
                 "up".to_string(),
                 KeyOrKeys::Multiple(vec!["k".to_string(), "Up".to_string()]),
@@ -772,6 +1078,7 @@ mod tests {
     #[test]
     fn test_config_with_contexts() {
         let config = KeyBindingsConfig {
+            always: HashMap::new(),
             global: [("quit".to_string(), KeyOrKeys::Single("q".to_string()))]
                 .into_iter()
                 .collect(),
@@ -823,4 +1130,152 @@ mod tests {
         assert_eq!(bindings.global_count(), 0);
         assert_eq!(bindings.total_count(), 0);
     }
+
+    #[test]
+    fn test_keys_for_includes_unshadowed_globals() {
+        let bindings = KeyBindings::builder()
+            .bind_multi("close", &["q", "Ctrl+w"])
+            .bind("help", "?")
+            .context("modal", |ctx| {
+                ctx.bind("close", "Escape").bind("confirm", "q")
+            })
+            .build();
+
+        let keys = |context| -> Vec<String> {
+            bindings
+                .keys_for(context, "close")
+                .iter()
+                .map(|k| k.to_string())
+                .collect()
+        };
+        assert_eq!(keys(None), vec!["q", "Ctrl+w"]);
+        // "q" is rebound to confirm inside the modal
+        assert_eq!(keys(Some("modal")), vec!["Esc", "Ctrl+w"]);
+        assert!(bindings.keys_for(None, "missing").is_empty());
+    }
+
+    #[test]
+    fn test_help_entries_sorted_and_deduplicated() {
+        let bindings = KeyBindings::builder()
+            .bind("quit", "q")
+            .bind_multi("down", &["j", "Down"])
+            .build();
+
+        let entries = bindings.help_entries(None);
+        let names: Vec<&str> = entries.iter().map(|e| e.action.name()).collect();
+        assert_eq!(names, vec!["down", "quit"]);
+        assert_eq!(entries[0].keys, vec!["Down", "j"]);
+    }
+
+    #[test]
+    fn test_to_markdown_sections_and_rows() {
+        let bindings = KeyBindings::builder()
+            .bind("quit", "q")
+            .bind_multi("save", &["Ctrl+s", "F2"])
+            .context("modal", |ctx| ctx.bind("close", "Escape").bind("quit", "q"))
+            .context("editor", |ctx| ctx.bind("save", "Ctrl+w"))
+            .build();
+
+        let markdown = bindings.to_markdown();
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(
+            lines[..6],
+            [
+                "## Global",
+                "",
+                "| Keys | Action |",
+                "| --- | --- |",
+                "| `q` | quit |",
+                "| `F2`, `Ctrl+s` | save |",
+            ]
+        );
+
+        // Contexts follow in name order, listing only what differs from global
+        let editor = markdown.find("## editor").unwrap();
+        let modal = markdown.find("## modal").unwrap();
+        assert!(editor < modal);
+        assert!(markdown[editor..modal].contains("| `F2`, `Ctrl+s`, `Ctrl+w` | save |"));
+        assert!(!markdown[editor..modal].contains("quit"));
+        assert!(markdown[modal..].contains("| `Esc` | close |"));
+        assert!(!markdown[modal..].contains("quit"));
+
+        // Pipes in key names are escaped so the table stays intact
+        let pipe = KeyBindings::builder().bind("or", "|").build();
+        assert!(pipe.to_markdown().contains("| `\\|` | or |"));
+    }
+
+    #[test]
+    fn test_unbind_removes_only_that_sequence() {
+        let mut bindings = KeyBindings::builder()
+            .bind_multi("down", &["j", "Down"])
+            .bind("quit", "q")
+            .context("modal", |ctx| {
+                ctx.bind("close", "q").bind("confirm", "Enter")
+            })
+            .build();
+        let seq = |key: &str| parse_key_sequence(key).unwrap();
+        let name = |action: Option<&Action>| action.map(|a| a.name().to_string());
+
+        let removed = bindings.unbind(None, &seq("j"));
+        assert_eq!(name(removed.as_ref()), Some("down".to_string()));
+        assert!(bindings.lookup(None, &seq("j")).is_none());
+        assert_eq!(
+            name(bindings.lookup(None, &seq("Down"))),
+            Some("down".into())
+        );
+        assert!(bindings.unbind(None, &seq("j")).is_none());
+
+        // Unbinding in a context reveals the global binding again
+        assert_eq!(
+            name(bindings.lookup(Some("modal"), &seq("q"))),
+            Some("close".into())
+        );
+        bindings.unbind(Some("modal"), &seq("q"));
+        assert_eq!(
+            name(bindings.lookup(Some("modal"), &seq("q"))),
+            Some("quit".into())
+        );
+        assert_eq!(
+            name(bindings.lookup(Some("modal"), &seq("Enter"))),
+            Some("confirm".into())
+        );
+        assert!(bindings.unbind(Some("missing"), &seq("q")).is_none());
+    }
+
+    #[test]
+    fn test_always_bindings_win_inside_context() {
+        let bindings = KeyBindings::builder()
+            .always(|a| a.bind("quit", "Escape"))
+            .bind("back", "Escape")
+            .context("modal", |ctx| {
+                ctx.bind("close", "Escape").bind("confirm", "Enter")
+            })
+            .context("insert", |ctx| ctx.bind("newline", "Enter"))
+            .build();
+        let esc_seq = KeySequence::single(KeyBinding::new(KeyCode::Esc));
+        let enter_seq = KeySequence::single(KeyBinding::new(KeyCode::Enter));
+        let name = |action: Option<&Action>| action.map(|a| a.name().to_string());
+
+        assert_eq!(
+            name(bindings.lookup(Some("modal"), &esc_seq)),
+            Some("quit".into())
+        );
+        assert_eq!(
+            name(bindings.lookup_resolved(Some("insert"), Some("modal"), &esc_seq)),
+            Some("quit".into())
+        );
+        // The mode is consulted before the context
+        assert_eq!(
+            name(bindings.lookup_resolved(Some("insert"), Some("modal"), &enter_seq)),
+            Some("newline".into())
+        );
+        assert_eq!(
+            name(bindings.lookup_resolved(None, Some("modal"), &enter_seq)),
+            Some("confirm".into())
+        );
+
+        assert_eq!(bindings.always_count(), 1);
+        assert_eq!(bindings.total_count(), 5);
+        assert!(bindings.keys_for(Some("modal"), "close").is_empty());
+    }
 }
@@ -0,0 +1,133 @@
+//! Chords of keys pressed together.
+//!
+//! A [`KeyChord`] is a set of keys held down at the same time, like `j` and
+//! `k` pressed together. Unlike a [`KeySequence`](super::KeySequence), the
+//! order the keys go down in does not matter.
+//!
+//! Legacy terminals never report key releases, so a chord cannot be told
+//! apart from two quick presses. The [`InputMatcher`](super::InputMatcher)
+//! only matches chords once it is told the terminal has enhanced keyboard
+//! support.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use tuilib::input::{KeyBinding, KeyChord};
+//! use terminput::KeyCode;
+//!
+//! let escape = KeyChord::new(vec![
+//!     KeyBinding::new(KeyCode::Char('j')),
+//!     KeyBinding::new(KeyCode::Char('k')),
+//! ]);
+//! assert_eq!(escape.len(), 2);
+//! assert_eq!(escape.to_string(), "j & k");
+//! ```
+
+use std::fmt;
+
+use super::KeyBinding;
+
+/// A set of keys that must be held down together.
+///
+/// Duplicate keys are ignored and the order of the keys does not affect
+/// equality.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::input::{KeyBinding, KeyChord};
+/// use terminput::KeyCode;
+///
+/// let j = KeyBinding::new(KeyCode::Char('j'));
+/// let k = KeyBinding::new(KeyCode::Char('k'));
+///
+/// assert_eq!(
+///     KeyChord::new(vec![j.clone(), k.clone()]),
+///     KeyChord::new(vec![k, j]),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyChord {
+    keys: Vec<KeyBinding>,
+}
+
+impl KeyChord {
+    /// Creates a chord from the keys that make it up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keys` has fewer than two distinct keys.
+    pub fn new(keys: Vec<KeyBinding>) -> Self {
+        let mut distinct: Vec<KeyBinding> = Vec::with_capacity(keys.len());
+        for key in keys {
+            if !distinct.contains(&key) {
+                distinct.push(key);
+            }
+        }
+        assert!(distinct.len() >= 2, "Key chord needs at least two keys");
+        Self { keys: distinct }
+    }
+
+    /// Returns the number of keys in the chord.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Always returns false; a chord has at least two keys.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the keys in the chord, in the order they were given.
+    pub fn keys(&self) -> &[KeyBinding] {
+        &self.keys
+    }
+
+    /// Returns true if `held` is exactly the keys of this chord, in any
+    /// order.
+    pub fn matches(&self, held: &[KeyBinding]) -> bool {
+        held.len() == self.keys.len() && held.iter().all(|key| self.keys.contains(key))
+    }
+}
+
+impl PartialEq for KeyChord {
+    fn eq(&self, other: &Self) -> bool {
+        self.matches(&other.keys)
+    }
+}
+
+impl Eq for KeyChord {}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key_strs: Vec<String> = self.keys.iter().map(|k| k.to_string()).collect();
+        write!(f, "{}", key_strs.join(" & "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use terminput::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_chord_ignores_order_and_duplicates() {
+        let j = KeyBinding::new(KeyCode::Char('j'));
+        let k = KeyBinding::new(KeyCode::Char('k'));
+        let chord = KeyChord::new(vec![j.clone(), k.clone(), j.clone()]);
+
+        assert_eq!(chord.len(), 2);
+        assert!(chord.matches(&[k.clone(), j.clone()]));
+        assert!(!chord.matches(std::slice::from_ref(&j)));
+
+        let ctrl_k = KeyBinding::new(KeyCode::Char('k')).with_modifiers(KeyModifiers::CTRL);
+        assert!(!chord.matches(&[j, ctrl_k]));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least two keys")]
+    fn test_chord_needs_two_keys() {
+        let j = KeyBinding::new(KeyCode::Char('j'));
+        KeyChord::new(vec![j.clone(), j]);
+    }
+}
@@ -0,0 +1,240 @@
+//! Detection of keys pressed twice in quick succession.
+//!
+//! A [`DoublePressDetector`] maps a key to two actions: one for each press,
+//! and one for a second press that follows the first within a time window.
+//! This suits lists where Enter selects an item and a quick second Enter
+//! opens it. Like a mouse double-click, the first press always produces the
+//! single action straight away; the double action follows on the second
+//! press.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use tuilib::event::ManualClock;
+//! use tuilib::input::{Action, DoublePressDetector, KeyBinding};
+//! use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+//!
+//! let clock = ManualClock::new();
+//! let mut detector =
+//!     DoublePressDetector::new_with_clock(Duration::from_millis(300), Arc::new(clock.clone()));
+//! detector.register(
+//!     KeyBinding::new(KeyCode::Enter),
+//!     Action::new("activate"),
+//!     Action::new("activate_double"),
+//! );
+//!
+//! let enter = KeyEvent {
+//!     code: KeyCode::Enter,
+//!     modifiers: KeyModifiers::NONE,
+//!     kind: KeyEventKind::Press,
+//!     state: KeyEventState::NONE,
+//! };
+//! assert_eq!(detector.process(&enter), Some(Action::new("activate")));
+//! clock.advance(Duration::from_millis(100));
+//! assert_eq!(detector.process(&enter), Some(Action::new("activate_double")));
+//! ```
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use terminput::{KeyEvent, KeyEventKind};
+
+use super::{Action, KeyBinding};
+use crate::event::{Clock, SystemClock};
+
+/// Default time within which a second press counts as a double press.
+const DEFAULT_WINDOW: Duration = Duration::from_millis(300);
+
+/// Turns repeated presses of a key into single and double press actions.
+pub struct DoublePressDetector {
+    /// Registered keys with their single and double press actions.
+    bindings: Vec<(KeyBinding, Action, Action)>,
+    /// How soon after the first press the second must come.
+    window: Duration,
+    /// Source of key press timestamps.
+    clock: Arc<dyn Clock + Send + Sync>,
+    /// The last single press that could still become a double press.
+    last_press: Option<(KeyBinding, Instant)>,
+}
+
+impl DoublePressDetector {
+    /// Creates a detector counting presses within `window` as doubles.
+    pub fn new(window: Duration) -> Self {
+        Self::new_with_clock(window, Arc::new(SystemClock))
+    }
+
+    /// Creates a detector that reads time from `clock`.
+    ///
+    /// Use a [`ManualClock`](crate::event::ManualClock) to test double
+    /// presses without sleeping.
+    pub fn new_with_clock(window: Duration, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self {
+            bindings: Vec::new(),
+            window,
+            clock,
+            last_press: None,
+        }
+    }
+
+    /// Registers a key with the actions for a single and a double press.
+    ///
+    /// Registering a key again replaces its actions.
+    pub fn register(&mut self, binding: KeyBinding, single: Action, double: Action) {
+        self.bindings.retain(|(key, _, _)| key != &binding);
+        self.bindings.push((binding, single, double));
+    }
+
+    /// Returns the double press window.
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Sets the double press window.
+    pub fn set_window(&mut self, window: Duration) {
+        self.window = window;
+    }
+
+    /// Processes a key event, returning the action it triggers.
+    ///
+    /// A press of a registered key returns its single action, or its double
+    /// action if the previous press was of the same key within the window.
+    /// A double press is complete, so the press after it starts over with
+    /// the single action. Unregistered keys return `None`, and any other
+    /// key in between breaks up a double press. Releases and auto-repeats
+    /// from holding a key down return `None` and are not counted as
+    /// presses.
+    pub fn process(&mut self, event: &KeyEvent) -> Option<Action> {
+        if event.kind != KeyEventKind::Press {
+            return None;
+        }
+        let now = self.clock.now();
+        let key = KeyBinding::with_mods(event.code, event.modifiers);
+
+        let Some((_, single, double)) = self.bindings.iter().find(|(k, _, _)| k == &key) else {
+            self.last_press = None;
+            return None;
+        };
+
+        let is_double = self.last_press.as_ref().is_some_and(|(last, at)| {
+            last == &key && now.saturating_duration_since(*at) <= self.window
+        });
+        if is_double {
+            self.last_press = None;
+            return Some(double.clone());
+        }
+        let single = single.clone();
+        self.last_press = Some((key, now));
+        Some(single)
+    }
+
+    /// Forgets the last press, so the next press counts as a single.
+    pub fn reset(&mut self) {
+        self.last_press = None;
+    }
+}
+
+impl Default for DoublePressDetector {
+    fn default() -> Self {
+        Self::new(DEFAULT_WINDOW)
+    }
+}
+
+impl std::fmt::Debug for DoublePressDetector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DoublePressDetector")
+            .field("binding_count", &self.bindings.len())
+            .field("window", &self.window)
+            .field("pending", &self.last_press.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::ManualClock;
+    use terminput::{KeyCode, KeyEventState, KeyModifiers};
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    fn detector(clock: &ManualClock) -> DoublePressDetector {
+        let mut detector = DoublePressDetector::new_with_clock(
+            Duration::from_millis(300),
+            Arc::new(clock.clone()),
+        );
+        detector.register(
+            KeyBinding::new(KeyCode::Enter),
+            Action::new("activate"),
+            Action::new("activate_double"),
+        );
+        detector
+    }
+
+    fn names(actions: &[Option<Action>]) -> Vec<&str> {
+        actions.iter().flatten().map(|a| a.name()).collect()
+    }
+
+    #[test]
+    fn test_double_press_within_window() {
+        let clock = ManualClock::new();
+        let mut detector = detector(&clock);
+        let enter = press(KeyCode::Enter);
+
+        let first = detector.process(&enter);
+        clock.advance(Duration::from_millis(300));
+        let second = detector.process(&enter);
+        // A third press starts a new pair
+        clock.advance(Duration::from_millis(100));
+        let third = detector.process(&enter);
+
+        assert_eq!(
+            names(&[first, second, third]),
+            ["activate", "activate_double", "activate"]
+        );
+    }
+
+    #[test]
+    fn test_presses_outside_window_or_interrupted_are_singles() {
+        let clock = ManualClock::new();
+        let mut detector = detector(&clock);
+        let enter = press(KeyCode::Enter);
+
+        let first = detector.process(&enter);
+        clock.advance(Duration::from_millis(301));
+        let second = detector.process(&enter);
+        assert_eq!(names(&[first, second]), ["activate", "activate"]);
+
+        // Another key between the presses breaks up the pair
+        clock.advance(Duration::from_millis(400));
+        detector.process(&enter);
+        assert_eq!(detector.process(&press(KeyCode::Down)), None);
+        assert_eq!(detector.process(&enter), Some(Action::new("activate")));
+
+        // Releases and repeats from holding the key are ignored
+        let release = KeyEvent {
+            kind: KeyEventKind::Release,
+            ..enter
+        };
+        let repeat = KeyEvent {
+            kind: KeyEventKind::Repeat,
+            ..enter
+        };
+        clock.advance(Duration::from_millis(400));
+        detector.process(&enter);
+        assert_eq!(detector.process(&repeat), None);
+        assert_eq!(detector.process(&release), None);
+        assert_eq!(
+            detector.process(&enter),
+            Some(Action::new("activate_double"))
+        );
+    }
+}
@@ -108,6 +108,14 @@ pub enum HandleResult {
     Continue,
     /// The action was handled. Stop propagation.
     Handled,
+    /// The action was handled. Stop propagation and skip any remaining
+    /// handling at the same node, like the DOM's `stopImmediatePropagation`.
+    ///
+    /// The router never calls a node again once it has handled an action,
+    /// so it treats this like `Handled`. Handlers that run several listeners
+    /// of their own can check [`is_immediate`](Self::is_immediate) to skip
+    /// the rest.
+    StopImmediate,
     /// This handler doesn't care about this action.
     /// Continue propagation to the next handler.
     #[default]
@@ -117,12 +125,18 @@ pub enum HandleResult {
 impl HandleResult {
     /// Returns true if propagation should stop after this result.
     pub fn should_stop(&self) -> bool {
-        matches!(self, HandleResult::Handled)
+        matches!(self, HandleResult::Handled | HandleResult::StopImmediate)
     }
 
     /// Returns true if this result indicates the action was handled.
     pub fn is_handled(&self) -> bool {
-        matches!(self, HandleResult::Handled)
+        matches!(self, HandleResult::Handled | HandleResult::StopImmediate)
+    }
+
+    /// Returns true if remaining handling at the same node should be
+    /// skipped.
+    pub fn is_immediate(&self) -> bool {
+        matches!(self, HandleResult::StopImmediate)
     }
 
     /// Returns true if this result indicates the handler ignored the action.
@@ -141,6 +155,7 @@ impl fmt::Display for HandleResult {
         match self {
             HandleResult::Continue => write!(f, "Continue"),
             HandleResult::Handled => write!(f, "Handled"),
+            HandleResult::StopImmediate => write!(f, "StopImmediate"),
             HandleResult::Ignored => write!(f, "Ignored"),
         }
     }
@@ -282,6 +297,11 @@ mod tests {
         assert!(HandleResult::Handled.should_stop());
         assert!(HandleResult::Handled.is_handled());
         assert!(!HandleResult::Handled.should_continue());
+        assert!(!HandleResult::Handled.is_immediate());
+
+        assert!(HandleResult::StopImmediate.should_stop());
+        assert!(HandleResult::StopImmediate.is_handled());
+        assert!(HandleResult::StopImmediate.is_immediate());
 
         assert!(!HandleResult::Continue.should_stop());
         assert!(!HandleResult::Continue.is_handled());
@@ -302,6 +322,7 @@ mod tests {
         assert_eq!(format!("{}", HandleResult::Continue), "Continue");
         assert_eq!(format!("{}", HandleResult::Handled), "Handled");
         assert_eq!(format!("{}", HandleResult::Ignored), "Ignored");
+        assert_eq!(format!("{}", HandleResult::StopImmediate), "StopImmediate");
     }
 
     struct TestHandler {
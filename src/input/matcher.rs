@@ -41,11 +41,19 @@
 //! }
 //! ```
 
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use terminput::KeyEvent;
+use terminput::{KeyEvent, KeyEventKind};
 
-use super::{Action, KeyBinding, KeySequence};
+use super::{Action, KeyBinding, KeyBindings, KeyChord, KeySequence};
+use crate::event::{Clock, SystemClock};
+
+/// A predicate deciding whether a guarded binding is currently enabled.
+///
+/// See [`InputMatcher::register_guarded`].
+pub type BindingGuard = Box<dyn Fn() -> bool + Send + Sync>;
 
 /// Result of processing an input event.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -56,12 +64,49 @@ pub enum MatchResult {
     Pending,
     /// A complete binding matched, returning the associated action.
     Matched(Action),
+    /// A pending sequence was broken by a key that did not continue it, but
+    /// the keys before that key form a complete binding of their own.
+    ///
+    /// For example, with both `Ctrl+X` and `Ctrl+X Ctrl+S` bound, pressing
+    /// `Ctrl+X` then `q` yields the `Ctrl+X` action followed by the result
+    /// of matching `q` on its own.
+    Flushed {
+        /// The action bound to the keys before the breaking key.
+        action: Action,
+        /// The result of matching the breaking key on its own.
+        then: Box<MatchResult>,
+    },
+    /// A registered [`KeyChord`] matched: its keys were pressed together
+    /// within the chord window.
+    ///
+    /// Only produced when the matcher has been told the terminal supports
+    /// enhanced keyboard reporting.
+    Chord(Action),
+    /// A binding registered with a cooldown matched, but fired too recently
+    /// to fire again. The action is not meant to be dispatched.
+    Cooldown(Action),
 }
 
 impl MatchResult {
     /// Returns true if this result is a match.
+    ///
+    /// [`Flushed`](MatchResult::Flushed) and [`Chord`](MatchResult::Chord)
+    /// results count as matches, since they always carry an action.
     pub fn is_matched(&self) -> bool {
-        matches!(self, MatchResult::Matched(_))
+        matches!(
+            self,
+            MatchResult::Matched(_) | MatchResult::Flushed { .. } | MatchResult::Chord(_)
+        )
+    }
+
+    /// Returns true if a binding matched but is cooling down.
+    pub fn is_cooldown(&self) -> bool {
+        matches!(self, MatchResult::Cooldown(_))
+    }
+
+    /// Returns true if this result is a chord match.
+    pub fn is_chord(&self) -> bool {
+        matches!(self, MatchResult::Chord(_))
     }
 
     /// Returns true if this result is pending (partial sequence).
@@ -75,27 +120,57 @@ impl MatchResult {
     }
 
     /// Returns the matched action if this is a match.
+    ///
+    /// For a [`Flushed`](MatchResult::Flushed) result this is the first
+    /// action; use [`into_actions`](Self::into_actions) to get them all.
     pub fn action(&self) -> Option<&Action> {
         match self {
-            MatchResult::Matched(action) => Some(action),
+            MatchResult::Matched(action)
+            | MatchResult::Flushed { action, .. }
+            | MatchResult::Chord(action) => Some(action),
             _ => None,
         }
     }
 
     /// Consumes the result and returns the action if matched.
+    ///
+    /// For a [`Flushed`](MatchResult::Flushed) result this is the first
+    /// action; use [`into_actions`](Self::into_actions) to get them all.
     pub fn into_action(self) -> Option<Action> {
         match self {
-            MatchResult::Matched(action) => Some(action),
+            MatchResult::Matched(action)
+            | MatchResult::Flushed { action, .. }
+            | MatchResult::Chord(action) => Some(action),
             _ => None,
         }
     }
-}
 
-/// A registered binding with its associated action.
-#[derive(Debug, Clone)]
-struct RegisteredBinding {
-    sequence: KeySequence,
-    action: Action,
+    /// Consumes the result and returns every action it carries, in the
+    /// order the keys were pressed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::{Action, MatchResult};
+    ///
+    /// let result = MatchResult::Flushed {
+    ///     action: Action::new("cut"),
+    ///     then: Box::new(MatchResult::Matched(Action::new("quit"))),
+    /// };
+    /// let names: Vec<_> = result.into_actions().iter().map(|a| a.name().to_string()).collect();
+    /// assert_eq!(names, ["cut", "quit"]);
+    /// ```
+    pub fn into_actions(self) -> Vec<Action> {
+        match self {
+            MatchResult::Matched(action) | MatchResult::Chord(action) => vec![action],
+            MatchResult::Flushed { action, then } => {
+                let mut actions = vec![action];
+                actions.extend(then.into_actions());
+                actions
+            }
+            MatchResult::NoMatch | MatchResult::Pending | MatchResult::Cooldown(_) => Vec::new(),
+        }
+    }
 }
 
 /// Matches input events against registered key bindings.
@@ -128,10 +203,33 @@ struct RegisteredBinding {
 /// );
 /// ```
 pub struct InputMatcher {
-    bindings: Vec<RegisteredBinding>,
+    /// All registered bindings, in registration order.
+    bindings: Vec<(KeySequence, Action)>,
+    /// Action for each complete sequence; the first registration wins.
+    complete: HashMap<Vec<KeyBinding>, Action>,
+    /// Every strict prefix of a registered sequence.
+    prefixes: HashSet<Vec<KeyBinding>>,
     pending_keys: Vec<KeyBinding>,
     last_key_time: Option<Instant>,
     sequence_timeout: Duration,
+    /// Source of key press timestamps.
+    clock: Arc<dyn Clock + Send + Sync>,
+    /// Registered chords, in registration order.
+    chords: Vec<(KeyChord, Action)>,
+    /// How close together the keys of a chord must be pressed.
+    chord_window: Duration,
+    /// Whether the terminal reports key releases, enabling chords.
+    keyboard_enhanced: bool,
+    /// Keys pressed recently and not yet released, with when they went down.
+    held: Vec<(KeyBinding, Instant)>,
+    /// Minimum time between firings, for sequences registered with one.
+    cooldowns: HashMap<Vec<KeyBinding>, Duration>,
+    /// When each sequence with a cooldown last fired.
+    last_fired: HashMap<Vec<KeyBinding>, Instant>,
+    /// Pending window overrides, for sequences registered with one.
+    timeouts: HashMap<Vec<KeyBinding>, Duration>,
+    /// Guarded bindings for each sequence, in registration order.
+    guarded: HashMap<Vec<KeyBinding>, Vec<(BindingGuard, Action)>>,
 }
 
 impl InputMatcher {
@@ -150,14 +248,85 @@ impl InputMatcher {
     /// let matcher = InputMatcher::new(Duration::from_millis(500));
     /// ```
     pub fn new(sequence_timeout: Duration) -> Self {
+        Self::new_with_clock(sequence_timeout, Arc::new(SystemClock))
+    }
+
+    /// Creates a new input matcher that reads time from `clock`.
+    ///
+    /// Use a [`ManualClock`](crate::event::ManualClock) to test sequence
+    /// timeouts without sleeping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use tuilib::event::ManualClock;
+    /// use tuilib::input::InputMatcher;
+    ///
+    /// let clock = ManualClock::new();
+    /// let matcher = InputMatcher::new_with_clock(Duration::from_millis(500), Arc::new(clock.clone()));
+    /// ```
+    pub fn new_with_clock(sequence_timeout: Duration, clock: Arc<dyn Clock + Send + Sync>) -> Self {
         Self {
             bindings: Vec::new(),
+            complete: HashMap::new(),
+            prefixes: HashSet::new(),
             pending_keys: Vec::new(),
             last_key_time: None,
             sequence_timeout,
+            clock,
+            chords: Vec::new(),
+            chord_window: Duration::from_millis(50),
+            keyboard_enhanced: false,
+            held: Vec::new(),
+            cooldowns: HashMap::new(),
+            last_fired: HashMap::new(),
+            timeouts: HashMap::new(),
+            guarded: HashMap::new(),
         }
     }
 
+    /// Creates a matcher with every binding active in `context`.
+    ///
+    /// Always bindings are registered first, then the context's bindings,
+    /// then the global ones, so precedence matches [`KeyBindings::lookup`].
+    /// With `None` only always and global bindings are registered. The matcher uses the default 1
+    /// second sequence timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::{InputMatcher, KeyBindings};
+    ///
+    /// let bindings = KeyBindings::builder()
+    ///     .bind("quit", "q")
+    ///     .bind("save", "Ctrl+x Ctrl+s")
+    ///     .context("modal", |ctx| ctx.bind("close", "Escape"))
+    ///     .build();
+    ///
+    /// let matcher = InputMatcher::from_key_bindings(&bindings, Some("modal"));
+    /// assert_eq!(matcher.binding_count(), 3);
+    /// ```
+    pub fn from_key_bindings(bindings: &KeyBindings, context: Option<&str>) -> Self {
+        let mut matcher = Self::with_default_timeout();
+
+        let context_bindings = context.and_then(|name| bindings.context_bindings(name));
+        for map in std::iter::once(bindings.always_bindings())
+            .chain(context_bindings)
+            .chain(std::iter::once(bindings.global_bindings()))
+        {
+            // Sort so the registration order does not depend on hashing
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by_cached_key(|(sequence, _)| sequence.to_string());
+            for (sequence, action) in entries {
+                matcher.register(sequence.clone(), action.clone());
+            }
+        }
+
+        matcher
+    }
+
     /// Creates a new matcher with a default 1 second timeout.
     pub fn with_default_timeout() -> Self {
         Self::new(Duration::from_secs(1))
@@ -187,7 +356,155 @@ impl InputMatcher {
     /// );
     /// ```
     pub fn register(&mut self, sequence: KeySequence, action: Action) {
-        self.bindings.push(RegisteredBinding { sequence, action });
+        let keys = sequence.keys();
+        self.index_prefixes(keys);
+        self.complete
+            .entry(keys.to_vec())
+            .or_insert_with(|| action.clone());
+        self.bindings.push((sequence, action));
+    }
+
+    /// Registers a key sequence whose action fires at most once per
+    /// `cooldown`.
+    ///
+    /// While the cooldown runs, matching the sequence again returns
+    /// [`MatchResult::Cooldown`] instead of a match, so holding down a key
+    /// bound to an expensive action does not fire it on every repeat. Time
+    /// is read from the matcher's clock.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tuilib::input::{Action, InputMatcher, KeyBinding, KeySequence};
+    /// use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+    ///
+    /// let mut matcher = InputMatcher::new(Duration::from_millis(1000));
+    /// matcher.register_with_cooldown(
+    ///     KeySequence::single(KeyBinding::new(KeyCode::Char('r'))),
+    ///     Action::new("refresh"),
+    ///     Duration::from_secs(60),
+    /// );
+    ///
+    /// let event = KeyEvent {
+    ///     code: KeyCode::Char('r'),
+    ///     modifiers: KeyModifiers::NONE,
+    ///     kind: KeyEventKind::Press,
+    ///     state: KeyEventState::NONE,
+    /// };
+    /// assert!(matcher.process(&event).is_matched());
+    /// assert!(matcher.process(&event).is_cooldown());
+    /// ```
+    pub fn register_with_cooldown(
+        &mut self,
+        sequence: KeySequence,
+        action: Action,
+        cooldown: Duration,
+    ) {
+        self.cooldowns.insert(sequence.keys().to_vec(), cooldown);
+        self.register(sequence, action);
+    }
+
+    /// Registers a key sequence with its own pending timeout.
+    ///
+    /// While the keys typed so far could still complete this sequence, the
+    /// matcher waits up to `timeout` for the next key instead of the
+    /// default [`sequence_timeout`](Self::sequence_timeout). This suits
+    /// leader-key menus, which should give up sooner (or later) than other
+    /// sequences. When several sequences could still complete, the longest
+    /// of their timeouts applies, so none is cut short.
+    ///
+    /// For a timeout per context, create a matcher per context with
+    /// [`from_key_bindings`](Self::from_key_bindings) and set its
+    /// [`sequence_timeout`](Self::set_sequence_timeout).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tuilib::input::{Action, InputMatcher, KeySequence};
+    /// use tuilib::input::parser::parse_key_sequence;
+    ///
+    /// let mut matcher = InputMatcher::new(Duration::from_millis(1000));
+    /// matcher.register_with_timeout(
+    ///     parse_key_sequence("Space f").unwrap(),
+    ///     Action::new("find_file"),
+    ///     Duration::from_millis(300),
+    /// );
+    /// ```
+    pub fn register_with_timeout(
+        &mut self,
+        sequence: KeySequence,
+        action: Action,
+        timeout: Duration,
+    ) {
+        self.timeouts.insert(sequence.keys().to_vec(), timeout);
+        self.register(sequence, action);
+    }
+
+    /// Registers a key sequence that only fires while `guard` returns true.
+    ///
+    /// The guard is checked each time the sequence is completed. While it
+    /// holds, the guarded binding takes priority over bindings registered
+    /// for the same sequence without a guard; while it does not, the
+    /// sequence falls back to the next guarded binding whose guard holds,
+    /// then to the unguarded binding, and otherwise produces
+    /// [`MatchResult::NoMatch`]. This lets one key do different things
+    /// depending on application state.
+    ///
+    /// Guarded bindings are not part of the [`bindings`](Self::bindings)
+    /// snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use tuilib::input::{Action, InputMatcher, KeyBinding, KeySequence};
+    /// use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+    ///
+    /// let dirty = Arc::new(AtomicBool::new(false));
+    /// let ctrl_s = KeySequence::single(
+    ///     KeyBinding::new(KeyCode::Char('s')).with_modifiers(KeyModifiers::CTRL),
+    /// );
+    ///
+    /// let mut matcher = InputMatcher::new(Duration::from_millis(1000));
+    /// let is_dirty = Arc::clone(&dirty);
+    /// matcher.register_guarded(
+    ///     ctrl_s,
+    ///     Action::new("save"),
+    ///     Box::new(move || is_dirty.load(Ordering::Relaxed)),
+    /// );
+    ///
+    /// let event = KeyEvent {
+    ///     code: KeyCode::Char('s'),
+    ///     modifiers: KeyModifiers::CTRL,
+    ///     kind: KeyEventKind::Press,
+    ///     state: KeyEventState::NONE,
+    /// };
+    /// assert!(matcher.process(&event).is_no_match());
+    ///
+    /// dirty.store(true, Ordering::Relaxed);
+    /// assert_eq!(matcher.process(&event).action().unwrap().name(), "save");
+    /// ```
+    pub fn register_guarded(&mut self, sequence: KeySequence, action: Action, guard: BindingGuard) {
+        let keys = sequence.keys();
+        self.index_prefixes(keys);
+        self.guarded
+            .entry(keys.to_vec())
+            .or_default()
+            .push((guard, action));
+    }
+
+    /// Indexes every strict prefix of `keys`, so partial matches are a
+    /// single lookup.
+    fn index_prefixes(&mut self, keys: &[KeyBinding]) {
+        for len in 1..keys.len() {
+            if !self.prefixes.contains(&keys[..len]) {
+                self.prefixes.insert(keys[..len].to_vec());
+            }
+        }
     }
 
     /// Registers multiple key sequences that trigger the same action.
@@ -201,10 +518,7 @@ impl InputMatcher {
     /// * `action` - The action to trigger when any sequence matches
     pub fn register_multiple(&mut self, sequences: Vec<KeySequence>, action: Action) {
         for sequence in sequences {
-            self.bindings.push(RegisteredBinding {
-                sequence,
-                action: action.clone(),
-            });
+            self.register(sequence, action.clone());
         }
     }
 
@@ -220,6 +534,77 @@ impl InputMatcher {
         self.register(KeySequence::single(binding), action);
     }
 
+    /// Registers a chord of keys pressed together that triggers an action.
+    ///
+    /// Chords are only matched once
+    /// [`set_keyboard_enhanced`](Self::set_keyboard_enhanced) reports that
+    /// the terminal can tell held keys apart from quick presses. The first
+    /// key of a chord is still matched on its own as it goes down, so chords
+    /// work best with keys that are not bound individually.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tuilib::input::{Action, InputMatcher, KeyBinding, KeyChord};
+    /// use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+    ///
+    /// let mut matcher = InputMatcher::new(Duration::from_millis(1000));
+    /// matcher.set_keyboard_enhanced(true);
+    /// matcher.register_chord(
+    ///     KeyChord::new(vec![
+    ///         KeyBinding::new(KeyCode::Char('j')),
+    ///         KeyBinding::new(KeyCode::Char('k')),
+    ///     ]),
+    ///     Action::new("escape"),
+    /// );
+    ///
+    /// let press = |c| KeyEvent {
+    ///     code: KeyCode::Char(c),
+    ///     modifiers: KeyModifiers::NONE,
+    ///     kind: KeyEventKind::Press,
+    ///     state: KeyEventState::NONE,
+    /// };
+    /// assert!(matcher.process(&press('j')).is_no_match());
+    /// assert!(matcher.process(&press('k')).is_chord());
+    /// ```
+    pub fn register_chord(&mut self, chord: KeyChord, action: Action) {
+        self.chords.push((chord, action));
+    }
+
+    /// Returns the number of registered chords.
+    pub fn chord_count(&self) -> usize {
+        self.chords.len()
+    }
+
+    /// Sets whether the terminal has enhanced keyboard support.
+    ///
+    /// Pass the result of
+    /// [`supports_keyboard_enhancement`](crate::event::supports_keyboard_enhancement),
+    /// and enable the protocol with
+    /// [`TerminalOptions::keyboard_enhancement`](crate::event::TerminalOptions::keyboard_enhancement)
+    /// so that the terminal actually reports releases. When enabled, registered chords are matched and key releases are
+    /// tracked instead of being matched as presses.
+    pub fn set_keyboard_enhanced(&mut self, enhanced: bool) {
+        self.keyboard_enhanced = enhanced;
+        self.held.clear();
+    }
+
+    /// Returns true if chords are being matched.
+    pub fn is_keyboard_enhanced(&self) -> bool {
+        self.keyboard_enhanced
+    }
+
+    /// Returns how close together the keys of a chord must be pressed.
+    pub fn chord_window(&self) -> Duration {
+        self.chord_window
+    }
+
+    /// Sets how close together the keys of a chord must be pressed.
+    pub fn set_chord_window(&mut self, window: Duration) {
+        self.chord_window = window;
+    }
+
     /// Processes an input event and returns the match result.
     ///
     /// This method maintains internal state for multi-key sequences.
@@ -234,12 +619,37 @@ impl InputMatcher {
     /// - `MatchResult::Matched(action)` if a binding completed
     /// - `MatchResult::Pending` if a partial sequence matched
     /// - `MatchResult::NoMatch` if no binding matched
+    /// - `MatchResult::Flushed { .. }` if the key broke a pending sequence
+    ///   whose earlier keys are bound on their own
+    /// - `MatchResult::Chord(action)` if the key completed a chord
+    /// - `MatchResult::Cooldown(action)` if a binding matched while its
+    ///   cooldown was running
+    ///
+    /// With enhanced keyboard support, key releases return
+    /// `MatchResult::NoMatch` and leave any pending sequence untouched.
     pub fn process(&mut self, event: &KeyEvent) -> MatchResult {
-        let now = Instant::now();
+        let now = self.clock.now();
+
+        if self.keyboard_enhanced {
+            match event.kind {
+                KeyEventKind::Release => {
+                    self.held.retain(|(key, _)| key.key() != event.code);
+                    return MatchResult::NoMatch;
+                }
+                KeyEventKind::Press => {
+                    let key = KeyBinding::with_mods(event.code, event.modifiers);
+                    if let Some(action) = self.match_chord(key, now) {
+                        self.reset_sequence();
+                        return MatchResult::Chord(action);
+                    }
+                }
+                KeyEventKind::Repeat => {}
+            }
+        }
 
         // Check for sequence timeout
         if let Some(last_time) = self.last_key_time {
-            if now.duration_since(last_time) > self.sequence_timeout {
+            if now.duration_since(last_time) > self.pending_timeout() {
                 self.reset_sequence();
             }
         }
@@ -259,31 +669,132 @@ impl InputMatcher {
 
         // Check for complete matches - only if no longer sequence is possible
         if let Some(action) = self.find_complete_match() {
-            self.reset_sequence();
-            return MatchResult::Matched(action);
+            return self.fire_pending(action, now);
         }
 
         // No match - try just this key alone (reset sequence and retry)
         if self.pending_keys.len() > 1 {
+            // Keys before this one may have a binding of their own, which
+            // was only waiting in case the sequence continued
+            let prefix = self.pending_keys[..self.pending_keys.len() - 1].to_vec();
+            let flushed = match self.action_for(&prefix) {
+                Some(action) => self.fire(&prefix, action, now).into_action(),
+                None => None,
+            };
+
             self.pending_keys.clear();
             self.pending_keys.push(key_binding);
+            let result = self.match_single_key(now);
+
+            return match flushed {
+                Some(action) => MatchResult::Flushed {
+                    action,
+                    then: Box::new(result),
+                },
+                None => result,
+            };
+        }
 
-            // Check for partial matches first
-            if self.has_partial_match() {
-                return MatchResult::Pending;
-            }
+        self.reset_sequence();
+        MatchResult::NoMatch
+    }
 
-            // Check single key match
-            if let Some(action) = self.find_complete_match() {
-                self.reset_sequence();
-                return MatchResult::Matched(action);
-            }
+    /// Records a key press and returns the action of the chord it completes.
+    fn match_chord(&mut self, key: KeyBinding, now: Instant) -> Option<Action> {
+        if self.chords.is_empty() {
+            return None;
+        }
+
+        // Keys pressed before the window are held, not chorded
+        let window = self.chord_window;
+        self.held
+            .retain(|(held, at)| held != &key && now.saturating_duration_since(*at) <= window);
+        self.held.push((key, now));
+
+        let held: Vec<KeyBinding> = self.held.iter().map(|(key, _)| key.clone()).collect();
+        let (_, action) = self.chords.iter().find(|(chord, _)| chord.matches(&held))?;
+        let action = action.clone();
+        self.held.clear();
+        Some(action)
+    }
+
+    /// Matches the single pending key after a broken sequence.
+    fn match_single_key(&mut self, now: Instant) -> MatchResult {
+        // Check for partial matches first
+        if self.has_partial_match() {
+            return MatchResult::Pending;
+        }
+
+        // Check single key match
+        if let Some(action) = self.find_complete_match() {
+            return self.fire_pending(action, now);
         }
 
         self.reset_sequence();
         MatchResult::NoMatch
     }
 
+    /// Resolves a pending sequence once the sequence timeout has elapsed.
+    ///
+    /// `now` should come from the same clock as the matcher's (the system
+    /// clock unless it was created with [`new_with_clock`](Self::new_with_clock)).
+    ///
+    /// When a prefix such as `g` is pending because `gg` is also bound, the
+    /// matcher waits for another key. Call this periodically (for example
+    /// on every [`AppEvent::Tick`](crate::event::AppEvent::Tick)) so that
+    /// when the user stops typing, the shorter binding fires.
+    ///
+    /// # Returns
+    ///
+    /// - `None` if nothing is pending or the timeout has not elapsed yet
+    /// - `Some(MatchResult::Matched(action))` if the pending keys form a
+    ///   complete binding on their own
+    /// - `Some(MatchResult::NoMatch)` if the pending keys were abandoned
+    ///
+    /// The pending sequence is reset whenever `Some` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use tuilib::input::{Action, InputMatcher, KeyBinding, KeySequence, MatchResult};
+    /// use terminput::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+    ///
+    /// let g = KeyBinding::new(KeyCode::Char('g'));
+    /// let mut matcher = InputMatcher::new(Duration::from_millis(500));
+    /// matcher.register(KeySequence::single(g.clone()), Action::new("next_tab"));
+    /// matcher.register(KeySequence::new(vec![g.clone(), g]), Action::new("go_top"));
+    ///
+    /// let event = KeyEvent {
+    ///     code: KeyCode::Char('g'),
+    ///     modifiers: KeyModifiers::NONE,
+    ///     kind: KeyEventKind::Press,
+    ///     state: KeyEventState::NONE,
+    /// };
+    /// assert!(matcher.process(&event).is_pending());
+    ///
+    /// let later = Instant::now() + Duration::from_secs(1);
+    /// assert_eq!(
+    ///     matcher.poll(later),
+    ///     Some(MatchResult::Matched(Action::new("next_tab")))
+    /// );
+    /// ```
+    pub fn poll(&mut self, now: Instant) -> Option<MatchResult> {
+        let last_time = self.last_key_time?;
+        if self.pending_keys.is_empty()
+            || now.saturating_duration_since(last_time) <= self.pending_timeout()
+        {
+            return None;
+        }
+
+        let result = match self.find_complete_match() {
+            Some(action) => self.fire_pending(action, now),
+            None => MatchResult::NoMatch,
+        };
+        self.reset_sequence();
+        Some(result)
+    }
+
     /// Resets the sequence matching state.
     ///
     /// Call this when you want to cancel any pending sequence.
@@ -302,64 +813,218 @@ impl InputMatcher {
         &self.pending_keys
     }
 
-    /// Returns the sequence timeout duration.
+    /// Resolves a timed-out sequence using the matcher's own clock.
+    ///
+    /// Equivalent to `poll(clock.now())`.
+    pub fn poll_now(&mut self) -> Option<MatchResult> {
+        self.poll(self.clock.now())
+    }
+
+    /// Returns the default sequence timeout duration.
     pub fn sequence_timeout(&self) -> Duration {
         self.sequence_timeout
     }
 
-    /// Sets a new sequence timeout.
+    /// Returns how long the matcher waits for the next key of the pending
+    /// sequence.
+    ///
+    /// This is the longest timeout among the sequences the pending keys
+    /// could still complete, counting sequences registered without their
+    /// own timeout as the default [`sequence_timeout`](Self::sequence_timeout).
+    /// With nothing pending it is the default.
+    pub fn pending_timeout(&self) -> Duration {
+        if self.pending_keys.is_empty() || self.timeouts.is_empty() {
+            return self.sequence_timeout;
+        }
+        self.bindings
+            .iter()
+            .filter(|(sequence, _)| {
+                sequence.len() > self.pending_keys.len() && sequence.starts_with(&self.pending_keys)
+            })
+            .map(|(sequence, _)| {
+                self.timeouts
+                    .get(sequence.keys())
+                    .copied()
+                    .unwrap_or(self.sequence_timeout)
+            })
+            .max()
+            .unwrap_or(self.sequence_timeout)
+    }
+
+    /// Sets a new default sequence timeout.
     pub fn set_sequence_timeout(&mut self, timeout: Duration) {
         self.sequence_timeout = timeout;
     }
 
-    /// Returns the number of registered bindings.
+    /// Returns the number of registered bindings, including guarded ones.
     pub fn binding_count(&self) -> usize {
-        self.bindings.len()
+        self.bindings.len() + self.guarded.values().map(Vec::len).sum::<usize>()
+    }
+
+    /// Returns a snapshot of the registered bindings, in registration order.
+    ///
+    /// Pass the result to [`load`](Self::load) to restore it later.
+    pub fn bindings(&self) -> Vec<(KeySequence, Action)> {
+        self.bindings.clone()
+    }
+
+    /// Replaces all registered bindings, resetting any pending sequence.
+    ///
+    /// As with [`register`](Self::register), when a sequence appears more
+    /// than once the first entry wins.
+    /// Chords, cooldowns, per-sequence timeouts and guarded bindings are not
+    /// part of the snapshot and are cleared.
+    pub fn load(&mut self, bindings: Vec<(KeySequence, Action)>) {
+        self.clear_bindings();
+        for (sequence, action) in bindings {
+            self.register(sequence, action);
+        }
+    }
+
+    /// Removes every binding for a key sequence, including guarded ones.
+    ///
+    /// The sequence's cooldown and pending timeout are dropped with it, and
+    /// any pending sequence is reset. Returns true if anything was removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::{Action, InputMatcher, KeyBinding, KeySequence};
+    /// use terminput::KeyCode;
+    ///
+    /// let mut matcher = InputMatcher::with_default_timeout();
+    /// let q = KeySequence::single(KeyBinding::new(KeyCode::Char('q')));
+    /// matcher.register(q.clone(), Action::new("quit"));
+    ///
+    /// assert!(matcher.unregister(&q));
+    /// assert!(!matcher.unregister(&q));
+    /// assert_eq!(matcher.binding_count(), 0);
+    /// ```
+    pub fn unregister(&mut self, sequence: &KeySequence) -> bool {
+        let keys = sequence.keys();
+        let before = self.binding_count();
+        self.bindings.retain(|(bound, _)| bound.keys() != keys);
+        self.guarded.remove(keys);
+        let removed = self.binding_count() != before;
+        if removed {
+            self.reindex();
+        }
+        removed
+    }
+
+    /// Removes every binding and chord that triggers the named action.
+    ///
+    /// Returns the number of bindings and chords removed. As with
+    /// [`unregister`](Self::unregister), settings for sequences left without
+    /// a binding are dropped and any pending sequence is reset.
+    pub fn unregister_action(&mut self, name: &str) -> usize {
+        let before = self.binding_count() + self.chords.len();
+        self.bindings.retain(|(_, action)| action.name() != name);
+        self.chords.retain(|(_, action)| action.name() != name);
+        for entries in self.guarded.values_mut() {
+            entries.retain(|(_, action)| action.name() != name);
+        }
+        self.guarded.retain(|_, entries| !entries.is_empty());
+        let removed = before - (self.binding_count() + self.chords.len());
+        if removed > 0 {
+            self.reindex();
+        }
+        removed
+    }
+
+    /// Rebuilds the lookup indexes after bindings are removed.
+    ///
+    /// Drops cooldowns and timeouts of sequences that are no longer bound,
+    /// and resets any pending sequence, since it may no longer lead
+    /// anywhere.
+    fn reindex(&mut self) {
+        self.complete.clear();
+        self.prefixes.clear();
+        let bindings = std::mem::take(&mut self.bindings);
+        for (sequence, action) in &bindings {
+            let keys = sequence.keys();
+            self.index_prefixes(keys);
+            self.complete
+                .entry(keys.to_vec())
+                .or_insert_with(|| action.clone());
+        }
+        self.bindings = bindings;
+        let guarded: Vec<Vec<KeyBinding>> = self.guarded.keys().cloned().collect();
+        for keys in &guarded {
+            self.index_prefixes(keys);
+        }
+
+        let bound = |keys: &Vec<KeyBinding>| {
+            self.complete.contains_key(keys) || self.guarded.contains_key(keys)
+        };
+        let (cooldowns, last_fired, timeouts) = (
+            std::mem::take(&mut self.cooldowns),
+            std::mem::take(&mut self.last_fired),
+            std::mem::take(&mut self.timeouts),
+        );
+        self.cooldowns = cooldowns.into_iter().filter(|(k, _)| bound(k)).collect();
+        self.last_fired = last_fired.into_iter().filter(|(k, _)| bound(k)).collect();
+        self.timeouts = timeouts.into_iter().filter(|(k, _)| bound(k)).collect();
+        self.held.clear();
+        self.reset_sequence();
     }
 
     /// Clears all registered bindings.
     pub fn clear_bindings(&mut self) {
         self.bindings.clear();
+        self.complete.clear();
+        self.prefixes.clear();
+        self.chords.clear();
+        self.held.clear();
+        self.cooldowns.clear();
+        self.last_fired.clear();
+        self.timeouts.clear();
+        self.guarded.clear();
         self.reset_sequence();
     }
 
     /// Finds a binding that completely matches the pending keys.
     fn find_complete_match(&self) -> Option<Action> {
-        for binding in &self.bindings {
-            if binding.sequence.len() == self.pending_keys.len() {
-                let matches = binding
-                    .sequence
-                    .keys()
-                    .iter()
-                    .zip(&self.pending_keys)
-                    .all(|(seq_key, pending_key)| seq_key == pending_key);
-
-                if matches {
-                    return Some(binding.action.clone());
-                }
+        self.action_for(&self.pending_keys)
+    }
+
+    /// Returns the action bound to `keys`, preferring the first guarded
+    /// binding whose guard holds.
+    fn action_for(&self, keys: &[KeyBinding]) -> Option<Action> {
+        let guarded = self
+            .guarded
+            .get(keys)
+            .and_then(|entries| entries.iter().find(|(guard, _)| guard()));
+        match guarded {
+            Some((_, action)) => Some(action.clone()),
+            None => self.complete.get(keys).cloned(),
+        }
+    }
+
+    /// Fires the action bound to the pending keys and resets the sequence.
+    fn fire_pending(&mut self, action: Action, now: Instant) -> MatchResult {
+        let keys = std::mem::take(&mut self.pending_keys);
+        self.reset_sequence();
+        self.fire(&keys, action, now)
+    }
+
+    /// Fires the action bound to `keys`, unless its cooldown is running.
+    fn fire(&mut self, keys: &[KeyBinding], action: Action, now: Instant) -> MatchResult {
+        let Some(&cooldown) = self.cooldowns.get(keys) else {
+            return MatchResult::Matched(action);
+        };
+        if let Some(&last) = self.last_fired.get(keys) {
+            if now.saturating_duration_since(last) < cooldown {
+                return MatchResult::Cooldown(action);
             }
         }
-        None
+        self.last_fired.insert(keys.to_vec(), now);
+        MatchResult::Matched(action)
     }
 
     /// Checks if any binding could potentially match with more keys.
     fn has_partial_match(&self) -> bool {
-        for binding in &self.bindings {
-            if binding.sequence.len() > self.pending_keys.len() {
-                let prefix_matches = binding
-                    .sequence
-                    .keys()
-                    .iter()
-                    .take(self.pending_keys.len())
-                    .zip(&self.pending_keys)
-                    .all(|(seq_key, pending_key)| seq_key == pending_key);
-
-                if prefix_matches {
-                    return true;
-                }
-            }
-        }
-        false
+        self.prefixes.contains(self.pending_keys.as_slice())
     }
 }
 
@@ -375,6 +1040,10 @@ impl std::fmt::Debug for InputMatcher {
             .field("binding_count", &self.bindings.len())
             .field("pending_keys", &self.pending_keys.len())
             .field("sequence_timeout", &self.sequence_timeout)
+            .field("timeout_overrides", &self.timeouts.len())
+            .field("guarded_sequences", &self.guarded.len())
+            .field("chord_count", &self.chords.len())
+            .field("keyboard_enhanced", &self.keyboard_enhanced)
             .finish()
     }
 }
@@ -506,6 +1175,52 @@ mod tests {
         assert_eq!(result2.action().unwrap().name(), "quit");
     }
 
+    #[test]
+    fn test_broken_sequence_flushes_bound_prefix() {
+        let ctrl_x = KeyBinding::new(KeyCode::Char('x')).with_modifiers(KeyModifiers::CTRL);
+        let ctrl_s = KeyBinding::new(KeyCode::Char('s')).with_modifiers(KeyModifiers::CTRL);
+        let mut matcher = InputMatcher::with_default_timeout();
+        matcher.register_key(ctrl_x.clone(), Action::new("cut"));
+        matcher.register(KeySequence::new(vec![ctrl_x, ctrl_s]), Action::new("save"));
+        matcher.register_key(KeyBinding::new(KeyCode::Char('q')), Action::new("quit"));
+
+        let ctrl_x_event = make_key_event(KeyCode::Char('x'), KeyModifiers::CTRL);
+        assert!(matcher.process(&ctrl_x_event).is_pending());
+
+        // Ctrl+X is not swallowed by the broken sequence
+        let result = matcher.process(&make_key_event(KeyCode::Char('q'), KeyModifiers::NONE));
+        assert_eq!(
+            result,
+            MatchResult::Flushed {
+                action: Action::new("cut"),
+                then: Box::new(MatchResult::Matched(Action::new("quit"))),
+            }
+        );
+        assert!(result.is_matched());
+        assert_eq!(result.action().unwrap().name(), "cut");
+        let names: Vec<String> = result
+            .into_actions()
+            .iter()
+            .map(|a| a.name().to_string())
+            .collect();
+        assert_eq!(names, vec!["cut", "quit"]);
+        assert!(!matcher.is_sequence_pending());
+
+        // An unbound breaking key still reports the flushed prefix
+        assert!(matcher.process(&ctrl_x_event).is_pending());
+        let result = matcher.process(&make_key_event(KeyCode::Char('z'), KeyModifiers::NONE));
+        assert_eq!(result.into_actions(), vec![Action::new("cut")]);
+
+        // A breaking key that starts a new sequence leaves it pending
+        assert!(matcher.process(&ctrl_x_event).is_pending());
+        let result = matcher.process(&ctrl_x_event);
+        assert!(matches!(
+            result,
+            MatchResult::Flushed { ref then, .. } if then.is_pending()
+        ));
+        assert!(matcher.is_sequence_pending());
+    }
+
     #[test]
     fn test_multiple_bindings_same_action() {
         let mut matcher = InputMatcher::with_default_timeout();
@@ -557,6 +1272,42 @@ mod tests {
         assert_eq!(matcher.binding_count(), 0);
     }
 
+    #[test]
+    fn test_unregister_sequence_keeps_siblings() {
+        let mut matcher = InputMatcher::with_default_timeout();
+        let g = || KeyBinding::new(KeyCode::Char('g'));
+        let gg = KeySequence::new(vec![g(), g()]);
+        let ge = KeySequence::new(vec![g(), KeyBinding::new(KeyCode::Char('e'))]);
+        matcher.register(gg.clone(), Action::new("top"));
+        matcher.register(ge.clone(), Action::new("bottom"));
+        matcher.register_key(KeyBinding::new(KeyCode::Char('k')), Action::new("up"));
+        matcher.register_key(KeyBinding::new(KeyCode::Up), Action::new("up"));
+
+        assert!(matcher.unregister(&gg));
+        assert!(!matcher.unregister(&gg));
+        assert_eq!(matcher.binding_count(), 3);
+
+        // "g g" no longer matches, but "g e" still does
+        let g_event = make_key_event(KeyCode::Char('g'), KeyModifiers::NONE);
+        assert!(matcher.process(&g_event).is_pending());
+        assert!(matcher.process(&g_event).action().is_none());
+        matcher.reset_sequence();
+        assert!(matcher.process(&g_event).is_pending());
+        let e_event = make_key_event(KeyCode::Char('e'), KeyModifiers::NONE);
+        assert_eq!(matcher.process(&e_event).action().unwrap().name(), "bottom");
+
+        // Removing the last sequence through "g" stops it from pending
+        matcher.unregister(&ge);
+        assert!(matcher.process(&g_event).is_no_match());
+
+        // Removing by action drops every sequence bound to it
+        assert_eq!(matcher.unregister_action("up"), 2);
+        assert_eq!(matcher.unregister_action("up"), 0);
+        let k_event = make_key_event(KeyCode::Char('k'), KeyModifiers::NONE);
+        assert!(matcher.process(&k_event).is_no_match());
+        assert_eq!(matcher.binding_count(), 0);
+    }
+
     #[test]
     fn test_match_result_helpers() {
         let matched = MatchResult::Matched(Action::new("test"));
@@ -668,4 +1419,515 @@ mod tests {
         assert!(result2.is_matched());
         assert_eq!(result2.action().unwrap().name(), "double_g");
     }
+
+    /// The original linear-scan matcher, used to check the indexed lookups.
+    fn reference_process(
+        bindings: &[(Vec<KeyBinding>, Action)],
+        pending: &mut Vec<KeyBinding>,
+        key: KeyBinding,
+    ) -> MatchResult {
+        let partial = |pending: &[KeyBinding]| {
+            bindings
+                .iter()
+                .any(|(seq, _)| seq.len() > pending.len() && seq.starts_with(pending))
+        };
+        let complete = |pending: &[KeyBinding]| {
+            bindings
+                .iter()
+                .find(|(seq, _)| seq.as_slice() == pending)
+                .map(|(_, action)| action.clone())
+        };
+
+        pending.push(key.clone());
+        for attempt in 0..2 {
+            if attempt == 1 {
+                if pending.len() == 1 {
+                    break;
+                }
+                pending.clear();
+                pending.push(key.clone());
+            }
+            if partial(pending) {
+                return MatchResult::Pending;
+            }
+            if let Some(action) = complete(pending) {
+                pending.clear();
+                return MatchResult::Matched(action);
+            }
+        }
+        pending.clear();
+        MatchResult::NoMatch
+    }
+
+    #[test]
+    fn test_indexed_matching_matches_linear_scan() {
+        let key = |c| KeyBinding::new(KeyCode::Char(c));
+        let ctrl = |c| KeyBinding::with_mods(KeyCode::Char(c), KeyModifiers::CTRL);
+        let bindings = vec![
+            (vec![key('g')], Action::new("g")),
+            (vec![key('g'), key('g')], Action::new("gg")),
+            (vec![key('g'), key('t')], Action::new("gt")),
+            (vec![key('d'), key('i'), key('w')], Action::new("diw")),
+            (vec![key('d'), key('d')], Action::new("dd")),
+            (vec![ctrl('x'), ctrl('s')], Action::new("save")),
+            (vec![key('q')], Action::new("quit")),
+            // Duplicate sequence: the first registration wins
+            (vec![key('q')], Action::new("shadowed")),
+        ];
+
+        let mut matcher = InputMatcher::new(Duration::from_secs(60));
+        for (seq, action) in &bindings {
+            matcher.register(KeySequence::new(seq.clone()), action.clone());
+        }
+
+        let inputs = [
+            key('g'),
+            key('g'),
+            key('g'),
+            key('t'),
+            key('d'),
+            key('i'),
+            key('x'),
+            key('d'),
+            key('d'),
+            key('q'),
+            ctrl('x'),
+            key('q'),
+            ctrl('x'),
+            ctrl('s'),
+            key('d'),
+            key('g'),
+            key('g'),
+            key('z'),
+        ];
+
+        let mut pending = Vec::new();
+        for input in inputs {
+            let event = make_key_event(input.key(), input.modifiers());
+            let expected = reference_process(&bindings, &mut pending, input.clone());
+            assert_eq!(matcher.process(&event), expected, "input {input:?}");
+            assert_eq!(matcher.pending_keys(), pending.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_large_binding_set() {
+        let mut matcher = InputMatcher::new(Duration::from_secs(60));
+        let chars: Vec<char> = ('a'..='z').collect();
+        for &first in &chars {
+            for &second in &chars {
+                matcher.register(
+                    KeySequence::new(vec![
+                        KeyBinding::with_mods(KeyCode::Char(first), KeyModifiers::CTRL),
+                        KeyBinding::new(KeyCode::Char(second)),
+                    ]),
+                    Action::new(format!("{first}{second}")),
+                );
+            }
+            matcher.register_key(
+                KeyBinding::new(KeyCode::Char(first)),
+                Action::new(first.to_string()),
+            );
+        }
+        assert_eq!(matcher.binding_count(), 26 * 26 + 26);
+
+        let result = matcher.process(&make_key_event(KeyCode::Char('m'), KeyModifiers::NONE));
+        assert_eq!(result, MatchResult::Matched(Action::new("m")));
+
+        let result = matcher.process(&make_key_event(KeyCode::Char('k'), KeyModifiers::CTRL));
+        assert!(result.is_pending());
+        let result = matcher.process(&make_key_event(KeyCode::Char('z'), KeyModifiers::NONE));
+        assert_eq!(result, MatchResult::Matched(Action::new("kz")));
+
+        matcher.clear_bindings();
+        let result = matcher.process(&make_key_event(KeyCode::Char('m'), KeyModifiers::NONE));
+        assert!(result.is_no_match());
+    }
+
+    #[test]
+    fn test_poll_fires_shorter_binding_after_timeout() {
+        let g = KeyBinding::new(KeyCode::Char('g'));
+        let mut matcher = InputMatcher::new(Duration::from_millis(500));
+        matcher.register(KeySequence::single(g.clone()), Action::new("g"));
+        matcher.register(KeySequence::new(vec![g.clone(), g]), Action::new("gg"));
+
+        // Nothing pending
+        assert_eq!(matcher.poll(Instant::now()), None);
+
+        let result = matcher.process(&make_key_event(KeyCode::Char('g'), KeyModifiers::NONE));
+        assert!(result.is_pending());
+        let pressed = Instant::now();
+
+        // Still within the timeout
+        assert_eq!(matcher.poll(pressed), None);
+        assert!(matcher.is_sequence_pending());
+
+        let result = matcher.poll(pressed + Duration::from_millis(600));
+        assert_eq!(result, Some(MatchResult::Matched(Action::new("g"))));
+        assert!(!matcher.is_sequence_pending());
+        assert_eq!(matcher.poll(pressed + Duration::from_millis(700)), None);
+    }
+
+    #[test]
+    fn test_poll_abandons_prefix_without_binding() {
+        let mut matcher = InputMatcher::new(Duration::from_millis(500));
+        matcher.register(
+            KeySequence::new(vec![
+                KeyBinding::new(KeyCode::Char('d')),
+                KeyBinding::new(KeyCode::Char('d')),
+            ]),
+            Action::new("dd"),
+        );
+
+        let result = matcher.process(&make_key_event(KeyCode::Char('d'), KeyModifiers::NONE));
+        assert!(result.is_pending());
+
+        let later = Instant::now() + Duration::from_secs(1);
+        assert_eq!(matcher.poll(later), Some(MatchResult::NoMatch));
+        assert!(!matcher.is_sequence_pending());
+    }
+
+    #[test]
+    fn test_sequence_timeout_with_manual_clock() {
+        use crate::event::ManualClock;
+
+        let clock = ManualClock::new();
+        let g = KeyBinding::new(KeyCode::Char('g'));
+        let mut matcher =
+            InputMatcher::new_with_clock(Duration::from_millis(500), Arc::new(clock.clone()));
+        matcher.register(KeySequence::single(g.clone()), Action::new("g"));
+        matcher.register(KeySequence::new(vec![g.clone(), g]), Action::new("gg"));
+        let press = || make_key_event(KeyCode::Char('g'), KeyModifiers::NONE);
+
+        // Two presses within the timeout complete the sequence
+        assert!(matcher.process(&press()).is_pending());
+        clock.advance(Duration::from_millis(400));
+        assert_eq!(matcher.poll_now(), None);
+        assert_eq!(
+            matcher.process(&press()),
+            MatchResult::Matched(Action::new("gg"))
+        );
+
+        // A press after the timeout starts a new sequence
+        assert!(matcher.process(&press()).is_pending());
+        clock.advance(Duration::from_millis(501));
+        assert!(matcher.process(&press()).is_pending());
+        assert_eq!(matcher.pending_keys().len(), 1);
+
+        // Polling after the timeout fires the single-key binding
+        clock.advance(Duration::from_millis(501));
+        assert_eq!(
+            matcher.poll_now(),
+            Some(MatchResult::Matched(Action::new("g")))
+        );
+    }
+
+    #[test]
+    fn test_chord_distinct_from_sequence() {
+        use crate::event::ManualClock;
+
+        let clock = ManualClock::new();
+        let j = KeyBinding::new(KeyCode::Char('j'));
+        let k = KeyBinding::new(KeyCode::Char('k'));
+        let mut matcher =
+            InputMatcher::new_with_clock(Duration::from_millis(500), Arc::new(clock.clone()));
+        matcher.register(
+            KeySequence::new(vec![j.clone(), k.clone()]),
+            Action::new("sequence"),
+        );
+        matcher.register_chord(KeyChord::new(vec![k, j]), Action::new("chord"));
+        let press = |c| make_key_event(KeyCode::Char(c), KeyModifiers::NONE);
+        let release = |c| KeyEvent {
+            kind: KeyEventKind::Release,
+            ..make_key_event(KeyCode::Char(c), KeyModifiers::NONE)
+        };
+
+        // Without enhanced keyboard support, only the sequence matches
+        assert!(matcher.process(&press('j')).is_pending());
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(
+            matcher.process(&press('k')),
+            MatchResult::Matched(Action::new("sequence"))
+        );
+
+        // Near-simultaneous presses match the chord instead
+        matcher.set_keyboard_enhanced(true);
+        assert!(matcher.process(&press('j')).is_pending());
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(
+            matcher.process(&press('k')),
+            MatchResult::Chord(Action::new("chord"))
+        );
+        assert!(!matcher.is_sequence_pending());
+        assert!(matcher.process(&release('j')).is_no_match());
+        assert!(matcher.process(&release('k')).is_no_match());
+
+        // Presses further apart than the chord window fall back to the sequence
+        assert!(matcher.process(&press('j')).is_pending());
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(
+            matcher.process(&press('k')),
+            MatchResult::Matched(Action::new("sequence"))
+        );
+        matcher.process(&release('j'));
+        matcher.process(&release('k'));
+
+        // A key released before the other goes down is not chorded
+        assert!(matcher.process(&press('j')).is_pending());
+        assert!(matcher.process(&release('j')).is_no_match());
+        assert_eq!(
+            matcher.process(&press('k')),
+            MatchResult::Matched(Action::new("sequence"))
+        );
+    }
+
+    #[test]
+    fn test_cooldown_suppresses_repeat_firing() {
+        use crate::event::ManualClock;
+
+        let clock = ManualClock::new();
+        let mut matcher =
+            InputMatcher::new_with_clock(Duration::from_millis(500), Arc::new(clock.clone()));
+        matcher.register_with_cooldown(
+            KeySequence::single(KeyBinding::new(KeyCode::Char('r'))),
+            Action::new("refresh"),
+            Duration::from_millis(100),
+        );
+        matcher.register_key(KeyBinding::new(KeyCode::Char('q')), Action::new("quit"));
+        let press = |c| make_key_event(KeyCode::Char(c), KeyModifiers::NONE);
+
+        assert_eq!(
+            matcher.process(&press('r')),
+            MatchResult::Matched(Action::new("refresh"))
+        );
+
+        // Firing again immediately is suppressed; other bindings are not
+        let suppressed = matcher.process(&press('r'));
+        assert_eq!(suppressed, MatchResult::Cooldown(Action::new("refresh")));
+        assert!(!suppressed.is_matched());
+        assert!(suppressed.into_actions().is_empty());
+        assert!(matcher.process(&press('q')).is_matched());
+
+        // Suppressed attempts do not extend the cooldown
+        clock.advance(Duration::from_millis(60));
+        assert!(matcher.process(&press('r')).is_cooldown());
+        clock.advance(Duration::from_millis(40));
+        assert_eq!(
+            matcher.process(&press('r')),
+            MatchResult::Matched(Action::new("refresh"))
+        );
+        assert!(matcher.process(&press('r')).is_cooldown());
+    }
+
+    #[test]
+    fn test_per_sequence_timeouts_expire_independently() {
+        use crate::event::ManualClock;
+
+        let clock = ManualClock::new();
+        let mut matcher =
+            InputMatcher::new_with_clock(Duration::from_millis(500), Arc::new(clock.clone()));
+        let key = |c| KeyBinding::new(KeyCode::Char(c));
+        matcher.register_with_timeout(
+            KeySequence::new(vec![key(' '), key('f')]),
+            Action::new("find_file"),
+            Duration::from_millis(100),
+        );
+        matcher.register_with_timeout(
+            KeySequence::new(vec![key('z'), key('z')]),
+            Action::new("center"),
+            Duration::from_millis(2000),
+        );
+        let press = |c| make_key_event(KeyCode::Char(c), KeyModifiers::NONE);
+
+        // The leader menu gives up after its short timeout
+        assert!(matcher.process(&press(' ')).is_pending());
+        assert_eq!(matcher.pending_timeout(), Duration::from_millis(100));
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(matcher.poll_now(), None);
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(matcher.poll_now(), Some(MatchResult::NoMatch));
+
+        // The long sequence outlasts the default timeout
+        assert!(matcher.process(&press('z')).is_pending());
+        assert_eq!(matcher.pending_timeout(), Duration::from_millis(2000));
+        clock.advance(Duration::from_millis(1500));
+        assert_eq!(matcher.poll_now(), None);
+        assert_eq!(
+            matcher.process(&press('z')),
+            MatchResult::Matched(Action::new("center"))
+        );
+
+        // A late second key starts over instead of completing the sequence
+        assert!(matcher.process(&press(' ')).is_pending());
+        clock.advance(Duration::from_millis(150));
+        assert!(matcher.process(&press('f')).is_no_match());
+        assert_eq!(matcher.pending_timeout(), matcher.sequence_timeout());
+    }
+
+    #[test]
+    fn test_from_key_bindings() {
+        let bindings = KeyBindings::builder()
+            .bind("quit", "q")
+            .bind("close_all", "Escape")
+            .bind("save", "Ctrl+x Ctrl+s")
+            .context("modal", |ctx| ctx.bind("close", "Escape"))
+            .build();
+
+        let mut global = InputMatcher::from_key_bindings(&bindings, None);
+        let mut modal = InputMatcher::from_key_bindings(&bindings, Some("modal"));
+        assert_eq!(global.binding_count(), 3);
+        assert_eq!(modal.binding_count(), 4);
+
+        let escape = make_key_event(KeyCode::Esc, KeyModifiers::NONE);
+        let q = make_key_event(KeyCode::Char('q'), KeyModifiers::NONE);
+        for (matcher, context) in [(&mut global, None), (&mut modal, Some("modal"))] {
+            for event in [&escape, &q] {
+                let sequence =
+                    KeySequence::single(KeyBinding::with_mods(event.code, event.modifiers));
+                let expected = bindings.lookup(context, &sequence).cloned();
+                assert_eq!(matcher.process(event).into_action(), expected);
+            }
+        }
+        assert_eq!(
+            modal.process(&escape).into_action(),
+            Some(Action::new("close"))
+        );
+
+        // Multi-key sequences are registered too
+        assert!(global
+            .process(&make_key_event(KeyCode::Char('x'), KeyModifiers::CTRL))
+            .is_pending());
+        assert_eq!(
+            global.process(&make_key_event(KeyCode::Char('s'), KeyModifiers::CTRL)),
+            MatchResult::Matched(Action::new("save"))
+        );
+
+        // Unknown contexts only get the global bindings
+        let unknown = InputMatcher::from_key_bindings(&bindings, Some("missing"));
+        assert_eq!(unknown.binding_count(), 3);
+    }
+
+    #[test]
+    fn test_from_key_bindings_registers_always_first() {
+        let bindings = KeyBindings::builder()
+            .always(|a| a.bind("quit", "Escape"))
+            .context("modal", |ctx| ctx.bind("close", "Escape"))
+            .build();
+
+        let mut modal = InputMatcher::from_key_bindings(&bindings, Some("modal"));
+        let escape = make_key_event(KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(
+            modal.process(&escape).into_action(),
+            Some(Action::new("quit"))
+        );
+    }
+
+    #[test]
+    fn test_bindings_snapshot_and_load() {
+        let q = KeySequence::single(KeyBinding::new(KeyCode::Char('q')));
+        let x = KeySequence::single(KeyBinding::new(KeyCode::Char('x')));
+
+        let mut matcher = InputMatcher::new(Duration::from_secs(1));
+        matcher.register(q.clone(), Action::new("quit"));
+        matcher.register(x.clone(), Action::new("delete"));
+        let snapshot = matcher.bindings();
+        assert_eq!(
+            snapshot,
+            vec![
+                (q.clone(), Action::new("quit")),
+                (x.clone(), Action::new("delete")),
+            ]
+        );
+
+        matcher.load(vec![(x, Action::new("close"))]);
+        assert_eq!(matcher.binding_count(), 1);
+        let event = make_key_event(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert!(matcher.process(&event).is_no_match());
+
+        matcher.load(snapshot);
+        assert_eq!(
+            matcher.process(&event),
+            MatchResult::Matched(Action::new("quit"))
+        );
+        let event = make_key_event(KeyCode::Char('x'), KeyModifiers::NONE);
+        assert_eq!(
+            matcher.process(&event),
+            MatchResult::Matched(Action::new("delete"))
+        );
+    }
+
+    #[test]
+    fn test_guarded_binding_follows_guard() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let dirty = Arc::new(AtomicBool::new(false));
+        let ctrl_s = KeySequence::single(
+            KeyBinding::new(KeyCode::Char('s')).with_modifiers(KeyModifiers::CTRL),
+        );
+        let mut matcher = InputMatcher::with_default_timeout();
+        let is_dirty = Arc::clone(&dirty);
+        matcher.register_guarded(
+            ctrl_s.clone(),
+            Action::new("save"),
+            Box::new(move || is_dirty.load(Ordering::Relaxed)),
+        );
+        assert_eq!(matcher.binding_count(), 1);
+
+        let event = make_key_event(KeyCode::Char('s'), KeyModifiers::CTRL);
+        assert!(matcher.process(&event).is_no_match());
+        dirty.store(true, Ordering::Relaxed);
+        assert_eq!(
+            matcher.process(&event),
+            MatchResult::Matched(Action::new("save"))
+        );
+
+        // An unguarded binding for the same keys applies when the guard fails,
+        // even though it was registered later
+        matcher.register(ctrl_s, Action::new("save_as"));
+        assert_eq!(
+            matcher.process(&event),
+            MatchResult::Matched(Action::new("save"))
+        );
+        dirty.store(false, Ordering::Relaxed);
+        assert_eq!(
+            matcher.process(&event),
+            MatchResult::Matched(Action::new("save_as"))
+        );
+    }
+
+    #[test]
+    fn test_guarded_sequence_and_clear() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let enabled = Arc::new(AtomicBool::new(true));
+        let g = KeyBinding::new(KeyCode::Char('g'));
+        let mut matcher = InputMatcher::with_default_timeout();
+        let guard = Arc::clone(&enabled);
+        matcher.register_guarded(
+            KeySequence::new(vec![g.clone(), g]),
+            Action::new("go_top"),
+            Box::new(move || guard.load(Ordering::Relaxed)),
+        );
+
+        let event = make_key_event(KeyCode::Char('g'), KeyModifiers::NONE);
+        assert!(matcher.process(&event).is_pending());
+        assert_eq!(
+            matcher.process(&event),
+            MatchResult::Matched(Action::new("go_top"))
+        );
+
+        // With the guard off the sequence doesn't complete; the second key
+        // starts a new one instead
+        enabled.store(false, Ordering::Relaxed);
+        assert!(matcher.process(&event).is_pending());
+        assert!(matcher.process(&event).is_pending());
+        matcher.reset_sequence();
+
+        // Guarded bindings are not part of the snapshot
+        assert!(matcher.bindings().is_empty());
+        matcher.clear_bindings();
+        assert_eq!(matcher.binding_count(), 0);
+        enabled.store(true, Ordering::Relaxed);
+        assert!(matcher.process(&event).is_no_match());
+    }
 }
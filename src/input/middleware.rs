@@ -37,7 +37,8 @@
 //! impl ActionMiddleware for ActionRemapper {
 //!     fn before(&mut self, action: &Action) -> MiddlewareResult {
 //!         if let Some(new_name) = self.mappings.get(action.name()) {
-//!             MiddlewareResult::Continue(Some(Action::new(new_name.clone())))
+//!             // Keep any arguments attached to the original action
+//!             MiddlewareResult::Continue(Some(action.renamed(new_name.clone())))
 //!         } else {
 //!             MiddlewareResult::Continue(None)
 //!         }
@@ -46,8 +47,21 @@
 //!     fn after(&mut self, _action: &Action, _result: &HandleResult) {}
 //! }
 //! ```
+//!
+//! ## Async Dispatch Middleware
+//!
+//! [`AsyncDispatchMiddleware`] runs selected actions as background tasks
+//! and delivers their results to the event loop, so slow work such as a
+//! network request never blocks the UI.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+use tokio::sync::mpsc;
 
 use super::{Action, HandleResult};
+use crate::event::AppEvent;
 
 /// Result of middleware processing before action dispatch.
 ///
@@ -344,6 +358,103 @@ impl ActionMiddleware for TracingMiddleware {
     }
 }
 
+/// A boxed background task started for an action.
+type AsyncTask<M> =
+    Box<dyn Fn(&Action) -> Pin<Box<dyn Future<Output = AppEvent<M>> + Send>> + Send + Sync>;
+
+/// Middleware that runs actions as background tokio tasks.
+///
+/// For each registered action name, a task is spawned when the action is
+/// dispatched and the event it resolves to is sent to the event loop through
+/// the sender from [`EventLoop::sender`](crate::event::EventLoop::sender).
+/// The action is then stopped so the synchronous handlers don't also run it.
+/// Other actions pass through unchanged.
+///
+/// Tasks are spawned on the current tokio runtime. If there is none, the
+/// action passes through to the handlers instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use tuilib::event::AppEvent;
+/// use tuilib::input::{Action, ActionRouter, AsyncDispatchMiddleware};
+///
+/// # async fn example(sender: tokio::sync::mpsc::Sender<AppEvent<String>>) {
+/// let mut router = ActionRouter::new();
+/// router.add_middleware(AsyncDispatchMiddleware::new(sender).on("fetch", |_action| async {
+///     // Fetch something without blocking the UI...
+///     AppEvent::Message("fetched".to_string())
+/// }));
+/// # }
+/// ```
+pub struct AsyncDispatchMiddleware<M = String> {
+    sender: mpsc::Sender<AppEvent<M>>,
+    tasks: HashMap<String, AsyncTask<M>>,
+}
+
+impl<M: Send + 'static> AsyncDispatchMiddleware<M> {
+    /// Creates a middleware that delivers task results through `sender`.
+    pub fn new(sender: mpsc::Sender<AppEvent<M>>) -> Self {
+        Self {
+            sender,
+            tasks: HashMap::new(),
+        }
+    }
+
+    /// Runs `task` in the background whenever the named action is dispatched.
+    ///
+    /// The task receives the dispatched action and resolves to the event
+    /// sent back to the event loop. Registering the same name again replaces
+    /// the previous task.
+    pub fn on<F, Fut>(mut self, action: impl Into<String>, task: F) -> Self
+    where
+        F: Fn(Action) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AppEvent<M>> + Send + 'static,
+    {
+        self.tasks.insert(
+            action.into(),
+            Box::new(move |action: &Action| Box::pin(task(action.clone()))),
+        );
+        self
+    }
+
+    /// Returns true if the named action runs in the background.
+    pub fn handles(&self, action: &str) -> bool {
+        self.tasks.contains_key(action)
+    }
+}
+
+impl<M: Send + 'static> ActionMiddleware for AsyncDispatchMiddleware<M> {
+    fn before(&mut self, action: &Action) -> MiddlewareResult {
+        let Some(task) = self.tasks.get(action.name()) else {
+            return MiddlewareResult::pass();
+        };
+
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            tracing::warn!(action = %action, "No tokio runtime; running action synchronously");
+            return MiddlewareResult::pass();
+        };
+
+        let future = task(action);
+        let sender = self.sender.clone();
+        let name = action.name().to_string();
+        runtime.spawn(async move {
+            let event = future.await;
+            if sender.send(event).await.is_err() {
+                tracing::debug!(action = %name, "Event loop closed before async action finished");
+            }
+        });
+
+        MiddlewareResult::Stop
+    }
+
+    fn after(&mut self, _action: &Action, _result: &HandleResult) {}
+
+    fn name(&self) -> &str {
+        "async_dispatch"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,7 +552,7 @@ mod tests {
             }
 
             if let Some(ref to) = self.transform_to {
-                MiddlewareResult::transform(Action::new(to.clone()))
+                MiddlewareResult::transform(action.renamed(to.clone()))
             } else {
                 MiddlewareResult::pass()
             }
@@ -588,4 +699,101 @@ mod tests {
         let m = TracingMiddleware::trace();
         assert_eq!(m.name(), "tracing");
     }
+
+    #[tokio::test]
+    async fn test_async_dispatch_delivers_result() {
+        use crate::input::{ActionHandler, ActionRouter, Phase};
+
+        struct Root {
+            handled: Vec<String>,
+        }
+
+        impl ActionHandler for Root {
+            fn handle(&mut self, action: &Action, _phase: Phase) -> HandleResult {
+                self.handled.push(action.name().to_string());
+                HandleResult::Handled
+            }
+
+            fn id(&self) -> &str {
+                "root"
+            }
+        }
+
+        let (tx, mut rx) = mpsc::channel(4);
+        let middleware = AsyncDispatchMiddleware::new(tx).on("fetch", |action| async move {
+            tokio::task::yield_now().await;
+            AppEvent::Message(format!("{} done", action.name()))
+        });
+        assert!(middleware.handles("fetch"));
+        assert!(!middleware.handles("quit"));
+
+        let mut router = ActionRouter::new();
+        router.add_middleware(middleware);
+        let mut root = Root {
+            handled: Vec::new(),
+        };
+
+        // The async action is not handled synchronously
+        let result = router.dispatch(&mut root, Action::new("fetch"));
+        assert!(!result.was_handled());
+        assert!(root.handled.is_empty());
+
+        match rx.recv().await {
+            Some(AppEvent::Message(message)) => assert_eq!(message, "fetch done"),
+            other => panic!("unexpected event: {other:?}"),
+        }
+
+        // Other actions reach the handlers
+        let result = router.dispatch(&mut root, Action::new("quit"));
+        assert!(result.was_handled());
+        assert_eq!(root.handled, vec!["quit"]);
+    }
+
+    #[test]
+    fn test_action_args_reach_handler() {
+        use crate::input::{ActionHandler, ActionRouter, ActionValue, Phase};
+
+        struct Root {
+            received: Vec<Action>,
+        }
+
+        impl ActionHandler for Root {
+            fn handle(&mut self, action: &Action, _phase: Phase) -> HandleResult {
+                self.received.push(action.clone());
+                HandleResult::Handled
+            }
+
+            fn id(&self) -> &str {
+                "root"
+            }
+        }
+
+        let mut router = ActionRouter::new();
+        router.add_middleware(TestMiddleware::new("remap").transform("navigate"));
+        let mut root = Root {
+            received: Vec::new(),
+        };
+
+        let action = Action::new("go")
+            .with_arg("target", "settings")
+            .with_arg("count", 2);
+        assert!(router.dispatch(&mut root, action).was_handled());
+
+        // The remapped action keeps its arguments
+        let received = &root.received[0];
+        assert_eq!(received.name(), "navigate");
+        assert_eq!(
+            received.arg("target").and_then(ActionValue::as_str),
+            Some("settings")
+        );
+        assert_eq!(received.arg("count").and_then(ActionValue::as_int), Some(2));
+    }
+
+    #[test]
+    fn test_async_dispatch_without_runtime_passes_through() {
+        let (tx, _rx) = mpsc::channel::<AppEvent>(1);
+        let mut middleware =
+            AsyncDispatchMiddleware::new(tx).on("fetch", |_| async { AppEvent::Tick });
+        assert!(middleware.before(&Action::new("fetch")).should_continue());
+    }
 }
@@ -13,9 +13,12 @@
 //! - [`Action`]: Named semantic actions like "quit", "save", "navigate_up"
 //! - [`KeyBinding`]: A single key with optional modifiers (e.g., "Ctrl+S")
 //! - [`KeySequence`]: One or more keys in sequence (e.g., "Ctrl+X Ctrl+S")
+//! - [`KeyChord`]: Keys held down together (e.g., "j & k"), with enhanced keyboard support
 //! - [`KeyBindings`]: Container for keybindings with context support
 //! - [`KeyBindingsBuilder`]: Fluent API for declarative keybinding configuration
+//! - [`presets`]: Vim, Emacs and standard keybinding presets
 //! - [`InputMatcher`]: Matches input events against registered bindings
+//! - [`DoublePressDetector`]: Tells single presses of a key from quick double presses
 //!
 //! ## Action Routing
 //!
@@ -91,7 +94,8 @@
 //! };
 //!
 //! match matcher.process(&event) {
-//!     tuilib::input::MatchResult::Matched(action) => {
+//!     tuilib::input::MatchResult::Matched(action)
+//!     | tuilib::input::MatchResult::Chord(action) => {
 //!         println!("Action: {}", action.name());
 //!     }
 //!     tuilib::input::MatchResult::Pending => {
@@ -100,6 +104,15 @@
 //!     tuilib::input::MatchResult::NoMatch => {
 //!         println!("No binding matched");
 //!     }
+//!     tuilib::input::MatchResult::Cooldown(action) => {
+//!         println!("{} fired too recently", action.name());
+//!     }
+//!     result @ tuilib::input::MatchResult::Flushed { .. } => {
+//!         // A broken sequence whose first keys are bound on their own
+//!         for action in result.into_actions() {
+//!             println!("Action: {}", action.name());
+//!         }
+//!     }
 //! }
 //! ```
 //!
@@ -214,22 +227,30 @@
 mod action;
 mod binding;
 pub mod bindings;
+mod chord;
+mod double_press;
 mod handler;
 mod matcher;
 pub mod middleware;
 pub mod parser;
+pub mod presets;
 mod router;
 mod sequence;
 mod terminput_ext;
 
 // Core types
-pub use action::Action;
+pub use action::{Action, ActionValue};
 pub use binding::KeyBinding;
-pub use bindings::{ContextBuilder, KeyBindings, KeyBindingsBuilder, KeyBindingsConfig, KeyOrKeys};
+pub use bindings::{
+    ContextBuilder, HelpEntry, KeyBindings, KeyBindingsBuilder, KeyBindingsConfig, KeyOrKeys,
+};
+pub use chord::KeyChord;
+pub use double_press::DoublePressDetector;
 pub use handler::{ActionHandler, HandleResult, Phase};
-pub use matcher::{InputMatcher, MatchResult};
+pub use matcher::{BindingGuard, InputMatcher, MatchResult};
 pub use middleware::{
-    ActionMiddleware, MiddlewareChain, MiddlewareResult, PassthroughMiddleware, TracingMiddleware,
+    ActionMiddleware, AsyncDispatchMiddleware, MiddlewareChain, MiddlewareResult,
+    PassthroughMiddleware, TracingMiddleware,
 };
 pub use router::{ActionRouter, DispatchResult};
 pub use sequence::{KeySequence, KeySequenceBuilder};
@@ -0,0 +1,236 @@
+//! Ready-made keybinding presets.
+//!
+//! [`KeyBindings::vim_defaults`], [`KeyBindings::emacs_defaults`] and
+//! [`KeyBindings::standard_defaults`] bind the same set of action names in
+//! the key style of each profile, so an application can handle one set of
+//! actions and let users pick their preferred keys. Overrides are applied
+//! with [`KeyBindings::merge`].
+//!
+//! # Actions
+//!
+//! | Action | Vim | Emacs | Standard |
+//! |--------|-----|-------|----------|
+//! | `move_up` | `k`, `Up` | `Ctrl+p`, `Up` | `Up` |
+//! | `move_down` | `j`, `Down` | `Ctrl+n`, `Down` | `Down` |
+//! | `move_left` | `h`, `Left` | `Ctrl+b`, `Left` | `Left` |
+//! | `move_right` | `l`, `Right` | `Ctrl+f`, `Right` | `Right` |
+//! | `page_up` | `Ctrl+b`, `PageUp` | `Alt+v`, `PageUp` | `PageUp` |
+//! | `page_down` | `Ctrl+f`, `PageDown` | `Ctrl+v`, `PageDown` | `PageDown` |
+//! | `go_to_top` | `g g` | `Alt+<` | `Ctrl+Home` |
+//! | `go_to_bottom` | `G` | `Alt+>` | `Ctrl+End` |
+//! | `line_start` | `0`, `Home` | `Ctrl+a`, `Home` | `Home` |
+//! | `line_end` | `$`, `End` | `Ctrl+e`, `End` | `End` |
+//! | `focus_next` | `Tab` | `Tab` | `Tab` |
+//! | `focus_prev` | `Shift+Tab` | `Shift+Tab` | `Shift+Tab` |
+//! | `delete` | `x`, `Delete` | `Ctrl+d`, `Delete` | `Delete` |
+//! | `undo` | `u` | `Ctrl+/` | `Ctrl+z` |
+//! | `redo` | `Ctrl+r` | `Ctrl+?` | `Ctrl+y` |
+//! | `copy` | `y y` | `Alt+w` | `Ctrl+c` |
+//! | `cut` | `d d` | `Ctrl+w` | `Ctrl+x` |
+//! | `paste` | `p` | `Ctrl+y` | `Ctrl+v` |
+//! | `search` | `/` | `Ctrl+s` | `Ctrl+f` |
+//! | `save` | `: w` | `Ctrl+x Ctrl+s` | `Ctrl+s` |
+//! | `quit` | `q`, `: q` | `Ctrl+x Ctrl+c` | `Ctrl+q` |
+//! | `help` | `?` | `Ctrl+h` | `F1` |
+//! | `confirm` | `Enter` | `Enter` | `Enter` |
+//! | `cancel` | `Escape` | `Ctrl+g`, `Escape` | `Escape` |
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::input::{KeyBindings, KeySequence};
+//! use tuilib::input::parser::parse_key_sequence;
+//!
+//! let mut bindings = KeyBindings::vim_defaults();
+//! bindings.merge(KeyBindings::builder().bind("quit", "Ctrl+q").build());
+//!
+//! let lookup = |keys| {
+//!     let sequence: KeySequence = parse_key_sequence(keys).unwrap();
+//!     bindings.lookup(None, &sequence).map(|a| a.name().to_string())
+//! };
+//! assert_eq!(lookup("j").as_deref(), Some("move_down"));
+//! assert_eq!(lookup("Ctrl+q").as_deref(), Some("quit"));
+//! ```
+
+use super::{KeyBindings, KeyBindingsBuilder};
+
+impl KeyBindings {
+    /// Creates Vim-style bindings: `hjkl` movement, `g g` and `G` to jump,
+    /// and `:`-prefixed sequences to save and quit.
+    ///
+    /// See the [`presets`](crate::input::presets) module for the full table.
+    pub fn vim_defaults() -> Self {
+        vim_builder().build()
+    }
+
+    /// Creates Emacs-style bindings: `Ctrl+n`/`Ctrl+p` movement, `Alt+w`,
+    /// `Ctrl+w` and `Ctrl+y` for the kill ring, and `Ctrl+x` prefixed
+    /// sequences to save and quit.
+    ///
+    /// See the [`presets`](crate::input::presets) module for the full table.
+    pub fn emacs_defaults() -> Self {
+        emacs_builder().build()
+    }
+
+    /// Creates bindings in the style of common desktop applications: arrow
+    /// key movement and `Ctrl` shortcuts such as `Ctrl+c`, `Ctrl+v` and
+    /// `Ctrl+s`.
+    ///
+    /// See the [`presets`](crate::input::presets) module for the full table.
+    pub fn standard_defaults() -> Self {
+        standard_builder().build()
+    }
+}
+
+/// Bindings shared by every preset.
+fn common_builder() -> KeyBindingsBuilder {
+    KeyBindingsBuilder::new()
+        .bind("focus_next", "Tab")
+        .bind("focus_prev", "Shift+Tab")
+        .bind("confirm", "Enter")
+}
+
+fn vim_builder() -> KeyBindingsBuilder {
+    common_builder()
+        .bind_multi("move_up", &["k", "Up"])
+        .bind_multi("move_down", &["j", "Down"])
+        .bind_multi("move_left", &["h", "Left"])
+        .bind_multi("move_right", &["l", "Right"])
+        .bind_multi("page_up", &["Ctrl+b", "PageUp"])
+        .bind_multi("page_down", &["Ctrl+f", "PageDown"])
+        .bind("go_to_top", "g g")
+        .bind("go_to_bottom", "G")
+        .bind_multi("line_start", &["0", "Home"])
+        .bind_multi("line_end", &["$", "End"])
+        .bind_multi("delete", &["x", "Delete"])
+        .bind("undo", "u")
+        .bind("redo", "Ctrl+r")
+        .bind("copy", "y y")
+        .bind("cut", "d d")
+        .bind("paste", "p")
+        .bind("search", "/")
+        .bind("save", ": w")
+        .bind_multi("quit", &["q", ": q"])
+        .bind("help", "?")
+        .bind("cancel", "Escape")
+}
+
+fn emacs_builder() -> KeyBindingsBuilder {
+    common_builder()
+        .bind_multi("move_up", &["Ctrl+p", "Up"])
+        .bind_multi("move_down", &["Ctrl+n", "Down"])
+        .bind_multi("move_left", &["Ctrl+b", "Left"])
+        .bind_multi("move_right", &["Ctrl+f", "Right"])
+        .bind_multi("page_up", &["Alt+v", "PageUp"])
+        .bind_multi("page_down", &["Ctrl+v", "PageDown"])
+        .bind("go_to_top", "Alt+<")
+        .bind("go_to_bottom", "Alt+>")
+        .bind_multi("line_start", &["Ctrl+a", "Home"])
+        .bind_multi("line_end", &["Ctrl+e", "End"])
+        .bind_multi("delete", &["Ctrl+d", "Delete"])
+        .bind("undo", "Ctrl+/")
+        .bind("redo", "Ctrl+?")
+        .bind("copy", "Alt+w")
+        .bind("cut", "Ctrl+w")
+        .bind("paste", "Ctrl+y")
+        .bind("search", "Ctrl+s")
+        .bind("save", "Ctrl+x Ctrl+s")
+        .bind("quit", "Ctrl+x Ctrl+c")
+        .bind("help", "Ctrl+h")
+        .bind_multi("cancel", &["Ctrl+g", "Escape"])
+}
+
+fn standard_builder() -> KeyBindingsBuilder {
+    common_builder()
+        .bind("move_up", "Up")
+        .bind("move_down", "Down")
+        .bind("move_left", "Left")
+        .bind("move_right", "Right")
+        .bind("page_up", "PageUp")
+        .bind("page_down", "PageDown")
+        .bind("go_to_top", "Ctrl+Home")
+        .bind("go_to_bottom", "Ctrl+End")
+        .bind("line_start", "Home")
+        .bind("line_end", "End")
+        .bind("delete", "Delete")
+        .bind("undo", "Ctrl+z")
+        .bind("redo", "Ctrl+y")
+        .bind("copy", "Ctrl+c")
+        .bind("cut", "Ctrl+x")
+        .bind("paste", "Ctrl+v")
+        .bind("search", "Ctrl+f")
+        .bind("save", "Ctrl+s")
+        .bind("quit", "Ctrl+q")
+        .bind("help", "F1")
+        .bind("cancel", "Escape")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::parser::parse_key_sequence;
+
+    fn action(bindings: &KeyBindings, keys: &str) -> Option<String> {
+        let sequence = parse_key_sequence(keys).unwrap();
+        bindings
+            .lookup(None, &sequence)
+            .map(|a| a.name().to_string())
+    }
+
+    #[test]
+    fn test_presets_bind_representative_keys() {
+        for (name, builder) in [
+            ("vim", vim_builder()),
+            ("emacs", emacs_builder()),
+            ("standard", standard_builder()),
+        ] {
+            assert!(!builder.has_errors(), "{name}: {:?}", builder.errors());
+        }
+
+        let vim = KeyBindings::vim_defaults();
+        assert_eq!(action(&vim, "j").as_deref(), Some("move_down"));
+        assert_eq!(action(&vim, "g g").as_deref(), Some("go_to_top"));
+        assert_eq!(action(&vim, ": w").as_deref(), Some("save"));
+
+        let emacs = KeyBindings::emacs_defaults();
+        assert_eq!(action(&emacs, "Ctrl+n").as_deref(), Some("move_down"));
+        assert_eq!(action(&emacs, "Ctrl+x Ctrl+s").as_deref(), Some("save"));
+        assert_eq!(action(&emacs, "j"), None);
+
+        let standard = KeyBindings::standard_defaults();
+        assert_eq!(action(&standard, "Down").as_deref(), Some("move_down"));
+        assert_eq!(action(&standard, "Ctrl+v").as_deref(), Some("paste"));
+
+        // Every preset binds the same actions
+        let names = |bindings: &KeyBindings| {
+            let mut names: Vec<String> = bindings
+                .help_entries(None)
+                .into_iter()
+                .map(|e| e.action.name().to_string())
+                .collect();
+            names.sort();
+            names
+        };
+        assert_eq!(names(&vim), names(&emacs));
+        assert_eq!(names(&vim), names(&standard));
+    }
+
+    #[test]
+    fn test_presets_merge_with_overrides() {
+        let mut bindings = KeyBindings::emacs_defaults();
+        let overrides = KeyBindings::builder()
+            .bind("quit", "Ctrl+q")
+            .context("modal", |ctx| ctx.bind("close", "Ctrl+g"))
+            .build();
+        bindings.merge(overrides);
+
+        assert_eq!(action(&bindings, "Ctrl+q").as_deref(), Some("quit"));
+        // Preset bindings not overridden are kept
+        assert_eq!(action(&bindings, "Ctrl+x Ctrl+c").as_deref(), Some("quit"));
+        assert_eq!(action(&bindings, "Ctrl+n").as_deref(), Some("move_down"));
+
+        let ctrl_g = parse_key_sequence("Ctrl+g").unwrap();
+        let close = bindings.lookup(Some("modal"), &ctrl_g);
+        assert_eq!(close.map(|a| a.name()), Some("close"));
+    }
+}
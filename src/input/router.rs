@@ -5,6 +5,7 @@
 //! - Stop propagation
 //! - Middleware for logging and transformation
 //! - Async handler support
+//! - Closures registered by action name for app-global actions
 //!
 //! # Event Propagation Model
 //!
@@ -27,6 +28,10 @@
 //!                    └─────────┘
 //! ```
 //!
+//! A handler stops propagation by returning [`HandleResult::Handled`] or
+//! [`HandleResult::StopImmediate`]; no handler is called after either. Every
+//! handler call is recorded in [`DispatchResult::visited`] for debugging.
+//!
 //! # Examples
 //!
 //! ## Basic Dispatch
@@ -57,6 +62,31 @@
 //! let result = router.dispatch(&mut app, Action::new("quit"));
 //! ```
 //!
+//! ## Handlers by Action Name
+//!
+//! Actions that no handler in the tree consumes fall through to closures
+//! registered with [`ActionRouter::on`], so app-global actions don't need a
+//! handler tree of their own:
+//!
+//! ```rust
+//! use tuilib::input::{Action, ActionRouter, HandleResult, Phase};
+//! # use tuilib::input::ActionHandler;
+//! # struct Root;
+//! # impl ActionHandler for Root {
+//! #     fn handle(&mut self, _: &Action, _: Phase) -> HandleResult { HandleResult::Ignored }
+//! #     fn id(&self) -> &str { "root" }
+//! #     fn children(&self) -> &[Box<dyn ActionHandler>] { &[] }
+//! #     fn children_mut(&mut self) -> &mut [Box<dyn ActionHandler>] { &mut [] }
+//! # }
+//!
+//! let mut router = ActionRouter::new();
+//! router.on("quit", |_action| HandleResult::Handled);
+//!
+//! let result = router.dispatch(&mut Root, Action::new("quit"));
+//! assert!(result.was_handled());
+//! assert_eq!(result.handled_by.as_deref(), Some(ActionRouter::HANDLER_ID));
+//! ```
+//!
 //! ## With Middleware
 //!
 //! ```rust
@@ -66,6 +96,7 @@
 //! router.add_middleware(TracingMiddleware::debug());
 //! ```
 
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 
@@ -85,6 +116,11 @@ pub struct DispatchResult {
     pub handled_in: Option<Phase>,
     /// Whether propagation was stopped.
     pub propagation_stopped: bool,
+    /// Every handler called, as `(id, phase)`, in call order.
+    ///
+    /// Closures registered with [`ActionRouter::on`] are listed as
+    /// [`ActionRouter::HANDLER_ID`].
+    pub visited: Vec<(String, Phase)>,
 }
 
 impl DispatchResult {
@@ -95,6 +131,7 @@ impl DispatchResult {
             handled_by: None,
             handled_in: None,
             propagation_stopped: false,
+            visited: Vec::new(),
         }
     }
 
@@ -105,6 +142,7 @@ impl DispatchResult {
             handled_by: Some(by.to_string()),
             handled_in: Some(phase),
             propagation_stopped: true,
+            visited: Vec::new(),
         }
     }
 
@@ -114,12 +152,16 @@ impl DispatchResult {
     }
 }
 
+/// A closure registered with [`ActionRouter::on`].
+type ActionCallback = Box<dyn FnMut(&Action) -> HandleResult + Send>;
+
 /// Routes actions through a component hierarchy with capture/bubble propagation.
 ///
 /// The router manages:
 /// - Two-phase propagation (capture and bubble)
 /// - Middleware chain for action transformation
 /// - Handler registration and dispatch
+/// - Closures registered by action name, run after the bubble phase
 ///
 /// # Example
 ///
@@ -133,16 +175,48 @@ impl DispatchResult {
 /// ```
 pub struct ActionRouter {
     middleware: MiddlewareChain,
+    /// Closures keyed by the action name they handle.
+    handlers: HashMap<String, ActionCallback>,
 }
 
 impl ActionRouter {
+    /// The [`DispatchResult::handled_by`] value for actions handled by a
+    /// closure registered with [`on`](Self::on).
+    pub const HANDLER_ID: &'static str = "router";
+
     /// Creates a new action router.
     pub fn new() -> Self {
         Self {
             middleware: MiddlewareChain::new(),
+            handlers: HashMap::new(),
         }
     }
 
+    /// Registers a closure to handle actions with the given name.
+    ///
+    /// The closure runs after the bubble phase, and only if no handler in
+    /// the tree handled the action. If it returns
+    /// [`HandleResult::Handled`], the action is reported as handled in the
+    /// bubble phase by [`HANDLER_ID`](Self::HANDLER_ID). Registering a
+    /// closure for a name that already has one replaces it.
+    pub fn on<F>(&mut self, action: impl Into<String>, handler: F)
+    where
+        F: FnMut(&Action) -> HandleResult + Send + 'static,
+    {
+        self.handlers.insert(action.into(), Box::new(handler));
+    }
+
+    /// Removes the closure registered for an action name, returning true if
+    /// there was one.
+    pub fn off(&mut self, action: &str) -> bool {
+        self.handlers.remove(action).is_some()
+    }
+
+    /// Returns true if a closure is registered for the action name.
+    pub fn has_handler(&self, action: &str) -> bool {
+        self.handlers.contains_key(action)
+    }
+
     /// Adds middleware to the router.
     ///
     /// Middleware is executed in the order it was added.
@@ -185,18 +259,46 @@ impl ActionRouter {
 
     /// Internal dispatch implementation that handles the two-phase propagation.
     fn dispatch_internal(
-        &self,
+        &mut self,
         root: &mut dyn ActionHandler,
         action: &Action,
         focus_path: &[usize],
+    ) -> DispatchResult {
+        let mut visited = Vec::new();
+        let mut result = self.propagate(root, action, focus_path, &mut visited);
+        result.visited = visited;
+        result
+    }
+
+    /// Runs both phases and then any registered closure, recording each
+    /// handler called in `visited`.
+    fn propagate(
+        &mut self,
+        root: &mut dyn ActionHandler,
+        action: &Action,
+        focus_path: &[usize],
+        visited: &mut Vec<(String, Phase)>,
     ) -> DispatchResult {
         // Capture phase: root → target
-        if let Some(result) = self.capture_phase(root, action, focus_path, 0) {
+        if let Some(result) = self.capture_phase(root, action, focus_path, 0, visited) {
             return result;
         }
 
         // Bubble phase: target → root
-        self.bubble_phase(root, action, focus_path, 0)
+        let result = self.bubble_phase(root, action, focus_path, 0, visited);
+        if result.was_handled() {
+            return result;
+        }
+
+        // Finally, any closure registered for the action
+        let Some(handler) = self.handlers.get_mut(action.name()) else {
+            return result;
+        };
+        visited.push((Self::HANDLER_ID.to_string(), Phase::Bubble));
+        if handler(action).should_stop() {
+            return DispatchResult::handled(Self::HANDLER_ID, Phase::Bubble);
+        }
+        result
     }
 
     /// Capture phase: dispatches from root toward target.
@@ -206,9 +308,11 @@ impl ActionRouter {
         action: &Action,
         focus_path: &[usize],
         depth: usize,
+        visited: &mut Vec<(String, Phase)>,
     ) -> Option<DispatchResult> {
         // Handle at current node
         let result = handler.handle(action, Phase::Capture);
+        visited.push((handler.id().to_string(), Phase::Capture));
         if result.should_stop() {
             return Some(DispatchResult::handled(handler.id(), Phase::Capture));
         }
@@ -223,6 +327,7 @@ impl ActionRouter {
                     action,
                     focus_path,
                     depth + 1,
+                    visited,
                 );
             }
         }
@@ -237,14 +342,20 @@ impl ActionRouter {
         action: &Action,
         focus_path: &[usize],
         depth: usize,
+        visited: &mut Vec<(String, Phase)>,
     ) -> DispatchResult {
         // First, recurse to child if we have more path
         if depth < focus_path.len() {
             let child_idx = focus_path[depth];
             let children = handler.children_mut();
             if child_idx < children.len() {
-                let result =
-                    self.bubble_phase(&mut *children[child_idx], action, focus_path, depth + 1);
+                let result = self.bubble_phase(
+                    &mut *children[child_idx],
+                    action,
+                    focus_path,
+                    depth + 1,
+                    visited,
+                );
                 if result.was_handled() {
                     return result;
                 }
@@ -253,6 +364,7 @@ impl ActionRouter {
 
         // Handle at current node
         let result = handler.handle(action, Phase::Bubble);
+        visited.push((handler.id().to_string(), Phase::Bubble));
         if result.should_stop() {
             return DispatchResult::handled(handler.id(), Phase::Bubble);
         }
@@ -327,6 +439,7 @@ impl std::fmt::Debug for ActionRouter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ActionRouter")
             .field("middleware_count", &self.middleware.len())
+            .field("handler_count", &self.handlers.len())
             .finish()
     }
 }
@@ -342,6 +455,7 @@ mod tests {
         children: Vec<Box<dyn ActionHandler>>,
         handle_in_capture: Option<String>,
         handle_in_bubble: Option<String>,
+        immediate: bool,
         calls: Arc<Mutex<Vec<(String, Phase)>>>,
     }
 
@@ -353,6 +467,7 @@ mod tests {
                 children: Vec::new(),
                 handle_in_capture: None,
                 handle_in_bubble: None,
+                immediate: false,
                 calls: Arc::new(Mutex::new(Vec::new())),
             }
         }
@@ -372,6 +487,11 @@ mod tests {
             self
         }
 
+        fn immediate(mut self) -> Self {
+            self.immediate = true;
+            self
+        }
+
         fn with_child(mut self, child: TestHandler) -> Self {
             self.children.push(Box::new(child));
             self
@@ -390,6 +510,9 @@ mod tests {
             match phase {
                 Phase::Capture => {
                     if let Some(ref handle_action) = self.handle_in_capture {
+                        if action.name() == handle_action && self.immediate {
+                            return HandleResult::StopImmediate;
+                        }
                         if action.name() == handle_action {
                             return HandleResult::Handled;
                         }
@@ -726,4 +849,130 @@ mod tests {
         assert_eq!(result.handled_by.as_deref(), Some("root"));
         assert_eq!(result.handled_in, Some(Phase::Capture));
     }
+
+    #[test]
+    fn test_registered_closures_handle_unconsumed_actions() {
+        let mut router = ActionRouter::new();
+        let ran = Arc::new(Mutex::new(Vec::new()));
+
+        let quit_calls = Arc::clone(&ran);
+        router.on("quit", move |action| {
+            quit_calls.lock().unwrap().push(action.name().to_string());
+            HandleResult::Handled
+        });
+        let help_calls = Arc::clone(&ran);
+        router.on("help", move |action| {
+            help_calls.lock().unwrap().push(action.name().to_string());
+            HandleResult::Handled
+        });
+        assert!(router.has_handler("quit"));
+
+        let mut root = TestHandler::new("root").with_child(TestHandler::new("child").focused());
+        for name in ["quit", "help"] {
+            let result = router.dispatch(&mut root, Action::new(name));
+            assert!(result.was_handled());
+            assert_eq!(result.handled_by.as_deref(), Some(ActionRouter::HANDLER_ID));
+            assert_eq!(result.handled_in, Some(Phase::Bubble));
+        }
+        assert_eq!(*ran.lock().unwrap(), vec!["quit", "help"]);
+
+        // Unregistered actions are still not handled
+        let result = router.dispatch(&mut root, Action::new("save"));
+        assert!(!result.was_handled());
+        assert!(router.off("help"));
+        assert!(!router
+            .dispatch(&mut root, Action::new("help"))
+            .was_handled());
+    }
+
+    #[test]
+    fn test_tree_handlers_take_precedence_over_closures() {
+        let mut router = ActionRouter::new();
+        let ran = Arc::new(Mutex::new(0));
+        let count = Arc::clone(&ran);
+        router.on("click", move |_| {
+            *count.lock().unwrap() += 1;
+            HandleResult::Handled
+        });
+
+        let mut root = TestHandler::new("root").handles_bubble("click");
+        let result = router.dispatch(&mut root, Action::new("click"));
+        assert_eq!(result.handled_by.as_deref(), Some("root"));
+        assert_eq!(*ran.lock().unwrap(), 0);
+
+        // A closure that declines leaves the action unhandled
+        router.on("click", |_| HandleResult::Continue);
+        let mut root = TestHandler::new("root");
+        assert!(!router
+            .dispatch(&mut root, Action::new("click"))
+            .was_handled());
+    }
+
+    #[test]
+    fn test_stop_immediate_skips_target_bubble() {
+        let mut router = ActionRouter::new();
+        router.on("x", |_| HandleResult::Handled);
+        let visit = |id: &str, phase| (id.to_string(), phase);
+
+        // Handled in capture at the target ends dispatch there
+        let leaf = TestHandler::new("leaf").focused().handles_capture("x");
+        let leaf_calls = leaf.calls.clone();
+        let mut root = TestHandler::new("root").with_child(leaf);
+        let result = router.dispatch(&mut root, Action::new("x"));
+        assert_eq!(result.handled_by.as_deref(), Some("leaf"));
+        assert_eq!(result.handled_in, Some(Phase::Capture));
+        assert_eq!(
+            result.visited,
+            vec![visit("root", Phase::Capture), visit("leaf", Phase::Capture)]
+        );
+        assert_eq!(leaf_calls.lock().unwrap().len(), 1);
+
+        // StopImmediate skips the target's bubble handling and the closure
+        let leaf = TestHandler::new("leaf")
+            .focused()
+            .handles_capture("x")
+            .immediate();
+        let leaf_calls = leaf.calls.clone();
+        let mut root = TestHandler::new("root").with_child(leaf);
+        let result = router.dispatch(&mut root, Action::new("x"));
+        assert!(result.was_handled());
+        assert_eq!(result.handled_by.as_deref(), Some("leaf"));
+        assert_eq!(
+            result.visited,
+            vec![visit("root", Phase::Capture), visit("leaf", Phase::Capture)]
+        );
+        assert_eq!(
+            *leaf_calls.lock().unwrap(),
+            vec![visit("leaf", Phase::Capture)]
+        );
+    }
+
+    #[test]
+    fn test_visited_path_records_full_dispatch() {
+        let mut router = ActionRouter::new();
+        router.on("save", |_| HandleResult::Handled);
+        let visit = |id: &str, phase| (id.to_string(), phase);
+
+        let leaf = TestHandler::new("leaf").focused();
+        let mut root = TestHandler::new("root").with_child(leaf);
+        let result = router.dispatch(&mut root, Action::new("save"));
+        assert_eq!(
+            result.visited,
+            vec![
+                visit("root", Phase::Capture),
+                visit("leaf", Phase::Capture),
+                visit("leaf", Phase::Bubble),
+                visit("root", Phase::Bubble),
+                visit(ActionRouter::HANDLER_ID, Phase::Bubble),
+            ]
+        );
+
+        // Ancestors that stop propagation in capture don't reach bubble
+        let leaf = TestHandler::new("leaf").focused();
+        let mut root = TestHandler::new("root")
+            .handles_capture("save")
+            .with_child(leaf);
+        let result = router.dispatch(&mut root, Action::new("save"));
+        assert_eq!(result.visited, vec![visit("root", Phase::Capture)]);
+    }
 }
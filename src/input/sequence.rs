@@ -129,6 +129,61 @@ impl KeySequence {
         &self.keys[0]
     }
 
+    /// Returns the last key binding in this sequence.
+    pub fn last(&self) -> &KeyBinding {
+        &self.keys[self.keys.len() - 1]
+    }
+
+    /// Returns true if this sequence begins with the given keys.
+    ///
+    /// An empty slice is a prefix of every sequence, and every sequence
+    /// starts with its own keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::{KeyBinding, KeySequence};
+    /// use terminput::KeyCode;
+    ///
+    /// let g = KeyBinding::new(KeyCode::Char('g'));
+    /// let goto_top = KeySequence::new(vec![g.clone(), g.clone()]);
+    ///
+    /// assert!(goto_top.starts_with(&[g.clone()]));
+    /// assert!(!goto_top.starts_with(&[KeyBinding::new(KeyCode::Char('d'))]));
+    /// ```
+    pub fn starts_with(&self, keys: &[KeyBinding]) -> bool {
+        self.keys.starts_with(keys)
+    }
+
+    /// Returns true if this sequence is a strict prefix of `other`.
+    ///
+    /// This is the relationship the [`InputMatcher`](super::InputMatcher)
+    /// uses to decide that a sequence is pending: after `Ctrl+X` it waits to
+    /// see whether `Ctrl+X Ctrl+S` follows. A sequence is not a prefix of
+    /// itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::input::KeySequenceBuilder;
+    /// use terminput::{KeyCode, KeyModifiers};
+    ///
+    /// let ctrl_x = KeySequenceBuilder::new()
+    ///     .key(KeyCode::Char('x'), KeyModifiers::CTRL)
+    ///     .build();
+    /// let save = KeySequenceBuilder::new()
+    ///     .key(KeyCode::Char('x'), KeyModifiers::CTRL)
+    ///     .key(KeyCode::Char('s'), KeyModifiers::CTRL)
+    ///     .build();
+    ///
+    /// assert!(ctrl_x.is_prefix_of(&save));
+    /// assert!(!save.is_prefix_of(&ctrl_x));
+    /// assert!(!save.is_prefix_of(&save));
+    /// ```
+    pub fn is_prefix_of(&self, other: &KeySequence) -> bool {
+        self.len() < other.len() && other.starts_with(&self.keys)
+    }
+
     /// Checks if a given key event matches the binding at the specified position.
     ///
     /// # Arguments
@@ -308,6 +363,34 @@ mod tests {
         assert_eq!(seq.first().key(), KeyCode::Char('a'));
     }
 
+    #[test]
+    fn test_sequence_prefix_predicates() {
+        let ctrl_x = KeyBinding::new(KeyCode::Char('x')).with_modifiers(KeyModifiers::CTRL);
+        let ctrl_s = KeyBinding::new(KeyCode::Char('s')).with_modifiers(KeyModifiers::CTRL);
+        let ctrl_c = KeyBinding::new(KeyCode::Char('c')).with_modifiers(KeyModifiers::CTRL);
+        let prefix = KeySequence::single(ctrl_x.clone());
+        let save = KeySequence::new(vec![ctrl_x.clone(), ctrl_s.clone()]);
+        let quit = KeySequence::new(vec![ctrl_x.clone(), ctrl_c.clone()]);
+        let plain_x = KeySequence::single(KeyBinding::new(KeyCode::Char('x')));
+
+        assert!(prefix.is_prefix_of(&save));
+        assert!(prefix.is_prefix_of(&quit));
+        assert!(!save.is_prefix_of(&prefix));
+        assert!(!save.is_prefix_of(&quit));
+        assert!(!prefix.is_prefix_of(&prefix));
+        // Modifiers are part of the key
+        assert!(!plain_x.is_prefix_of(&save));
+
+        assert!(save.starts_with(&[]));
+        assert!(save.starts_with(std::slice::from_ref(&ctrl_x)));
+        assert!(save.starts_with(&[ctrl_x.clone(), ctrl_s.clone()]));
+        assert!(!save.starts_with(&[ctrl_x.clone(), ctrl_s.clone(), ctrl_s.clone()]));
+
+        assert_eq!(save.first(), &ctrl_x);
+        assert_eq!(save.last(), &ctrl_s);
+        assert_eq!(prefix.last(), prefix.first());
+    }
+
     #[test]
     fn test_sequence_matches_at() {
         let seq = KeySequence::new(vec![
@@ -39,17 +39,24 @@
 //!
 //! ## Modules
 //!
+//! - [`clipboard`]: Clipboard access for text components
 //! - [`components`]: UI components (buttons, inputs, etc.)
 //! - [`input`]: Input action mapping and keyboard handling
 //! - [`focus`]: Focus management and navigation
+//! - [`text`]: Text layout helpers such as word wrapping
 //! - [`theme`]: Theming and design tokens
 //! - [`event`]: Async event loop infrastructure
 //! - [`tracing`]: Structured logging and debugging with setup helpers
+//! - `test_util`: Render helpers for component tests (`test-util` feature)
 
+pub mod clipboard;
 pub mod components;
 pub mod event;
 pub mod focus;
 pub mod input;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+pub mod text;
 pub mod theme;
 pub mod tracing;
 
@@ -12,7 +12,7 @@
 //! This prelude includes:
 //!
 //! - All types from `ratatui::prelude::*`
-//! - Core component traits: [`Component`], [`Focusable`], [`Renderable`]
+//! - Core component traits: [`Component`], [`Focusable`], [`Renderable`], [`Measurable`]
 //! - Convenience types: [`FocusWrapper`], [`FocusableComponent`]
 //! - Input types: [`Action`], [`KeyBinding`], [`KeyBindings`], [`KeySequence`], [`InputMatcher`]
 //! - Focus types: [`FocusId`], [`FocusManager`], [`FocusRing`], [`FocusTrap`]
@@ -27,7 +27,8 @@ pub use ratatui::prelude::*;
 
 // Core component traits
 pub use crate::components::{
-    Component, FocusWrapper, Focusable, FocusableComponent, Renderable, StatelessComponent,
+    Component, FocusWrapper, Focusable, FocusableComponent, Measurable, Renderable,
+    StatelessComponent,
 };
 
 // Input types
@@ -36,7 +37,9 @@ pub use crate::input::{
 };
 
 // Focus types
-pub use crate::focus::{FocusDirection, FocusId, FocusManager, FocusResult, FocusRing, FocusTrap};
+pub use crate::focus::{
+    FocusDirection, FocusId, FocusManager, FocusRestore, FocusResult, FocusRing, FocusTrap,
+};
 
 // Theme types
 pub use crate::theme::{ColorPalette, Theme, ThemeBuilder};
@@ -44,7 +47,7 @@ pub use crate::theme::{ColorPalette, Theme, ThemeBuilder};
 // Event loop types
 pub use crate::event::{
     restore_terminal, setup_terminal, AppEvent, ControlFlow, Debouncer, EventLoop, EventLoopConfig,
-    Throttle,
+    TerminalEvent, Throttle,
 };
 
 // Tracing types
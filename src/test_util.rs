@@ -0,0 +1,170 @@
+//! Helpers for testing how components render.
+//!
+//! Available with the `test-util` feature. Components are rendered into an
+//! in-memory ratatui [`TestBackend`], and the resulting [`Buffer`] can be
+//! dumped to strings for easy assertions.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::prelude::Rect;
+//! use tuilib::components::modal::Button;
+//! use tuilib::test_util::{assert_renders_to, render, to_lines};
+//!
+//! let button = Button::new("ok", "OK");
+//! let buffer = render(&button, Rect::new(0, 0, 6, 3));
+//! assert_eq!(to_lines(&buffer)[1], "│ OK │");
+//!
+//! assert_renders_to(
+//!     &button,
+//!     Rect::new(0, 0, 6, 3),
+//!     &["╭────╮", "│ OK │", "╰────╯"],
+//! );
+//! ```
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+
+use crate::components::Renderable;
+
+/// An in-memory terminal for rendering components in tests.
+#[derive(Debug)]
+pub struct TestTerminal {
+    terminal: Terminal<TestBackend>,
+}
+
+impl TestTerminal {
+    /// Creates a terminal of the given size.
+    pub fn new(width: u16, height: u16) -> Self {
+        let terminal = Terminal::new(TestBackend::new(width, height))
+            .expect("creating a terminal on a TestBackend cannot fail");
+        Self { terminal }
+    }
+
+    /// Returns the full terminal area.
+    pub fn area(&self) -> Rect {
+        self.buffer().area
+    }
+
+    /// Clears the terminal and renders a component into `area`.
+    pub fn render<R: Renderable + ?Sized>(&mut self, component: &R, area: Rect) -> &Buffer {
+        self.terminal
+            .clear()
+            .expect("clearing a TestBackend cannot fail");
+        self.terminal
+            .draw(|frame| component.render(frame, area))
+            .expect("drawing to a TestBackend cannot fail");
+        self.buffer()
+    }
+
+    /// Returns the current contents of the terminal.
+    pub fn buffer(&self) -> &Buffer {
+        self.terminal.backend().buffer()
+    }
+
+    /// Returns the terminal contents as one string per row.
+    pub fn to_lines(&self) -> Vec<String> {
+        to_lines(self.buffer())
+    }
+}
+
+/// Renders a component into `area` of a terminal just large enough to hold it.
+pub fn render<R: Renderable + ?Sized>(component: &R, area: Rect) -> Buffer {
+    let mut terminal = TestTerminal::new(area.right(), area.bottom());
+    terminal.render(component, area).clone()
+}
+
+/// Returns the buffer contents as one string per row.
+pub fn to_lines(buffer: &Buffer) -> Vec<String> {
+    lines_in(buffer, buffer.area)
+}
+
+/// Returns the contents of `area` within the buffer as one string per row.
+///
+/// Cells hidden behind a wide character are skipped, so each string reads
+/// as it appears on screen.
+pub fn lines_in(buffer: &Buffer, area: Rect) -> Vec<String> {
+    let area = buffer.area.intersection(area);
+    (area.top()..area.bottom())
+        .map(|y| {
+            let mut line = String::new();
+            let mut skip = 0;
+            for x in area.left()..area.right() {
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                let symbol = buffer[(x, y)].symbol();
+                skip = Span::raw(symbol).width().saturating_sub(1);
+                line.push_str(symbol);
+            }
+            line
+        })
+        .collect()
+}
+
+/// Asserts that a component renders `expected` into `area`.
+///
+/// Only the cells inside `area` are compared, one string per row.
+///
+/// # Panics
+///
+/// Panics with both renderings if the output differs.
+#[track_caller]
+pub fn assert_renders_to<R: Renderable + ?Sized>(component: &R, area: Rect, expected: &[&str]) {
+    let buffer = render(component, area);
+    let actual = lines_in(&buffer, area);
+    assert!(
+        actual == expected,
+        "rendered output differs\nexpected:\n{}\nactual:\n{}",
+        expected.join("\n"),
+        actual.join("\n"),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::modal::Button;
+    use crate::components::Focusable;
+
+    #[test]
+    fn test_render_button() {
+        let button = Button::new("save", "Save");
+        let buffer = render(&button, Rect::new(0, 0, 8, 3));
+
+        assert_eq!(buffer.area, Rect::new(0, 0, 8, 3));
+        assert_eq!(to_lines(&buffer), vec!["╭──────╮", "│ Save │", "╰──────╯"]);
+    }
+
+    #[test]
+    fn test_assert_renders_to_offset_area() {
+        let mut button = Button::new("ok", "OK");
+        button.set_focused(true);
+
+        assert_renders_to(
+            &button,
+            Rect::new(2, 1, 6, 3),
+            &["╭────╮", "│ OK │", "╰────╯"],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "rendered output differs")]
+    fn test_assert_renders_to_mismatch() {
+        let button = Button::new("ok", "OK");
+        assert_renders_to(&button, Rect::new(0, 0, 6, 3), &["", "│ NO │", ""]);
+    }
+
+    #[test]
+    fn test_terminal_reuse_and_wide_chars() {
+        let mut terminal = TestTerminal::new(6, 3);
+        terminal.render(&Button::new("a", "日本"), terminal.area());
+        assert_eq!(terminal.to_lines()[1], "│日本│");
+
+        // Rendering again starts from a clean buffer
+        terminal.render(&Button::new("b", "x"), Rect::new(0, 0, 3, 3));
+        assert_eq!(terminal.to_lines()[1], "│x│   ");
+    }
+}
@@ -0,0 +1,165 @@
+//! Fuzzy subsequence matching for filtering lists as the user types.
+//!
+//! [`fuzzy_match`] checks whether every character of a query appears in a
+//! candidate in order, ignoring case, and scores the best such alignment.
+//! Matches at the start of words and runs of consecutive characters score
+//! higher; characters skipped between matches score lower. Higher scores
+//! are better, so sorting candidates by descending score puts the closest
+//! matches first.
+
+/// Score for each matched character.
+const SCORE_MATCH: i32 = 16;
+
+/// Bonus for matching the character right after the previous match.
+const BONUS_CONSECUTIVE: i32 = 12;
+
+/// Bonus for matching the first character of a word.
+const BONUS_WORD_START: i32 = 8;
+
+/// Penalty for each candidate character skipped between matches.
+const PENALTY_GAP: i32 = 1;
+
+/// Maximum penalty for candidate characters before the first match.
+const MAX_LEADING_PENALTY: i32 = 3;
+
+/// The result of matching a query against a candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// How well the query matched; higher is better.
+    pub score: i32,
+    /// Character indices in the candidate that matched the query, in order.
+    pub positions: Vec<usize>,
+}
+
+/// Matches a query against a candidate as a case-insensitive subsequence.
+///
+/// Returns `None` if some query character cannot be matched in order.
+/// Whitespace in the query is ignored, and an empty query matches every
+/// candidate with a score of zero.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::text::fuzzy_match;
+///
+/// let word_starts = fuzzy_match("of", "Open File").unwrap();
+/// let inside = fuzzy_match("of", "Go to File").unwrap();
+/// assert_eq!(word_starts.positions, vec![0, 5]);
+/// assert!(word_starts.score > inside.score);
+///
+/// assert!(fuzzy_match("xyz", "Open File").is_none());
+/// ```
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    let chars: Vec<char> = candidate.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    if query.len() > chars.len() {
+        return None;
+    }
+
+    // best[i][j]: best score with query[i] matched at candidate[j], and the
+    // candidate index query[i - 1] was matched at to get it
+    let mut best: Vec<Vec<Option<(i32, usize)>>> = vec![vec![None; chars.len()]; query.len()];
+    for (i, &q) in query.iter().enumerate() {
+        for j in (0..chars.len()).filter(|&j| lower[j] == q) {
+            let base = SCORE_MATCH + word_start_bonus(&chars, j);
+            best[i][j] = if i == 0 {
+                let leading = (j as i32).min(MAX_LEADING_PENALTY);
+                Some((base - leading * PENALTY_GAP, 0))
+            } else {
+                (0..j)
+                    .filter_map(|k| {
+                        let (score, _) = best[i - 1][k]?;
+                        let link = if k + 1 == j {
+                            BONUS_CONSECUTIVE
+                        } else {
+                            -((j - k - 1) as i32) * PENALTY_GAP
+                        };
+                        Some((score + base + link, k))
+                    })
+                    .max_by_key(|&(score, k)| (score, std::cmp::Reverse(k)))
+            };
+        }
+    }
+
+    // Pick the best final match, preferring the earliest on ties
+    let last = query.len() - 1;
+    let (mut j, score) = (0..chars.len())
+        .filter_map(|j| best[last][j].map(|(score, _)| (j, score)))
+        .max_by_key(|&(j, score)| (score, std::cmp::Reverse(j)))?;
+
+    let mut positions = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        positions[i] = j;
+        if let Some((_, prev)) = best[i][j] {
+            j = prev;
+        }
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Returns the bonus for a match at `index`, if it starts a word.
+///
+/// Words start at the beginning of the text, after a separator such as a
+/// space, `_`, `-`, `/`, `.` or `:`, and at an uppercase letter following a
+/// lowercase one.
+fn word_start_bonus(chars: &[char], index: usize) -> i32 {
+    let Some(index_before) = index.checked_sub(1) else {
+        return BONUS_WORD_START;
+    };
+    let (prev, current) = (chars[index_before], chars[index]);
+    let after_separator = prev.is_whitespace() || matches!(prev, '_' | '-' | '/' | '.' | ':');
+    let camel_case = prev.is_lowercase() && current.is_uppercase();
+    if after_separator || camel_case {
+        BONUS_WORD_START
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_requires_ordered_subsequence() {
+        assert!(fuzzy_match("fo", "Open File").is_none());
+        assert!(fuzzy_match("longer than text", "short").is_none());
+        assert_eq!(fuzzy_match("", "anything").unwrap().score, 0);
+
+        let m = fuzzy_match("OPF", "open file").unwrap();
+        assert_eq!(m.positions, vec![0, 1, 5]);
+        // Whitespace in the query is ignored
+        assert_eq!(
+            fuzzy_match("o f", "Open File").unwrap().positions,
+            vec![0, 5]
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_match_prefers_word_starts_and_runs() {
+        // Picks the word-start 'S' over the earlier inner ones
+        let m = fuzzy_match("s", "class Size").unwrap();
+        assert_eq!(m.positions, vec![6]);
+
+        let score = |query, text| fuzzy_match(query, text).unwrap().score;
+        assert!(score("fil", "File") > score("fil", "Profile"));
+        assert!(score("gtf", "Go to File") > score("gtf", "Go Left Fast"));
+        assert!(score("save", "Save All") > score("save", "Sa Very Eager"));
+        assert!(score("tw", "toggleWordWrap") > score("tw", "totalwidth"));
+    }
+}
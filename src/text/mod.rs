@@ -0,0 +1,20 @@
+//! Text layout helpers shared by text-rendering components.
+//!
+//! - [`wrap`]: word wrapping, height measurement and truncation by display
+//!   width
+//! - [`fuzzy`]: fuzzy subsequence matching for filtering lists
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::text::{measure_height, wrap_text};
+//!
+//! assert_eq!(wrap_text("the quick brown fox", 10), vec!["the quick", "brown fox"]);
+//! assert_eq!(measure_height("the quick brown fox", 10), 2);
+//! ```
+
+pub mod fuzzy;
+pub mod wrap;
+
+pub use fuzzy::{fuzzy_match, FuzzyMatch};
+pub use wrap::{display_width, measure_height, truncate, wrap_text};
@@ -0,0 +1,198 @@
+//! Word wrapping by display width.
+//!
+//! [`wrap_text`] breaks text into lines no wider than a given number of
+//! terminal cells. Lines break between words where possible, and words
+//! wider than a whole line are split. Widths are measured with
+//! `unicode-width`, so wide characters such as CJK count as two cells.
+//!
+//! [`measure_height`] returns the number of lines the same text wraps to,
+//! for sizing an area before rendering into it, and [`truncate`] cuts text
+//! that must stay on one line.
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Returns the number of terminal cells `text` occupies.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::text::display_width;
+///
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("日本"), 4);
+/// ```
+pub fn display_width(text: &str) -> usize {
+    text.width()
+}
+
+/// Word-wraps text to at most `width` cells per line.
+///
+/// Each line of the input is wrapped separately, so explicit newlines are
+/// kept and blank lines stay blank. Runs of whitespace between words are
+/// collapsed to a single space. A width of zero is treated as one.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::text::wrap_text;
+///
+/// assert_eq!(wrap_text("one two three", 7), vec!["one two", "three"]);
+/// assert_eq!(wrap_text("abcdefgh ij", 3), vec!["abc", "def", "gh", "ij"]);
+/// ```
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        let mut line_width = 0;
+
+        for word in paragraph.split_whitespace() {
+            let word_width = display_width(word);
+            let needed = if line.is_empty() {
+                word_width
+            } else {
+                line_width + 1 + word_width
+            };
+
+            if needed <= width {
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(word);
+                line_width = needed;
+                continue;
+            }
+
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+
+            if word_width <= width {
+                line.push_str(word);
+                line_width = word_width;
+                continue;
+            }
+
+            // Hard-break words that do not fit on a line of their own
+            for c in word.chars() {
+                let char_width = c.width().unwrap_or(0);
+                if line_width + char_width > width && !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0;
+                }
+                line.push(c);
+                line_width += char_width;
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Returns the number of lines `text` wraps to at `width` cells.
+///
+/// This is the length of [`wrap_text`]'s result; empty text has no lines.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::text::measure_height;
+///
+/// assert_eq!(measure_height("one two three", 7), 2);
+/// assert_eq!(measure_height("", 7), 0);
+/// ```
+pub fn measure_height(text: &str, width: usize) -> usize {
+    wrap_text(text, width).len()
+}
+
+/// Truncates text to `width` cells, ending with `…` if it overflows.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::text::truncate;
+///
+/// assert_eq!(truncate("a long value", 6), "a lon…");
+/// assert_eq!(truncate("short", 10), "short");
+/// ```
+pub fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut truncated = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width > width - 1 {
+            break;
+        }
+        truncated.push(c);
+        used += char_width;
+    }
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wraps_on_word_boundaries() {
+        assert_eq!(wrap_text("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap_text("abcdefgh ij", 3), vec!["abc", "def", "gh", "ij"]);
+        assert_eq!(wrap_text("first\nsecond", 20), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn test_long_words_hard_break() {
+        assert_eq!(
+            wrap_text("a supercalifragilistic word", 8),
+            vec!["a", "supercal", "ifragili", "stic", "word"]
+        );
+        assert_eq!(wrap_text("abc", 0), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let text = "first paragraph here\n\nsecond   one";
+        assert_eq!(
+            wrap_text(text, 10),
+            vec!["first", "paragraph", "here", "", "second one"]
+        );
+        assert_eq!(measure_height(text, 10), 5);
+        assert_eq!(measure_height(text, 40), 3);
+    }
+
+    #[test]
+    fn test_cjk_width() {
+        // Each character is two cells wide
+        assert_eq!(
+            wrap_text("日本語テキスト", 6),
+            vec!["日本語", "テキス", "ト"]
+        );
+        // An odd width cannot fit a third character
+        assert_eq!(wrap_text("日本語 abc", 5), vec!["日本", "語", "abc"]);
+        for line in wrap_text("漢字とかなの混じった文です", 7) {
+            assert!(display_width(&line) <= 7, "{line:?}");
+        }
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("a long value", 6), "a lon…");
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("abc", 0), "");
+        assert_eq!(truncate("abc", 1), "…");
+        // A wide character that does not fit before the ellipsis is dropped
+        assert_eq!(truncate("日本語", 4), "日…");
+    }
+}
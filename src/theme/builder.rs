@@ -3,10 +3,12 @@
 //! The [`ThemeBuilder`] provides a fluent API for creating themes with
 //! custom colors, border styles, text styles, and component configurations.
 
+use std::collections::HashMap;
+
 use ratatui::style::Color;
 use ratatui::widgets::BorderType;
 
-use super::colors::ColorPalette;
+use super::colors::{ColorPalette, ColorRole};
 use super::component::{
     ButtonStyle, ComponentStyles, InputStyle, ListStyle, ModalStyle, TableStyle, TabsStyle,
 };
@@ -153,6 +155,36 @@ impl ThemeBuilder {
         self
     }
 
+    /// Sets the color for a single palette role.
+    pub fn color(mut self, role: ColorRole, color: Color) -> Self {
+        self.colors.set(role, color);
+        self
+    }
+
+    /// Applies a set of color overrides on top of the current palette.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use tuilib::theme::{ColorRole, ThemeBuilder};
+    /// use ratatui::style::Color;
+    ///
+    /// let overrides = HashMap::from([
+    ///     (ColorRole::Primary, Color::Cyan),
+    ///     (ColorRole::BorderFocused, Color::Cyan),
+    /// ]);
+    ///
+    /// let theme = ThemeBuilder::new().light_base().with_overrides(overrides).build();
+    /// assert_eq!(theme.colors().border_focused, Color::Cyan);
+    /// ```
+    pub fn with_overrides(mut self, overrides: HashMap<ColorRole, Color>) -> Self {
+        for (role, color) in overrides {
+            self.colors.set(role, color);
+        }
+        self
+    }
+
     /// Sets the entire border styles configuration.
     pub fn borders(mut self, borders: BorderStyles) -> Self {
         self.borders = borders;
@@ -177,6 +209,18 @@ impl ThemeBuilder {
         self
     }
 
+    /// Sets the disabled border type.
+    pub fn disabled_border_type(mut self, border_type: BorderType) -> Self {
+        self.borders.disabled = border_type;
+        self
+    }
+
+    /// Sets the hovered border type.
+    pub fn hover_border_type(mut self, border_type: BorderType) -> Self {
+        self.borders.hover = border_type;
+        self
+    }
+
     /// Uses modern border styles (rounded corners).
     pub fn modern_borders(mut self) -> Self {
         self.borders = BorderStyles::modern();
@@ -368,4 +412,24 @@ mod tests {
         let theme = ThemeBuilder::new().button_style(button_style).build();
         assert_eq!(theme.components().button.padding, 5);
     }
+
+    #[test]
+    fn test_builder_with_overrides() {
+        let overrides = HashMap::from([
+            (ColorRole::Primary, Color::Yellow),
+            (ColorRole::Error, Color::LightRed),
+        ]);
+        let theme = ThemeBuilder::new()
+            .light_base()
+            .with_overrides(overrides)
+            .build();
+
+        assert_eq!(theme.colors().primary, Color::Yellow);
+        assert_eq!(theme.colors().error, Color::LightRed);
+        assert_eq!(theme.colors().background, ColorPalette::light().background);
+        assert_eq!(
+            theme.primary_text_style().fg,
+            Some(theme.colors().text_primary)
+        );
+    }
 }
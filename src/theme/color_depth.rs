@@ -0,0 +1,286 @@
+//! Terminal color depth detection and color downsampling.
+//!
+//! Not every terminal supports 24-bit color. This module provides
+//! [`ColorDepth`] for describing what a terminal can display and for
+//! mapping [`Color::Rgb`] values onto the closest color the terminal
+//! actually supports.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::theme::ColorDepth;
+//! use ratatui::style::Color;
+//!
+//! // #00afff is entry 39 of the xterm 256-color palette
+//! let color = ColorDepth::Ansi256.downsample(Color::Rgb(0, 175, 255));
+//! assert_eq!(color, Color::Indexed(39));
+//! ```
+
+use ratatui::style::Color;
+
+/// The 16 ANSI colors with their conventional xterm RGB values.
+const ANSI_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (128, 0, 0)),
+    (Color::Green, (0, 128, 0)),
+    (Color::Yellow, (128, 128, 0)),
+    (Color::Blue, (0, 0, 128)),
+    (Color::Magenta, (128, 0, 128)),
+    (Color::Cyan, (0, 128, 128)),
+    (Color::Gray, (192, 192, 192)),
+    (Color::DarkGray, (128, 128, 128)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (0, 0, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Channel levels used by the 6x6x6 color cube (indices 16-231).
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The color capabilities of a terminal.
+///
+/// Themes default to [`ColorDepth::TrueColor`], which leaves colors
+/// untouched. Lower depths cause theme style accessors to map RGB colors
+/// to the nearest supported palette entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorDepth {
+    /// 24-bit RGB color.
+    #[default]
+    TrueColor,
+    /// The xterm 256-color palette.
+    Ansi256,
+    /// The 16 basic ANSI colors.
+    Ansi16,
+}
+
+impl ColorDepth {
+    /// Detects the color depth of the current terminal from the
+    /// `COLORTERM` and `TERM` environment variables.
+    pub fn detect() -> Self {
+        Self::detect_from(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    /// Determines the color depth from explicit `COLORTERM` and `TERM` values.
+    ///
+    /// - `COLORTERM=truecolor` or `COLORTERM=24bit` means [`ColorDepth::TrueColor`]
+    /// - A `TERM` containing `256color` means [`ColorDepth::Ansi256`]
+    /// - Anything else falls back to [`ColorDepth::Ansi16`]
+    pub fn detect_from(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if let Some(colorterm) = colorterm {
+            let colorterm = colorterm.to_ascii_lowercase();
+            if colorterm == "truecolor" || colorterm == "24bit" {
+                return ColorDepth::TrueColor;
+            }
+        }
+
+        match term {
+            Some(term) if term.contains("truecolor") || term.contains("direct") => {
+                ColorDepth::TrueColor
+            }
+            Some(term) if term.contains("256color") => ColorDepth::Ansi256,
+            _ => ColorDepth::Ansi16,
+        }
+    }
+
+    /// Maps a color to the nearest color representable at this depth.
+    ///
+    /// Named colors and [`Color::Reset`] are always representable and pass
+    /// through unchanged. Indexed colors above 15 are converted when the
+    /// depth is [`ColorDepth::Ansi16`].
+    pub fn downsample(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorDepth::TrueColor, _) => color,
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+            (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => rgb_to_ansi16(r, g, b),
+            (ColorDepth::Ansi16, Color::Indexed(i)) if i < 16 => ANSI_COLORS[i as usize].0,
+            (ColorDepth::Ansi16, Color::Indexed(i)) => {
+                let (r, g, b) = indexed_to_rgb(i);
+                rgb_to_ansi16(r, g, b)
+            }
+            _ => color,
+        }
+    }
+}
+
+/// Squared Euclidean distance between two RGB colors.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+/// Returns the index of the cube level closest to a channel value.
+fn nearest_cube_level(value: u8) -> usize {
+    CUBE_LEVELS
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, level)| (**level as i32 - value as i32).abs())
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// Maps an RGB color to the nearest entry of the xterm 256-color palette.
+///
+/// Both the 6x6x6 color cube and the 24-step grayscale ramp are considered,
+/// and whichever is closer wins.
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let (ri, gi, bi) = (
+        nearest_cube_level(r),
+        nearest_cube_level(g),
+        nearest_cube_level(b),
+    );
+    let cube_rgb = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+    // Grayscale ramp: 232-255 cover 8, 18, ..., 238
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_value = 8 + gray_step * 10;
+    let gray_index = 232 + gray_step;
+
+    if distance((r, g, b), (gray_value, gray_value, gray_value)) < distance((r, g, b), cube_rgb) {
+        gray_index
+    } else {
+        cube_index as u8
+    }
+}
+
+/// Maps an RGB color to the nearest of the 16 basic ANSI colors.
+pub fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// Returns the RGB value of an entry in the xterm 256-color palette.
+pub(crate) fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI_COLORS[index as usize].1,
+        16..=231 => {
+            let i = (index - 16) as usize;
+            (
+                CUBE_LEVELS[i / 36],
+                CUBE_LEVELS[(i / 6) % 6],
+                CUBE_LEVELS[i % 6],
+            )
+        }
+        _ => {
+            let v = 8 + (index - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+/// Resolves a color to its RGB value.
+///
+/// Named and indexed colors use their conventional xterm values. Returns
+/// `None` for [`Color::Reset`], whose appearance depends on the terminal.
+pub(crate) fn color_to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Reset => None,
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Indexed(i) => Some(indexed_to_rgb(i)),
+        named => ANSI_COLORS
+            .iter()
+            .find(|(c, _)| *c == named)
+            .map(|(_, rgb)| *rgb),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb_to_ansi256_cube() {
+        assert_eq!(rgb_to_ansi256(0, 175, 255), 39);
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+    }
+
+    #[test]
+    fn test_rgb_to_ansi256_grayscale() {
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+        assert_eq!(rgb_to_ansi256(30, 30, 30), 234);
+    }
+
+    #[test]
+    fn test_rgb_to_ansi16() {
+        assert_eq!(rgb_to_ansi16(250, 10, 10), Color::LightRed);
+        assert_eq!(rgb_to_ansi16(10, 10, 10), Color::Black);
+        assert_eq!(rgb_to_ansi16(240, 240, 240), Color::White);
+    }
+
+    #[test]
+    fn test_indexed_to_rgb_round_trip() {
+        for index in 16..=255u8 {
+            let (r, g, b) = indexed_to_rgb(index);
+            assert_eq!(indexed_to_rgb(rgb_to_ansi256(r, g, b)), (r, g, b));
+        }
+    }
+
+    #[test]
+    fn test_color_to_rgb() {
+        assert_eq!(color_to_rgb(Color::Rgb(1, 2, 3)), Some((1, 2, 3)));
+        assert_eq!(color_to_rgb(Color::White), Some((255, 255, 255)));
+        assert_eq!(color_to_rgb(Color::Indexed(39)), Some((0, 175, 255)));
+        assert_eq!(color_to_rgb(Color::Reset), None);
+    }
+
+    #[test]
+    fn test_downsample_truecolor_passthrough() {
+        let color = Color::Rgb(12, 34, 56);
+        assert_eq!(ColorDepth::TrueColor.downsample(color), color);
+    }
+
+    #[test]
+    fn test_downsample_ansi16() {
+        assert_eq!(
+            ColorDepth::Ansi16.downsample(Color::Indexed(9)),
+            Color::LightRed
+        );
+        assert_eq!(
+            ColorDepth::Ansi16.downsample(Color::Indexed(196)),
+            Color::LightRed
+        );
+        assert_eq!(ColorDepth::Ansi16.downsample(Color::Cyan), Color::Cyan);
+    }
+
+    #[test]
+    fn test_downsample_keeps_named_colors() {
+        assert_eq!(ColorDepth::Ansi256.downsample(Color::Cyan), Color::Cyan);
+        assert_eq!(ColorDepth::Ansi256.downsample(Color::Reset), Color::Reset);
+    }
+
+    #[test]
+    fn test_detect_from() {
+        assert_eq!(
+            ColorDepth::detect_from(Some("truecolor"), Some("xterm")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::detect_from(Some("24bit"), None),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::detect_from(None, Some("xterm-256color")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            ColorDepth::detect_from(None, Some("xterm")),
+            ColorDepth::Ansi16
+        );
+        assert_eq!(ColorDepth::detect_from(None, None), ColorDepth::Ansi16);
+    }
+}
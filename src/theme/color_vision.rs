@@ -0,0 +1,186 @@
+//! Color vision deficiency simulation.
+//!
+//! This module provides [`ColorDeficiency`] for previewing how colors appear
+//! to people with common forms of color blindness, and the perceptual color
+//! difference used by [`ColorPalette::is_distinguishable`].
+//!
+//! Simulation uses the Machado, Oliveira and Fernandes (2009) matrices at
+//! full severity, applied in linear RGB. Differences are CIE76 distances in
+//! CIELAB under the D65 white point.
+//!
+//! [`ColorPalette::is_distinguishable`]: super::ColorPalette::is_distinguishable
+
+use ratatui::style::Color;
+
+use super::color_depth::color_to_rgb;
+
+/// The minimum CIE76 color difference for two colors to be told apart at a
+/// glance.
+///
+/// A difference of about 2.3 is just noticeable side by side. Status colors
+/// are usually seen apart from each other, so a much larger gap is needed.
+pub const MIN_COLOR_DIFFERENCE: f32 = 30.0;
+
+/// A form of color vision deficiency.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::theme::ColorDeficiency;
+/// use ratatui::style::Color;
+///
+/// // Pure red and green look alike without working green cones
+/// let red = ColorDeficiency::Deuteranopia.simulate(Color::Rgb(255, 0, 0));
+/// let green = ColorDeficiency::Deuteranopia.simulate(Color::Rgb(0, 255, 0));
+/// assert!(matches!((red, green), (Color::Rgb(..), Color::Rgb(..))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorDeficiency {
+    /// Missing red (long-wavelength) cones; reds look dark and green-like
+    Protanopia,
+    /// Missing green (medium-wavelength) cones; the most common red-green
+    /// deficiency
+    Deuteranopia,
+    /// Missing blue (short-wavelength) cones; blues and greens, and yellows
+    /// and pinks, are confused
+    Tritanopia,
+}
+
+impl ColorDeficiency {
+    /// All simulated deficiencies.
+    pub const ALL: [ColorDeficiency; 3] = [
+        ColorDeficiency::Protanopia,
+        ColorDeficiency::Deuteranopia,
+        ColorDeficiency::Tritanopia,
+    ];
+
+    /// Returns the simulation matrix for linear RGB.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            ColorDeficiency::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            ColorDeficiency::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            ColorDeficiency::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+
+    /// Returns how a color appears with this deficiency.
+    ///
+    /// Named and indexed colors are resolved using their conventional xterm
+    /// RGB values and the result is always [`Color::Rgb`], except for
+    /// [`Color::Reset`], which is returned unchanged.
+    pub fn simulate(self, color: Color) -> Color {
+        match color_to_rgb(color) {
+            Some(rgb) => {
+                let [r, g, b] = self.simulate_linear(to_linear(rgb)).map(linear_to_srgb);
+                Color::Rgb(r, g, b)
+            }
+            None => color,
+        }
+    }
+
+    /// Applies the simulation matrix to a linear RGB color.
+    fn simulate_linear(self, rgb: [f32; 3]) -> [f32; 3] {
+        self.matrix()
+            .map(|row| (row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]).clamp(0.0, 1.0))
+    }
+}
+
+/// Returns the CIE76 difference between two colors as seen with an
+/// optional deficiency.
+///
+/// [`Color::Reset`] is treated as black.
+pub(crate) fn color_difference(a: Color, b: Color, deficiency: Option<ColorDeficiency>) -> f32 {
+    let perceived = |color: Color| {
+        let rgb = to_linear(color_to_rgb(color).unwrap_or((0, 0, 0)));
+        let rgb = deficiency.map_or(rgb, |d| d.simulate_linear(rgb));
+        linear_to_lab(rgb)
+    };
+    let (a, b) = (perceived(a), perceived(b));
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+/// Converts sRGB channels to linear RGB.
+fn to_linear((r, g, b): (u8, u8, u8)) -> [f32; 3] {
+    [r, g, b].map(|c| {
+        let c = c as f32 / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    })
+}
+
+/// Converts a linear RGB channel back to sRGB.
+fn linear_to_srgb(c: f32) -> u8 {
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// Converts linear RGB to CIELAB under the D65 white point.
+fn linear_to_lab([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.950_47;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.088_83;
+    let f = |t: f32| {
+        if t > 0.008_856 {
+            t.cbrt()
+        } else {
+            7.787 * t + 16.0 / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulation_collapses_red_green() {
+        let red = Color::Rgb(255, 0, 0);
+        let green = Color::Rgb(0, 255, 0);
+        let normal = color_difference(red, green, None);
+
+        for deficiency in [ColorDeficiency::Protanopia, ColorDeficiency::Deuteranopia] {
+            let simulated = color_difference(red, green, Some(deficiency));
+            assert!(simulated < normal / 2.0, "{deficiency:?}: {simulated}");
+        }
+        // Tritanopes keep red-green discrimination
+        let tritan = color_difference(red, green, Some(ColorDeficiency::Tritanopia));
+        assert!(tritan > MIN_COLOR_DIFFERENCE);
+    }
+
+    #[test]
+    fn test_simulate_preserves_grays_and_reset() {
+        for deficiency in ColorDeficiency::ALL {
+            for level in [0, 128, 255] {
+                let gray = Color::Rgb(level, level, level);
+                let Color::Rgb(r, g, b) = deficiency.simulate(gray) else {
+                    panic!("expected an RGB color");
+                };
+                for channel in [r, g, b] {
+                    assert!(channel.abs_diff(level) <= 2, "{deficiency:?} {level}");
+                }
+            }
+            assert_eq!(deficiency.simulate(Color::Reset), Color::Reset);
+        }
+    }
+}
@@ -6,6 +6,102 @@
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 
+use super::color_depth::color_to_rgb;
+use super::color_vision::{color_difference, ColorDeficiency, MIN_COLOR_DIFFERENCE};
+
+/// The minimum contrast ratio for normal text under WCAG 2.x level AA.
+pub const MIN_CONTRAST_RATIO: f32 = 4.5;
+
+/// A semantic color role in a [`ColorPalette`].
+///
+/// Each variant corresponds to one palette field, allowing individual
+/// colors to be read or replaced without rebuilding the whole palette.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::theme::{ColorPalette, ColorRole};
+/// use ratatui::style::Color;
+///
+/// let mut palette = ColorPalette::dark();
+/// palette.set(ColorRole::Primary, Color::Cyan);
+/// assert_eq!(palette.get(ColorRole::Primary), Color::Cyan);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorRole {
+    /// Primary accent color
+    Primary,
+    /// Secondary accent color
+    Secondary,
+    /// Main background color
+    Background,
+    /// Elevated surface color
+    Surface,
+    /// Error state color
+    Error,
+    /// Warning state color
+    Warning,
+    /// Success state color
+    Success,
+    /// Information state color
+    Info,
+    /// Primary text color
+    TextPrimary,
+    /// Secondary text color
+    TextSecondary,
+    /// Disabled text color
+    TextDisabled,
+    /// Default border color
+    Border,
+    /// Focused element border color
+    BorderFocused,
+}
+
+impl ColorRole {
+    /// All color roles, in palette field order.
+    pub const ALL: [ColorRole; 13] = [
+        ColorRole::Primary,
+        ColorRole::Secondary,
+        ColorRole::Background,
+        ColorRole::Surface,
+        ColorRole::Error,
+        ColorRole::Warning,
+        ColorRole::Success,
+        ColorRole::Info,
+        ColorRole::TextPrimary,
+        ColorRole::TextSecondary,
+        ColorRole::TextDisabled,
+        ColorRole::Border,
+        ColorRole::BorderFocused,
+    ];
+
+    /// Returns the palette field name for this role (e.g. `"text_primary"`).
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorRole::Primary => "primary",
+            ColorRole::Secondary => "secondary",
+            ColorRole::Background => "background",
+            ColorRole::Surface => "surface",
+            ColorRole::Error => "error",
+            ColorRole::Warning => "warning",
+            ColorRole::Success => "success",
+            ColorRole::Info => "info",
+            ColorRole::TextPrimary => "text_primary",
+            ColorRole::TextSecondary => "text_secondary",
+            ColorRole::TextDisabled => "text_disabled",
+            ColorRole::Border => "border",
+            ColorRole::BorderFocused => "border_focused",
+        }
+    }
+}
+
+impl std::fmt::Display for ColorRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 /// A complete color palette for a theme.
 ///
 /// The palette defines semantic color roles rather than specific colors,
@@ -32,32 +128,46 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(palette.background, Color::Rgb(30, 30, 46));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ColorPalette {
     /// Primary accent color for interactive elements
+    #[serde(with = "super::serialization::color")]
     pub primary: Color,
     /// Secondary accent color for complementary actions
+    #[serde(with = "super::serialization::color")]
     pub secondary: Color,
     /// Main background color
+    #[serde(with = "super::serialization::color")]
     pub background: Color,
     /// Elevated surface color (cards, modals)
+    #[serde(with = "super::serialization::color")]
     pub surface: Color,
     /// Error state color
+    #[serde(with = "super::serialization::color")]
     pub error: Color,
     /// Warning state color
+    #[serde(with = "super::serialization::color")]
     pub warning: Color,
     /// Success state color
+    #[serde(with = "super::serialization::color")]
     pub success: Color,
     /// Information state color
+    #[serde(with = "super::serialization::color")]
     pub info: Color,
     /// Primary text color (highest contrast)
+    #[serde(with = "super::serialization::color")]
     pub text_primary: Color,
     /// Secondary text color (medium contrast)
+    #[serde(with = "super::serialization::color")]
     pub text_secondary: Color,
     /// Disabled text color (lowest contrast)
+    #[serde(with = "super::serialization::color")]
     pub text_disabled: Color,
     /// Default border color
+    #[serde(with = "super::serialization::color")]
     pub border: Color,
     /// Focused element border color
+    #[serde(with = "super::serialization::color")]
     pub border_focused: Color,
 }
 
@@ -96,6 +206,56 @@ impl ColorPalette {
         }
     }
 
+    /// Returns the color assigned to a role.
+    pub fn get(&self, role: ColorRole) -> Color {
+        match role {
+            ColorRole::Primary => self.primary,
+            ColorRole::Secondary => self.secondary,
+            ColorRole::Background => self.background,
+            ColorRole::Surface => self.surface,
+            ColorRole::Error => self.error,
+            ColorRole::Warning => self.warning,
+            ColorRole::Success => self.success,
+            ColorRole::Info => self.info,
+            ColorRole::TextPrimary => self.text_primary,
+            ColorRole::TextSecondary => self.text_secondary,
+            ColorRole::TextDisabled => self.text_disabled,
+            ColorRole::Border => self.border,
+            ColorRole::BorderFocused => self.border_focused,
+        }
+    }
+
+    /// Assigns a color to a role.
+    pub fn set(&mut self, role: ColorRole, color: Color) {
+        let slot = match role {
+            ColorRole::Primary => &mut self.primary,
+            ColorRole::Secondary => &mut self.secondary,
+            ColorRole::Background => &mut self.background,
+            ColorRole::Surface => &mut self.surface,
+            ColorRole::Error => &mut self.error,
+            ColorRole::Warning => &mut self.warning,
+            ColorRole::Success => &mut self.success,
+            ColorRole::Info => &mut self.info,
+            ColorRole::TextPrimary => &mut self.text_primary,
+            ColorRole::TextSecondary => &mut self.text_secondary,
+            ColorRole::TextDisabled => &mut self.text_disabled,
+            ColorRole::Border => &mut self.border,
+            ColorRole::BorderFocused => &mut self.border_focused,
+        };
+        *slot = color;
+    }
+
+    /// Linearly interpolates every role between this palette and another.
+    ///
+    /// See [`lerp_color`] for how individual colors are blended.
+    pub fn lerp(&self, other: &ColorPalette, t: f32) -> ColorPalette {
+        let mut palette = self.clone();
+        for role in ColorRole::ALL {
+            palette.set(role, lerp_color(self.get(role), other.get(role), t));
+        }
+        palette
+    }
+
     /// Creates the default dark color palette.
     ///
     /// Uses a modern dark theme inspired by popular color schemes
@@ -141,6 +301,206 @@ impl ColorPalette {
             border_focused: Color::Rgb(30, 102, 245),  // Blue
         }
     }
+
+    /// Creates a high-contrast color palette.
+    ///
+    /// Every text and status color has a contrast ratio of at least
+    /// [`MIN_CONTRAST_RATIO`] against both the background and surface colors.
+    pub fn high_contrast() -> Self {
+        Self {
+            primary: Color::Rgb(0, 215, 255),
+            secondary: Color::Rgb(255, 175, 255),
+            background: Color::Rgb(0, 0, 0),
+            surface: Color::Rgb(28, 28, 28),
+            error: Color::Rgb(255, 110, 110),
+            warning: Color::Rgb(255, 215, 0),
+            success: Color::Rgb(0, 255, 135),
+            info: Color::Rgb(95, 215, 255),
+            text_primary: Color::Rgb(255, 255, 255),
+            text_secondary: Color::Rgb(215, 215, 215),
+            text_disabled: Color::Rgb(150, 150, 150),
+            border: Color::Rgb(190, 190, 190),
+            border_focused: Color::Rgb(255, 215, 0),
+        }
+    }
+
+    /// Creates a dark palette for deuteranopia (green-blind) users.
+    ///
+    /// The dark palette's red and green status colors look alike without
+    /// working green cones, so success is blue and error a bright red that
+    /// stays apart from the yellow warning. Every pair of status colors is
+    /// [distinguishable](Self::is_distinguishable) under deuteranopia.
+    pub fn deuteranopia() -> Self {
+        Self {
+            error: Color::Rgb(255, 110, 110),
+            warning: Color::Rgb(240, 228, 66),
+            success: Color::Rgb(100, 170, 255),
+            info: Color::Rgb(230, 230, 240),
+            ..Self::dark()
+        }
+    }
+
+    /// Creates a dark palette for protanopia (red-blind) users.
+    ///
+    /// Protanopia confuses red and green like deuteranopia, and also makes
+    /// reds look darker, so error is a lighter coral than the deuteranopia
+    /// preset's red to keep it well clear of the background. Every pair of
+    /// status colors is [distinguishable](Self::is_distinguishable) under
+    /// protanopia.
+    pub fn protanopia() -> Self {
+        Self {
+            error: Color::Rgb(255, 140, 120),
+            warning: Color::Rgb(240, 228, 66),
+            success: Color::Rgb(100, 170, 255),
+            info: Color::Rgb(230, 230, 240),
+            ..Self::dark()
+        }
+    }
+
+    /// Creates a dark palette for tritanopia (blue-blind) users.
+    ///
+    /// Red and green remain distinct, but blues and greens, and yellows and
+    /// light pinks, run together, so success is teal and info a lavender
+    /// that stays apart from the yellow warning. Every pair of status colors
+    /// is [distinguishable](Self::is_distinguishable) under tritanopia.
+    pub fn tritanopia() -> Self {
+        Self {
+            error: Color::Rgb(255, 110, 110),
+            warning: Color::Rgb(240, 228, 66),
+            success: Color::Rgb(0, 200, 170),
+            info: Color::Rgb(180, 160, 255),
+            ..Self::dark()
+        }
+    }
+
+    /// Returns true if two colors remain easy to tell apart for someone
+    /// with the given color vision deficiency.
+    ///
+    /// Both colors are passed through a simulation of the deficiency and
+    /// compared by perceptual difference, which must be at least
+    /// [`MIN_COLOR_DIFFERENCE`]. Named and indexed colors are resolved using
+    /// their conventional xterm RGB values; [`Color::Reset`] is treated as
+    /// black.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tuilib::theme::{ColorDeficiency, ColorPalette};
+    ///
+    /// let palette = ColorPalette::dark();
+    /// let deuteranopia = ColorDeficiency::Deuteranopia;
+    /// assert!(!ColorPalette::is_distinguishable(palette.error, palette.success, deuteranopia));
+    ///
+    /// let palette = ColorPalette::deuteranopia();
+    /// assert!(ColorPalette::is_distinguishable(palette.error, palette.success, deuteranopia));
+    /// ```
+    pub fn is_distinguishable(a: Color, b: Color, deficiency: ColorDeficiency) -> bool {
+        color_difference(a, b, Some(deficiency)) >= MIN_COLOR_DIFFERENCE
+    }
+
+    /// Calculates the WCAG contrast ratio between two colors.
+    ///
+    /// The result ranges from 1.0 (identical luminance) to 21.0 (black on
+    /// white). Named and indexed colors are resolved using their conventional
+    /// xterm RGB values; [`Color::Reset`] is treated as black.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tuilib::theme::ColorPalette;
+    /// use ratatui::style::Color;
+    ///
+    /// let ratio = ColorPalette::contrast_ratio(Color::White, Color::Black);
+    /// assert!((ratio - 21.0).abs() < 0.01);
+    /// ```
+    pub fn contrast_ratio(fg: Color, bg: Color) -> f32 {
+        let l1 = relative_luminance(fg);
+        let l2 = relative_luminance(bg);
+        let (lighter, darker) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Returns the foreground/background role pairs checked for legibility.
+    ///
+    /// Each entry is a `"foreground/background"` label with the two colors.
+    /// Disabled text is excluded, as WCAG exempts inactive elements.
+    pub fn contrast_pairs(&self) -> Vec<(String, Color, Color)> {
+        let foregrounds = [
+            ("text_primary", self.text_primary),
+            ("text_secondary", self.text_secondary),
+            ("primary", self.primary),
+            ("secondary", self.secondary),
+            ("error", self.error),
+            ("warning", self.warning),
+            ("success", self.success),
+            ("info", self.info),
+        ];
+        let backgrounds = [("background", self.background), ("surface", self.surface)];
+
+        backgrounds
+            .iter()
+            .flat_map(|(bg_name, bg)| {
+                foregrounds
+                    .iter()
+                    .map(move |(fg_name, fg)| (format!("{fg_name}/{bg_name}"), *fg, *bg))
+            })
+            .collect()
+    }
+}
+
+/// Linearly interpolates between two colors.
+///
+/// Both colors are resolved to RGB (named and indexed colors use their
+/// conventional xterm values) and each channel is blended by `t`, which is
+/// clamped to `[0, 1]`. Identical colors are returned unchanged, and
+/// [`Color::Reset`] cannot be blended so it snaps at `t >= 0.5`.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::theme::lerp_color;
+/// use ratatui::style::Color;
+///
+/// let mid = lerp_color(Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 50), 0.5);
+/// assert_eq!(mid, Color::Rgb(100, 50, 25));
+/// ```
+pub fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let t = clamp_unit(t);
+    if from == to {
+        return from;
+    }
+
+    match (color_to_rgb(from), color_to_rgb(to)) {
+        (Some((r1, g1, b1)), Some((r2, g2, b2))) => {
+            let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+            Color::Rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2))
+        }
+        _ if t >= 0.5 => to,
+        _ => from,
+    }
+}
+
+/// Clamps an interpolation factor to `[0, 1]`, treating NaN as 0.
+pub(crate) fn clamp_unit(t: f32) -> f32 {
+    if t.is_nan() {
+        0.0
+    } else {
+        t.clamp(0.0, 1.0)
+    }
+}
+
+/// Calculates the WCAG relative luminance of a color.
+fn relative_luminance(color: Color) -> f32 {
+    let (r, g, b) = color_to_rgb(color).unwrap_or((0, 0, 0));
+    let channel = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
 }
 
 impl Default for ColorPalette {
@@ -201,4 +561,166 @@ mod tests {
         let palette2 = palette1.clone();
         assert_eq!(palette1, palette2);
     }
+
+    #[test]
+    fn test_contrast_ratio_extremes() {
+        let max = ColorPalette::contrast_ratio(Color::White, Color::Black);
+        assert!((max - 21.0).abs() < 0.01);
+
+        let min = ColorPalette::contrast_ratio(Color::Red, Color::Red);
+        assert!((min - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_contrast_ratio_is_symmetric() {
+        let a = Color::Rgb(30, 102, 245);
+        let b = Color::Rgb(239, 241, 245);
+        assert_eq!(
+            ColorPalette::contrast_ratio(a, b),
+            ColorPalette::contrast_ratio(b, a)
+        );
+    }
+
+    #[test]
+    fn test_contrast_ratio_known_value() {
+        // #777777 on white is the classic borderline 4.48:1
+        let ratio = ColorPalette::contrast_ratio(Color::Rgb(0x77, 0x77, 0x77), Color::White);
+        assert!((ratio - 4.48).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_high_contrast_palette_meets_minimum() {
+        let palette = ColorPalette::high_contrast();
+        for (label, fg, bg) in palette.contrast_pairs() {
+            let ratio = ColorPalette::contrast_ratio(fg, bg);
+            assert!(ratio >= MIN_CONTRAST_RATIO, "{label} is only {ratio:.2}:1");
+        }
+    }
+
+    #[test]
+    fn test_get_and_set_by_role() {
+        let mut palette = ColorPalette::dark();
+        for role in ColorRole::ALL {
+            palette.set(role, Color::Indexed(7));
+            assert_eq!(palette.get(role), Color::Indexed(7));
+        }
+        assert_eq!(palette.text_primary, Color::Indexed(7));
+    }
+
+    #[test]
+    fn test_color_role_names() {
+        assert_eq!(ColorRole::TextPrimary.name(), "text_primary");
+        assert_eq!(ColorRole::BorderFocused.to_string(), "border_focused");
+    }
+
+    #[test]
+    fn test_lerp_color_endpoints() {
+        let a = Color::Rgb(10, 20, 30);
+        let b = Color::Rgb(200, 150, 100);
+        assert_eq!(lerp_color(a, b, 0.0), a);
+        assert_eq!(lerp_color(a, b, 1.0), b);
+        // Out-of-range factors are clamped
+        assert_eq!(lerp_color(a, b, -1.0), a);
+        assert_eq!(lerp_color(a, b, 2.0), b);
+        assert_eq!(lerp_color(a, b, f32::NAN), a);
+    }
+
+    #[test]
+    fn test_lerp_color_resolves_named_and_indexed() {
+        // Black is (0, 0, 0); index 231 is (255, 255, 255)
+        let mid = lerp_color(Color::Black, Color::Indexed(231), 0.5);
+        assert_eq!(mid, Color::Rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn test_lerp_color_reset_snaps() {
+        assert_eq!(lerp_color(Color::Reset, Color::Red, 0.4), Color::Reset);
+        assert_eq!(lerp_color(Color::Reset, Color::Red, 0.5), Color::Red);
+    }
+
+    #[test]
+    fn test_palette_lerp_midpoint() {
+        let dark = ColorPalette::dark();
+        let light = ColorPalette::light();
+        let mid = dark.lerp(&light, 0.5);
+
+        // background: (30, 30, 46) -> (239, 241, 245)
+        assert_eq!(mid.background, Color::Rgb(135, 136, 146));
+        assert_eq!(dark.lerp(&light, 0.0), dark);
+        assert_eq!(dark.lerp(&light, 1.0), light);
+    }
+
+    #[test]
+    fn test_deuteranopia_flags_default_red_green() {
+        let deuteranopia = ColorDeficiency::Deuteranopia;
+        for palette in [ColorPalette::dark(), ColorPalette::light()] {
+            assert!(!ColorPalette::is_distinguishable(
+                palette.error,
+                palette.success,
+                deuteranopia
+            ));
+        }
+
+        let preset = ColorPalette::deuteranopia();
+        assert!(ColorPalette::is_distinguishable(
+            preset.error,
+            preset.success,
+            deuteranopia
+        ));
+    }
+
+    #[test]
+    fn test_vision_presets_keep_status_colors_apart() {
+        let presets = [
+            (ColorPalette::deuteranopia(), ColorDeficiency::Deuteranopia),
+            (ColorPalette::protanopia(), ColorDeficiency::Protanopia),
+            (ColorPalette::tritanopia(), ColorDeficiency::Tritanopia),
+        ];
+        for (palette, deficiency) in presets {
+            let status = [
+                palette.error,
+                palette.warning,
+                palette.success,
+                palette.info,
+            ];
+            for (i, a) in status.iter().enumerate() {
+                for b in &status[i + 1..] {
+                    assert!(
+                        ColorPalette::is_distinguishable(*a, *b, deficiency),
+                        "{deficiency:?}: {a:?} vs {b:?}"
+                    );
+                }
+            }
+
+            // Status text stays legible on the dark background
+            for color in status {
+                let ratio = ColorPalette::contrast_ratio(color, palette.surface);
+                assert!(ratio >= MIN_CONTRAST_RATIO, "{deficiency:?}: {color:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_protanopia_error_stays_light() {
+        let protanopia = ColorDeficiency::Protanopia;
+        let preset = ColorPalette::protanopia();
+        assert_ne!(preset.error, ColorPalette::deuteranopia().error);
+        assert!(ColorPalette::is_distinguishable(
+            preset.error,
+            preset.background,
+            protanopia
+        ));
+        assert!(ColorPalette::is_distinguishable(
+            preset.error,
+            preset.info,
+            protanopia
+        ));
+
+        // Red loses brightness under protanopia, so the preset's error
+        // stands further from the background than the deuteranopia red
+        let from_background = |color| color_difference(color, preset.background, Some(protanopia));
+        assert!(
+            from_background(preset.error) > from_background(ColorPalette::deuteranopia().error)
+        );
+    }
 }
@@ -5,6 +5,7 @@
 
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::BorderType;
+use serde::{Deserialize, Serialize};
 
 /// Style configuration for all themed components.
 ///
@@ -19,7 +20,8 @@ use ratatui::widgets::BorderType;
 /// let styles = ComponentStyles::default();
 /// assert!(styles.button.use_border);
 /// ```
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ComponentStyles {
     /// Style for button components
     pub button: ButtonStyle,
@@ -57,18 +59,25 @@ impl ComponentStyles {
 }
 
 /// Style configuration for button components.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ButtonStyle {
     /// Whether to show a border around buttons
     pub use_border: bool,
     /// Border type for buttons
+    #[serde(with = "super::serialization::border_type")]
     pub border_type: BorderType,
     /// Additional padding around button text
     pub padding: u16,
     /// Text modifier for focused buttons
+    #[serde(with = "super::serialization::modifier")]
     pub focused_modifier: Modifier,
     /// Text modifier for pressed buttons
+    #[serde(with = "super::serialization::modifier")]
     pub pressed_modifier: Modifier,
+    /// Text modifier for the mnemonic character of a button label
+    #[serde(with = "super::serialization::modifier")]
+    pub mnemonic_modifier: Modifier,
 }
 
 impl Default for ButtonStyle {
@@ -79,22 +88,28 @@ impl Default for ButtonStyle {
             padding: 1,
             focused_modifier: Modifier::BOLD,
             pressed_modifier: Modifier::REVERSED,
+            mnemonic_modifier: Modifier::UNDERLINED,
         }
     }
 }
 
 /// Style configuration for text input components.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct InputStyle {
     /// Whether to show a border around inputs
     pub use_border: bool,
     /// Border type for inputs
+    #[serde(with = "super::serialization::border_type")]
     pub border_type: BorderType,
     /// Cursor style modifier
+    #[serde(with = "super::serialization::modifier")]
     pub cursor_modifier: Modifier,
     /// Placeholder text modifier
+    #[serde(with = "super::serialization::modifier")]
     pub placeholder_modifier: Modifier,
     /// Selection highlight modifier
+    #[serde(with = "super::serialization::modifier")]
     pub selection_modifier: Modifier,
 }
 
@@ -111,15 +126,18 @@ impl Default for InputStyle {
 }
 
 /// Style configuration for table components.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TableStyle {
     /// Whether to show row separators
     pub show_row_separators: bool,
     /// Whether to show column separators
     pub show_column_separators: bool,
     /// Header text modifier
+    #[serde(with = "super::serialization::modifier")]
     pub header_modifier: Modifier,
     /// Selected row modifier
+    #[serde(with = "super::serialization::modifier")]
     pub selected_modifier: Modifier,
     /// Whether to highlight rows on hover/selection
     pub highlight_rows: bool,
@@ -138,13 +156,16 @@ impl Default for TableStyle {
 }
 
 /// Style configuration for modal dialog components.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ModalStyle {
     /// Border type for modals
+    #[serde(with = "super::serialization::border_type")]
     pub border_type: BorderType,
     /// Whether to show a shadow effect
     pub show_shadow: bool,
     /// Title text modifier
+    #[serde(with = "super::serialization::modifier")]
     pub title_modifier: Modifier,
     /// Whether to center the modal content
     pub center_content: bool,
@@ -162,7 +183,8 @@ impl Default for ModalStyle {
 }
 
 /// Style configuration for list components.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ListStyle {
     /// Whether to show bullets/markers
     pub show_markers: bool,
@@ -171,6 +193,7 @@ pub struct ListStyle {
     /// Marker character for selected items
     pub selected_marker: char,
     /// Selected item modifier
+    #[serde(with = "super::serialization::modifier")]
     pub selected_modifier: Modifier,
     /// Whether to highlight the entire row or just the marker
     pub highlight_full_row: bool,
@@ -189,13 +212,16 @@ impl Default for ListStyle {
 }
 
 /// Style configuration for tab components.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TabsStyle {
     /// Separator between tabs
     pub separator: String,
     /// Active tab modifier
+    #[serde(with = "super::serialization::modifier")]
     pub active_modifier: Modifier,
     /// Inactive tab modifier
+    #[serde(with = "super::serialization::modifier")]
     pub inactive_modifier: Modifier,
     /// Whether to show a border around the tab bar
     pub use_border: bool,
@@ -284,6 +310,74 @@ impl Default for ComputedStyle {
     }
 }
 
+/// A style override for a single component instance.
+///
+/// Overrides are registered on a [`Theme`](super::Theme) against a
+/// component's [`FocusId`](crate::focus::FocusId), letting one component be
+/// styled differently without creating a new theme. Unset fields fall back
+/// to the theme's regular styles.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::focus::FocusId;
+/// use tuilib::theme::{ComponentStyleOverride, Theme};
+/// use ratatui::style::{Color, Modifier};
+///
+/// let mut theme = Theme::dark();
+/// theme.override_for(
+///     "launch",
+///     ComponentStyleOverride::new()
+///         .fg(Color::Yellow)
+///         .add_modifier(Modifier::ITALIC),
+/// );
+///
+/// let style = theme.resolve_button_style(&FocusId::new("launch"), false);
+/// assert_eq!(style.fg, Some(Color::Yellow));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ComponentStyleOverride {
+    /// Foreground color override
+    pub fg: Option<Color>,
+    /// Background color override
+    pub bg: Option<Color>,
+    /// Border color override
+    pub border: Option<Color>,
+    /// Additional modifiers
+    pub add_modifier: Modifier,
+}
+
+impl ComponentStyleOverride {
+    /// Creates an empty override.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the foreground color.
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Overrides the background color.
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Overrides the border color.
+    pub fn border(mut self, color: Color) -> Self {
+        self.border = Some(color);
+        self
+    }
+
+    /// Adds modifiers on top of the base style.
+    pub fn add_modifier(mut self, modifier: Modifier) -> Self {
+        self.add_modifier |= modifier;
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
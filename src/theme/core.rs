@@ -3,11 +3,20 @@
 //! This module provides the [`Theme`] struct which combines all styling
 //! elements into a cohesive theme definition.
 
+use std::collections::HashMap;
+use std::path::Path;
+
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::BorderType;
+use serde::{Deserialize, Serialize};
+
+use crate::focus::FocusId;
 
 use super::builder::ThemeBuilder;
-use super::colors::ColorPalette;
-use super::component::{ComponentStyles, ComputedStyle};
+use super::color_depth::ColorDepth;
+use super::colors::{clamp_unit, ColorPalette, ColorRole, MIN_CONTRAST_RATIO};
+use super::component::{ComponentStyleOverride, ComponentStyles, ComputedStyle};
+use super::serialization::ThemeError;
 use super::styles::{BorderStyles, TextStyles};
 
 /// A complete theme definition.
@@ -55,18 +64,51 @@ use super::styles::{BorderStyles, TextStyles};
 /// let normal = theme.button_normal_style();
 /// let focused = theme.button_focused_style();
 /// ```
-#[derive(Debug, Clone)]
+///
+/// # Theme Files
+///
+/// Themes can be saved to and loaded from TOML files. Any section omitted
+/// from a theme file falls back to its default:
+///
+/// ```rust
+/// use tuilib::theme::Theme;
+/// use ratatui::style::Color;
+///
+/// let theme = Theme::from_toml_str(r##"
+///     name = "Ocean"
+///
+///     [colors]
+///     primary = "#00afff"
+///     background = "black"
+///     border = "24"
+/// "##).unwrap();
+///
+/// assert_eq!(theme.name(), "Ocean");
+/// assert_eq!(theme.colors().primary, Color::Rgb(0, 175, 255));
+/// assert_eq!(theme.colors().border, Color::Indexed(24));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Theme {
     /// Theme name for identification
     name: String,
     /// Color palette
+    #[serde(default)]
     colors: ColorPalette,
     /// Border style configuration
+    #[serde(default)]
     borders: BorderStyles,
     /// Text style configuration
+    #[serde(default)]
     text: TextStyles,
     /// Component-specific styles
+    #[serde(default)]
     components: ComponentStyles,
+    /// Color depth used when computing styles
+    #[serde(skip)]
+    color_depth: ColorDepth,
+    /// Per-component style overrides
+    #[serde(skip)]
+    overrides: HashMap<FocusId, ComponentStyleOverride>,
 }
 
 impl Theme {
@@ -84,6 +126,8 @@ impl Theme {
             borders,
             text,
             components,
+            color_depth: ColorDepth::default(),
+            overrides: HashMap::new(),
         }
     }
 
@@ -118,6 +162,59 @@ impl Theme {
         )
     }
 
+    /// Creates a high-contrast theme for accessibility.
+    ///
+    /// All text and status colors meet the WCAG AA contrast ratio of 4.5:1
+    /// against the background and surface colors, and focused elements use
+    /// thick borders so focus is visible without relying on color alone.
+    pub fn high_contrast() -> Self {
+        Self::new(
+            "High Contrast",
+            ColorPalette::high_contrast(),
+            BorderStyles::new(
+                BorderType::Plain,
+                BorderType::Thick,
+                BorderType::Double,
+                BorderType::Plain,
+            ),
+            TextStyles::default(),
+            ComponentStyles::default(),
+        )
+    }
+
+    /// Creates a dark theme for deuteranopia (green-blind) users.
+    ///
+    /// Uses [`ColorPalette::deuteranopia`], whose status colors stay
+    /// distinguishable without relying on red versus green.
+    pub fn deuteranopia() -> Self {
+        Self::vision_preset("Deuteranopia", ColorPalette::deuteranopia())
+    }
+
+    /// Creates a dark theme for protanopia (red-blind) users.
+    ///
+    /// Uses [`ColorPalette::protanopia`].
+    pub fn protanopia() -> Self {
+        Self::vision_preset("Protanopia", ColorPalette::protanopia())
+    }
+
+    /// Creates a dark theme for tritanopia (blue-blind) users.
+    ///
+    /// Uses [`ColorPalette::tritanopia`].
+    pub fn tritanopia() -> Self {
+        Self::vision_preset("Tritanopia", ColorPalette::tritanopia())
+    }
+
+    /// Creates a theme styled like the dark theme with the given palette.
+    fn vision_preset(name: &str, colors: ColorPalette) -> Self {
+        Self::new(
+            name,
+            colors,
+            BorderStyles::modern(),
+            TextStyles::default(),
+            ComponentStyles::default(),
+        )
+    }
+
     /// Returns the theme name.
     pub fn name(&self) -> &str {
         &self.name
@@ -128,6 +225,31 @@ impl Theme {
         &self.colors
     }
 
+    /// Returns the color assigned to a palette role.
+    pub fn color(&self, role: ColorRole) -> Color {
+        self.colors.get(role)
+    }
+
+    /// Replaces the color assigned to a palette role.
+    ///
+    /// Style accessors read from the palette on every call, so the change
+    /// is reflected immediately in styles such as
+    /// [`primary_text_style`](Self::primary_text_style).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tuilib::theme::{ColorRole, Theme};
+    /// use ratatui::style::Color;
+    ///
+    /// let mut theme = Theme::dark();
+    /// theme.set_color(ColorRole::Primary, Color::Magenta);
+    /// assert_eq!(theme.button_focused_style().fg, Some(Color::Magenta));
+    /// ```
+    pub fn set_color(&mut self, role: ColorRole, color: Color) {
+        self.colors.set(role, color);
+    }
+
     /// Returns the border styles.
     pub fn borders(&self) -> &BorderStyles {
         &self.borders
@@ -143,61 +265,135 @@ impl Theme {
         &self.components
     }
 
+    /// Returns the color depth used when computing styles.
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Sets the color depth used when computing styles.
+    ///
+    /// When the depth is lower than [`ColorDepth::TrueColor`], the computed
+    /// style accessors (such as [`primary_text_style`](Self::primary_text_style))
+    /// map RGB palette colors to the nearest color the terminal supports.
+    /// The palette itself is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tuilib::theme::{ColorDepth, Theme};
+    /// use ratatui::style::Color;
+    ///
+    /// let theme = Theme::dark().with_color_depth(ColorDepth::Ansi256);
+    /// assert!(matches!(theme.primary_text_style().fg, Some(Color::Indexed(_))));
+    /// ```
+    pub fn with_color_depth(mut self, depth: ColorDepth) -> Self {
+        self.color_depth = depth;
+        self
+    }
+
+    /// Maps a palette color to the theme's color depth.
+    fn adapt_color(&self, color: Color) -> Color {
+        self.color_depth.downsample(color)
+    }
+
+    // ===== Serialization =====
+
+    /// Parses a theme from a TOML string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::ParseError`] if the string is not valid TOML
+    /// or contains an unrecognized color, modifier, or border type.
+    pub fn from_toml_str(s: &str) -> Result<Self, ThemeError> {
+        toml::from_str(s).map_err(|e| ThemeError::ParseError(e.to_string()))
+    }
+
+    /// Serializes this theme to a TOML string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::SerializeError`] if serialization fails.
+    pub fn to_toml_string(&self) -> Result<String, ThemeError> {
+        toml::to_string_pretty(self).map_err(|e| ThemeError::SerializeError(e.to_string()))
+    }
+
+    /// Loads a theme from a TOML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let contents = std::fs::read_to_string(path)?;
+        Self::from_toml_str(&contents)
+    }
+
+    /// Saves this theme to a TOML file, creating or overwriting it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the theme cannot be serialized or the file
+    /// cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ThemeError> {
+        let contents = self.to_toml_string()?;
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
     // ===== Computed Styles =====
 
     /// Returns the style for primary text.
     pub fn primary_text_style(&self) -> Style {
-        Style::default().fg(self.colors.text_primary)
+        Style::default().fg(self.adapt_color(self.colors.text_primary))
     }
 
     /// Returns the style for secondary text.
     pub fn secondary_text_style(&self) -> Style {
-        Style::default().fg(self.colors.text_secondary)
+        Style::default().fg(self.adapt_color(self.colors.text_secondary))
     }
 
     /// Returns the style for disabled text.
     pub fn disabled_text_style(&self) -> Style {
-        Style::default().fg(self.colors.text_disabled)
+        Style::default().fg(self.adapt_color(self.colors.text_disabled))
     }
 
     /// Returns the style for error text.
     pub fn error_text_style(&self) -> Style {
-        Style::default().fg(self.colors.error)
+        Style::default().fg(self.adapt_color(self.colors.error))
     }
 
     /// Returns the style for warning text.
     pub fn warning_text_style(&self) -> Style {
-        Style::default().fg(self.colors.warning)
+        Style::default().fg(self.adapt_color(self.colors.warning))
     }
 
     /// Returns the style for success text.
     pub fn success_text_style(&self) -> Style {
-        Style::default().fg(self.colors.success)
+        Style::default().fg(self.adapt_color(self.colors.success))
     }
 
     /// Returns the style for info text.
     pub fn info_text_style(&self) -> Style {
-        Style::default().fg(self.colors.info)
+        Style::default().fg(self.adapt_color(self.colors.info))
     }
 
     /// Returns the style for heading text.
     pub fn heading_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_primary)
+            .fg(self.adapt_color(self.colors.text_primary))
             .add_modifier(self.text.heading.add_modifier)
     }
 
     /// Returns the style for emphasized text.
     pub fn emphasis_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_primary)
+            .fg(self.adapt_color(self.colors.text_primary))
             .add_modifier(self.text.emphasis.add_modifier)
     }
 
     /// Returns the style for muted text.
     pub fn muted_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_secondary)
+            .fg(self.adapt_color(self.colors.text_secondary))
             .add_modifier(self.text.muted.add_modifier)
     }
 
@@ -205,12 +401,25 @@ impl Theme {
 
     /// Returns the style for default borders.
     pub fn border_style(&self) -> Style {
-        Style::default().fg(self.colors.border)
+        Style::default().fg(self.adapt_color(self.colors.border))
     }
 
     /// Returns the style for focused borders.
     pub fn border_focused_style(&self) -> Style {
-        Style::default().fg(self.colors.border_focused)
+        Style::default().fg(self.adapt_color(self.colors.border_focused))
+    }
+
+    /// Returns the style for borders of disabled elements.
+    pub fn border_disabled_style(&self) -> Style {
+        Style::default().fg(self.adapt_color(self.colors.text_disabled))
+    }
+
+    /// Returns the style for borders of elements under the mouse pointer.
+    ///
+    /// This is the default border brightened, so hovering stands out without
+    /// looking like focus.
+    pub fn border_hover_style(&self) -> Style {
+        self.border_style().add_modifier(Modifier::BOLD)
     }
 
     // ===== Button Styles =====
@@ -218,31 +427,31 @@ impl Theme {
     /// Returns the style for normal (unfocused) buttons.
     pub fn button_normal_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_primary)
-            .bg(self.colors.surface)
+            .fg(self.adapt_color(self.colors.text_primary))
+            .bg(self.adapt_color(self.colors.surface))
     }
 
     /// Returns the style for focused buttons.
     pub fn button_focused_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.primary)
-            .bg(self.colors.surface)
+            .fg(self.adapt_color(self.colors.primary))
+            .bg(self.adapt_color(self.colors.surface))
             .add_modifier(self.components.button.focused_modifier)
     }
 
     /// Returns the style for pressed buttons.
     pub fn button_pressed_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.primary)
-            .bg(self.colors.surface)
+            .fg(self.adapt_color(self.colors.primary))
+            .bg(self.adapt_color(self.colors.surface))
             .add_modifier(self.components.button.pressed_modifier)
     }
 
     /// Returns the style for disabled buttons.
     pub fn button_disabled_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_disabled)
-            .bg(self.colors.surface)
+            .fg(self.adapt_color(self.colors.text_disabled))
+            .bg(self.adapt_color(self.colors.surface))
     }
 
     // ===== Input Styles =====
@@ -250,29 +459,29 @@ impl Theme {
     /// Returns the style for normal (unfocused) text inputs.
     pub fn input_normal_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_primary)
-            .bg(self.colors.background)
+            .fg(self.adapt_color(self.colors.text_primary))
+            .bg(self.adapt_color(self.colors.background))
     }
 
     /// Returns the style for focused text inputs.
     pub fn input_focused_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_primary)
-            .bg(self.colors.background)
+            .fg(self.adapt_color(self.colors.text_primary))
+            .bg(self.adapt_color(self.colors.background))
     }
 
     /// Returns the style for input placeholders.
     pub fn input_placeholder_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_disabled)
+            .fg(self.adapt_color(self.colors.text_disabled))
             .add_modifier(self.components.input.placeholder_modifier)
     }
 
     /// Returns the style for input cursors.
     pub fn input_cursor_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.background)
-            .bg(self.colors.text_primary)
+            .fg(self.adapt_color(self.colors.background))
+            .bg(self.adapt_color(self.colors.text_primary))
             .add_modifier(self.components.input.cursor_modifier)
     }
 
@@ -281,19 +490,19 @@ impl Theme {
     /// Returns the style for table headers.
     pub fn table_header_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_primary)
+            .fg(self.adapt_color(self.colors.text_primary))
             .add_modifier(self.components.table.header_modifier)
     }
 
     /// Returns the style for normal table rows.
     pub fn table_row_style(&self) -> Style {
-        Style::default().fg(self.colors.text_primary)
+        Style::default().fg(self.adapt_color(self.colors.text_primary))
     }
 
     /// Returns the style for selected table rows.
     pub fn table_selected_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.primary)
+            .fg(self.adapt_color(self.colors.primary))
             .add_modifier(self.components.table.selected_modifier)
     }
 
@@ -301,13 +510,13 @@ impl Theme {
 
     /// Returns the style for normal list items.
     pub fn list_item_style(&self) -> Style {
-        Style::default().fg(self.colors.text_primary)
+        Style::default().fg(self.adapt_color(self.colors.text_primary))
     }
 
     /// Returns the style for selected list items.
     pub fn list_selected_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.primary)
+            .fg(self.adapt_color(self.colors.primary))
             .add_modifier(self.components.list.selected_modifier)
     }
 
@@ -316,15 +525,15 @@ impl Theme {
     /// Returns the style for modal titles.
     pub fn modal_title_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_primary)
+            .fg(self.adapt_color(self.colors.text_primary))
             .add_modifier(self.components.modal.title_modifier)
     }
 
     /// Returns the style for modal content.
     pub fn modal_content_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_primary)
-            .bg(self.colors.surface)
+            .fg(self.adapt_color(self.colors.text_primary))
+            .bg(self.adapt_color(self.colors.surface))
     }
 
     // ===== Tab Styles =====
@@ -332,17 +541,87 @@ impl Theme {
     /// Returns the style for active tabs.
     pub fn tab_active_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.primary)
+            .fg(self.adapt_color(self.colors.primary))
             .add_modifier(self.components.tabs.active_modifier)
     }
 
     /// Returns the style for inactive tabs.
     pub fn tab_inactive_style(&self) -> Style {
         Style::default()
-            .fg(self.colors.text_secondary)
+            .fg(self.adapt_color(self.colors.text_secondary))
             .add_modifier(self.components.tabs.inactive_modifier)
     }
 
+    // ===== Component Overrides =====
+
+    /// Registers a style override for the component with the given ID.
+    ///
+    /// Replaces any existing override for that component.
+    pub fn override_for(&mut self, id: impl Into<FocusId>, style: ComponentStyleOverride) {
+        self.overrides.insert(id.into(), style);
+    }
+
+    /// Registers a style override and returns the theme, for chaining.
+    pub fn with_override(mut self, id: impl Into<FocusId>, style: ComponentStyleOverride) -> Self {
+        self.override_for(id, style);
+        self
+    }
+
+    /// Removes the style override for a component, returning it if present.
+    pub fn clear_override(&mut self, id: &FocusId) -> Option<ComponentStyleOverride> {
+        self.overrides.remove(id)
+    }
+
+    /// Returns the style override for a component, if any.
+    pub fn style_override(&self, id: &FocusId) -> Option<&ComponentStyleOverride> {
+        self.overrides.get(id)
+    }
+
+    /// Applies a component's override (if any) on top of a base style.
+    pub fn resolve_style(&self, id: &FocusId, base: Style) -> Style {
+        let Some(style) = self.overrides.get(id) else {
+            return base;
+        };
+
+        let mut resolved = base.add_modifier(style.add_modifier);
+        if let Some(fg) = style.fg {
+            resolved = resolved.fg(self.adapt_color(fg));
+        }
+        if let Some(bg) = style.bg {
+            resolved = resolved.bg(self.adapt_color(bg));
+        }
+        resolved
+    }
+
+    /// Applies a component's border color override (if any) on top of a
+    /// base border style.
+    pub fn resolve_border_style(&self, id: &FocusId, base: Style) -> Style {
+        match self.overrides.get(id).and_then(|style| style.border) {
+            Some(border) => base.fg(self.adapt_color(border)),
+            None => base,
+        }
+    }
+
+    /// Returns the button style for a specific component, honoring overrides.
+    pub fn resolve_button_style(&self, id: &FocusId, focused: bool) -> Style {
+        let base = if focused {
+            self.button_focused_style()
+        } else {
+            self.button_normal_style()
+        };
+        self.resolve_style(id, base)
+    }
+
+    /// Returns the text input style for a specific component, honoring overrides.
+    pub fn resolve_input_style(&self, id: &FocusId, focused: bool) -> Style {
+        let base = if focused {
+            self.input_focused_style()
+        } else {
+            self.input_normal_style()
+        };
+        self.resolve_style(id, base)
+    }
+
     // ===== Utility Methods =====
 
     /// Creates a computed style from colors and modifiers.
@@ -352,7 +631,61 @@ impl Theme {
         bg: Option<Color>,
         modifiers: Modifier,
     ) -> ComputedStyle {
-        ComputedStyle::new(fg, bg, modifiers)
+        ComputedStyle::new(
+            fg.map(|c| self.adapt_color(c)),
+            bg.map(|c| self.adapt_color(c)),
+            modifiers,
+        )
+    }
+
+    /// Interpolates between this theme and another.
+    ///
+    /// Every palette color is blended by `t` (clamped to `[0, 1]`), which
+    /// allows an animated cross-fade between themes driven by tick events.
+    /// Settings that cannot be blended, such as the name, border types, and
+    /// modifiers, switch over to `other` once `t >= 0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tuilib::theme::Theme;
+    ///
+    /// let dark = Theme::dark();
+    /// let light = Theme::light();
+    ///
+    /// assert_eq!(dark.lerp(&light, 0.0), dark);
+    /// assert_eq!(dark.lerp(&light, 1.0), light);
+    /// assert_eq!(dark.lerp(&light, 0.75).name(), "Light");
+    /// ```
+    pub fn lerp(&self, other: &Theme, t: f32) -> Theme {
+        let t = clamp_unit(t);
+        let base = if t >= 0.5 { other } else { self };
+        Theme {
+            colors: self.colors.lerp(&other.colors, t),
+            ..base.clone()
+        }
+    }
+
+    /// Checks the palette for foreground/background pairs with poor contrast.
+    ///
+    /// Returns each pair whose WCAG contrast ratio is below 4.5:1, labelled
+    /// as `"foreground/background"` (for example `"text_secondary/surface"`)
+    /// along with its ratio. An empty result means the theme passes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tuilib::theme::Theme;
+    ///
+    /// assert!(Theme::high_contrast().validate_contrast().is_empty());
+    /// ```
+    pub fn validate_contrast(&self) -> Vec<(String, f32)> {
+        self.colors
+            .contrast_pairs()
+            .into_iter()
+            .map(|(label, fg, bg)| (label, ColorPalette::contrast_ratio(fg, bg)))
+            .filter(|(_, ratio)| *ratio < MIN_CONTRAST_RATIO)
+            .collect()
     }
 
     /// Returns whether this is a dark theme.
@@ -456,6 +789,30 @@ mod tests {
         assert!(focused.add_modifier.contains(Modifier::BOLD));
     }
 
+    #[test]
+    fn test_border_state_styles_are_distinct() {
+        for theme in [Theme::dark(), Theme::light()] {
+            let styles = [
+                theme.border_style(),
+                theme.border_focused_style(),
+                theme.border_disabled_style(),
+                theme.border_hover_style(),
+            ];
+            for (i, a) in styles.iter().enumerate() {
+                for b in &styles[i + 1..] {
+                    assert_ne!(a, b, "{}", theme.name());
+                }
+            }
+        }
+
+        let theme = Theme::dark();
+        assert_eq!(
+            theme.border_disabled_style().fg,
+            Some(theme.colors().text_disabled)
+        );
+        assert_eq!(theme.border_hover_style().fg, Some(theme.colors().border));
+    }
+
     #[test]
     fn test_input_styles() {
         let theme = Theme::dark();
@@ -518,4 +875,269 @@ mod tests {
         assert_eq!(computed.bg, Some(Color::Blue));
         assert!(computed.modifiers.contains(Modifier::BOLD));
     }
+
+    #[test]
+    fn test_toml_round_trip_dark() {
+        let theme = Theme::dark();
+        let toml = theme.to_toml_string().unwrap();
+        let parsed = Theme::from_toml_str(&toml).unwrap();
+        assert_eq!(parsed, theme);
+    }
+
+    #[test]
+    fn test_toml_round_trip_light() {
+        let theme = Theme::light();
+        let toml = theme.to_toml_string().unwrap();
+        let parsed = Theme::from_toml_str(&toml).unwrap();
+        assert_eq!(parsed, theme);
+    }
+
+    #[test]
+    fn test_toml_colors_as_strings() {
+        let theme = Theme::builder()
+            .primary_color(Color::Cyan)
+            .secondary_color(Color::Indexed(13))
+            .background_color(Color::Rgb(0, 175, 255))
+            .build();
+        let toml = theme.to_toml_string().unwrap();
+
+        assert!(toml.contains(r#"primary = "cyan""#));
+        assert!(toml.contains(r#"secondary = "13""#));
+        assert!(toml.contains(r##"background = "#00afff""##));
+    }
+
+    #[test]
+    fn test_parse_hand_written_hex_theme() {
+        let theme = Theme::from_toml_str(
+            r##"
+            name = "Hex"
+
+            [colors]
+            primary = "#ff8800"
+            secondary = "#8800FF"
+            background = "#101010"
+            surface = "#202020"
+            error = "#ff0000"
+            warning = "#ffff00"
+            success = "#00ff00"
+            info = "#00ffff"
+            text_primary = "#eeeeee"
+            text_secondary = "#aaaaaa"
+            text_disabled = "#666666"
+            border = "#333333"
+            border_focused = "#ff8800"
+
+            [borders]
+            default = "plain"
+            modal = "thick"
+
+            [text.heading]
+            add_modifier = ["bold", "underlined"]
+
+            [components.button]
+            focused_modifier = ["bold", "italic"]
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(theme.name(), "Hex");
+        assert_eq!(theme.colors().primary, Color::Rgb(0xff, 0x88, 0x00));
+        assert_eq!(theme.colors().secondary, Color::Rgb(0x88, 0x00, 0xff));
+        assert_eq!(theme.colors().background, Color::Rgb(0x10, 0x10, 0x10));
+        assert!(theme.is_dark());
+        assert_eq!(theme.borders().default, BorderType::Plain);
+        assert_eq!(theme.borders().modal, BorderType::Thick);
+        // Unspecified fields keep their defaults
+        assert_eq!(theme.borders().focused, BorderType::Rounded);
+        assert_eq!(
+            theme.text().heading.add_modifier,
+            Modifier::BOLD | Modifier::UNDERLINED
+        );
+        assert_eq!(
+            theme.components().button.focused_modifier,
+            Modifier::BOLD | Modifier::ITALIC
+        );
+        assert!(theme.components().button.use_border);
+    }
+
+    #[test]
+    fn test_parse_invalid_color() {
+        let result = Theme::from_toml_str(
+            r#"
+            name = "Bad"
+            [colors]
+            primary = "not-a-color"
+            "#,
+        );
+        assert!(matches!(result, Err(ThemeError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path =
+            std::env::temp_dir().join(format!("tuilib-theme-test-{}.toml", std::process::id()));
+        let theme = Theme::light();
+        theme.save(&path).unwrap();
+        let loaded = Theme::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded, theme);
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let result = Theme::load("/nonexistent/tuilib/theme.toml");
+        assert!(matches!(result, Err(ThemeError::IoError(_))));
+    }
+
+    #[test]
+    fn test_color_depth_default_is_truecolor() {
+        let theme = Theme::dark();
+        assert_eq!(theme.color_depth(), ColorDepth::TrueColor);
+        assert_eq!(
+            theme.primary_text_style().fg,
+            Some(theme.colors().text_primary)
+        );
+    }
+
+    #[test]
+    fn test_color_depth_downsamples_styles() {
+        let theme = Theme::builder()
+            .primary_color(Color::Rgb(0, 175, 255))
+            .build()
+            .with_color_depth(ColorDepth::Ansi256);
+
+        assert_eq!(theme.button_focused_style().fg, Some(Color::Indexed(39)));
+        // The palette keeps the original color
+        assert_eq!(theme.colors().primary, Color::Rgb(0, 175, 255));
+
+        let theme = theme.with_color_depth(ColorDepth::Ansi16);
+        assert_eq!(theme.button_focused_style().fg, Some(Color::LightCyan));
+    }
+
+    #[test]
+    fn test_high_contrast_theme() {
+        let theme = Theme::high_contrast();
+        assert_eq!(theme.name(), "High Contrast");
+        assert!(theme.is_dark());
+        assert!(theme.validate_contrast().is_empty());
+    }
+
+    #[test]
+    fn test_validate_contrast_flags_low_contrast() {
+        let theme = Theme::builder()
+            .background_color(Color::Rgb(40, 40, 40))
+            .text_secondary_color(Color::Rgb(60, 60, 60))
+            .build();
+
+        let issues = theme.validate_contrast();
+        let (_, ratio) = issues
+            .iter()
+            .find(|(label, _)| label == "text_secondary/background")
+            .expect("low contrast pair should be reported");
+        assert!(*ratio < MIN_CONTRAST_RATIO);
+        assert!(!issues
+            .iter()
+            .any(|(label, _)| label == "text_primary/background"));
+    }
+
+    #[test]
+    fn test_set_color_updates_styles() {
+        let mut theme = Theme::dark();
+        theme.set_color(ColorRole::Primary, Color::Magenta);
+        theme.set_color(ColorRole::TextPrimary, Color::White);
+
+        assert_eq!(theme.color(ColorRole::Primary), Color::Magenta);
+        assert_eq!(theme.button_focused_style().fg, Some(Color::Magenta));
+        assert_eq!(theme.primary_text_style().fg, Some(Color::White));
+        // Other roles are untouched
+        assert_eq!(theme.colors().secondary, ColorPalette::dark().secondary);
+    }
+
+    #[test]
+    fn test_lerp_endpoints() {
+        let dark = Theme::dark();
+        let light = Theme::light();
+        assert_eq!(dark.lerp(&light, 0.0), dark);
+        assert_eq!(dark.lerp(&light, 1.0), light);
+    }
+
+    #[test]
+    fn test_lerp_midpoint_averages_channels() {
+        let from = Theme::builder()
+            .primary_color(Color::Rgb(0, 100, 200))
+            .build();
+        let to = Theme::builder()
+            .primary_color(Color::Rgb(100, 200, 0))
+            .build();
+
+        let mid = from.lerp(&to, 0.5);
+        assert_eq!(mid.colors().primary, Color::Rgb(50, 150, 100));
+    }
+
+    #[test]
+    fn test_lerp_snaps_non_color_settings() {
+        let dark = Theme::dark();
+        let classic = Theme::builder()
+            .name("Classic")
+            .light_base()
+            .classic_borders()
+            .build();
+
+        let early = dark.lerp(&classic, 0.49);
+        assert_eq!(early.name(), "Dark");
+        assert_eq!(early.borders(), dark.borders());
+
+        let late = dark.lerp(&classic, 0.5);
+        assert_eq!(late.name(), "Classic");
+        assert_eq!(late.borders(), classic.borders());
+    }
+
+    #[test]
+    fn test_override_applies_only_to_target() {
+        let theme = Theme::dark().with_override(
+            "special",
+            ComponentStyleOverride::new()
+                .fg(Color::Yellow)
+                .border(Color::Red)
+                .add_modifier(Modifier::ITALIC),
+        );
+        let special = FocusId::new("special");
+        let other = FocusId::new("other");
+
+        let style = theme.resolve_button_style(&special, false);
+        assert_eq!(style.fg, Some(Color::Yellow));
+        assert_eq!(style.bg, Some(theme.colors().surface));
+        assert!(style.add_modifier.contains(Modifier::ITALIC));
+
+        assert_eq!(
+            theme.resolve_button_style(&other, false),
+            theme.button_normal_style()
+        );
+        assert_eq!(
+            theme
+                .resolve_border_style(&special, theme.border_style())
+                .fg,
+            Some(Color::Red)
+        );
+        assert_eq!(
+            theme.resolve_border_style(&other, theme.border_style()),
+            theme.border_style()
+        );
+    }
+
+    #[test]
+    fn test_clear_override() {
+        let mut theme = Theme::dark();
+        let id = FocusId::new("input");
+        theme.override_for(id.clone(), ComponentStyleOverride::new().bg(Color::Blue));
+        assert!(theme.style_override(&id).is_some());
+        assert_eq!(theme.resolve_input_style(&id, true).bg, Some(Color::Blue));
+
+        assert!(theme.clear_override(&id).is_some());
+        assert!(theme.style_override(&id).is_none());
+        assert_eq!(
+            theme.resolve_input_style(&id, true),
+            theme.input_focused_style()
+        );
+    }
 }
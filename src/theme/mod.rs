@@ -10,10 +10,15 @@
 //!
 //! - [`Theme`]: The main theme struct combining all styling elements
 //! - [`ThemeBuilder`]: A fluent builder API for creating custom themes
+//! - [`ThemeRegistry`]: Named themes with runtime switching
 //! - [`ColorPalette`]: Semantic color definitions (primary, secondary, etc.)
 //! - [`BorderStyles`]: Border type configurations for different states
 //! - [`TextStyles`] and [`TextStyle`]: Text modifier configurations
 //! - [`ComponentStyles`]: Component-specific style configurations
+//! - [`ColorDepth`]: Terminal color support and RGB downsampling
+//! - [`ColorDeficiency`]: Color vision deficiency simulation
+//! - [`ThemeError`]: Errors from loading or saving theme files
+//! - `ThemeWatcher`: Live reloading of theme files (requires the `theme-watch` feature)
 //!
 //! # Quick Start
 //!
@@ -28,6 +33,12 @@
 //! // Or the light theme
 //! let theme = Theme::light();
 //!
+//! // Or the high-contrast accessibility theme
+//! let theme = Theme::high_contrast();
+//!
+//! // Or a theme for deuteranopia, protanopia or tritanopia
+//! let theme = Theme::deuteranopia();
+//!
 //! // Access computed styles
 //! let text_style = theme.primary_text_style();
 //! let button_style = theme.button_focused_style();
@@ -50,6 +61,22 @@
 //!     .build();
 //! ```
 //!
+//! # Theme Files
+//!
+//! Themes can be saved to and loaded from TOML files, letting applications
+//! ship themes alongside their binaries:
+//!
+//! ```rust,no_run
+//! use tuilib::theme::Theme;
+//!
+//! Theme::dark().save("my-theme.toml")?;
+//! let theme = Theme::load("my-theme.toml")?;
+//! # Ok::<(), tuilib::theme::ThemeError>(())
+//! ```
+//!
+//! Colors are written as names (`"cyan"`), 256-color indices (`"13"`),
+//! or hex strings (`"#00afff"`).
+//!
 //! # Color Palette
 //!
 //! The [`ColorPalette`] provides semantic color roles:
@@ -111,17 +138,29 @@
 //! ```
 
 mod builder;
+mod color_depth;
+mod color_vision;
 mod colors;
 mod component;
 mod core;
+mod registry;
+mod serialization;
 mod styles;
+#[cfg(feature = "theme-watch")]
+mod watcher;
 
 // Main types
 pub use builder::ThemeBuilder;
-pub use colors::ColorPalette;
+pub use color_depth::{rgb_to_ansi16, rgb_to_ansi256, ColorDepth};
+pub use color_vision::{ColorDeficiency, MIN_COLOR_DIFFERENCE};
+pub use colors::{lerp_color, ColorPalette, ColorRole, MIN_CONTRAST_RATIO};
 pub use component::{
-    ButtonStyle, ComponentStyles, ComputedStyle, InputStyle, ListStyle, ModalStyle, TableStyle,
-    TabsStyle,
+    ButtonStyle, ComponentStyleOverride, ComponentStyles, ComputedStyle, InputStyle, ListStyle,
+    ModalStyle, TableStyle, TabsStyle,
 };
 pub use core::Theme;
+pub use registry::ThemeRegistry;
+pub use serialization::{format_color, parse_color, ThemeError};
 pub use styles::{BorderStyles, TextStyle, TextStyles};
+#[cfg(feature = "theme-watch")]
+pub use watcher::{ThemeEvent, ThemeWatcher};
@@ -0,0 +1,234 @@
+//! Named theme collections with runtime switching.
+//!
+//! A [`ThemeRegistry`] holds the themes an application ships, tracks which
+//! one is active, and cycles through them in registration order.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{Theme, ThemeError};
+
+/// A collection of named themes with one marked as current.
+///
+/// The first theme registered becomes the current theme. Themes keep their
+/// registration order, which is the order [`cycle`](Self::cycle) visits them
+/// and [`names`](Self::names) lists them.
+///
+/// # Example
+///
+/// ```rust
+/// use tuilib::theme::{Theme, ThemeRegistry};
+///
+/// let mut themes = ThemeRegistry::builtin();
+/// assert_eq!(themes.current_name(), Some("dark"));
+///
+/// themes.cycle();
+/// assert_eq!(themes.current().map(Theme::name), Some(Theme::light().name()));
+///
+/// assert!(themes.set_current("high_contrast"));
+/// assert!(!themes.set_current("missing"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ThemeRegistry {
+    /// Themes by name.
+    themes: HashMap<String, Theme>,
+    /// Theme names in registration order.
+    order: Vec<String>,
+    /// Index into `order` of the current theme.
+    current: Option<usize>,
+}
+
+impl ThemeRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with the built-in themes.
+    ///
+    /// The themes are registered as `dark`, `light` and `high_contrast`, in
+    /// that order, with `dark` current.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register("dark", Theme::dark());
+        registry.register("light", Theme::light());
+        registry.register("high_contrast", Theme::high_contrast());
+        registry
+    }
+
+    /// Loads every `.toml` file in a directory as a theme.
+    ///
+    /// Each theme is registered under its file stem, so `ocean.toml` becomes
+    /// `ocean`. Files are registered in file name order and the first one
+    /// becomes current. Other files are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read or any theme file
+    /// cannot be read or parsed. Parse errors name the offending file.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tuilib::theme::ThemeRegistry;
+    ///
+    /// let themes = ThemeRegistry::load_dir("themes")?;
+    /// for name in themes.names() {
+    ///     println!("{name}");
+    /// }
+    /// # Ok::<(), tuilib::theme::ThemeError>(())
+    /// ```
+    pub fn load_dir(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(path)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "toml") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut registry = Self::new();
+        for path in paths {
+            let contents = std::fs::read_to_string(&path)?;
+            let theme = Theme::from_toml_str(&contents).map_err(|e| match e {
+                ThemeError::ParseError(msg) => {
+                    ThemeError::ParseError(format!("{}: {}", path.display(), msg))
+                }
+                other => other,
+            })?;
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            registry.register(name, theme);
+        }
+        Ok(registry)
+    }
+
+    /// Registers a theme under a name, returning the theme it replaced.
+    ///
+    /// Replacing a theme keeps its place in the cycle order. The first theme
+    /// registered becomes current.
+    pub fn register(&mut self, name: impl Into<String>, theme: Theme) -> Option<Theme> {
+        let name = name.into();
+        if !self.themes.contains_key(&name) {
+            self.order.push(name.clone());
+        }
+        if self.current.is_none() {
+            self.current = Some(0);
+        }
+        self.themes.insert(name, theme)
+    }
+
+    /// Returns the theme registered under a name.
+    pub fn get(&self, name: &str) -> Option<&Theme> {
+        self.themes.get(name)
+    }
+
+    /// Returns true if a theme is registered under the name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.themes.contains_key(name)
+    }
+
+    /// Returns the registered names in registration order.
+    pub fn names(&self) -> Vec<&str> {
+        self.order.iter().map(String::as_str).collect()
+    }
+
+    /// Returns the number of registered themes.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns true if no themes are registered.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns the current theme, if any themes are registered.
+    pub fn current(&self) -> Option<&Theme> {
+        self.current_name().and_then(|name| self.themes.get(name))
+    }
+
+    /// Returns the name of the current theme.
+    pub fn current_name(&self) -> Option<&str> {
+        self.current.map(|index| self.order[index].as_str())
+    }
+
+    /// Makes the named theme current.
+    ///
+    /// Returns false, leaving the current theme unchanged, if no theme is
+    /// registered under the name.
+    pub fn set_current(&mut self, name: &str) -> bool {
+        match self.order.iter().position(|n| n == name) {
+            Some(index) => {
+                self.current = Some(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Advances to the next theme, wrapping after the last, and returns it.
+    ///
+    /// Returns `None` if the registry is empty.
+    pub fn cycle(&mut self) -> Option<&Theme> {
+        let next = (self.current? + 1) % self.order.len();
+        self.current = Some(next);
+        self.current()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_wraps_and_updates_current() {
+        let mut themes = ThemeRegistry::new();
+        assert!(themes.cycle().is_none());
+        assert!(themes.current().is_none());
+
+        themes.register("dark", Theme::dark());
+        themes.register("light", Theme::light());
+        themes.register("contrast", Theme::high_contrast());
+        assert_eq!(themes.names(), vec!["dark", "light", "contrast"]);
+
+        let mut visited = Vec::new();
+        for _ in 0..4 {
+            let name = themes.cycle().unwrap().name().to_string();
+            assert_eq!(themes.current().unwrap().name(), name);
+            visited.push(themes.current_name().unwrap().to_string());
+        }
+        assert_eq!(visited, vec!["light", "contrast", "dark", "light"]);
+
+        // Replacing a theme keeps its position
+        assert!(themes.register("dark", Theme::light()).is_some());
+        assert_eq!(themes.names(), vec!["dark", "light", "contrast"]);
+        assert!(themes.set_current("contrast"));
+        assert!(!themes.set_current("missing"));
+        assert_eq!(themes.current_name(), Some("contrast"));
+    }
+
+    #[test]
+    fn test_load_dir_registers_by_file_stem() {
+        let dir = std::env::temp_dir().join(format!("tuilib-registry-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Theme::light().save(dir.join("b-light.toml")).unwrap();
+        Theme::dark().save(dir.join("a-dark.toml")).unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a theme").unwrap();
+
+        let mut themes = ThemeRegistry::load_dir(&dir).unwrap();
+        assert_eq!(themes.names(), vec!["a-dark", "b-light"]);
+        assert_eq!(themes.current(), Some(&Theme::dark()));
+        assert_eq!(themes.cycle(), Some(&Theme::light()));
+
+        std::fs::write(dir.join("c-broken.toml"), "colors = 3").unwrap();
+        let err = ThemeRegistry::load_dir(&dir).unwrap_err();
+        assert!(err.to_string().contains("c-broken.toml"), "{err}");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,303 @@
+//! Serialization support for themes.
+//!
+//! This module provides the textual formats used when saving and loading
+//! themes, along with the [`ThemeError`] type returned by theme file
+//! operations.
+//!
+//! # Color Format
+//!
+//! Colors are written in one of three forms, all of which are accepted
+//! when reading a theme:
+//!
+//! - **Named**: `"cyan"`, `"light_blue"`, `"reset"`
+//! - **Indexed**: `"13"` (a 256-color palette index)
+//! - **Hex**: `"#00afff"` (a 24-bit RGB color)
+//!
+//! # Example
+//!
+//! ```rust
+//! use tuilib::theme::{format_color, parse_color};
+//! use ratatui::style::Color;
+//!
+//! assert_eq!(parse_color("#00afff"), Some(Color::Rgb(0, 175, 255)));
+//! assert_eq!(parse_color("13"), Some(Color::Indexed(13)));
+//! assert_eq!(format_color(Color::LightBlue), "light_blue");
+//! ```
+
+use std::fmt;
+
+use ratatui::style::{Color, Modifier};
+use ratatui::widgets::BorderType;
+
+/// Named colors and their serialized names.
+const NAMED_COLORS: &[(&str, Color)] = &[
+    ("reset", Color::Reset),
+    ("black", Color::Black),
+    ("red", Color::Red),
+    ("green", Color::Green),
+    ("yellow", Color::Yellow),
+    ("blue", Color::Blue),
+    ("magenta", Color::Magenta),
+    ("cyan", Color::Cyan),
+    ("gray", Color::Gray),
+    ("dark_gray", Color::DarkGray),
+    ("light_red", Color::LightRed),
+    ("light_green", Color::LightGreen),
+    ("light_yellow", Color::LightYellow),
+    ("light_blue", Color::LightBlue),
+    ("light_magenta", Color::LightMagenta),
+    ("light_cyan", Color::LightCyan),
+    ("white", Color::White),
+];
+
+/// Modifiers and their serialized names.
+const MODIFIERS: &[(&str, Modifier)] = &[
+    ("bold", Modifier::BOLD),
+    ("dim", Modifier::DIM),
+    ("italic", Modifier::ITALIC),
+    ("underlined", Modifier::UNDERLINED),
+    ("slow_blink", Modifier::SLOW_BLINK),
+    ("rapid_blink", Modifier::RAPID_BLINK),
+    ("reversed", Modifier::REVERSED),
+    ("hidden", Modifier::HIDDEN),
+    ("crossed_out", Modifier::CROSSED_OUT),
+];
+
+/// Border types and their serialized names.
+const BORDER_TYPES: &[(&str, BorderType)] = &[
+    ("plain", BorderType::Plain),
+    ("rounded", BorderType::Rounded),
+    ("double", BorderType::Double),
+    ("thick", BorderType::Thick),
+    ("quadrant_inside", BorderType::QuadrantInside),
+    ("quadrant_outside", BorderType::QuadrantOutside),
+];
+
+/// Normalizes a name for lookup: lowercase with `-` and spaces as `_`.
+fn normalize(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| match c {
+            '-' | ' ' => '_',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+/// Formats a color as a string.
+///
+/// Named colors use their snake_case name, indexed colors use their
+/// decimal index, and RGB colors use `#rrggbb` hex notation.
+pub fn format_color(color: Color) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        Color::Indexed(i) => i.to_string(),
+        named => NAMED_COLORS
+            .iter()
+            .find(|(_, c)| *c == named)
+            .map(|(name, _)| (*name).to_string())
+            .unwrap_or_else(|| "reset".to_string()),
+    }
+}
+
+/// Parses a color from a name, a 256-color index, or a `#rrggbb` hex string.
+///
+/// Names are matched case-insensitively, and `-` or spaces may be used in
+/// place of `_` (so `"Light-Blue"` parses as [`Color::LightBlue`]).
+///
+/// Returns `None` if the string is not a recognized color.
+pub fn parse_color(s: &str) -> Option<Color> {
+    let s = s.trim();
+
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+        return Some(Color::Rgb(r, g, b));
+    }
+
+    if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+        return s.parse::<u8>().ok().map(Color::Indexed);
+    }
+
+    let name = normalize(s);
+    let name = match name.as_str() {
+        "grey" => "gray",
+        "dark_grey" | "darkgray" | "darkgrey" => "dark_gray",
+        other => other,
+    };
+    NAMED_COLORS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, c)| *c)
+}
+
+/// Error type for theme loading and saving failures.
+#[derive(Debug)]
+pub enum ThemeError {
+    /// Failed to read or write the theme file.
+    IoError(std::io::Error),
+    /// The theme file could not be parsed.
+    ParseError(String),
+    /// The theme could not be serialized.
+    SerializeError(String),
+    /// The theme file could not be watched for changes.
+    WatchError(String),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::IoError(e) => write!(f, "IO error: {}", e),
+            ThemeError::ParseError(e) => write!(f, "Failed to parse theme: {}", e),
+            ThemeError::SerializeError(e) => write!(f, "Failed to serialize theme: {}", e),
+            ThemeError::WatchError(e) => write!(f, "Failed to watch theme file: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeError::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ThemeError {
+    fn from(err: std::io::Error) -> Self {
+        ThemeError::IoError(err)
+    }
+}
+
+/// Serde support for [`Color`] fields using [`format_color`] / [`parse_color`].
+pub(crate) mod color {
+    use ratatui::style::Color;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::format_color(*color))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        super::parse_color(&s).ok_or_else(|| de::Error::custom(format!("invalid color: {s:?}")))
+    }
+}
+
+/// Serde support for [`Modifier`] fields as a list of modifier names.
+pub(crate) mod modifier {
+    use ratatui::style::Modifier;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(modifier: &Modifier, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            super::MODIFIERS
+                .iter()
+                .filter(|(_, m)| modifier.contains(*m))
+                .map(|(name, _)| *name),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Modifier, D::Error> {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        names.iter().try_fold(Modifier::empty(), |acc, name| {
+            let normalized = super::normalize(name);
+            super::MODIFIERS
+                .iter()
+                .find(|(n, _)| *n == normalized)
+                .map(|(_, m)| acc | *m)
+                .ok_or_else(|| de::Error::custom(format!("invalid modifier: {name:?}")))
+        })
+    }
+}
+
+/// Serde support for [`BorderType`] fields as snake_case names.
+pub(crate) mod border_type {
+    use ratatui::widgets::BorderType;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        border_type: &BorderType,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let name = super::BORDER_TYPES
+            .iter()
+            .find(|(_, b)| b == border_type)
+            .map(|(name, _)| *name)
+            .unwrap_or("plain");
+        serializer.serialize_str(name)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BorderType, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let normalized = super::normalize(&s);
+        super::BORDER_TYPES
+            .iter()
+            .find(|(n, _)| *n == normalized)
+            .map(|(_, b)| *b)
+            .ok_or_else(|| de::Error::custom(format!("invalid border type: {s:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_color() {
+        assert_eq!(format_color(Color::Cyan), "cyan");
+        assert_eq!(format_color(Color::DarkGray), "dark_gray");
+        assert_eq!(format_color(Color::Indexed(13)), "13");
+        assert_eq!(format_color(Color::Rgb(0, 175, 255)), "#00afff");
+    }
+
+    #[test]
+    fn test_parse_color_named() {
+        assert_eq!(parse_color("cyan"), Some(Color::Cyan));
+        assert_eq!(parse_color("Light-Blue"), Some(Color::LightBlue));
+        assert_eq!(parse_color("dark grey"), Some(Color::DarkGray));
+        assert_eq!(parse_color("reset"), Some(Color::Reset));
+    }
+
+    #[test]
+    fn test_parse_color_indexed() {
+        assert_eq!(parse_color("13"), Some(Color::Indexed(13)));
+        assert_eq!(parse_color("255"), Some(Color::Indexed(255)));
+        assert_eq!(parse_color("256"), None);
+    }
+
+    #[test]
+    fn test_parse_color_hex() {
+        assert_eq!(parse_color("#00afff"), Some(Color::Rgb(0, 175, 255)));
+        assert_eq!(parse_color("#00AFFF"), Some(Color::Rgb(0, 175, 255)));
+        assert_eq!(parse_color("#00aff"), None);
+        assert_eq!(parse_color("#00afgg"), None);
+    }
+
+    #[test]
+    fn test_parse_color_invalid() {
+        assert_eq!(parse_color(""), None);
+        assert_eq!(parse_color("not-a-color"), None);
+    }
+
+    #[test]
+    fn test_color_round_trip() {
+        for (_, color) in NAMED_COLORS {
+            assert_eq!(parse_color(&format_color(*color)), Some(*color));
+        }
+        for color in [Color::Indexed(42), Color::Rgb(1, 2, 3)] {
+            assert_eq!(parse_color(&format_color(color)), Some(color));
+        }
+    }
+
+    #[test]
+    fn test_theme_error_display() {
+        let err = ThemeError::ParseError("bad".to_string());
+        assert_eq!(err.to_string(), "Failed to parse theme: bad");
+    }
+}
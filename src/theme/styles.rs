@@ -5,6 +5,7 @@
 
 use ratatui::style::{Modifier, Style};
 use ratatui::widgets::BorderType;
+use serde::{Deserialize, Serialize};
 
 /// Border style configuration for themed components.
 ///
@@ -19,20 +20,31 @@ use ratatui::widgets::BorderType;
 /// let borders = BorderStyles::default();
 /// assert_eq!(borders.default, BorderType::Rounded);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BorderStyles {
     /// Default border style for unfocused elements
+    #[serde(with = "super::serialization::border_type")]
     pub default: BorderType,
     /// Border style for focused elements
+    #[serde(with = "super::serialization::border_type")]
     pub focused: BorderType,
     /// Border style for modal dialogs
+    #[serde(with = "super::serialization::border_type")]
     pub modal: BorderType,
     /// Border style for disabled elements
+    #[serde(with = "super::serialization::border_type")]
     pub disabled: BorderType,
+    /// Border style for elements under the mouse pointer
+    #[serde(with = "super::serialization::border_type")]
+    pub hover: BorderType,
 }
 
 impl BorderStyles {
     /// Creates a new border styles configuration.
+    ///
+    /// Hovered elements use the `focused` border type; set
+    /// [`hover`](Self::hover) to change it.
     pub fn new(
         default: BorderType,
         focused: BorderType,
@@ -44,6 +56,7 @@ impl BorderStyles {
             focused,
             modal,
             disabled,
+            hover: focused,
         }
     }
 
@@ -54,6 +67,7 @@ impl BorderStyles {
             focused: BorderType::Rounded,
             modal: BorderType::Double,
             disabled: BorderType::Plain,
+            hover: BorderType::Rounded,
         }
     }
 
@@ -64,6 +78,7 @@ impl BorderStyles {
             focused: BorderType::Plain,
             modal: BorderType::Double,
             disabled: BorderType::Plain,
+            hover: BorderType::Plain,
         }
     }
 
@@ -74,6 +89,7 @@ impl BorderStyles {
             focused: BorderType::Plain,
             modal: BorderType::Plain,
             disabled: BorderType::Plain,
+            hover: BorderType::Plain,
         }
     }
 }
@@ -98,7 +114,8 @@ impl Default for BorderStyles {
 /// let text = TextStyles::default();
 /// assert!(text.heading.add_modifier.contains(Modifier::BOLD));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TextStyles {
     /// Style for main headings
     pub heading: TextStyle,
@@ -170,9 +187,11 @@ impl Default for TextStyles {
 /// assert!(ratatui_style.add_modifier.contains(Modifier::BOLD));
 /// assert!(ratatui_style.add_modifier.contains(Modifier::ITALIC));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TextStyle {
     /// Modifiers to add to the text
+    #[serde(with = "super::serialization::modifier")]
     pub add_modifier: Modifier,
 }
 
@@ -240,6 +259,8 @@ mod tests {
         assert_eq!(borders.default, BorderType::Rounded);
         assert_eq!(borders.focused, BorderType::Rounded);
         assert_eq!(borders.modal, BorderType::Double);
+        assert_eq!(borders.disabled, BorderType::Plain);
+        assert_eq!(borders.hover, BorderType::Rounded);
     }
 
     #[test]
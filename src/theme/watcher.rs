@@ -0,0 +1,366 @@
+//! Live theme reloading from a watched file.
+//!
+//! This module provides [`ThemeWatcher`], which watches a theme file on disk
+//! and reparses it whenever it changes. It is intended for theme development:
+//! edit the TOML file and see the result without restarting the application.
+//!
+//! Requires the `theme-watch` feature.
+//!
+//! # Example
+//!
+//! Forward reloaded themes into the event loop as messages:
+//!
+//! ```rust,no_run
+//! use tuilib::event::{AppEvent, EventLoop, EventLoopConfig};
+//! use tuilib::theme::{ThemeEvent, ThemeWatcher};
+//!
+//! enum Msg {
+//!     Theme(ThemeEvent),
+//! }
+//!
+//! let event_loop: EventLoop<Msg> = EventLoop::new(EventLoopConfig::default());
+//! let tx = event_loop.sender();
+//!
+//! let _watcher = ThemeWatcher::new("theme.toml", move |event| {
+//!     let _ = tx.blocking_send(AppEvent::Message(Msg::Theme(event)));
+//! })?;
+//! # Ok::<(), tuilib::theme::ThemeError>(())
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use super::core::Theme;
+use super::serialization::ThemeError;
+use crate::event::{Clock, Debouncer, SystemClock};
+
+/// An event produced by a [`ThemeWatcher`].
+#[derive(Debug)]
+pub enum ThemeEvent {
+    /// The theme file changed and was parsed successfully.
+    Reloaded(Theme),
+    /// The theme file changed but could not be read or parsed.
+    Error(ThemeError),
+}
+
+impl ThemeEvent {
+    /// Returns true if this is a successful reload.
+    pub fn is_reloaded(&self) -> bool {
+        matches!(self, ThemeEvent::Reloaded(_))
+    }
+
+    /// Returns true if this is an error event.
+    pub fn is_error(&self) -> bool {
+        matches!(self, ThemeEvent::Error(_))
+    }
+}
+
+/// Type alias for theme reload callbacks.
+type ReloadCallback = Box<dyn FnMut(ThemeEvent) + Send + 'static>;
+
+/// Reloads a theme file and reports the result to a callback.
+struct Reloader<C = SystemClock> {
+    path: PathBuf,
+    debouncer: Debouncer<(), C>,
+    callback: ReloadCallback,
+}
+
+impl<C: Clock> Reloader<C> {
+    fn new(path: PathBuf, debouncer: Debouncer<(), C>, callback: ReloadCallback) -> Self {
+        Self {
+            path,
+            debouncer,
+            callback,
+        }
+    }
+
+    /// Reparses the theme file and invokes the callback with the result.
+    fn reload(&mut self) {
+        let event = match Theme::load(&self.path) {
+            Ok(theme) => ThemeEvent::Reloaded(theme),
+            Err(e) => ThemeEvent::Error(e),
+        };
+        (self.callback)(event);
+    }
+
+    /// Returns true if a filesystem event refers to the watched file.
+    fn is_relevant(&self, event: &notify::Event) -> bool {
+        matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+            && event
+                .paths
+                .iter()
+                .any(|p| p.file_name() == self.path.file_name())
+    }
+
+    /// Records a filesystem event, starting or restarting the quiet period
+    /// if it refers to the watched file.
+    fn handle(&mut self, event: notify::Result<notify::Event>) {
+        match event {
+            Ok(event) if self.is_relevant(&event) => self.debouncer.push(()),
+            Ok(_) => {}
+            Err(e) => (self.callback)(ThemeEvent::Error(ThemeError::WatchError(e.to_string()))),
+        }
+    }
+
+    /// Reloads the theme if changes to it have been quiet for the debounce
+    /// delay.
+    fn reload_if_quiet(&mut self) {
+        let now = self.debouncer.clock().now();
+        if self.debouncer.try_emit(now).is_some() {
+            self.reload();
+        }
+    }
+
+    /// Processes filesystem events until the watcher is dropped.
+    ///
+    /// Reloads happen once changes to the theme file have been quiet for the
+    /// debounce delay, so an editor's burst of writes results in a single
+    /// reload. Events for other files in the directory are ignored and do
+    /// not hold the reload back.
+    fn run(mut self, rx: mpsc::Receiver<notify::Result<notify::Event>>) {
+        loop {
+            // Wait only as long as the pending reload allows
+            let received = if self.debouncer.is_pending() {
+                rx.recv_timeout(self.debouncer.remaining())
+            } else {
+                rx.recv().map_err(|_| mpsc::RecvTimeoutError::Disconnected)
+            };
+            match received {
+                Ok(event) => self.handle(event),
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+            self.reload_if_quiet();
+        }
+    }
+}
+
+/// Watches a theme file and reloads it when it changes.
+///
+/// The watcher runs on a background thread. Each change to the file is
+/// debounced, reparsed with [`Theme::load`], and delivered to the callback
+/// as a [`ThemeEvent`]. Parse errors are delivered as [`ThemeEvent::Error`]
+/// so a half-edited file never brings the application down.
+///
+/// Watching stops when the `ThemeWatcher` is dropped.
+pub struct ThemeWatcher {
+    path: PathBuf,
+    watcher: Option<RecommendedWatcher>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ThemeWatcher {
+    /// Starts watching a theme file with the default debounce delay (50ms).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::WatchError`] if the file cannot be watched.
+    pub fn new<F>(path: impl AsRef<Path>, callback: F) -> Result<Self, ThemeError>
+    where
+        F: FnMut(ThemeEvent) + Send + 'static,
+    {
+        Self::with_delay(path, Debouncer::default().delay(), callback)
+    }
+
+    /// Starts watching a theme file with a custom debounce delay.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::WatchError`] if the file cannot be watched.
+    pub fn with_delay<F>(
+        path: impl AsRef<Path>,
+        delay: Duration,
+        callback: F,
+    ) -> Result<Self, ThemeError>
+    where
+        F: FnMut(ThemeEvent) + Send + 'static,
+    {
+        let path = path.as_ref().to_path_buf();
+        let (tx, rx) = mpsc::channel();
+
+        let mut watcher =
+            notify::recommended_watcher(tx).map_err(|e| ThemeError::WatchError(e.to_string()))?;
+
+        // Watch the parent directory: editors often save by replacing the
+        // file, which would silently end a watch on the file itself.
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(|e| ThemeError::WatchError(e.to_string()))?;
+
+        let reloader = Reloader::new(path.clone(), Debouncer::new(delay), Box::new(callback));
+        let handle = std::thread::spawn(move || reloader.run(rx));
+
+        Ok(Self {
+            path,
+            watcher: Some(watcher),
+            handle: Some(handle),
+        })
+    }
+
+    /// Returns the path of the watched theme file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ThemeWatcher {
+    fn drop(&mut self) {
+        // Dropping the watcher closes the event channel, ending the thread
+        drop(self.watcher.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl std::fmt::Debug for ThemeWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThemeWatcher")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("tuilib-watch-{}-{}.toml", name, std::process::id()))
+    }
+
+    fn collecting_reloader<C: Clock>(
+        path: PathBuf,
+        clock: C,
+    ) -> (Reloader<C>, Arc<Mutex<Vec<ThemeEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let reloader = Reloader::new(
+            path,
+            Debouncer::with_clock(Duration::from_millis(10), clock),
+            Box::new(move |event| sink.lock().unwrap().push(event)),
+        );
+        (reloader, events)
+    }
+
+    #[test]
+    fn test_reload_delivers_updated_theme() {
+        let path = temp_path("reload");
+        Theme::dark().save(&path).unwrap();
+        let (mut reloader, events) = collecting_reloader(path.clone(), SystemClock);
+
+        reloader.reload();
+        Theme::light().save(&path).unwrap();
+        reloader.reload();
+        std::fs::remove_file(&path).ok();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        match (&events[0], &events[1]) {
+            (ThemeEvent::Reloaded(first), ThemeEvent::Reloaded(second)) => {
+                assert_eq!(first.name(), "Dark");
+                assert_eq!(second.name(), "Light");
+            }
+            other => panic!("unexpected events: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_reload_reports_parse_error() {
+        let path = temp_path("bad");
+        std::fs::write(&path, "name = \"Broken\"\n[colors]\nprimary = \"nope\"\n").unwrap();
+        let (mut reloader, events) = collecting_reloader(path.clone(), SystemClock);
+
+        reloader.reload();
+        std::fs::remove_file(&path).ok();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_error());
+        assert!(matches!(
+            events[0],
+            ThemeEvent::Error(ThemeError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_relevant_filters_other_files() {
+        let path = temp_path("filter");
+        let (reloader, _) = collecting_reloader(path.clone(), SystemClock);
+
+        let modify = notify::Event::new(EventKind::Modify(notify::event::ModifyKind::Any));
+        assert!(reloader.is_relevant(&modify.clone().add_path(path)));
+        assert!(!reloader.is_relevant(&modify.add_path(temp_path("other"))));
+
+        let access = notify::Event::new(EventKind::Access(notify::event::AccessKind::Any))
+            .add_path(temp_path("filter"));
+        assert!(!reloader.is_relevant(&access));
+    }
+
+    #[test]
+    fn test_other_files_do_not_delay_reload() {
+        use crate::event::ManualClock;
+
+        let path = temp_path("noise");
+        Theme::light().save(&path).unwrap();
+        let clock = ManualClock::new();
+        let (mut reloader, events) = collecting_reloader(path.clone(), clock.clone());
+
+        let modify = notify::Event::new(EventKind::Modify(notify::event::ModifyKind::Any));
+        let noise = modify.clone().add_path(temp_path("noise-other"));
+        reloader.handle(Ok(modify.add_path(path.clone())));
+
+        // A steady stream of events for other files arrives faster than the
+        // delay, but the reload still happens once the delay has passed
+        for _ in 0..3 {
+            clock.advance(Duration::from_millis(4));
+            reloader.handle(Ok(noise.clone()));
+            reloader.reload_if_quiet();
+        }
+        std::fs::remove_file(&path).ok();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_reloaded());
+        assert!(!reloader.debouncer.is_pending());
+    }
+
+    #[test]
+    fn test_watcher_reloads_on_change() {
+        let path = temp_path("live");
+        Theme::dark().save(&path).unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let watcher = ThemeWatcher::with_delay(&path, Duration::from_millis(20), move |event| {
+            sink.lock().unwrap().push(event)
+        })
+        .unwrap();
+        assert_eq!(watcher.path(), path.as_path());
+
+        Theme::light().save(&path).unwrap();
+        for _ in 0..100 {
+            if !events.lock().unwrap().is_empty() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        drop(watcher);
+        std::fs::remove_file(&path).ok();
+
+        let events = events.lock().unwrap();
+        assert!(matches!(
+            events.last(),
+            Some(ThemeEvent::Reloaded(theme)) if theme.name() == "Light"
+        ));
+    }
+}
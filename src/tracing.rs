@@ -50,8 +50,117 @@
 //!     .with_target_level("tuilib::event", tracing::Level::DEBUG)
 //!     .with_target_level("tuilib::focus", tracing::Level::TRACE);
 //! ```
+//!
+//! Many targets can be configured at once with a `RUST_LOG`-style string:
+//!
+//! ```rust
+//! use tuilib::tracing::TracingConfig;
+//!
+//! let config = TracingConfig::new()
+//!     .with_log_file("app.log")
+//!     .with_filter_directives("tuilib=trace,tuilib::input=debug,warn");
+//! ```
+//!
+//! # Output Formats
+//!
+//! Logs are written as human-readable text by default. Use
+//! [`TracingFormat::Json`] to write one JSON object per line for log tooling:
+//!
+//! ```rust
+//! use tuilib::tracing::{TracingConfig, TracingFormat};
+//!
+//! let config = TracingConfig::new()
+//!     .with_log_file("app.jsonl")
+//!     .with_format(TracingFormat::Json);
+//! ```
+//!
+//! # Log Rotation
+//!
+//! Long-running applications can keep the log file from growing without
+//! bound by rotating it on a schedule or when it reaches a size:
+//!
+//! ```rust
+//! use tuilib::tracing::{Rotation, TracingConfig};
+//!
+//! // Keep debug.log under 10 MB, with up to 3 older files
+//! let config = TracingConfig::new()
+//!     .with_log_file("debug.log")
+//!     .with_rotation(Rotation::size(10 * 1024 * 1024, 3));
+//! ```
+//!
+//! # In-App Logs
+//!
+//! A [`LogBuffer`] keeps the most recent events in memory so they can be
+//! shown inside the TUI with [`LogView`](crate::components::LogView):
+//!
+//! ```rust
+//! use tuilib::tracing::{LogBuffer, TracingConfig};
+//!
+//! let logs = LogBuffer::new(500);
+//! let config = TracingConfig::new()
+//!     .with_log_file("app.log")
+//!     .with_log_buffer(logs.clone());
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
 
-use std::path::PathBuf;
+use tracing_subscriber::fmt::format::Format;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::{Layer, Registry};
+
+/// Output format for log lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TracingFormat {
+    /// Single-line human-readable output.
+    #[default]
+    Full,
+    /// Multi-line output that is easier to read for humans.
+    Pretty,
+    /// Shorter single-line output.
+    Compact,
+    /// One JSON object per line, including the current span and span list.
+    Json,
+}
+
+/// When the log file is rotated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    /// Never rotate; the log file grows without bound.
+    #[default]
+    Never,
+    /// Start a new file every hour, named with the date and hour as a suffix
+    /// (e.g. `app.log.2024-01-31-13`).
+    Hourly,
+    /// Start a new file every day, named with the date as a suffix
+    /// (e.g. `app.log.2024-01-31`).
+    Daily,
+    /// Rotate when the file would grow past `max_bytes`.
+    ///
+    /// The current file is renamed to `app.log.1`, older backups shift up
+    /// (`app.log.1` becomes `app.log.2`, and so on), and backups beyond
+    /// `max_backups` are deleted.
+    Size {
+        /// Maximum size of the log file in bytes.
+        max_bytes: u64,
+        /// Number of rotated files to keep.
+        max_backups: usize,
+    },
+}
+
+impl Rotation {
+    /// Creates a size-based rotation keeping `max_backups` old files.
+    pub fn size(max_bytes: u64, max_backups: usize) -> Self {
+        Rotation::Size {
+            max_bytes,
+            max_backups,
+        }
+    }
+}
 
 /// Configuration for tracing initialization.
 ///
@@ -82,6 +191,8 @@ pub struct TracingConfig {
     pub log_file: Option<PathBuf>,
     /// Per-target log level overrides.
     pub target_levels: Vec<(String, tracing::Level)>,
+    /// Extra `RUST_LOG`-style filter directives.
+    pub filter_directives: Vec<String>,
     /// Whether to include timestamps in log output.
     pub include_timestamps: bool,
     /// Whether to include target (module path) in log output.
@@ -90,6 +201,12 @@ pub struct TracingConfig {
     pub include_file_line: bool,
     /// Whether to include span information.
     pub include_spans: bool,
+    /// Output format for log lines.
+    pub format: TracingFormat,
+    /// When the log file is rotated.
+    pub rotation: Rotation,
+    /// In-memory buffer that also receives events.
+    pub log_buffer: Option<LogBuffer>,
 }
 
 impl Default for TracingConfig {
@@ -98,10 +215,14 @@ impl Default for TracingConfig {
             level: tracing::Level::INFO,
             log_file: None,
             target_levels: Vec::new(),
+            filter_directives: Vec::new(),
             include_timestamps: true,
             include_target: true,
             include_file_line: false,
             include_spans: true,
+            format: TracingFormat::Full,
+            rotation: Rotation::Never,
+            log_buffer: None,
         }
     }
 }
@@ -185,6 +306,38 @@ impl TracingConfig {
         self
     }
 
+    /// Adds filter directives in the `RUST_LOG` format.
+    ///
+    /// Directives are comma-separated `target=level` pairs; a bare level
+    /// replaces the default level. They are applied after the default level
+    /// and before the overrides from
+    /// [`with_target_level`](Self::with_target_level), so those take
+    /// precedence for the same target. If any directive is invalid, the
+    /// whole filter falls back to `info`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::tracing::TracingConfig;
+    ///
+    /// let config = TracingConfig::new()
+    ///     .with_filter_directives("tuilib=trace,tuilib::input=debug,warn");
+    /// assert_eq!(
+    ///     config.build_filter_directive(),
+    ///     "info,tuilib=trace,tuilib::input=debug,warn"
+    /// );
+    /// ```
+    pub fn with_filter_directives(mut self, directives: &str) -> Self {
+        self.filter_directives.extend(
+            directives
+                .split(',')
+                .map(str::trim)
+                .filter(|d| !d.is_empty())
+                .map(str::to_string),
+        );
+        self
+    }
+
     /// Sets whether to include timestamps in log output.
     ///
     /// Default is `true`.
@@ -217,11 +370,55 @@ impl TracingConfig {
         self
     }
 
+    /// Sets the output format.
+    ///
+    /// Default is [`TracingFormat::Full`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::tracing::{TracingConfig, TracingFormat};
+    ///
+    /// let config = TracingConfig::new().with_format(TracingFormat::Json);
+    /// assert_eq!(config.format, TracingFormat::Json);
+    /// ```
+    pub fn with_format(mut self, format: TracingFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets when the log file is rotated.
+    ///
+    /// Default is [`Rotation::Never`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tuilib::tracing::{Rotation, TracingConfig};
+    ///
+    /// let config = TracingConfig::new()
+    ///     .with_log_file("app.log")
+    ///     .with_rotation(Rotation::Daily);
+    /// ```
+    pub fn with_rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Also records events into an in-memory [`LogBuffer`].
+    ///
+    /// Events are filtered by the same levels as the log file.
+    pub fn with_log_buffer(mut self, buffer: LogBuffer) -> Self {
+        self.log_buffer = Some(buffer);
+        self
+    }
+
     /// Builds the filter directive string for tracing-subscriber.
     ///
     /// This creates a filter string like "info,tuilib::event=debug,tuilib::focus=trace".
     pub fn build_filter_directive(&self) -> String {
         let mut parts = vec![self.level.as_str().to_lowercase()];
+        parts.extend(self.filter_directives.iter().cloned());
 
         for (target, level) in &self.target_levels {
             parts.push(format!("{}={}", target, level.as_str().to_lowercase()));
@@ -340,25 +537,140 @@ impl From<std::io::Error> for TracingError {
 /// tracing::debug!(component = "button", "Button rendered");
 /// ```
 pub fn init_tracing(config: TracingConfig) -> Result<TracingGuard, TracingError> {
-    use tracing_subscriber::fmt::format::FmtSpan;
-    use tracing_subscriber::prelude::*;
-
     let log_file = config.log_file.clone().ok_or(TracingError::NoLogFile)?;
+    let writer = open_log_writer(&log_file, config.rotation)?;
+    let (non_blocking, guard) = tracing_appender::non_blocking(writer);
+
+    tracing::subscriber::set_global_default(build_subscriber(&config, non_blocking))
+        .map_err(|e| TracingError::SetGlobalError(e.to_string()))?;
 
+    Ok(TracingGuard {
+        _worker_guard: guard,
+    })
+}
+
+/// Opens the writer for `log_file`, rotating it as configured.
+fn open_log_writer(log_file: &Path, rotation: Rotation) -> io::Result<Box<dyn Write + Send>> {
     // Create parent directories if needed
-    if let Some(parent) = log_file.parent() {
-        if !parent.as_os_str().is_empty() {
-            std::fs::create_dir_all(parent)?;
+    let directory = log_file
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    std::fs::create_dir_all(directory)?;
+
+    let timed = match rotation {
+        Rotation::Never => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(log_file)?;
+            return Ok(Box::new(file));
+        }
+        Rotation::Size {
+            max_bytes,
+            max_backups,
+        } => {
+            let file = SizeRotatingFile::open(log_file, max_bytes, max_backups)?;
+            return Ok(Box::new(file));
+        }
+        Rotation::Hourly => tracing_appender::rolling::Rotation::HOURLY,
+        Rotation::Daily => tracing_appender::rolling::Rotation::DAILY,
+    };
+
+    let file_name = log_file
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "log file has no file name"))?;
+    let appender = tracing_appender::rolling::RollingFileAppender::builder()
+        .rotation(timed)
+        .filename_prefix(file_name.to_string_lossy())
+        .build(directory)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    Ok(Box::new(appender))
+}
+
+/// A log file that is rotated once it reaches a maximum size.
+#[derive(Debug)]
+struct SizeRotatingFile {
+    path: PathBuf,
+    file: File,
+    /// Current size of the file in bytes.
+    size: u64,
+    max_bytes: u64,
+    max_backups: usize,
+}
+
+impl SizeRotatingFile {
+    /// Opens `path` for appending.
+    fn open(path: &Path, max_bytes: u64, max_backups: usize) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            size,
+            max_bytes,
+            max_backups,
+        })
+    }
+
+    /// Returns the path of the `index`th backup, e.g. `app.log.1`.
+    fn backup_path(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        path.into()
+    }
+
+    /// Shifts the backups up by one and starts a new, empty file.
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_backups == 0 {
+            std::fs::remove_file(&self.path)?;
+        } else {
+            for index in (1..self.max_backups).rev() {
+                let from = self.backup_path(index);
+                if from.exists() {
+                    std::fs::rename(&from, self.backup_path(index + 1))?;
+                }
+            }
+            std::fs::rename(&self.path, self.backup_path(1))?;
         }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.size = 0;
+        Ok(())
     }
+}
 
-    // Create non-blocking file appender
-    let file = std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_file)?;
+impl Write for SizeRotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Each write is one formatted event, so rotating between writes
+        // never splits a line across files
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
 
-    let (non_blocking, guard) = tracing_appender::non_blocking(file);
+/// A formatting layer with its concrete type erased.
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+/// Builds the subscriber described by `config`, writing to `writer`.
+fn build_subscriber<W>(config: &TracingConfig, writer: W) -> impl tracing::Subscriber + Send + Sync
+where
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    use tracing_subscriber::fmt::format::FmtSpan;
+    use tracing_subscriber::prelude::*;
 
     // Build the filter
     let filter = tracing_subscriber::EnvFilter::try_new(config.build_filter_directive())
@@ -371,41 +683,210 @@ pub fn init_tracing(config: TracingConfig) -> Result<TracingGuard, TracingError>
         FmtSpan::NONE
     };
 
-    // Build the formatting layer with all options applied at once
-    // We need to handle the different type configurations separately to avoid
-    // incompatible types in if/else branches
-    let subscriber = tracing_subscriber::registry().with(filter);
-
-    if config.include_timestamps {
-        let fmt_layer = tracing_subscriber::fmt::layer()
-            .with_writer(non_blocking)
-            .with_ansi(false)
-            .with_target(config.include_target)
-            .with_file(config.include_file_line)
-            .with_line_number(config.include_file_line)
-            .with_span_events(span_events);
-
-        let subscriber = subscriber.with(fmt_layer);
-        tracing::subscriber::set_global_default(subscriber)
-            .map_err(|e| TracingError::SetGlobalError(e.to_string()))?;
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_ansi(false)
+        .with_target(config.include_target)
+        .with_file(config.include_file_line)
+        .with_line_number(config.include_file_line)
+        .with_span_events(span_events);
+
+    let timestamps = config.include_timestamps;
+    let fmt_layer = match config.format {
+        TracingFormat::Full => boxed(layer, timestamps),
+        TracingFormat::Pretty => boxed(layer.pretty(), timestamps),
+        TracingFormat::Compact => boxed(layer.compact(), timestamps),
+        TracingFormat::Json => boxed(
+            layer.json().with_current_span(true).with_span_list(true),
+            timestamps,
+        ),
+    };
+
+    tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(config.log_buffer.as_ref().map(LogBuffer::layer))
+        .with(filter)
+}
+
+/// Boxes a formatting layer, dropping timestamps if they are disabled.
+///
+/// Removing the timer changes the layer's type, so each format is boxed
+/// to give all of them the same type.
+fn boxed<N, L, T, W>(
+    layer: tracing_subscriber::fmt::Layer<Registry, N, Format<L, T>, W>,
+    timestamps: bool,
+) -> BoxedLayer
+where
+    N: for<'w> tracing_subscriber::fmt::FormatFields<'w> + 'static,
+    tracing_subscriber::fmt::Layer<Registry, N, Format<L, T>, W>: Layer<Registry> + Send + Sync,
+    tracing_subscriber::fmt::Layer<Registry, N, Format<L, ()>, W>: Layer<Registry> + Send + Sync,
+{
+    if timestamps {
+        Box::new(layer)
     } else {
-        let fmt_layer = tracing_subscriber::fmt::layer()
-            .with_writer(non_blocking)
-            .with_ansi(false)
-            .without_time()
-            .with_target(config.include_target)
-            .with_file(config.include_file_line)
-            .with_line_number(config.include_file_line)
-            .with_span_events(span_events);
+        Box::new(layer.without_time())
+    }
+}
 
-        let subscriber = subscriber.with(fmt_layer);
-        tracing::subscriber::set_global_default(subscriber)
-            .map_err(|e| TracingError::SetGlobalError(e.to_string()))?;
+/// A single event captured by a [`LogBuffer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    /// The event's level.
+    pub level: tracing::Level,
+    /// The module path or target the event came from.
+    pub target: String,
+    /// The event's message followed by its other fields as `key=value`.
+    pub message: String,
+}
+
+/// A bounded, shared buffer of recent log records.
+///
+/// Clones share the same records. Once the buffer is full, the oldest
+/// record is dropped for each new one.
+///
+/// # Examples
+///
+/// ```rust
+/// use tracing_subscriber::layer::SubscriberExt;
+/// use tuilib::tracing::LogBuffer;
+///
+/// let logs = LogBuffer::new(100);
+/// let subscriber = tracing_subscriber::registry().with(logs.layer());
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::warn!(path = "a.txt", "file missing");
+/// });
+///
+/// let records = logs.records();
+/// assert_eq!(records[0].level, tracing::Level::WARN);
+/// assert_eq!(records[0].message, "file missing path=a.txt");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LogBuffer {
+    records: Arc<Mutex<VecDeque<LogRecord>>>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    /// Creates an empty buffer holding at most `capacity` records.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
     }
 
-    Ok(TracingGuard {
-        _worker_guard: guard,
-    })
+    /// Returns the maximum number of records kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns a tracing layer that records events into this buffer.
+    pub fn layer(&self) -> LogLayer {
+        LogLayer {
+            buffer: self.clone(),
+        }
+    }
+
+    /// Adds a record, dropping the oldest if the buffer is full.
+    pub fn push(&self, record: LogRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut records = self.lock();
+        while records.len() >= self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Returns a copy of the records, oldest first.
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.lock().iter().cloned().collect()
+    }
+
+    /// Returns the number of records held.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if the buffer holds no records.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes all records.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<LogRecord>> {
+        // A panic while holding the lock cannot leave the queue inconsistent
+        self.records.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A tracing layer that records events into a [`LogBuffer`].
+///
+/// Created with [`LogBuffer::layer`].
+#[derive(Debug, Clone)]
+pub struct LogLayer {
+    buffer: LogBuffer,
+}
+
+impl<S: tracing::Subscriber> Layer<S> for LogLayer {
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+        self.buffer.push(LogRecord {
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: visitor.finish(),
+        });
+    }
+}
+
+/// Formats an event's fields as its message followed by `key=value` pairs.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+}
+
+impl tracing::field::Visit for MessageVisitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.record_debug(field, &format_args!("{value}"));
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+            return;
+        }
+        if !self.fields.is_empty() {
+            self.fields.push(' ');
+        }
+        let _ = write!(self.fields, "{}={:?}", field.name(), value);
+    }
 }
 
 /// Creates a span for component update operations.
@@ -497,10 +978,13 @@ mod tests {
         assert_eq!(config.level, tracing::Level::INFO);
         assert!(config.log_file.is_none());
         assert!(config.target_levels.is_empty());
+        assert!(config.filter_directives.is_empty());
         assert!(config.include_timestamps);
         assert!(config.include_target);
         assert!(!config.include_file_line);
         assert!(config.include_spans);
+        assert_eq!(config.format, TracingFormat::Full);
+        assert_eq!(config.rotation, Rotation::Never);
     }
 
     #[test]
@@ -512,7 +996,9 @@ mod tests {
             .with_timestamps(false)
             .with_target(false)
             .with_file_line(true)
-            .with_spans(false);
+            .with_spans(false)
+            .with_format(TracingFormat::Compact)
+            .with_rotation(Rotation::size(1024, 2));
 
         assert_eq!(config.level, tracing::Level::DEBUG);
         assert_eq!(config.log_file, Some(PathBuf::from("test.log")));
@@ -523,6 +1009,14 @@ mod tests {
         assert!(!config.include_target);
         assert!(config.include_file_line);
         assert!(!config.include_spans);
+        assert_eq!(config.format, TracingFormat::Compact);
+        assert_eq!(
+            config.rotation,
+            Rotation::Size {
+                max_bytes: 1024,
+                max_backups: 2
+            }
+        );
     }
 
     #[test]
@@ -562,4 +1056,155 @@ mod tests {
         let err: TracingError = io_err.into();
         assert!(matches!(err, TracingError::IoError(_)));
     }
+
+    #[test]
+    fn test_json_format_writes_json_lines() {
+        let path = std::env::temp_dir().join(format!("tuilib-json-{}.log", std::process::id()));
+        let file = std::fs::File::create(&path).unwrap();
+        let (writer, guard) = tracing_appender::non_blocking(file);
+
+        let config = TracingConfig::new()
+            .with_level(tracing::Level::DEBUG)
+            .with_spans(false)
+            .with_format(TracingFormat::Json);
+        tracing::subscriber::with_default(build_subscriber(&config, writer), || {
+            let _span = tracing::info_span!("load", file = "notes.txt").entered();
+            tracing::info!(bytes = 42, "loaded file");
+        });
+        // Dropping the guard flushes the writer
+        drop(guard);
+
+        let output = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 1, "unexpected output: {output}");
+
+        let event: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(event["level"], "INFO");
+        assert_eq!(event["target"], module_path!());
+        assert_eq!(event["fields"]["message"], "loaded file");
+        assert_eq!(event["fields"]["bytes"], 42);
+        assert_eq!(event["span"]["name"], "load");
+        assert_eq!(event["span"]["file"], "notes.txt");
+        assert_eq!(event["spans"][0]["name"], "load");
+        assert!(event["timestamp"].is_string());
+    }
+
+    #[test]
+    fn test_size_rotation_keeps_backups() {
+        let dir = std::env::temp_dir().join(format!("tuilib-rotation-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let log_file = dir.join("debug.log");
+
+        let config = TracingConfig::new()
+            .with_log_file(&log_file)
+            .with_timestamps(false)
+            .with_spans(false)
+            .with_rotation(Rotation::size(200, 2));
+        let writer = open_log_writer(&log_file, config.rotation).unwrap();
+        let (writer, guard) = tracing_appender::non_blocking(writer);
+        tracing::subscriber::with_default(build_subscriber(&config, writer), || {
+            for line in 0..40 {
+                tracing::info!(line, "filling the log file");
+            }
+        });
+        drop(guard);
+
+        let mut files: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+        assert_eq!(files, vec!["debug.log", "debug.log.1", "debug.log.2"]);
+
+        for file in &files {
+            let contents = std::fs::read_to_string(dir.join(file)).unwrap();
+            assert!(contents.len() <= 200, "{file} is {} bytes", contents.len());
+            assert!(contents.ends_with('\n'));
+        }
+        // The newest events are in the current file
+        let current = std::fs::read_to_string(&log_file).unwrap();
+        assert!(current.contains("line=39"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_log_buffer_records_events() {
+        use tracing_subscriber::prelude::*;
+
+        let logs = LogBuffer::new(3);
+        let subscriber = tracing_subscriber::registry().with(logs.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::trace!("starting");
+            tracing::debug!(id = 7, "loading");
+            tracing::info!("ready");
+            tracing::warn!(retries = 2, "slow");
+            tracing::error!(path = "a.txt");
+        });
+
+        // The oldest records were dropped
+        assert_eq!(logs.len(), 3);
+        let records = logs.records();
+        let levels: Vec<tracing::Level> = records.iter().map(|r| r.level).collect();
+        assert_eq!(
+            levels,
+            vec![
+                tracing::Level::INFO,
+                tracing::Level::WARN,
+                tracing::Level::ERROR
+            ]
+        );
+        assert_eq!(records[0].message, "ready");
+        assert_eq!(records[1].message, "slow retries=2");
+        assert_eq!(records[2].message, "path=a.txt");
+        assert_eq!(records[2].target, module_path!());
+
+        logs.clear();
+        assert!(logs.is_empty());
+    }
+
+    #[test]
+    fn test_log_buffer_follows_config_filter() {
+        let logs = LogBuffer::new(10);
+        let config = TracingConfig::new()
+            .with_level(tracing::Level::WARN)
+            .with_log_buffer(logs.clone());
+        tracing::subscriber::with_default(build_subscriber(&config, io::sink), || {
+            tracing::info!("hidden");
+            tracing::warn!("shown");
+        });
+
+        let messages: Vec<String> = logs.records().into_iter().map(|r| r.message).collect();
+        assert_eq!(messages, vec!["shown"]);
+    }
+
+    #[test]
+    fn test_filter_directives() {
+        let logs = LogBuffer::new(20);
+        let config = TracingConfig::new()
+            .with_filter_directives("tuilib=trace, tuilib::input=debug,warn")
+            .with_target_level("tuilib::input::matcher", tracing::Level::ERROR)
+            .with_log_buffer(logs.clone());
+        assert_eq!(
+            config.build_filter_directive(),
+            "info,tuilib=trace,tuilib::input=debug,warn,tuilib::input::matcher=error"
+        );
+
+        tracing::subscriber::with_default(build_subscriber(&config, io::sink), || {
+            tracing::trace!(target: "tuilib::focus", "focus trace");
+            tracing::trace!(target: "tuilib::input", "input trace");
+            tracing::debug!(target: "tuilib::input", "input debug");
+            tracing::warn!(target: "tuilib::input::matcher", "matcher warn");
+            tracing::error!(target: "tuilib::input::matcher", "matcher error");
+            tracing::info!(target: "other", "other info");
+            tracing::warn!(target: "other", "other warn");
+        });
+
+        let messages: Vec<String> = logs.records().into_iter().map(|r| r.message).collect();
+        assert_eq!(
+            messages,
+            vec!["focus trace", "input debug", "matcher error", "other warn"]
+        );
+    }
 }